@@ -1,5 +1,9 @@
 use {
-    crate::{idl::Idl, legacy_idl::LegacyIdl, util::idl_type_to_rust_type},
+    crate::{
+        idl::Idl,
+        legacy_idl::LegacyIdl,
+        util::{big_array_attribute, discriminator_bytes, idl_type_to_rust_type},
+    },
     askama::Template,
     heck::{ToSnakeCase, ToUpperCamelCase},
     sha2::{Digest, Sha256},
@@ -15,11 +19,18 @@ pub struct AccountData {
     pub requires_imports: bool,
 }
 
+impl AccountData {
+    pub fn discriminator_bytes(&self) -> String {
+        discriminator_bytes(&self.discriminator)
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub struct FieldData {
     pub name: String,
     pub rust_type: String,
+    pub attributes: Option<String>,
 }
 
 #[derive(Template)]
@@ -34,6 +45,10 @@ pub struct AccountsModTemplate<'a> {
     pub accounts: &'a Vec<AccountData>,
     pub decoder_name: String,
     pub program_struct_name: String,
+    pub legacy_accounts: &'a Vec<AccountData>,
+    pub upgrade_slot: Option<u64>,
+    pub allow_trailing_bytes: bool,
+    pub has_program_id: bool,
 }
 
 pub fn legacy_process_accounts(idl: &LegacyIdl) -> Vec<AccountData> {
@@ -57,6 +72,7 @@ pub fn legacy_process_accounts(idl: &LegacyIdl) -> Vec<AccountData> {
                 }
                 fields.push(FieldData {
                     name: field.name.to_snake_case(),
+                    attributes: big_array_attribute(&rust_type.0),
                     rust_type: rust_type.0,
                 });
             }
@@ -95,6 +111,7 @@ pub fn process_accounts(idl: &Idl) -> Vec<AccountData> {
                         }
                         account_fields.push(FieldData {
                             name: field.name.to_snake_case(),
+                            attributes: big_array_attribute(&rust_type.0),
                             rust_type: rust_type.0,
                         });
                     }
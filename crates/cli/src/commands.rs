@@ -20,6 +20,9 @@ pub enum Commands {
     #[command(name = "scaffold")]
     #[command(about = "Generate skeleton of the project.")]
     Scaffold(ScaffoldOptions),
+    #[command(name = "diff")]
+    #[command(about = "Report layout changes between two versions of an IDL.")]
+    Diff(DiffOptions),
 }
 
 #[derive(Parser)]
@@ -47,6 +50,20 @@ pub struct ParseOptions {
     #[arg(short, long, required_if_eq("idl", "ProgramAddress"))]
     #[arg(help = "Network URL to fetch the IDL from. Required if input is a program address.")]
     pub url: Option<Url>,
+
+    #[arg(long)]
+    #[arg(
+        help = "Path to a previous version of the IDL. Layouts that changed since are kept as versioned variants."
+    )]
+    pub legacy_idl: Option<String>,
+
+    #[arg(long, requires = "legacy_idl")]
+    #[arg(help = "Slot of the program upgrade. Older data tries the legacy layouts first.")]
+    pub upgrade_slot: Option<u64>,
+
+    #[arg(long, default_value_t = false)]
+    #[arg(help = "Fall back to decoding data that has bytes past the last field.")]
+    pub allow_trailing_bytes: bool,
 }
 
 #[derive(Parser)]
@@ -70,6 +87,21 @@ pub struct ScaffoldOptions {
     #[arg(short = 'm', long, default_value = "log")]
     #[arg(help = "Metrics to use.")]
     pub metrics: String,
+
+    #[arg(long)]
+    #[arg(help = "Storage to persist decoded data to: postgres, sqlite or clickhouse.")]
+    pub storage: Option<String>,
+}
+
+#[derive(Parser)]
+pub struct DiffOptions {
+    #[arg(long, required = true)]
+    #[arg(help = "Path to the previous IDL json file.")]
+    pub old: String,
+
+    #[arg(long, required = true)]
+    #[arg(help = "Path to the new IDL json file.")]
+    pub new: String,
 }
 
 #[derive(Clone, Debug)]
@@ -214,6 +246,39 @@ impl std::str::FromStr for Metrics {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Storage {
+    None,
+    Postgres,
+    Sqlite,
+    Clickhouse,
+}
+
+impl fmt::Display for Storage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Storage::None => write!(f, "none"),
+            Storage::Postgres => write!(f, "postgres"),
+            Storage::Sqlite => write!(f, "sqlite"),
+            Storage::Clickhouse => write!(f, "clickhouse"),
+        }
+    }
+}
+
+impl std::str::FromStr for Storage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(Storage::None),
+            "postgres" => Ok(Storage::Postgres),
+            "sqlite" => Ok(Storage::Sqlite),
+            "clickhouse" => Ok(Storage::Clickhouse),
+            _ => Err("Invalid Storage".to_string()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum Decoder {
     Drift,
@@ -0,0 +1,98 @@
+use {
+    crate::{idl::Idl, legacy_idl::LegacyIdl, util::idl_type_to_rust_type},
+    askama::Template,
+    heck::ToShoutySnakeCase,
+};
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct ConstantData {
+    pub name: String,
+    pub rust_type: String,
+    pub value: String,
+}
+
+#[derive(Template)]
+#[template(path = "constants.askama", escape = "none", ext = ".askama")]
+pub struct ConstantsTemplate<'a> {
+    pub constants: &'a Vec<ConstantData>,
+}
+
+pub fn legacy_process_constants(idl: &LegacyIdl) -> Vec<ConstantData> {
+    idl.constants
+        .iter()
+        .filter_map(|constant| {
+            constant_data(
+                &constant.name,
+                idl_type_to_rust_type(&constant.type_),
+                &constant.value,
+            )
+        })
+        .collect()
+}
+
+pub fn process_constants(idl: &Idl) -> Vec<ConstantData> {
+    idl.constants
+        .iter()
+        .filter_map(|constant| {
+            constant_data(
+                &constant.name,
+                idl_type_to_rust_type(&constant.type_),
+                &constant.value,
+            )
+        })
+        .collect()
+}
+
+fn constant_data(
+    name: &str,
+    (rust_type, is_defined): (String, bool),
+    value: &str,
+) -> Option<ConstantData> {
+    let name = name.to_shouty_snake_case();
+    let value = value.trim();
+
+    if is_defined {
+        println!(
+            "Skipping constant `{}` of unsupported type {}",
+            name, rust_type
+        );
+        return None;
+    }
+
+    let (rust_type, value) = match rust_type.as_str() {
+        "bool" | "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "u128" | "i128"
+        | "f32" | "f64" => (rust_type, value.to_string()),
+        "String" => ("&str".to_string(), quoted(value)),
+        "Vec<u8>" => ("&[u8]".to_string(), format!("&{}", value)),
+        "solana_sdk::pubkey::Pubkey" => (
+            rust_type,
+            format!(
+                "solana_sdk::pubkey::Pubkey::from_str_const({})",
+                quoted(value)
+            ),
+        ),
+        _ if rust_type.starts_with('[') => (rust_type, value.to_string()),
+        _ => {
+            println!(
+                "Skipping constant `{}` of unsupported type {}",
+                name, rust_type
+            );
+            return None;
+        }
+    };
+
+    Some(ConstantData {
+        name,
+        rust_type,
+        value,
+    })
+}
+
+fn quoted(value: &str) -> String {
+    if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        value.to_string()
+    } else {
+        format!("{:?}", value)
+    }
+}
@@ -0,0 +1,1204 @@
+use {
+    crate::{
+        accounts::{legacy_process_accounts, process_accounts, AccountData},
+        events::{legacy_process_events, process_events, EventData},
+        instructions::{legacy_process_instructions, process_instructions, InstructionData},
+        types::{legacy_process_types, process_types, EnumVariantFields, TypeData, TypeKind},
+        util::{legacy_read_idl, read_idl},
+    },
+    anyhow::{bail, Result},
+    std::{
+        collections::{HashMap, HashSet},
+        fmt,
+    },
+};
+
+/// The decodable layouts of an IDL, as produced by the code generators.
+pub struct IdlLayouts {
+    pub version: String,
+    pub accounts: Vec<AccountData>,
+    pub instructions: Vec<InstructionData>,
+    pub events: Vec<EventData>,
+    pub types: Vec<TypeData>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutKind {
+    Account,
+    Instruction,
+    Event,
+    Type,
+}
+
+impl fmt::Display for LayoutKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LayoutKind::Account => write!(f, "account"),
+            LayoutKind::Instruction => write!(f, "instruction"),
+            LayoutKind::Event => write!(f, "event"),
+            LayoutKind::Type => write!(f, "type"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum LayoutChange {
+    Added,
+    Removed,
+    DiscriminatorChanged {
+        old: String,
+        new: String,
+    },
+    /// New fields at the end of the layout. Data written before the upgrade
+    /// is too short for the new layout.
+    FieldsAppended(Vec<String>),
+    /// New variants at the end of an enum. Old data still decodes.
+    VariantsAppended(Vec<String>),
+    FieldsChanged(Vec<String>),
+    UsesChangedTypes(Vec<String>),
+}
+
+impl LayoutChange {
+    pub fn is_breaking(&self) -> bool {
+        !matches!(
+            self,
+            LayoutChange::Added | LayoutChange::VariantsAppended(_)
+        )
+    }
+
+    /// Whether data written with the old layout needs the old layout to be
+    /// decoded.
+    pub fn needs_legacy_layout(&self) -> bool {
+        matches!(
+            self,
+            LayoutChange::FieldsAppended(_)
+                | LayoutChange::FieldsChanged(_)
+                | LayoutChange::UsesChangedTypes(_)
+        )
+    }
+}
+
+impl fmt::Display for LayoutChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LayoutChange::Added => write!(f, "added"),
+            LayoutChange::Removed => write!(f, "removed"),
+            LayoutChange::DiscriminatorChanged { old, new } => {
+                write!(f, "discriminator changed from {old} to {new}")
+            }
+            LayoutChange::FieldsAppended(fields) => {
+                write!(f, "fields appended: {}", fields.join(", "))
+            }
+            LayoutChange::VariantsAppended(variants) => {
+                write!(f, "variants appended: {}", variants.join(", "))
+            }
+            LayoutChange::FieldsChanged(changes) => {
+                write!(f, "fields changed: {}", changes.join(", "))
+            }
+            LayoutChange::UsesChangedTypes(types) => {
+                write!(f, "uses changed types: {}", types.join(", "))
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LayoutDiff {
+    pub kind: LayoutKind,
+    pub name: String,
+    pub change: LayoutChange,
+}
+
+impl fmt::Display for LayoutDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{}] {} {}: {}",
+            if self.change.is_breaking() {
+                "BREAKING"
+            } else {
+                "compatible"
+            },
+            self.kind,
+            self.name,
+            self.change
+        )
+    }
+}
+
+pub fn load_idl_layouts(path: &str) -> Result<IdlLayouts> {
+    match read_idl(path) {
+        Ok(idl) => Ok(IdlLayouts {
+            version: idl.metadata.version.clone(),
+            accounts: process_accounts(&idl),
+            instructions: process_instructions(&idl),
+            events: process_events(&idl),
+            types: process_types(&idl),
+        }),
+        Err(_legacy_idl_err) => match legacy_read_idl(path) {
+            Ok(idl) => Ok(IdlLayouts {
+                version: idl.version.clone(),
+                accounts: legacy_process_accounts(&idl),
+                instructions: legacy_process_instructions(&idl),
+                events: legacy_process_events(&idl),
+                types: legacy_process_types(&idl),
+            }),
+            Err(idl_err) => {
+                bail!("{idl_err}");
+            }
+        },
+    }
+}
+
+/// Compares two IDLs and reports every layout that differs between them.
+pub fn diff_layouts(old: &IdlLayouts, new: &IdlLayouts) -> Vec<LayoutDiff> {
+    let mut diffs = Vec::new();
+
+    let old_types = old
+        .types
+        .iter()
+        .map(|ty| (ty.name.clone(), type_layout(ty)))
+        .collect::<HashMap<_, _>>();
+    let new_types = new
+        .types
+        .iter()
+        .map(|ty| (ty.name.clone(), type_layout(ty)))
+        .collect::<HashMap<_, _>>();
+
+    let mut changed_types = HashSet::new();
+    for ty in &new.types {
+        let Some(old_layout) = old_types.get(&ty.name) else {
+            diffs.push(LayoutDiff {
+                kind: LayoutKind::Type,
+                name: ty.name.clone(),
+                change: LayoutChange::Added,
+            });
+            continue;
+        };
+        let new_layout = &new_types[&ty.name];
+        if let Some(change) = compare_fields(old_layout, new_layout, ty.kind != TypeKind::Struct) {
+            if change.needs_legacy_layout() {
+                changed_types.insert(ty.name.clone());
+            }
+            diffs.push(LayoutDiff {
+                kind: LayoutKind::Type,
+                name: ty.name.clone(),
+                change,
+            });
+        }
+    }
+    for ty in &old.types {
+        if !new_types.contains_key(&ty.name) {
+            diffs.push(LayoutDiff {
+                kind: LayoutKind::Type,
+                name: ty.name.clone(),
+                change: LayoutChange::Removed,
+            });
+        }
+    }
+
+    // A type whose own fields are unchanged still changes layout when one of
+    // the types it embeds does.
+    loop {
+        let mut propagated = Vec::new();
+        for ty in &new.types {
+            if changed_types.contains(&ty.name) || !old_types.contains_key(&ty.name) {
+                continue;
+            }
+            let used = used_changed_types(&new_types[&ty.name], &changed_types);
+            if !used.is_empty() {
+                propagated.push((ty.name.clone(), used));
+            }
+        }
+        if propagated.is_empty() {
+            break;
+        }
+        for (name, used) in propagated {
+            changed_types.insert(name.clone());
+            diffs.push(LayoutDiff {
+                kind: LayoutKind::Type,
+                name,
+                change: LayoutChange::UsesChangedTypes(used),
+            });
+        }
+    }
+
+    diff_items(
+        &mut diffs,
+        LayoutKind::Account,
+        &changed_types,
+        old.accounts.iter().map(account_item),
+        new.accounts.iter().map(account_item),
+    );
+    diff_items(
+        &mut diffs,
+        LayoutKind::Instruction,
+        &changed_types,
+        old.instructions.iter().map(instruction_item),
+        new.instructions.iter().map(instruction_item),
+    );
+    diff_items(
+        &mut diffs,
+        LayoutKind::Event,
+        &changed_types,
+        old.events.iter().map(event_item),
+        new.events.iter().map(event_item),
+    );
+
+    diffs
+}
+
+/// Names of the types that data written with the old IDL needs but the new
+/// IDL no longer provides: types whose layout changed, including the types
+/// that embed them, and removed types.
+pub fn changed_type_names(diffs: &[LayoutDiff]) -> HashSet<String> {
+    diffs
+        .iter()
+        .filter(|diff| {
+            diff.kind == LayoutKind::Type
+                && (diff.change.needs_legacy_layout() || diff.change == LayoutChange::Removed)
+        })
+        .map(|diff| diff.name.clone())
+        .collect()
+}
+
+/// Breaking changes whose old data may still decode as the new layout: the
+/// discriminator is unchanged and the encoded length of both layouts is the
+/// same or unknown. Decoders try the current layout first, so without an
+/// upgrade slot such data is silently decoded with the wrong layout.
+pub fn ambiguous_layouts<'a>(
+    old: &IdlLayouts,
+    new: &IdlLayouts,
+    diffs: &'a [LayoutDiff],
+) -> Vec<&'a LayoutDiff> {
+    let old_sizes = TypeSizes::new(&old.types);
+    let new_sizes = TypeSizes::new(&new.types);
+
+    diffs
+        .iter()
+        .filter(|diff| diff.kind != LayoutKind::Type && diff.change.needs_legacy_layout())
+        .filter(|diff| {
+            // Old data is shorter than every value of the new layout.
+            if matches!(diff.change, LayoutChange::FieldsAppended(_)) {
+                return false;
+            }
+            let old_size = layout_items(old, diff.kind)
+                .find(|item| item.name == diff.name)
+                .and_then(|item| old_sizes.fields_size(&item.fields));
+            let new_size = layout_items(new, diff.kind)
+                .find(|item| item.name == diff.name)
+                .and_then(|item| new_sizes.fields_size(&item.fields));
+
+            match (old_size, new_size) {
+                (Some(old_size), Some(new_size)) => old_size == new_size,
+                _ => true,
+            }
+        })
+        .collect()
+}
+
+fn layout_items(layouts: &IdlLayouts, kind: LayoutKind) -> Box<dyn Iterator<Item = Item> + '_> {
+    match kind {
+        LayoutKind::Account => Box::new(layouts.accounts.iter().map(account_item)),
+        LayoutKind::Instruction => Box::new(layouts.instructions.iter().map(instruction_item)),
+        LayoutKind::Event => Box::new(layouts.events.iter().map(event_item)),
+        LayoutKind::Type => Box::new(std::iter::empty()),
+    }
+}
+
+/// Borsh encoded sizes of the fixed-size types of an IDL.
+struct TypeSizes<'a> {
+    types: HashMap<&'a str, &'a TypeData>,
+}
+
+impl<'a> TypeSizes<'a> {
+    const MAX_DEPTH: usize = 32;
+
+    fn new(types: &'a [TypeData]) -> Self {
+        Self {
+            types: types.iter().map(|ty| (ty.name.as_str(), ty)).collect(),
+        }
+    }
+
+    fn fields_size(&self, fields: &[Field]) -> Option<usize> {
+        fields
+            .iter()
+            .map(|(_, rust_type)| self.size(rust_type, 0))
+            .sum()
+    }
+
+    /// `None` when values of `rust_type` have no fixed size.
+    fn size(&self, rust_type: &str, depth: usize) -> Option<usize> {
+        if depth > Self::MAX_DEPTH {
+            return None;
+        }
+        let rust_type = rust_type.trim();
+
+        if let Some(array) = rust_type
+            .strip_prefix('[')
+            .and_then(|array| array.strip_suffix(']'))
+        {
+            let (element, len) = array.rsplit_once(';')?;
+            let len = len.trim().parse::<usize>().ok()?;
+            return Some(self.size(element, depth + 1)? * len);
+        }
+        if let Some(tuple) = rust_type
+            .strip_prefix('(')
+            .and_then(|tuple| tuple.strip_suffix(')'))
+        {
+            return split_top_level(tuple)
+                .into_iter()
+                .map(|element| self.size(element, depth + 1))
+                .sum();
+        }
+
+        match rust_type {
+            "bool" | "u8" | "i8" => Some(1),
+            "u16" | "i16" => Some(2),
+            "u32" | "i32" | "f32" => Some(4),
+            "u64" | "i64" | "f64" => Some(8),
+            "u128" | "i128" => Some(16),
+            _ if rust_type.ends_with("Pubkey") => Some(32),
+            _ => self.defined_size(rust_type, depth),
+        }
+    }
+
+    fn defined_size(&self, name: &str, depth: usize) -> Option<usize> {
+        let type_data = self.types.get(name)?;
+        match &type_data.kind {
+            TypeKind::Struct => type_data
+                .fields
+                .iter()
+                .map(|field| self.size(&field.rust_type, depth + 1))
+                .sum(),
+            TypeKind::Enum(variants) => {
+                let mut sizes = variants.iter().map(|variant| match &variant.fields {
+                    None => Some(0),
+                    Some(EnumVariantFields::Named(fields)) => fields
+                        .iter()
+                        .map(|field| self.size(&field.rust_type, depth + 1))
+                        .sum(),
+                    Some(EnumVariantFields::Unnamed(types)) => types
+                        .iter()
+                        .map(|rust_type| self.size(rust_type, depth + 1))
+                        .sum(),
+                });
+                let first = sizes.next().unwrap_or(Some(0))?;
+                sizes.all(|size| size == Some(first)).then_some(1 + first)
+            }
+        }
+    }
+}
+
+fn split_top_level(list: &str) -> Vec<&str> {
+    let mut elements = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (index, c) in list.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                elements.push(&list[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    if !list[start..].trim().is_empty() {
+        elements.push(&list[start..]);
+    }
+
+    elements
+}
+
+/// Renames every identifier of `rust_type` found in `renames`, leaving paths
+/// and generic arguments intact.
+pub fn rename_identifiers(rust_type: &str, renames: &HashMap<String, String>) -> String {
+    let mut result = String::with_capacity(rust_type.len());
+    let mut identifier = String::new();
+
+    for c in rust_type.chars().chain(std::iter::once(' ')) {
+        if c.is_alphanumeric() || c == '_' {
+            identifier.push(c);
+            continue;
+        }
+        if !identifier.is_empty() {
+            result.push_str(renames.get(&identifier).unwrap_or(&identifier));
+            identifier.clear();
+        }
+        result.push(c);
+    }
+    result.pop();
+
+    result
+}
+
+type Field = (String, String);
+
+struct Item {
+    name: String,
+    discriminator: String,
+    fields: Vec<Field>,
+}
+
+fn account_item(account: &AccountData) -> Item {
+    Item {
+        name: account.struct_name.clone(),
+        discriminator: account.discriminator.clone(),
+        fields: account
+            .fields
+            .iter()
+            .map(|field| (field.name.clone(), field.rust_type.clone()))
+            .collect(),
+    }
+}
+
+fn instruction_item(instruction: &InstructionData) -> Item {
+    Item {
+        name: instruction.struct_name.clone(),
+        discriminator: instruction.discriminator.clone(),
+        fields: instruction
+            .args
+            .iter()
+            .map(|arg| (arg.name.clone(), arg.rust_type.clone()))
+            .collect(),
+    }
+}
+
+fn event_item(event: &EventData) -> Item {
+    Item {
+        name: event.struct_name.clone(),
+        discriminator: event.discriminator.clone(),
+        fields: event
+            .args
+            .iter()
+            .map(|arg| (arg.name.clone(), arg.rust_type.clone()))
+            .collect(),
+    }
+}
+
+fn type_layout(type_data: &TypeData) -> Vec<Field> {
+    match &type_data.kind {
+        TypeKind::Struct => type_data
+            .fields
+            .iter()
+            .map(|field| (field.name.clone(), field.rust_type.clone()))
+            .collect(),
+        TypeKind::Enum(variants) => variants
+            .iter()
+            .map(|variant| {
+                let fields = match &variant.fields {
+                    None => String::new(),
+                    Some(EnumVariantFields::Named(fields)) => format!(
+                        "{{ {} }}",
+                        fields
+                            .iter()
+                            .map(|field| format!("{}: {}", field.name, field.rust_type))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    Some(EnumVariantFields::Unnamed(types)) => format!("({})", types.join(", ")),
+                };
+                (variant.name.clone(), fields)
+            })
+            .collect(),
+    }
+}
+
+fn diff_items(
+    diffs: &mut Vec<LayoutDiff>,
+    kind: LayoutKind,
+    changed_types: &HashSet<String>,
+    old_items: impl Iterator<Item = Item>,
+    new_items: impl Iterator<Item = Item>,
+) {
+    let old_items = old_items
+        .map(|item| (item.name.clone(), item))
+        .collect::<HashMap<_, _>>();
+    let mut seen = HashSet::new();
+
+    for item in new_items {
+        seen.insert(item.name.clone());
+        let Some(old_item) = old_items.get(&item.name) else {
+            diffs.push(LayoutDiff {
+                kind,
+                name: item.name,
+                change: LayoutChange::Added,
+            });
+            continue;
+        };
+
+        let change = if old_item.discriminator != item.discriminator {
+            Some(LayoutChange::DiscriminatorChanged {
+                old: old_item.discriminator.clone(),
+                new: item.discriminator.clone(),
+            })
+        } else {
+            compare_fields(&old_item.fields, &item.fields, false).or_else(|| {
+                let used = used_changed_types(&item.fields, changed_types);
+                (!used.is_empty()).then_some(LayoutChange::UsesChangedTypes(used))
+            })
+        };
+
+        if let Some(change) = change {
+            diffs.push(LayoutDiff {
+                kind,
+                name: item.name,
+                change,
+            });
+        }
+    }
+
+    let mut removed = old_items
+        .keys()
+        .filter(|name| !seen.contains(*name))
+        .collect::<Vec<_>>();
+    removed.sort();
+    for name in removed {
+        diffs.push(LayoutDiff {
+            kind,
+            name: name.clone(),
+            change: LayoutChange::Removed,
+        });
+    }
+}
+
+fn compare_fields(old: &[Field], new: &[Field], is_enum: bool) -> Option<LayoutChange> {
+    if old == new {
+        return None;
+    }
+
+    if new.len() > old.len() && new[..old.len()] == *old {
+        let appended = new[old.len()..]
+            .iter()
+            .map(|(name, rust_type)| {
+                if is_enum {
+                    name.clone()
+                } else {
+                    format!("{name}: {rust_type}")
+                }
+            })
+            .collect();
+        return Some(if is_enum {
+            LayoutChange::VariantsAppended(appended)
+        } else {
+            LayoutChange::FieldsAppended(appended)
+        });
+    }
+
+    let mut changes = Vec::new();
+    for (index, (name, rust_type)) in new.iter().enumerate() {
+        match old.get(index) {
+            Some((old_name, old_type)) if old_name == name && old_type == rust_type => {}
+            Some((old_name, old_type)) if old_name == name => {
+                changes.push(format!("{name} {old_type} -> {rust_type}"))
+            }
+            Some((old_name, old_type)) => {
+                changes.push(format!("{old_name}: {old_type} -> {name}: {rust_type}"))
+            }
+            None => changes.push(format!("+{name}: {rust_type}")),
+        }
+    }
+    for (name, rust_type) in old.iter().skip(new.len()) {
+        changes.push(format!("-{name}: {rust_type}"));
+    }
+
+    Some(LayoutChange::FieldsChanged(changes))
+}
+
+fn used_changed_types(fields: &[Field], changed_types: &HashSet<String>) -> Vec<String> {
+    let mut used = changed_types
+        .iter()
+        .filter(|changed| {
+            let renames = HashMap::from([((*changed).clone(), String::new())]);
+            fields
+                .iter()
+                .any(|(_, rust_type)| rename_identifiers(rust_type, &renames) != *rust_type)
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    used.sort();
+
+    used
+}
+
+/// Old layouts that data written before an IDL upgrade still needs, renamed
+/// so they can be generated next to the current ones.
+#[derive(Default)]
+pub struct LegacyLayouts {
+    pub accounts: Vec<AccountData>,
+    pub instructions: Vec<InstructionData>,
+    pub events: Vec<EventData>,
+    pub types: Vec<TypeData>,
+}
+
+impl LegacyLayouts {
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+            && self.instructions.is_empty()
+            && self.events.is_empty()
+            && self.types.is_empty()
+    }
+}
+
+/// Keeps the old layouts of every item whose layout broke between `old` and
+/// the IDL it was diffed against. Each kept item gets the old IDL version as
+/// a suffix, e.g. `TradeEvent` of version `1.2.0` becomes `TradeEventV1_2_0`
+/// in module `trade_event_v1_2_0`.
+pub fn legacy_layouts(old: IdlLayouts, diffs: &[LayoutDiff]) -> LegacyLayouts {
+    let suffix = version_suffix(&old.version);
+    let needs_legacy = |kind: LayoutKind, name: &str| {
+        diffs
+            .iter()
+            .any(|diff| diff.kind == kind && diff.name == name && diff.change.needs_legacy_layout())
+    };
+
+    let renames = changed_type_names(diffs)
+        .into_iter()
+        .map(|name| {
+            let versioned = format!("{name}{suffix}");
+            (name, versioned)
+        })
+        .collect::<HashMap<_, _>>();
+
+    let accounts = old
+        .accounts
+        .into_iter()
+        .filter(|account| needs_legacy(LayoutKind::Account, &account.struct_name))
+        .map(|mut account| {
+            account.struct_name = format!("{}{}", account.struct_name, suffix);
+            account.module_name = format!("{}_{}", account.module_name, suffix.to_lowercase());
+            for field in &mut account.fields {
+                field.rust_type = rename_identifiers(&field.rust_type, &renames);
+            }
+            account
+        })
+        .collect();
+
+    let instructions = old
+        .instructions
+        .into_iter()
+        .filter(|instruction| needs_legacy(LayoutKind::Instruction, &instruction.struct_name))
+        .map(|mut instruction| {
+            instruction.struct_name = format!("{}{}", instruction.struct_name, suffix);
+            instruction.module_name =
+                format!("{}_{}", instruction.module_name, suffix.to_lowercase());
+            instruction.pdas = Default::default();
+            for arg in &mut instruction.args {
+                arg.rust_type = rename_identifiers(&arg.rust_type, &renames);
+            }
+            instruction
+        })
+        .collect();
+
+    let events = old
+        .events
+        .into_iter()
+        .filter(|event| needs_legacy(LayoutKind::Event, &event.struct_name))
+        .map(|mut event| {
+            event.struct_name = format!("{}{}", event.struct_name, suffix);
+            event.module_name = format!("{}_{}", event.module_name, suffix.to_lowercase());
+            for arg in &mut event.args {
+                arg.rust_type = rename_identifiers(&arg.rust_type, &renames);
+            }
+            event
+        })
+        .collect();
+
+    let types = old
+        .types
+        .into_iter()
+        .filter(|type_data| renames.contains_key(&type_data.name))
+        .map(|mut type_data| {
+            type_data.name = renames[&type_data.name].clone();
+            for field in &mut type_data.fields {
+                field.rust_type = rename_identifiers(&field.rust_type, &renames);
+            }
+            if let TypeKind::Enum(variants) = &mut type_data.kind {
+                for variant in variants {
+                    match &mut variant.fields {
+                        Some(EnumVariantFields::Named(fields)) => {
+                            for field in fields {
+                                field.rust_type = rename_identifiers(&field.rust_type, &renames);
+                            }
+                        }
+                        Some(EnumVariantFields::Unnamed(types)) => {
+                            for rust_type in types {
+                                *rust_type = rename_identifiers(rust_type, &renames);
+                            }
+                        }
+                        None => {}
+                    }
+                }
+            }
+            type_data
+        })
+        .collect();
+
+    LegacyLayouts {
+        accounts,
+        instructions,
+        events,
+        types,
+    }
+}
+
+fn version_suffix(version: &str) -> String {
+    let version = version
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+
+    if version.is_empty() {
+        "Legacy".to_string()
+    } else {
+        format!("V{version}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{accounts::AccountsModTemplate, idl::Idl, instructions::InstructionsModTemplate},
+        askama::Template,
+        serde_json::{json, Value},
+    };
+
+    /// Layouts of an IDL with a `Pool` account, a `swap` instruction and the
+    /// given extra types.
+    fn layouts(version: &str, pool: Value, swap: Value, types: Value) -> IdlLayouts {
+        let mut all_types = vec![json!({
+            "name": "Pool",
+            "type": { "kind": "struct", "fields": pool }
+        })];
+        all_types.extend(types.as_array().cloned().unwrap_or_default());
+
+        let idl: Idl = serde_json::from_value(json!({
+            "address": "Poo1111111111111111111111111111111111111111",
+            "metadata": { "name": "amm", "version": version, "spec": "0.1.0", "description": "" },
+            "instructions": [
+                { "name": "swap", "discriminator": [248, 198, 158, 145, 225, 117, 135, 200], "args": swap }
+            ],
+            "accounts": [{ "name": "Pool", "discriminator": [241, 154, 109, 4, 17, 177, 109, 188] }],
+            "types": all_types
+        }))
+        .unwrap();
+
+        IdlLayouts {
+            version: idl.metadata.version.clone(),
+            accounts: process_accounts(&idl),
+            instructions: process_instructions(&idl),
+            events: process_events(&idl),
+            types: process_types(&idl),
+        }
+    }
+
+    fn pool_layouts(version: &str, pool: Value) -> IdlLayouts {
+        layouts(version, pool, json!([]), json!([]))
+    }
+
+    fn base_pool() -> Value {
+        json!([
+            { "name": "authority", "type": "pubkey" },
+            { "name": "liquidity", "type": "u64" }
+        ])
+    }
+
+    fn find<'a>(diffs: &'a [LayoutDiff], kind: LayoutKind, name: &str) -> Option<&'a LayoutDiff> {
+        diffs
+            .iter()
+            .find(|diff| diff.kind == kind && diff.name == name)
+    }
+
+    #[test]
+    fn test_unchanged_layouts() {
+        let old = pool_layouts("0.1.0", base_pool());
+        let new = pool_layouts("0.2.0", base_pool());
+
+        assert!(diff_layouts(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn test_added_and_removed_fields() {
+        let old = pool_layouts("0.1.0", base_pool());
+        let appended = pool_layouts(
+            "0.2.0",
+            json!([
+                { "name": "authority", "type": "pubkey" },
+                { "name": "liquidity", "type": "u64" },
+                { "name": "fee_rate", "type": "u16" }
+            ]),
+        );
+
+        let diffs = diff_layouts(&old, &appended);
+        let diff = find(&diffs, LayoutKind::Account, "Pool").unwrap();
+        assert_eq!(
+            diff.change,
+            LayoutChange::FieldsAppended(vec!["fee_rate: u16".to_string()])
+        );
+        assert!(diff.change.is_breaking());
+        assert!(diff.change.needs_legacy_layout());
+        // Old data is too short for the new layout.
+        assert!(ambiguous_layouts(&old, &appended, &diffs).is_empty());
+
+        let removed = pool_layouts("0.2.0", json!([{ "name": "authority", "type": "pubkey" }]));
+        let diffs = diff_layouts(&old, &removed);
+        assert_eq!(
+            find(&diffs, LayoutKind::Account, "Pool").unwrap().change,
+            LayoutChange::FieldsChanged(vec!["-liquidity: u64".to_string()])
+        );
+        assert!(ambiguous_layouts(&old, &removed, &diffs).is_empty());
+    }
+
+    #[test]
+    fn test_retyped_fields() {
+        let old = pool_layouts("0.1.0", base_pool());
+
+        let same_size = pool_layouts(
+            "0.2.0",
+            json!([
+                { "name": "authority", "type": "pubkey" },
+                { "name": "liquidity", "type": "i64" }
+            ]),
+        );
+        let diffs = diff_layouts(&old, &same_size);
+        assert_eq!(
+            find(&diffs, LayoutKind::Account, "Pool").unwrap().change,
+            LayoutChange::FieldsChanged(vec!["liquidity u64 -> i64".to_string()])
+        );
+        let ambiguous = ambiguous_layouts(&old, &same_size, &diffs);
+        assert_eq!(ambiguous.len(), 1);
+        assert_eq!(ambiguous[0].name, "Pool");
+
+        let resized = pool_layouts(
+            "0.2.0",
+            json!([
+                { "name": "authority", "type": "pubkey" },
+                { "name": "liquidity", "type": "u128" }
+            ]),
+        );
+        let diffs = diff_layouts(&old, &resized);
+        assert!(find(&diffs, LayoutKind::Account, "Pool")
+            .unwrap()
+            .change
+            .needs_legacy_layout());
+        assert!(ambiguous_layouts(&old, &resized, &diffs).is_empty());
+    }
+
+    #[test]
+    fn test_reordered_fields() {
+        let old = pool_layouts("0.1.0", base_pool());
+        let new = pool_layouts(
+            "0.2.0",
+            json!([
+                { "name": "liquidity", "type": "u64" },
+                { "name": "authority", "type": "pubkey" }
+            ]),
+        );
+
+        let diffs = diff_layouts(&old, &new);
+        let diff = find(&diffs, LayoutKind::Account, "Pool").unwrap();
+        assert_eq!(
+            diff.change,
+            LayoutChange::FieldsChanged(vec![
+                "authority: solana_sdk::pubkey::Pubkey -> liquidity: u64".to_string(),
+                "liquidity: u64 -> authority: solana_sdk::pubkey::Pubkey".to_string(),
+            ])
+        );
+        assert_eq!(
+            diff.to_string(),
+            "[BREAKING] account Pool: fields changed: authority: solana_sdk::pubkey::Pubkey -> \
+             liquidity: u64, liquidity: u64 -> authority: solana_sdk::pubkey::Pubkey"
+        );
+        assert_eq!(ambiguous_layouts(&old, &new, &diffs).len(), 1);
+    }
+
+    #[test]
+    fn test_variable_size_changes_are_ambiguous() {
+        let old = layouts(
+            "0.1.0",
+            base_pool(),
+            json!([{ "name": "route", "type": "bytes" }]),
+            json!([]),
+        );
+        let new = layouts(
+            "0.2.0",
+            base_pool(),
+            json!([{ "name": "route", "type": "string" }]),
+            json!([]),
+        );
+
+        let diffs = diff_layouts(&old, &new);
+        let ambiguous = ambiguous_layouts(&old, &new, &diffs);
+        assert_eq!(ambiguous.len(), 1);
+        assert_eq!(ambiguous[0].kind, LayoutKind::Instruction);
+        assert_eq!(ambiguous[0].name, "Swap");
+    }
+
+    #[test]
+    fn test_changed_types_propagate() {
+        let fees = |rate_type: &str| {
+            json!([{
+                "name": "Fees",
+                "type": { "kind": "struct", "fields": [{ "name": "rate", "type": rate_type }] }
+            }])
+        };
+        let swap = json!([{ "name": "fees", "type": { "defined": { "name": "Fees" } } }]);
+        let old = layouts("1.2.0", base_pool(), swap.clone(), fees("u16"));
+        let new = layouts("1.3.0", base_pool(), swap, fees("u32"));
+
+        let diffs = diff_layouts(&old, &new);
+        assert_eq!(
+            find(&diffs, LayoutKind::Type, "Fees").unwrap().change,
+            LayoutChange::FieldsChanged(vec!["rate u16 -> u32".to_string()])
+        );
+        assert_eq!(
+            find(&diffs, LayoutKind::Instruction, "Swap")
+                .unwrap()
+                .change,
+            LayoutChange::UsesChangedTypes(vec!["Fees".to_string()])
+        );
+        assert!(find(&diffs, LayoutKind::Account, "Pool").is_none());
+        assert!(ambiguous_layouts(&old, &new, &diffs).is_empty());
+
+        let legacy = legacy_layouts(old, &diffs);
+        assert!(legacy.accounts.is_empty());
+        assert_eq!(legacy.instructions.len(), 1);
+        assert_eq!(legacy.instructions[0].struct_name, "SwapV1_2_0");
+        assert_eq!(legacy.instructions[0].module_name, "swap_v1_2_0");
+        assert_eq!(legacy.instructions[0].args[0].rust_type, "FeesV1_2_0");
+        assert_eq!(legacy.types.len(), 1);
+        assert_eq!(legacy.types[0].name, "FeesV1_2_0");
+    }
+
+    #[test]
+    fn test_enum_variants() {
+        let side = |variants: Value| json!([{ "name": "Side", "type": { "kind": "enum", "variants": variants } }]);
+        let swap = json!([{ "name": "side", "type": { "defined": { "name": "Side" } } }]);
+        let old = layouts(
+            "0.1.0",
+            base_pool(),
+            swap.clone(),
+            side(json!([{ "name": "Bid" }, { "name": "Ask" }])),
+        );
+
+        let appended = layouts(
+            "0.2.0",
+            base_pool(),
+            swap.clone(),
+            side(json!([{ "name": "Bid" }, { "name": "Ask" }, { "name": "Both" }])),
+        );
+        let diffs = diff_layouts(&old, &appended);
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(
+            diffs[0].change,
+            LayoutChange::VariantsAppended(vec!["Both".to_string()])
+        );
+        assert!(!diffs[0].change.is_breaking());
+
+        let reordered = layouts(
+            "0.2.0",
+            base_pool(),
+            swap,
+            side(json!([{ "name": "Ask" }, { "name": "Bid" }])),
+        );
+        let diffs = diff_layouts(&old, &reordered);
+        assert_eq!(
+            find(&diffs, LayoutKind::Instruction, "Swap")
+                .unwrap()
+                .change,
+            LayoutChange::UsesChangedTypes(vec!["Side".to_string()])
+        );
+        assert_eq!(ambiguous_layouts(&old, &reordered, &diffs).len(), 1);
+    }
+
+    #[test]
+    fn test_added_removed_and_rediscriminated_items() {
+        let old = pool_layouts("0.1.0", base_pool());
+        let mut new = pool_layouts("0.2.0", base_pool());
+        new.instructions[0].discriminator = "0x0000000000000001".to_string();
+        let mut removed = new.accounts.remove(0);
+        removed.struct_name = "Position".to_string();
+        new.accounts.push(removed);
+
+        let diffs = diff_layouts(&old, &new);
+        assert_eq!(
+            find(&diffs, LayoutKind::Account, "Position")
+                .unwrap()
+                .change,
+            LayoutChange::Added
+        );
+        let pool = find(&diffs, LayoutKind::Account, "Pool").unwrap();
+        assert_eq!(pool.change, LayoutChange::Removed);
+        assert!(pool.change.is_breaking());
+        let swap = find(&diffs, LayoutKind::Instruction, "Swap").unwrap();
+        assert!(matches!(
+            swap.change,
+            LayoutChange::DiscriminatorChanged { .. }
+        ));
+        assert!(!swap.change.needs_legacy_layout());
+        assert!(legacy_layouts(old, &diffs).is_empty());
+    }
+
+    #[test]
+    fn test_rename_identifiers() {
+        let renames = HashMap::from([("Fees".to_string(), "FeesV1".to_string())]);
+
+        assert_eq!(
+            rename_identifiers("Option<Vec<(Fees, FeesConfig)>>", &renames),
+            "Option<Vec<(FeesV1, FeesConfig)>>"
+        );
+        assert_eq!(rename_identifiers("[Fees; 4]", &renames), "[FeesV1; 4]");
+    }
+
+    fn appended_layouts() -> (IdlLayouts, LegacyLayouts) {
+        let old = layouts(
+            "0.1.0",
+            base_pool(),
+            json!([{ "name": "amount", "type": "u64" }]),
+            json!([]),
+        );
+        let new = layouts(
+            "0.2.0",
+            json!([
+                { "name": "authority", "type": "pubkey" },
+                { "name": "liquidity", "type": "u64" },
+                { "name": "fee_rate", "type": "u16" }
+            ]),
+            json!([
+                { "name": "amount", "type": "u64" },
+                { "name": "min_out", "type": "u64" }
+            ]),
+            json!([]),
+        );
+        let diffs = diff_layouts(&old, &new);
+
+        (new, legacy_layouts(old, &diffs))
+    }
+
+    #[test]
+    fn test_render_legacy_accounts_for_program_owner() {
+        let (new, legacy) = appended_layouts();
+
+        let rendered = AccountsModTemplate {
+            accounts: &new.accounts,
+            decoder_name: "AmmDecoder".to_string(),
+            program_struct_name: "AmmAccount".to_string(),
+            legacy_accounts: &legacy.accounts,
+            upgrade_slot: Some(250_000_000),
+            allow_trailing_bytes: true,
+            has_program_id: true,
+        }
+        .render()
+        .unwrap();
+
+        // The current layout is tried for any owner, the legacy and
+        // trailing-bytes layouts only for accounts of the program.
+        let owner_check = rendered
+            .find("if account.owner != super::PROGRAM_ID {")
+            .unwrap();
+        let current = rendered.find("pool::Pool::deserialize").unwrap();
+        let legacy_layout = rendered
+            .find("pool_v0_1_0::PoolV0_1_0::deserialize")
+            .unwrap();
+        let trailing_bytes = rendered
+            .find("deserialize_with_trailing_bytes::<pool::Pool>")
+            .unwrap();
+        assert!(current < owner_check);
+        assert!(owner_check < legacy_layout);
+        assert!(owner_check < trailing_bytes);
+        assert!(rendered.contains("if slot < 250000000 && account.owner == super::PROGRAM_ID {"));
+    }
+
+    #[test]
+    fn test_render_legacy_instructions_for_program_id() {
+        let (new, legacy) = appended_layouts();
+        assert_eq!(legacy.instructions[0].struct_name, "SwapV0_1_0");
+
+        let rendered = InstructionsModTemplate {
+            instructions: &new.instructions,
+            decoder_name: "AmmDecoder".to_string(),
+            program_instruction_enum: "AmmInstruction".to_string(),
+            events: &new.events,
+            legacy_instructions: &legacy.instructions,
+            legacy_events: &legacy.events,
+            upgrade_slot: Some(250_000_000),
+            allow_trailing_bytes: true,
+            has_program_id: true,
+        }
+        .render()
+        .unwrap();
+
+        assert!(rendered.contains(
+            "        carbon_core::try_decode_instructions!(instruction,
+            AmmInstruction::Swap => swap::Swap,
+        )
+        .or_else(|| self.decode_legacy_instruction(instruction))
+        .or_else(|| self.decode_instruction_with_trailing_bytes(instruction))"
+        ));
+        assert!(rendered.contains(
+            "    fn decode_legacy_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<AmmInstruction>> {
+        if instruction.program_id != super::PROGRAM_ID {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            AmmInstruction::SwapV0_1_0 => swap_v0_1_0::SwapV0_1_0,
+        )
+    }
+
+    fn decode_instruction_with_trailing_bytes(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<AmmInstruction>> {
+        if instruction.program_id != super::PROGRAM_ID {
+            return None;
+        }
+"
+        ));
+        assert!(rendered.contains(
+            "        if slot < 250000000 {
+            let decoded_instruction = self.decode_legacy_instruction(instruction);"
+        ));
+    }
+
+    #[test]
+    fn test_render_without_legacy_layouts() {
+        let (new, _) = appended_layouts();
+
+        let accounts = AccountsModTemplate {
+            accounts: &new.accounts,
+            decoder_name: "AmmDecoder".to_string(),
+            program_struct_name: "AmmAccount".to_string(),
+            legacy_accounts: &Vec::new(),
+            upgrade_slot: None,
+            allow_trailing_bytes: false,
+            has_program_id: false,
+        }
+        .render()
+        .unwrap();
+        let instructions = InstructionsModTemplate {
+            instructions: &new.instructions,
+            decoder_name: "AmmDecoder".to_string(),
+            program_instruction_enum: "AmmInstruction".to_string(),
+            events: &new.events,
+            legacy_instructions: &Vec::new(),
+            legacy_events: &Vec::new(),
+            upgrade_slot: None,
+            allow_trailing_bytes: false,
+            has_program_id: false,
+        }
+        .render()
+        .unwrap();
+
+        assert!(!accounts.contains("PROGRAM_ID"));
+        assert!(!instructions.contains("PROGRAM_ID"));
+        assert!(!instructions.contains("impl AmmDecoder"));
+    }
+}
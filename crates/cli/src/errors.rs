@@ -0,0 +1,123 @@
+use {
+    crate::{idl::Idl, legacy_idl::LegacyIdl},
+    askama::Template,
+    heck::ToUpperCamelCase,
+};
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct ErrorData {
+    pub name: String,
+    pub code: u32,
+    pub message: String,
+}
+
+#[derive(Template)]
+#[template(path = "errors.askama", escape = "none", ext = ".askama")]
+pub struct ErrorsTemplate<'a> {
+    pub errors: &'a Vec<ErrorData>,
+    pub decoder_name: String,
+    pub program_error_enum: String,
+}
+
+pub fn legacy_process_errors(idl: &LegacyIdl) -> Vec<ErrorData> {
+    idl.errors
+        .iter()
+        .map(|error| ErrorData {
+            name: error.name.to_upper_camel_case(),
+            code: error.code as u32,
+            message: error.msg.clone().unwrap_or_default(),
+        })
+        .collect()
+}
+
+pub fn process_errors(idl: &Idl) -> Vec<ErrorData> {
+    idl.errors
+        .iter()
+        .map(|error| ErrorData {
+            name: error.name.to_upper_camel_case(),
+            code: error.code,
+            message: error.msg.clone().unwrap_or_default(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDL: &str = r#"{
+        "address": "Swap111111111111111111111111111111111111111",
+        "metadata": { "name": "swap", "version": "0.1.0", "spec": "0.1.0", "description": "" },
+        "errors": [
+            { "code": 6000, "name": "slippage_exceeded", "msg": "Slippage tolerance \"exceeded\"" },
+            { "code": 6001, "name": "InvalidMint" }
+        ]
+    }"#;
+
+    fn render() -> String {
+        let idl: Idl = serde_json::from_str(IDL).unwrap();
+        let errors = process_errors(&idl);
+        ErrorsTemplate {
+            errors: &errors,
+            decoder_name: "SwapDecoder".to_string(),
+            program_error_enum: "SwapError".to_string(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_process_errors() {
+        let idl: Idl = serde_json::from_str(IDL).unwrap();
+        let errors = process_errors(&idl);
+
+        let errors: Vec<(&str, u32, &str)> = errors
+            .iter()
+            .map(|error| (error.name.as_str(), error.code, error.message.as_str()))
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                ("SlippageExceeded", 6000, "Slippage tolerance \"exceeded\""),
+                ("InvalidMint", 6001, ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_from_code() {
+        let rendered = render();
+
+        assert!(rendered.contains(
+            "pub enum SwapError {
+    SlippageExceeded = 6000,
+    InvalidMint = 6001,
+}"
+        ));
+        assert!(rendered.contains(
+            "    pub fn from_code(code: u32) -> Option<Self> {
+        match code {
+            6000 => Some(Self::SlippageExceeded),
+            6001 => Some(Self::InvalidMint),
+            _ => None,
+        }
+    }"
+        ));
+        assert!(rendered.contains(
+            "            Self::SlippageExceeded => \"Slippage tolerance \\\"exceeded\\\"\",
+            Self::InvalidMint => \"\","
+        ));
+    }
+
+    #[test]
+    fn test_render_program_error_decoder() {
+        let rendered = render();
+
+        assert!(rendered.contains(
+            "impl carbon_core::program_error::ProgramErrorDecoder for SwapDecoder {
+    fn decode_program_error(&self, code: u32) -> Option<carbon_core::program_error::DecodedProgramError> {
+        let error = SwapError::from_code(code)?;"
+        ));
+    }
+}
@@ -1,5 +1,9 @@
 use {
-    crate::{idl::Idl, legacy_idl::LegacyIdl, util::idl_type_to_rust_type},
+    crate::{
+        idl::Idl,
+        legacy_idl::LegacyIdl,
+        util::{discriminator_bytes, idl_type_to_rust_type},
+    },
     askama::Template,
     heck::{ToSnakeCase, ToUpperCamelCase},
     sha2::{Digest, Sha256},
@@ -15,6 +19,12 @@ pub struct EventData {
     pub requires_imports: bool,
 }
 
+impl EventData {
+    pub fn discriminator_bytes(&self) -> String {
+        discriminator_bytes(&self.discriminator)
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub struct ArgumentData {
@@ -81,7 +81,9 @@ pub fn parse_codama(
                 && field.rust_type.ends_with("]")
                 && is_big_array(&field.rust_type)
         })
-    });
+    }) || accounts_data
+        .iter()
+        .any(|account| account.fields.iter().any(|field| is_big_array(&field.rust_type)));
 
     // Generate types
     let types_dir = format!("{}/types", src_dir);
@@ -128,6 +130,10 @@ pub fn parse_codama(
         accounts: &accounts_data,
         decoder_name: decoder_name.clone(),
         program_struct_name: program_struct_name.clone(),
+        legacy_accounts: &Vec::new(),
+        upgrade_slot: None,
+        allow_trailing_bytes: false,
+        has_program_id: false,
     };
     let accounts_mod_rendered = accounts_mod_template.render().unwrap();
     let accounts_mod_filename = format!("{}/mod.rs", accounts_dir);
@@ -162,6 +168,11 @@ pub fn parse_codama(
         decoder_name: decoder_name.clone(),
         program_instruction_enum: program_instruction_enum.clone(),
         events: &events_data,
+        legacy_instructions: &Vec::new(),
+        legacy_events: &Vec::new(),
+        upgrade_slot: None,
+        allow_trailing_bytes: false,
+        has_program_id: false,
     };
     let instructions_mod_rendered = instructions_mod_template.render().unwrap();
     let instructions_mod_filename = format!("{}/mod.rs", instructions_dir);
@@ -10,7 +10,9 @@ use {
         accounts::{AccountData, FieldData as AccountFieldData},
         events::EventData,
         instructions::{AccountMetaData, ArgumentData, InstructionData},
+        pdas::InstructionPdasData,
         types::{EnumVariantData, EnumVariantFields, FieldData, TypeData, TypeKind},
+        util::big_array_attribute,
     },
     heck::{ToSnakeCase, ToUpperCamelCase},
     std::collections::HashSet,
@@ -38,6 +40,7 @@ pub fn process_codama_accounts(program: &ProgramNode) -> Vec<AccountData> {
 
             fields.push(AccountFieldData {
                 name: field.name.to_snake_case(),
+                attributes: big_array_attribute(&rust_type.0),
                 rust_type: rust_type.0,
             });
         }
@@ -99,6 +102,7 @@ pub fn process_codama_instructions(program: &ProgramNode) -> Vec<InstructionData
             discriminator,
             args,
             accounts,
+            pdas: InstructionPdasData::default(),
             requires_imports,
         });
     }
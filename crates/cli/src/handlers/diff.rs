@@ -0,0 +1,40 @@
+use {
+    crate::diff::{diff_layouts, load_idl_layouts},
+    anyhow::Result,
+};
+
+pub fn diff(old: String, new: String) -> Result<()> {
+    let old_layouts = load_idl_layouts(&old)?;
+    let new_layouts = load_idl_layouts(&new)?;
+
+    let diffs = diff_layouts(&old_layouts, &new_layouts);
+
+    println!(
+        "Comparing {} (version {}) with {} (version {})",
+        old, old_layouts.version, new, new_layouts.version
+    );
+
+    if diffs.is_empty() {
+        println!("No layout changes.");
+        return Ok(());
+    }
+
+    for diff in &diffs {
+        println!("{}", diff);
+    }
+
+    let breaking = diffs
+        .iter()
+        .filter(|diff| diff.change.is_breaking())
+        .count();
+    println!("{} layout change(s), {} breaking.", diffs.len(), breaking);
+
+    if diffs.iter().any(|diff| diff.change.needs_legacy_layout()) {
+        println!(
+            "Data written before the upgrade needs the old layouts. Run `parse` with `--legacy-idl {}` to generate a version-aware decoder.",
+            old
+        );
+    }
+
+    Ok(())
+}
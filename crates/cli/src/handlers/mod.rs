@@ -6,3 +6,6 @@ pub use codama::*;
 
 mod process_pda_idl;
 pub use process_pda_idl::*;
+
+mod diff;
+pub use diff::*;
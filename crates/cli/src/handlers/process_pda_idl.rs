@@ -36,7 +36,13 @@ pub fn process_pda_idl(
 
     fs::write(&idl_path, idl)?;
 
-    handlers::parse(idl_path.clone(), output, as_crate).context("Couldn't parse IDL")?;
+    handlers::parse(
+        idl_path.clone(),
+        output,
+        as_crate,
+        handlers::VersioningOptions::default(),
+    )
+    .context("Couldn't parse IDL")?;
 
     // Clean up: Delete the IDL file after parsing
     if Path::new(&idl_path).exists() {
@@ -8,6 +8,8 @@ pub struct Idl {
     pub address: String,
     pub metadata: IdlMetadata,
     #[serde(default)]
+    pub constants: Vec<IdlConst>,
+    #[serde(default)]
     pub instructions: Vec<IdlInstruction>,
     #[serde(default)]
     pub accounts: Vec<IdlAccount>,
@@ -27,6 +29,14 @@ pub struct IdlMetadata {
     pub description: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IdlConst {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: LegacyIdlType,
+    pub value: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct IdlInstruction {
     pub name: String,
@@ -59,6 +69,8 @@ pub struct IdlInstructionAccount {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct IdlPda {
     pub seeds: Vec<IdlPdaSeed>,
+    #[serde(default)]
+    pub program: Option<IdlPdaSeed>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
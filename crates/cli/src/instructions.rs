@@ -3,7 +3,8 @@ use {
         events::EventData,
         idl::Idl,
         legacy_idl::{LegacyIdl, LegacyIdlInstructionDiscriminant},
-        util::idl_type_to_rust_type,
+        pdas::{process_instruction_pdas, InstructionPdasData},
+        util::{discriminator_bytes, idl_type_to_rust_type},
     },
     askama::Template,
     heck::{ToSnakeCase, ToUpperCamelCase},
@@ -18,9 +19,16 @@ pub struct InstructionData {
     pub discriminator: String,
     pub args: Vec<ArgumentData>,
     pub accounts: Vec<AccountMetaData>,
+    pub pdas: InstructionPdasData,
     pub requires_imports: bool,
 }
 
+impl InstructionData {
+    pub fn discriminator_bytes(&self) -> String {
+        discriminator_bytes(&self.discriminator)
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub struct ArgumentData {
@@ -50,6 +58,11 @@ pub struct InstructionsModTemplate<'a> {
     pub decoder_name: String,
     pub program_instruction_enum: String,
     pub events: &'a Vec<EventData>,
+    pub legacy_instructions: &'a Vec<InstructionData>,
+    pub legacy_events: &'a Vec<EventData>,
+    pub upgrade_slot: Option<u64>,
+    pub allow_trailing_bytes: bool,
+    pub has_program_id: bool,
 }
 
 pub fn legacy_process_instructions(idl: &LegacyIdl) -> Vec<InstructionData> {
@@ -92,6 +105,7 @@ pub fn legacy_process_instructions(idl: &LegacyIdl) -> Vec<InstructionData> {
             discriminator,
             args,
             accounts,
+            pdas: InstructionPdasData::default(),
             requires_imports,
         });
     }
@@ -131,12 +145,15 @@ pub fn process_instructions(idl: &Idl) -> Vec<InstructionData> {
             });
         }
 
+        let pdas = process_instruction_pdas(idl, instruction);
+
         instructions_data.push(InstructionData {
             struct_name,
             module_name,
             discriminator,
             args,
             accounts,
+            pdas,
             requires_imports,
         });
     }
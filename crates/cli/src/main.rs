@@ -5,16 +5,20 @@ use {
 
 pub mod accounts;
 pub mod commands;
+pub mod constants;
+pub mod diff;
+pub mod errors;
 pub mod events;
 pub mod handlers;
 pub mod idl;
 pub mod instructions;
 pub mod legacy_idl;
+pub mod pdas;
 pub mod project;
 pub mod types;
 pub mod util;
 
-use commands::{Datasource, Decoder, Metrics, Url};
+use commands::{Datasource, Decoder, Metrics, Storage, Url};
 use inquire::{
     error::InquireResult, required, Confirm, CustomType, InquireError, MultiSelect, Select, Text,
 };
@@ -27,7 +31,7 @@ fn main() -> InquireResult<()> {
 }
 
 fn process_prompts() -> InquireResult<()> {
-    let cmd = Select::new("Chose mode:", vec!["parse", "scaffold"]).prompt()?;
+    let cmd = Select::new("Chose mode:", vec!["parse", "scaffold", "diff"]).prompt()?;
 
     match cmd {
         "parse" => {
@@ -50,8 +54,13 @@ fn process_prompts() -> InquireResult<()> {
                                 .prompt()?;
                             let as_crate = Confirm::new("Generate as crate?").prompt()?;
 
-                            handlers::parse(path, output_dir, as_crate)
-                                .map_err(|e| InquireError::Custom(e.into()))?;
+                            handlers::parse(
+                                path,
+                                output_dir,
+                                as_crate,
+                                handlers::VersioningOptions::default(),
+                            )
+                            .map_err(|e| InquireError::Custom(e.into()))?;
                         }
                         IdlStandard::Codama => {
                             let event_hints = Text::new("Event hints:")
@@ -147,6 +156,17 @@ fn process_prompts() -> InquireResult<()> {
 
             let metrics =
                 Select::new("Select metrics:", vec![Metrics::Log, Metrics::Prometheus]).prompt()?;
+
+            let storage = Select::new(
+                "Select storage:",
+                vec![
+                    Storage::None,
+                    Storage::Postgres,
+                    Storage::Sqlite,
+                    Storage::Clickhouse,
+                ],
+            )
+            .prompt()?;
             handlers::scaffold(
                 name,
                 output_dir,
@@ -157,9 +177,20 @@ fn process_prompts() -> InquireResult<()> {
                     .join(","),
                 datasource.to_string(),
                 metrics.to_string(),
+                Some(storage.to_string()),
             )
             .map_err(|e| InquireError::Custom(e.into()))?;
         }
+        "diff" => {
+            let old = Text::new("Path to previous IDL file:")
+                .with_validator(required!("Please type a path to IDL"))
+                .prompt()?;
+            let new = Text::new("Path to new IDL file:")
+                .with_validator(required!("Please type a path to IDL"))
+                .prompt()?;
+
+            handlers::diff(old, new).map_err(|e| InquireError::Custom(e.into()))?;
+        }
         _ => unreachable!(),
     }
 
@@ -186,8 +217,17 @@ fn process_cli_params(cli: Cli) -> InquireResult<()> {
                                 .to_string(),
                         ));
                     }
-                    handlers::parse(path, options.output, options.as_crate)
-                        .map_err(|e| InquireError::Custom(e.into()))?;
+                    handlers::parse(
+                        path,
+                        options.output,
+                        options.as_crate,
+                        handlers::VersioningOptions {
+                            legacy_idl: options.legacy_idl,
+                            upgrade_slot: options.upgrade_slot,
+                            allow_trailing_bytes: options.allow_trailing_bytes,
+                        },
+                    )
+                    .map_err(|e| InquireError::Custom(e.into()))?;
                 }
             },
             IdlSource::ProgramAddress(program_address) => {
@@ -210,9 +250,13 @@ fn process_cli_params(cli: Cli) -> InquireResult<()> {
                 options.decoders,
                 options.data_source,
                 options.metrics,
+                options.storage,
             )
             .map_err(|e| InquireError::Custom(e.into()))?;
         }
+        Commands::Diff(options) => {
+            handlers::diff(options.old, options.new).map_err(|e| InquireError::Custom(e.into()))?;
+        }
     };
 
     Ok(())
@@ -0,0 +1,541 @@
+use {
+    crate::{
+        idl::{Idl, IdlInstruction, IdlInstructionAccount, IdlPdaSeed},
+        legacy_idl::LegacyIdlType,
+        util::idl_type_to_rust_type,
+    },
+    heck::{ToSnakeCase, ToUpperCamelCase},
+};
+
+const PUBKEY_TYPE: &str = "solana_sdk::pubkey::Pubkey";
+const DEFAULT_PROGRAM_ID: &str = "&super::super::PROGRAM_ID";
+
+#[allow(dead_code)]
+#[derive(Debug, Default)]
+pub struct InstructionPdasData {
+    pub pdas: Vec<PdaData>,
+    pub checks: Vec<PdaCheckData>,
+    /// The PDA accounts `verify_pdas` can't check, because their seeds read
+    /// account data or aren't supported.
+    pub unchecked: Vec<String>,
+    pub uses_args: bool,
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct PdaData {
+    pub account_name: String,
+    pub fn_name: String,
+    pub params: Vec<PdaParamData>,
+    pub seeds: Vec<String>,
+    pub program_id: String,
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct PdaParamData {
+    pub name: String,
+    pub rust_type: String,
+    /// Expression resolving the parameter from the arranged accounts or the
+    /// instruction args. `None` when the seed reads account data, which
+    /// can't be checked from the instruction alone.
+    pub source: Option<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct PdaCheckData {
+    pub account_name: String,
+    /// Expression deriving the account's address from the arranged accounts
+    /// and the instruction args.
+    pub derived_address: String,
+}
+
+pub fn process_instruction_pdas(idl: &Idl, instruction: &IdlInstruction) -> InstructionPdasData {
+    let mut pdas_data = InstructionPdasData::default();
+
+    for account in &instruction.accounts {
+        if account.pda.is_none() {
+            continue;
+        }
+
+        let Some(pda) = process_pda(idl, instruction, account) else {
+            pdas_data.unchecked.push(account.name.to_snake_case());
+            continue;
+        };
+
+        if pda.params.iter().all(|param| param.source.is_some()) {
+            let sources = pda
+                .params
+                .iter()
+                .filter_map(|param| param.source.clone())
+                .collect::<Vec<_>>();
+            if sources.iter().any(|source| source.contains("args.")) {
+                pdas_data.uses_args = true;
+            }
+            pdas_data.checks.push(PdaCheckData {
+                account_name: pda.account_name.clone(),
+                derived_address: format!("{}({}).0", pda.fn_name, sources.join(", ")),
+            });
+        } else {
+            pdas_data.unchecked.push(pda.account_name.clone());
+        }
+
+        pdas_data.pdas.push(pda);
+    }
+
+    pdas_data
+}
+
+fn process_pda(
+    idl: &Idl,
+    instruction: &IdlInstruction,
+    account: &IdlInstructionAccount,
+) -> Option<PdaData> {
+    let pda = account.pda.as_ref()?;
+    let account_name = account.name.to_snake_case();
+    let mut params: Vec<PdaParamData> = Vec::new();
+    let mut seeds = Vec::new();
+
+    for seed in &pda.seeds {
+        let Some((seed_expr, param)) = process_seed(idl, instruction, seed) else {
+            println!(
+                "Skipping PDA helper for `{}` in `{}`: unsupported seed {:?}",
+                account.name, instruction.name, seed
+            );
+            return None;
+        };
+        seeds.push(seed_expr);
+        if let Some(param) = param {
+            if !params.iter().any(|p| p.name == param.name) {
+                params.push(param);
+            }
+        }
+    }
+
+    let program_id = match &pda.program {
+        None => DEFAULT_PROGRAM_ID.to_string(),
+        Some(program) => match program.kind.as_str() {
+            "const" => match program.value.as_deref() {
+                Some(bytes) if bytes.len() == 32 => {
+                    format!("&{}::new_from_array({:?})", PUBKEY_TYPE, bytes)
+                }
+                _ => return None,
+            },
+            "account" => {
+                let path = program.path.as_deref()?;
+                if path.contains('.') {
+                    return None;
+                }
+                let name = path.to_snake_case();
+                if !params.iter().any(|p| p.name == name) {
+                    params.push(PdaParamData {
+                        name: name.clone(),
+                        rust_type: format!("&{}", PUBKEY_TYPE),
+                        source: Some(format!("&accounts.{}", name)),
+                    });
+                }
+                name
+            }
+            _ => return None,
+        },
+    };
+
+    Some(PdaData {
+        fn_name: format!("find_{}_address", account_name),
+        account_name,
+        params,
+        seeds,
+        program_id,
+    })
+}
+
+fn process_seed(
+    idl: &Idl,
+    instruction: &IdlInstruction,
+    seed: &IdlPdaSeed,
+) -> Option<(String, Option<PdaParamData>)> {
+    match seed.kind.as_str() {
+        "const" => Some((const_seed_expr(seed.value.as_deref()?), None)),
+        "arg" => {
+            let path = seed.path.as_deref()?;
+            let mut segments = path.split('.');
+            let root = segments.next()?;
+            let arg = instruction
+                .args
+                .iter()
+                .find(|arg| arg.name.to_snake_case() == root.to_snake_case())?;
+            let idl_type = resolve_field_path(idl, &arg.type_, segments)?;
+            let rust_type = idl_type_to_rust_type(idl_type).0;
+            let source = format!(
+                "args.{}",
+                path.split('.')
+                    .map(|segment| segment.to_snake_case())
+                    .collect::<Vec<_>>()
+                    .join(".")
+            );
+
+            seed_param(path, &rust_type, Some(source))
+        }
+        "account" => {
+            let path = seed.path.as_deref()?;
+            let mut segments = path.split('.');
+            let root = segments.next()?;
+            instruction
+                .accounts
+                .iter()
+                .find(|account| account.name.to_snake_case() == root.to_snake_case())?;
+
+            if path.contains('.') {
+                // The seed reads a field of the account's data, so its type
+                // comes from the account definition.
+                let rust_type = seed
+                    .account
+                    .as_deref()
+                    .and_then(|account_type| {
+                        resolve_defined_field_path(idl, account_type, segments)
+                    })
+                    .map(|idl_type| idl_type_to_rust_type(idl_type).0)
+                    .unwrap_or_else(|| PUBKEY_TYPE.to_string());
+
+                seed_param(path, &rust_type, None)
+            } else {
+                let source = format!("accounts.{}", root.to_snake_case());
+                seed_param(path, PUBKEY_TYPE, Some(source))
+            }
+        }
+        _ => None,
+    }
+}
+
+fn seed_param(
+    path: &str,
+    rust_type: &str,
+    source: Option<String>,
+) -> Option<(String, Option<PdaParamData>)> {
+    let name = path.replace('.', "_").to_snake_case();
+
+    let (param_type, seed_expr, by_ref) = match rust_type {
+        PUBKEY_TYPE => (
+            format!("&{}", PUBKEY_TYPE),
+            format!("{}.as_ref()", name),
+            true,
+        ),
+        "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "u128" | "i128" => (
+            rust_type.to_string(),
+            format!("&{}.to_le_bytes()", name),
+            false,
+        ),
+        "bool" => ("bool".to_string(), format!("&[{} as u8]", name), false),
+        "String" => ("&str".to_string(), format!("{}.as_bytes()", name), true),
+        "Vec<u8>" => ("&[u8]".to_string(), name.clone(), true),
+        _ if rust_type.starts_with("[u8;") => (format!("&{}", rust_type), name.clone(), true),
+        _ => return None,
+    };
+
+    Some((
+        seed_expr,
+        Some(PdaParamData {
+            name,
+            rust_type: param_type,
+            source: source.map(|source| {
+                if by_ref {
+                    format!("&{}", source)
+                } else {
+                    source
+                }
+            }),
+        }),
+    ))
+}
+
+fn const_seed_expr(bytes: &[u8]) -> String {
+    if !bytes.is_empty()
+        && bytes
+            .iter()
+            .all(|b| b.is_ascii_alphanumeric() || *b == b'_' || *b == b'-')
+    {
+        format!("b\"{}\"", String::from_utf8_lossy(bytes))
+    } else {
+        format!("&{:?}", bytes)
+    }
+}
+
+fn resolve_field_path<'a, 'b>(
+    idl: &'a Idl,
+    idl_type: &'a LegacyIdlType,
+    mut segments: impl Iterator<Item = &'b str>,
+) -> Option<&'a LegacyIdlType> {
+    match segments.next() {
+        None => Some(idl_type),
+        Some(segment) => {
+            let type_name = match idl_type {
+                LegacyIdlType::Defined { defined } => defined.as_str(),
+                LegacyIdlType::DefinedWithName { defined } => defined.name.as_str(),
+                _ => return None,
+            };
+            let field_type = defined_field(idl, type_name, segment)?;
+            resolve_field_path(idl, field_type, segments)
+        }
+    }
+}
+
+fn resolve_defined_field_path<'a, 'b>(
+    idl: &'a Idl,
+    type_name: &str,
+    mut segments: impl Iterator<Item = &'b str>,
+) -> Option<&'a LegacyIdlType> {
+    let field_type = defined_field(idl, type_name, segments.next()?)?;
+    resolve_field_path(idl, field_type, segments)
+}
+
+fn defined_field<'a>(idl: &'a Idl, type_name: &str, field_name: &str) -> Option<&'a LegacyIdlType> {
+    idl.types
+        .iter()
+        .find(|ty| ty.name.to_upper_camel_case() == type_name.to_upper_camel_case())?
+        .type_
+        .fields
+        .as_ref()?
+        .iter()
+        .find(|field| field.name.to_snake_case() == field_name.to_snake_case())
+        .map(|field| &field.type_)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::instructions::{process_instructions, InstructionsStructTemplate},
+        askama::Template,
+    };
+
+    const IDL: &str = r#"{
+        "address": "Vau1t11111111111111111111111111111111111111",
+        "metadata": { "name": "vault", "version": "0.1.0", "spec": "0.1.0", "description": "" },
+        "instructions": [
+            {
+                "name": "initialize_vault",
+                "discriminator": [48, 191, 163, 44, 71, 129, 63, 164],
+                "accounts": [
+                    { "name": "payer", "writable": true, "signer": true },
+                    {
+                        "name": "config",
+                        "pda": { "seeds": [{ "kind": "const", "value": [99, 111, 110, 102, 105, 103] }] }
+                    },
+                    {
+                        "name": "vault",
+                        "writable": true,
+                        "pda": {
+                            "seeds": [
+                                { "kind": "const", "value": [118, 97, 117, 108, 116] },
+                                { "kind": "account", "path": "payer" },
+                                { "kind": "arg", "path": "vault_id" }
+                            ]
+                        }
+                    },
+                    {
+                        "name": "authority_state",
+                        "pda": {
+                            "seeds": [
+                                { "kind": "const", "value": [255, 1] },
+                                { "kind": "account", "path": "config.authority", "account": "Config" }
+                            ]
+                        }
+                    },
+                    {
+                        "name": "metadata",
+                        "pda": {
+                            "seeds": [{ "kind": "arg", "path": "params.name" }],
+                            "program": {
+                                "kind": "const",
+                                "value": [11, 112, 101, 177, 227, 209, 124, 69, 56, 157, 82, 127, 107, 4, 195, 205,
+                                          88, 184, 108, 115, 26, 160, 253, 181, 73, 182, 209, 188, 3, 248, 41, 70]
+                            }
+                        }
+                    },
+                    {
+                        "name": "unsupported",
+                        "pda": { "seeds": [{ "kind": "arg", "path": "params.weights" }] }
+                    }
+                ],
+                "args": [
+                    { "name": "vault_id", "type": "u64" },
+                    { "name": "params", "type": { "defined": { "name": "VaultParams" } } }
+                ]
+            }
+        ],
+        "types": [
+            {
+                "name": "Config",
+                "type": { "kind": "struct", "fields": [{ "name": "authority", "type": "pubkey" }] }
+            },
+            {
+                "name": "VaultParams",
+                "type": {
+                    "kind": "struct",
+                    "fields": [
+                        { "name": "name", "type": "string" },
+                        { "name": "weights", "type": { "vec": "u16" } }
+                    ]
+                }
+            }
+        ]
+    }"#;
+
+    fn idl() -> Idl {
+        serde_json::from_str(IDL).unwrap()
+    }
+
+    #[test]
+    fn test_process_instruction_pdas() {
+        let idl = idl();
+        let pdas_data = process_instruction_pdas(&idl, &idl.instructions[0]);
+
+        let fn_names: Vec<&str> = pdas_data
+            .pdas
+            .iter()
+            .map(|pda| pda.fn_name.as_str())
+            .collect();
+        assert_eq!(
+            fn_names,
+            [
+                "find_config_address",
+                "find_vault_address",
+                "find_authority_state_address",
+                "find_metadata_address",
+            ]
+        );
+
+        let vault = &pdas_data.pdas[1];
+        assert_eq!(
+            vault.seeds,
+            ["b\"vault\"", "payer.as_ref()", "&vault_id.to_le_bytes()"]
+        );
+        assert_eq!(vault.program_id, DEFAULT_PROGRAM_ID);
+        assert_eq!(vault.params[0].rust_type, "&solana_sdk::pubkey::Pubkey");
+        assert_eq!(vault.params[0].source.as_deref(), Some("&accounts.payer"));
+        assert_eq!(vault.params[1].rust_type, "u64");
+        assert_eq!(vault.params[1].source.as_deref(), Some("args.vault_id"));
+
+        let authority_state = &pdas_data.pdas[2];
+        assert_eq!(
+            authority_state.seeds,
+            ["&[255, 1]", "config_authority.as_ref()"]
+        );
+        assert_eq!(authority_state.params[0].source, None);
+
+        let metadata = &pdas_data.pdas[3];
+        assert_eq!(metadata.seeds, ["params_name.as_bytes()"]);
+        assert_eq!(metadata.params[0].rust_type, "&str");
+        assert!(metadata
+            .program_id
+            .starts_with("&solana_sdk::pubkey::Pubkey::new_from_array([11, 112,"));
+
+        // The authority state seed reads account data, so it can't be
+        // checked from the instruction.
+        let checks: Vec<(&str, &str)> = pdas_data
+            .checks
+            .iter()
+            .map(|check| (check.account_name.as_str(), check.derived_address.as_str()))
+            .collect();
+        assert_eq!(
+            checks,
+            [
+                ("config", "find_config_address().0"),
+                (
+                    "vault",
+                    "find_vault_address(&accounts.payer, args.vault_id).0"
+                ),
+                ("metadata", "find_metadata_address(&args.params.name).0"),
+            ]
+        );
+        assert_eq!(pdas_data.unchecked, ["authority_state", "unsupported"]);
+        assert!(pdas_data.uses_args);
+    }
+
+    #[test]
+    fn test_render_pdas() {
+        let instructions = process_instructions(&idl());
+        let rendered = InstructionsStructTemplate {
+            instruction: &instructions[0],
+        }
+        .render()
+        .unwrap();
+
+        assert!(rendered.contains(
+            "pub fn find_vault_address(
+    payer: &solana_sdk::pubkey::Pubkey,
+    vault_id: u64,
+) -> (solana_sdk::pubkey::Pubkey, u8) {
+    solana_sdk::pubkey::Pubkey::find_program_address(
+        &[
+            b\"vault\",
+            payer.as_ref(),
+            &vault_id.to_le_bytes(),
+        ],
+        &super::super::PROGRAM_ID,
+    )
+}"
+        ));
+        assert!(rendered.contains("pub fn find_authority_state_address(\n    config_authority: &solana_sdk::pubkey::Pubkey,\n)"));
+        assert!(!rendered.contains("find_unsupported_address"));
+        assert!(rendered.contains(
+            "pub const UNCHECKED_PDA_ACCOUNTS: &[&str] = &[
+    \"authority_state\",
+    \"unsupported\",
+];"
+        ));
+        assert!(rendered.contains(
+            "/// Returns `None` if none of them can be checked.
+/// Doesn't check `authority_state`, `unsupported`,
+/// which are listed in [`UNCHECKED_PDA_ACCOUNTS`].
+pub fn verify_pdas(
+    accounts: &InitializeVaultInstructionAccounts,
+    args: &InitializeVault,
+) -> Option<Result<(), carbon_core::pda::PdaMismatch>> {
+    if accounts.config != find_config_address().0 {
+        return Some(Err(carbon_core::pda::PdaMismatch {
+            account: \"config\",
+        }));
+    }
+    if accounts.vault != find_vault_address(&accounts.payer, args.vault_id).0 {
+        return Some(Err(carbon_core::pda::PdaMismatch {
+            account: \"vault\",
+        }));
+    }
+    if accounts.metadata != find_metadata_address(&args.params.name).0 {
+        return Some(Err(carbon_core::pda::PdaMismatch {
+            account: \"metadata\",
+        }));
+    }
+    Some(Ok(()))
+}"
+        ));
+    }
+
+    #[test]
+    fn test_render_without_pdas() {
+        let mut idl = idl();
+        for account in &mut idl.instructions[0].accounts {
+            account.pda = None;
+        }
+        let instructions = process_instructions(&idl);
+        let rendered = InstructionsStructTemplate {
+            instruction: &instructions[0],
+        }
+        .render()
+        .unwrap();
+
+        assert!(!rendered.contains("find_program_address"));
+        assert!(rendered.contains("pub const UNCHECKED_PDA_ACCOUNTS: &[&str] = &[];"));
+        assert!(rendered.contains(
+            "/// Returns `None` if none of them can be checked.
+pub fn verify_pdas(
+    _accounts: &InitializeVaultInstructionAccounts,
+    _args: &InitializeVault,
+) -> Option<Result<(), carbon_core::pda::PdaMismatch>> {
+    None
+}"
+        ));
+    }
+}
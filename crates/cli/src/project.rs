@@ -9,6 +9,7 @@ pub struct DataSourceData {
 pub struct DecoderData {
     pub name: String,
     pub module_name: String,
+    pub has_accounts: bool,
 }
 
 #[derive(Debug)]
@@ -17,10 +18,29 @@ pub struct MetricsData {
     pub module_name: String,
 }
 
+#[derive(Debug)]
+pub struct StorageData {
+    pub kind: String,
+}
+
 #[derive(Template)]
 #[template(path = "project.askama", escape = "none", ext = ".askama")]
 pub struct ProjectTemplate<'a> {
     pub data_source: &'a DataSourceData,
     pub decoders: &'a [DecoderData],
     pub metrics: &'a MetricsData,
+    pub storage: Option<&'a StorageData>,
+}
+
+#[derive(Template)]
+#[template(path = "storage.askama", escape = "none", ext = ".askama")]
+pub struct StorageTemplate<'a> {
+    pub storage: &'a StorageData,
+}
+
+#[derive(Template)]
+#[template(path = "migration.askama", escape = "none", ext = ".askama")]
+pub struct MigrationTemplate<'a> {
+    pub decoders: &'a [DecoderData],
+    pub storage: &'a StorageData,
 }
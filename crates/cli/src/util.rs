@@ -117,6 +117,10 @@ pub fn idl_type_to_rust_type(idl_type: &LegacyIdlType) -> (String, bool) {
     }
 }
 
+pub fn big_array_attribute(rust_type: &str) -> Option<String> {
+    is_big_array(rust_type).then(|| "#[serde(with = \"serde_big_array::BigArray\")]".to_string())
+}
+
 pub fn is_big_array(rust_type: &str) -> bool {
     if rust_type.starts_with("[") && rust_type.ends_with("]") {
         if let Some(semicolon_index) = rust_type.find(';') {
@@ -130,3 +134,16 @@ pub fn is_big_array(rust_type: &str) -> bool {
     }
     false
 }
+
+pub fn discriminator_bytes(discriminator: &str) -> String {
+    let bytes = hex::decode(discriminator.trim_start_matches("0x")).unwrap_or_default();
+
+    format!(
+        "&[{}]",
+        bytes
+            .iter()
+            .map(|byte| format!("{:#04x}", byte))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
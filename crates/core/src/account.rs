@@ -52,10 +52,17 @@
 //!   handling in the pipeline.
 
 use {
-    crate::{error::CarbonResult, metrics::MetricsCollection, processor::Processor},
+    crate::{
+        decode::{DecodeFailure, DecodeOutcome, DEFAULT_DISCRIMINATOR_LEN},
+        error::CarbonResult,
+        latency::{LatencyMonitor, PipeLatency},
+        metrics::{self, MetricsCollection},
+        processor::Processor,
+        telemetry::{traced, traced_sync},
+    },
     async_trait::async_trait,
     solana_pubkey::Pubkey,
-    std::sync::Arc,
+    std::{sync::Arc, time::Instant},
 };
 
 /// Holds metadata for an account update, including the slot and public key.
@@ -114,6 +121,22 @@ pub struct DecodedAccount<T> {
 ///
 /// - `AccountType`: The data type resulting from decoding the account, specific
 ///   to the application.
+///
+/// # Provided Methods
+///
+/// - `decode_account_at_slot`: Decodes an account observed at a given slot.
+///   Decoders whose layouts changed over the program's lifetime override it
+///   to prefer the layout that was live at that slot. By default the slot is
+///   ignored.
+/// - `program_id`: The program whose accounts the decoder handles. Accounts
+///   owned by it that fail to decode are reported as failures rather than
+///   ignored. Defaults to `None`.
+/// - `diagnose_account`: Explains why an account owned by `program_id` could
+///   not be decoded. Defaults to an unknown discriminator.
+/// - `decode_account_outcome`: Decodes an account at a given slot into a
+///   `DecodeOutcome`, which the `AccountPipe` uses to count failures.
+/// - `name`: The name used for the `decoder` label of the pipe's metrics.
+///   Defaults to the implementing type's name.
 pub trait AccountDecoder<'a> {
     type AccountType;
 
@@ -121,6 +144,43 @@ pub trait AccountDecoder<'a> {
         &self,
         account: &'a solana_account::Account,
     ) -> Option<DecodedAccount<Self::AccountType>>;
+
+    fn decode_account_at_slot(
+        &self,
+        account: &'a solana_account::Account,
+        _slot: u64,
+    ) -> Option<DecodedAccount<Self::AccountType>> {
+        self.decode_account(account)
+    }
+
+    fn program_id(&self) -> Option<Pubkey> {
+        None
+    }
+
+    fn diagnose_account(&self, account: &'a solana_account::Account) -> DecodeFailure {
+        DecodeFailure::unknown_discriminator(&account.data, DEFAULT_DISCRIMINATOR_LEN)
+    }
+
+    fn decode_account_outcome(
+        &self,
+        account: &'a solana_account::Account,
+        slot: u64,
+    ) -> DecodeOutcome<DecodedAccount<Self::AccountType>> {
+        if let Some(decoded_account) = self.decode_account_at_slot(account, slot) {
+            return DecodeOutcome::Decoded(decoded_account);
+        }
+
+        match self.program_id() {
+            Some(program_id) if program_id == account.owner => {
+                DecodeOutcome::Failed(self.diagnose_account(account))
+            }
+            _ => DecodeOutcome::NotOwned,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        metrics::short_type_name(std::any::type_name::<Self>())
+    }
 }
 
 /// The input type for the account processor.
@@ -146,16 +206,20 @@ pub type AccountProcessorInputType<T> = (AccountMetadata, DecodedAccount<T>);
 ///   structured form.
 /// - `processor`: A `Processor` that handles the processing logic for decoded
 ///   accounts.
+/// - `latency`: The pipe's latency totals, set when the pipeline is built.
 pub struct AccountPipe<T: Send> {
     pub decoder: Box<dyn for<'a> AccountDecoder<'a, AccountType = T> + Send + Sync + 'static>,
     pub processor: Box<dyn Processor<InputType = AccountProcessorInputType<T>> + Send + Sync>,
+    pub latency: Option<Arc<PipeLatency>>,
 }
 
 /// A trait for processing account updates in the pipeline asynchronously.
 ///
 /// `AccountPipes` defines the `run` method for processing account updates in
 /// the pipeline. Implementations should handle the decoding and processing of
-/// the account data, and update metrics as needed.
+/// the account data, and update metrics as needed. `AccountPipe` records the
+/// `pipe_*` series labeled with its processor, its decoder and the account's
+/// owner.
 ///
 /// # Example
 ///
@@ -185,10 +249,23 @@ pub trait AccountPipes: Send + Sync {
         account_with_metadata: (AccountMetadata, solana_account::Account),
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
+
+    /// Registers the pipe with the pipeline's latency monitor, so its runs
+    /// are checked against its processor's budget. Called once when the
+    /// pipeline is built.
+    fn register_latency(&mut self, _latency: &LatencyMonitor) {}
 }
 
 #[async_trait]
 impl<T: Send> AccountPipes for AccountPipe<T> {
+    fn register_latency(&mut self, latency: &LatencyMonitor) {
+        self.latency = Some(latency.register(&[
+            ("pipe", "account"),
+            ("processor", self.processor.name()),
+            ("decoder", self.decoder.name()),
+        ]));
+    }
+
     async fn run(
         &mut self,
         account_with_metadata: (AccountMetadata, solana_account::Account),
@@ -199,10 +276,68 @@ impl<T: Send> AccountPipes for AccountPipe<T> {
             account_with_metadata,
         );
 
-        if let Some(decoded_account) = self.decoder.decode_account(&account_with_metadata.1) {
-            self.processor
-                .process((account_with_metadata.0, decoded_account), metrics)
-                .await?;
+        let outcome = traced_sync!(
+            self.decoder
+                .decode_account_outcome(&account_with_metadata.1, account_with_metadata.0.slot),
+            "decode",
+            decoder = self.decoder.name()
+        );
+
+        match outcome {
+            DecodeOutcome::Decoded(decoded_account) => {
+                let pubkey = account_with_metadata.0.pubkey;
+                let program_id = decoded_account.owner.to_string();
+                let labels = [
+                    ("pipe", "account"),
+                    ("processor", self.processor.name()),
+                    ("decoder", self.decoder.name()),
+                    ("program_id", program_id.as_str()),
+                ];
+
+                metrics
+                    .increment_counter("pipe_updates_decoded", &labels, 1)
+                    .await?;
+
+                let start = Instant::now();
+                let result = traced!(
+                    self.processor
+                        .process((account_with_metadata.0, decoded_account), metrics.clone()),
+                    "process",
+                    decoder = self.decoder.name(),
+                    program_id = program_id.as_str(),
+                    slot = account_with_metadata.0.slot
+                )
+                .await;
+
+                metrics
+                    .record_pipe_run(&labels, self.latency.as_deref(), &pubkey, start, result)
+                    .await?;
+            }
+            DecodeOutcome::Failed(failure) => {
+                let program_id = account_with_metadata.1.owner.to_string();
+                let labels = [
+                    ("pipe", "account"),
+                    ("decoder", self.decoder.name()),
+                    ("program_id", program_id.as_str()),
+                ];
+
+                log::debug!(
+                    "{} could not decode account {}: {}",
+                    self.decoder.name(),
+                    account_with_metadata.0.pubkey,
+                    failure
+                );
+
+                metrics
+                    .record_decode_failure(
+                        &labels,
+                        account_with_metadata.0.slot,
+                        &failure,
+                        &account_with_metadata.1.data,
+                    )
+                    .await?;
+            }
+            DecodeOutcome::NotOwned => {}
         }
         Ok(())
     }
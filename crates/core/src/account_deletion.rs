@@ -10,11 +10,14 @@
 
 use {
     crate::{
-        datasource::AccountDeletion, error::CarbonResult, metrics::MetricsCollection,
+        datasource::AccountDeletion,
+        error::CarbonResult,
+        latency::{LatencyMonitor, PipeLatency},
+        metrics::MetricsCollection,
         processor::Processor,
     },
     async_trait::async_trait,
-    std::sync::Arc,
+    std::{sync::Arc, time::Instant},
 };
 
 /// A processing pipe for handling account deletions.
@@ -56,6 +59,7 @@ use {
 ///
 /// - `processor`: A boxed `Processor` that handles the specific logic of
 ///   processing an account deletion event.
+/// - `latency`: The pipe's latency totals, set when the pipeline is built.
 ///
 /// # Notes
 ///
@@ -65,6 +69,7 @@ use {
 ///   managing updates.
 pub struct AccountDeletionPipe {
     pub processor: Box<dyn Processor<InputType = AccountDeletion> + Send + Sync>,
+    pub latency: Option<Arc<PipeLatency>>,
 }
 
 /// A trait for handling account deletion events in the pipeline.
@@ -149,10 +154,22 @@ pub trait AccountDeletionPipes: Send + Sync {
         account_deletion: AccountDeletion,
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
+
+    /// Registers the pipe with the pipeline's latency monitor, so its runs
+    /// are checked against its processor's budget. Called once when the
+    /// pipeline is built.
+    fn register_latency(&mut self, _latency: &LatencyMonitor) {}
 }
 
 #[async_trait]
 impl AccountDeletionPipes for AccountDeletionPipe {
+    fn register_latency(&mut self, latency: &LatencyMonitor) {
+        self.latency = Some(latency.register(&[
+            ("pipe", "account_deletion"),
+            ("processor", self.processor.name()),
+        ]));
+    }
+
     async fn run(
         &mut self,
         account_deletion: AccountDeletion,
@@ -163,8 +180,24 @@ impl AccountDeletionPipes for AccountDeletionPipe {
             account_deletion,
         );
 
-        self.processor.process(account_deletion, metrics).await?;
+        let start = Instant::now();
+        let pubkey = account_deletion.pubkey;
+        let result = self
+            .processor
+            .process(account_deletion, metrics.clone())
+            .await;
 
-        Ok(())
+        metrics
+            .record_pipe_run(
+                &[
+                    ("pipe", "account_deletion"),
+                    ("processor", self.processor.name()),
+                ],
+                self.latency.as_deref(),
+                &pubkey,
+                start,
+                result,
+            )
+            .await
     }
 }
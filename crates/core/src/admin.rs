@@ -0,0 +1,815 @@
+//! An optional HTTP server for probing and controlling a running pipeline.
+//!
+//! When an `AdminServer` is configured on the pipeline builder, the pipeline
+//! serves the following endpoints while it runs:
+//!
+//! - `GET /healthz`: Liveness. Returns `200` while the pipeline's run loop is
+//!   making progress and `503` once it has been stuck for longer than the
+//!   liveness timeout.
+//! - `GET /readyz`: Readiness. Returns `200` when every datasource is running
+//!   and not paused and, if `max_slot_lag` is set, the slot lag is below it. Returns `503`
+//!   with the reason otherwise.
+//! - `GET /status`: A JSON snapshot with the state, latest slot, queue depth,
+//!   dropped updates and error count of each datasource, plus the
+//!   pipeline-wide counters.
+//! - `POST /datasources/pause` and `POST /datasources/resume`: Pause or resume
+//!   all datasources. `POST /datasources/{name}/pause` and
+//!   `POST /datasources/{name}/resume` target the datasources with that name.
+//! - `POST /shutdown`: Shuts the pipeline down according to its
+//!   `ShutdownStrategy`, the same way Ctrl-C does.
+//!
+//! ## Notes
+//!
+//! - The status is read from the series the pipeline already records. The
+//!   admin server adds a `Metrics` implementation to the pipeline that keeps
+//!   the latest counter and gauge values in memory.
+//! - Pausing a datasource drops its updates instead of forwarding them to the
+//!   pipeline. The datasources send with `try_send`, so holding their updates
+//!   back would only make them drop updates themselves once the channel is
+//!   full. The dropped updates are counted in `datasource_updates_dropped`.
+//! - A connection that does not send its request head within 5 seconds is
+//!   closed.
+//! - The server has no authentication. Bind it to a private address.
+
+use {
+    crate::{
+        error::{CarbonResult, Error},
+        metrics::{series_key, Metrics},
+    },
+    async_trait::async_trait,
+    std::{
+        collections::HashMap,
+        net::SocketAddr,
+        sync::{
+            atomic::{AtomicBool, AtomicU64, Ordering},
+            Arc, Mutex, RwLock,
+        },
+        time::{Duration, Instant},
+    },
+    tokio::{
+        io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+        net::{TcpListener, TcpStream},
+        sync::watch,
+        task::JoinHandle,
+    },
+    tokio_util::sync::CancellationToken,
+};
+
+/// The default time without run loop progress after which `/healthz` fails.
+pub const DEFAULT_LIVENESS_TIMEOUT: Duration = Duration::from_secs(60);
+
+const MAX_REQUEST_HEAD_LINES: usize = 100;
+
+/// How long a connection may take to send its request head.
+const REQUEST_READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Configuration of the admin HTTP server.
+///
+/// # Fields
+///
+/// - `address`: The address to serve the endpoints on.
+/// - `max_slot_lag`: The slot lag above which `/readyz` reports the pipeline
+///   as not ready. If `None`, the lag is not checked.
+/// - `liveness_timeout`: How long the run loop may go without progress before
+///   `/healthz` fails. Defaults to 60 seconds.
+///
+/// # Example
+///
+/// ```rust
+/// let builder = PipelineBuilder::new()
+///     .admin_server(
+///         AdminServer::new(([0, 0, 0, 0], 8080))
+///             .max_slot_lag(150)
+///             .liveness_timeout(Duration::from_secs(30)),
+///     );
+/// ```
+pub struct AdminServer {
+    pub address: SocketAddr,
+    pub max_slot_lag: Option<u64>,
+    pub liveness_timeout: Duration,
+    status_metrics: Arc<StatusMetrics>,
+}
+
+impl AdminServer {
+    pub fn new(address: impl Into<SocketAddr>) -> Self {
+        Self {
+            address: address.into(),
+            max_slot_lag: None,
+            liveness_timeout: DEFAULT_LIVENESS_TIMEOUT,
+            status_metrics: Arc::new(StatusMetrics::default()),
+        }
+    }
+
+    /// Sets the slot lag above which `/readyz` fails.
+    pub fn max_slot_lag(mut self, max_slot_lag: u64) -> Self {
+        self.max_slot_lag = Some(max_slot_lag);
+        self
+    }
+
+    /// Sets how long the run loop may go without progress before `/healthz`
+    /// fails.
+    pub fn liveness_timeout(mut self, liveness_timeout: Duration) -> Self {
+        self.liveness_timeout = liveness_timeout;
+        self
+    }
+
+    /// Returns the metrics sink the status endpoints read from, to be added to
+    /// the pipeline's `MetricsCollection`.
+    pub(crate) fn status_metrics(&self) -> Arc<StatusMetrics> {
+        Arc::clone(&self.status_metrics)
+    }
+
+    /// Binds the listener and serves the endpoints until the returned task is
+    /// dropped.
+    pub(crate) async fn start(&self, state: Arc<AdminState>) -> CarbonResult<AdminServerTask> {
+        let listener = TcpListener::bind(self.address).await.map_err(|err| {
+            Error::Custom(format!(
+                "Failed to bind admin server to {}: {err}",
+                self.address
+            ))
+        })?;
+
+        log::info!("admin server listening on {}", self.address);
+
+        let config = Arc::new(AdminConfig {
+            max_slot_lag: self.max_slot_lag,
+            liveness_timeout: self.liveness_timeout,
+            read_timeout: REQUEST_READ_TIMEOUT,
+            status_metrics: self.status_metrics(),
+        });
+
+        let task = tokio::spawn(async move {
+            loop {
+                let (stream, _) = match listener.accept().await {
+                    Ok(connection) => connection,
+                    Err(err) => {
+                        log::error!("admin server failed to accept a connection: {}", err);
+                        continue;
+                    }
+                };
+
+                let state = Arc::clone(&state);
+                let config = Arc::clone(&config);
+                tokio::spawn(async move {
+                    if let Err(err) = handle_connection(stream, &state, &config).await {
+                        log::debug!("admin server connection error: {}", err);
+                    }
+                });
+            }
+        });
+
+        Ok(AdminServerTask(task))
+    }
+}
+
+/// The admin server's accept loop, stopped when dropped.
+pub(crate) struct AdminServerTask(JoinHandle<()>);
+
+impl Drop for AdminServerTask {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+struct AdminConfig {
+    max_slot_lag: Option<u64>,
+    liveness_timeout: Duration,
+    read_timeout: Duration,
+    status_metrics: Arc<StatusMetrics>,
+}
+
+/// The lifecycle state of a datasource.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum DatasourceState {
+    Running,
+    Finished,
+    Failed(String),
+}
+
+/// Tracks the state of a datasource and lets the admin server pause it. The
+/// updates of a paused datasource are dropped.
+pub(crate) struct DatasourceControl {
+    pub(crate) name: &'static str,
+    state: Mutex<DatasourceState>,
+    paused: watch::Sender<bool>,
+}
+
+impl DatasourceControl {
+    pub(crate) fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            state: Mutex::new(DatasourceState::Running),
+            paused: watch::Sender::new(false),
+        }
+    }
+
+    pub(crate) fn set_state(&self, state: DatasourceState) {
+        *self.state.lock().unwrap() = state;
+    }
+
+    /// Marks the datasource as finished, unless it has failed.
+    pub(crate) fn mark_finished(&self) {
+        let mut state = self.state.lock().unwrap();
+        if *state == DatasourceState::Running {
+            *state = DatasourceState::Finished;
+        }
+    }
+
+    fn state(&self) -> DatasourceState {
+        self.state.lock().unwrap().clone()
+    }
+
+    fn set_paused(&self, paused: bool) {
+        self.paused.send_replace(paused);
+    }
+
+    pub(crate) fn is_paused(&self) -> bool {
+        *self.paused.borrow()
+    }
+}
+
+/// The pipeline state shared with the admin server.
+pub(crate) struct AdminState {
+    datasources: Vec<Arc<DatasourceControl>>,
+    shutdown: CancellationToken,
+    shutting_down: AtomicBool,
+    started: Instant,
+    last_heartbeat_millis: AtomicU64,
+}
+
+impl AdminState {
+    pub(crate) fn new(
+        datasources: Vec<Arc<DatasourceControl>>,
+        shutdown: CancellationToken,
+    ) -> Self {
+        Self {
+            datasources,
+            shutdown,
+            shutting_down: AtomicBool::new(false),
+            started: Instant::now(),
+            last_heartbeat_millis: AtomicU64::new(0),
+        }
+    }
+
+    /// Records that the run loop is making progress.
+    pub(crate) fn heartbeat(&self) {
+        self.last_heartbeat_millis
+            .store(self.started.elapsed().as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Resumes all datasources, so the updates they still send are processed
+    /// during a shutdown.
+    pub(crate) fn resume_datasources(&self) {
+        self.set_paused(None, false);
+    }
+
+    pub(crate) fn set_shutting_down(&self) {
+        self.shutting_down.store(true, Ordering::Relaxed);
+    }
+
+    fn since_last_heartbeat(&self) -> Duration {
+        let last_heartbeat =
+            Duration::from_millis(self.last_heartbeat_millis.load(Ordering::Relaxed));
+        self.started.elapsed().saturating_sub(last_heartbeat)
+    }
+
+    fn is_healthy(&self, config: &AdminConfig) -> bool {
+        self.since_last_heartbeat() <= config.liveness_timeout
+    }
+
+    /// Returns why the pipeline is not ready, or `None` if it is.
+    fn not_ready_reason(&self, config: &AdminConfig) -> Option<String> {
+        if self.shutting_down.load(Ordering::Relaxed) {
+            return Some("pipeline is shutting down".to_string());
+        }
+
+        for datasource in &self.datasources {
+            if datasource.is_paused() {
+                return Some(format!("datasource {} is paused", datasource.name));
+            }
+
+            match datasource.state() {
+                DatasourceState::Running => {}
+                DatasourceState::Finished => {
+                    return Some(format!("datasource {} has finished", datasource.name))
+                }
+                DatasourceState::Failed(error) => {
+                    return Some(format!(
+                        "datasource {} has failed: {}",
+                        datasource.name, error
+                    ))
+                }
+            }
+        }
+
+        if let Some(max_slot_lag) = config.max_slot_lag {
+            let slot_lag = config.status_metrics.gauge("slot_lag", &[]) as u64;
+            if slot_lag > max_slot_lag {
+                return Some(format!("slot lag {} is above {}", slot_lag, max_slot_lag));
+            }
+        }
+
+        None
+    }
+
+    fn status(&self, config: &AdminConfig) -> serde_json::Value {
+        let metrics = &config.status_metrics;
+
+        let datasources = self
+            .datasources
+            .iter()
+            .map(|datasource| {
+                let labels = [("datasource", datasource.name)];
+                let updates_received: u64 = ["account", "transaction", "account_deletion"]
+                    .iter()
+                    .map(|update_type| {
+                        metrics.counter(
+                            "datasource_updates_received",
+                            &[
+                                ("datasource", datasource.name),
+                                ("update_type", update_type),
+                            ],
+                        )
+                    })
+                    .sum();
+
+                let (state, error) = match datasource.state() {
+                    DatasourceState::Running if datasource.is_paused() => ("paused", None),
+                    DatasourceState::Running => ("running", None),
+                    DatasourceState::Finished => ("finished", None),
+                    DatasourceState::Failed(error) => ("failed", Some(error)),
+                };
+
+                serde_json::json!({
+                    "name": datasource.name,
+                    "state": state,
+                    "error": error,
+                    "latest_slot": metrics.gauge("datasource_latest_slot", &labels) as u64,
+                    "updates_received": updates_received,
+                    "updates_queued": metrics.gauge("datasource_updates_queued", &labels) as u64,
+                    "updates_dropped": metrics.counter("datasource_updates_dropped", &labels),
+                    "errors": metrics.counter("datasource_errors", &labels),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::json!({
+            "uptime_seconds": self.started.elapsed().as_secs(),
+            "healthy": self.is_healthy(config),
+            "ready": self.not_ready_reason(config).is_none(),
+            "shutting_down": self.shutting_down.load(Ordering::Relaxed),
+            "highest_slot_processed": metrics.gauge("highest_slot_processed", &[]) as u64,
+            "slot_lag": metrics.gauge("slot_lag", &[]) as u64,
+            "updates": {
+                "received": metrics.counter("updates_received", &[]),
+                "processed": metrics.counter("updates_processed", &[]),
+                "successful": metrics.counter("updates_successful", &[]),
+                "failed": metrics.counter("updates_failed", &[]),
+                "queued": metrics.gauge("updates_queued", &[]) as u64,
+            },
+            "datasources": datasources,
+        })
+    }
+
+    /// Pauses or resumes the datasources called `name`, or all datasources if
+    /// `name` is `None`. Returns the number of datasources affected.
+    fn set_paused(&self, name: Option<&str>, paused: bool) -> usize {
+        let mut affected = 0;
+
+        for datasource in &self.datasources {
+            if name.is_none_or(|name| name == datasource.name) {
+                datasource.set_paused(paused);
+                affected += 1;
+            }
+        }
+
+        affected
+    }
+}
+
+/// Keeps the latest value of every counter and gauge series so the admin
+/// endpoints can report them.
+#[derive(Default)]
+pub(crate) struct StatusMetrics {
+    counters: RwLock<HashMap<String, u64>>,
+    gauges: RwLock<HashMap<String, f64>>,
+}
+
+impl StatusMetrics {
+    fn counter(&self, name: &str, labels: &[(&str, &str)]) -> u64 {
+        self.counters
+            .read()
+            .unwrap()
+            .get(&series_key(name, labels))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn gauge(&self, name: &str, labels: &[(&str, &str)]) -> f64 {
+        self.gauges
+            .read()
+            .unwrap()
+            .get(&series_key(name, labels))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl Metrics for StatusMetrics {
+    async fn initialize(&self) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    async fn flush(&self) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    async fn shutdown(&self) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    async fn update_gauge(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        self.gauges
+            .write()
+            .unwrap()
+            .insert(series_key(name, labels), value);
+        Ok(())
+    }
+
+    async fn increment_counter(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: u64,
+    ) -> CarbonResult<()> {
+        *self
+            .counters
+            .write()
+            .unwrap()
+            .entry(series_key(name, labels))
+            .or_default() += value;
+        Ok(())
+    }
+
+    async fn record_histogram(
+        &self,
+        _name: &str,
+        _labels: &[(&str, &str)],
+        _value: f64,
+    ) -> CarbonResult<()> {
+        Ok(())
+    }
+}
+
+struct Response {
+    status: u16,
+    content_type: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn text(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            body: body.into(),
+        }
+    }
+
+    fn json(value: serde_json::Value) -> Self {
+        Self {
+            status: 200,
+            content_type: "application/json",
+            body: value.to_string(),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            202 => "Accepted",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            503 => "Service Unavailable",
+            _ => "",
+        }
+    }
+}
+
+async fn handle_connection(
+    stream: TcpStream,
+    state: &AdminState,
+    config: &AdminConfig,
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream);
+
+    let request_line = tokio::time::timeout(config.read_timeout, read_request_head(&mut reader))
+        .await
+        .map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "timed out reading the request head",
+            )
+        })??;
+
+    let mut parts = request_line.split_whitespace();
+    let response = match (parts.next(), parts.next()) {
+        (Some(method), Some(target)) => {
+            let path = target.split('?').next().unwrap_or(target);
+            route(method, path, state, config)
+        }
+        _ => Response::text(400, "bad request"),
+    };
+
+    let mut stream = reader.into_inner();
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        response.reason(),
+        response.content_type,
+        response.body.len(),
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(response.body.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Reads the request line and skips the headers; none of the endpoints take a
+/// body.
+async fn read_request_head(reader: &mut BufReader<TcpStream>) -> std::io::Result<String> {
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+
+    for _ in 0..MAX_REQUEST_HEAD_LINES {
+        let mut header = String::new();
+        if reader.read_line(&mut header).await? == 0 || header.trim().is_empty() {
+            break;
+        }
+    }
+
+    Ok(request_line)
+}
+
+fn route(method: &str, path: &str, state: &AdminState, config: &AdminConfig) -> Response {
+    let segments = path
+        .trim_matches('/')
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+
+    match (method, segments.as_slice()) {
+        ("GET", ["healthz"]) => {
+            if state.is_healthy(config) {
+                Response::text(200, "ok")
+            } else {
+                Response::text(
+                    503,
+                    format!("no progress for {:?}", state.since_last_heartbeat()),
+                )
+            }
+        }
+        ("GET", ["readyz"]) => match state.not_ready_reason(config) {
+            None => Response::text(200, "ready"),
+            Some(reason) => Response::text(503, reason),
+        },
+        ("GET", ["status"]) => Response::json(state.status(config)),
+        ("POST", ["datasources", action @ ("pause" | "resume")]) => {
+            let affected = state.set_paused(None, *action == "pause");
+            log::info!("admin server: {}d {} datasource(s)", action, affected);
+            Response::text(200, format!("{}d {} datasource(s)", action, affected))
+        }
+        ("POST", ["datasources", name, action @ ("pause" | "resume")]) => {
+            match state.set_paused(Some(name), *action == "pause") {
+                0 => Response::text(404, format!("unknown datasource {}", name)),
+                affected => {
+                    log::info!("admin server: {}d datasource {}", action, name);
+                    Response::text(200, format!("{}d {} datasource(s)", action, affected))
+                }
+            }
+        }
+        ("POST", ["shutdown"]) => {
+            log::info!("admin server: shutdown requested");
+            state.shutdown.cancel();
+            Response::text(202, "shutting down")
+        }
+        (_, ["healthz" | "readyz" | "status" | "shutdown"]) | (_, ["datasources", ..]) => {
+            Response::text(405, "method not allowed")
+        }
+        _ => Response::text(404, "not found"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        tokio::io::{AsyncReadExt, AsyncWriteExt},
+    };
+
+    fn admin(datasources: &[&'static str]) -> (AdminState, AdminConfig) {
+        let state = AdminState::new(
+            datasources
+                .iter()
+                .map(|name| Arc::new(DatasourceControl::new(name)))
+                .collect(),
+            CancellationToken::new(),
+        );
+        let config = AdminConfig {
+            max_slot_lag: Some(100),
+            liveness_timeout: Duration::from_secs(60),
+            read_timeout: Duration::from_millis(100),
+            status_metrics: Arc::new(StatusMetrics::default()),
+        };
+        (state, config)
+    }
+
+    #[test]
+    fn test_healthz() {
+        let (state, mut config) = admin(&["rpc"]);
+        state.heartbeat();
+        assert_eq!(route("GET", "/healthz", &state, &config).status, 200);
+
+        config.liveness_timeout = Duration::ZERO;
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(route("GET", "/healthz", &state, &config).status, 503);
+
+        assert_eq!(route("POST", "/healthz", &state, &config).status, 405);
+    }
+
+    #[tokio::test]
+    async fn test_readyz() {
+        let (state, config) = admin(&["rpc", "grpc"]);
+        assert_eq!(route("GET", "/readyz", &state, &config).status, 200);
+
+        config
+            .status_metrics
+            .update_gauge("slot_lag", &[], 150.0)
+            .await
+            .unwrap();
+        let response = route("GET", "/readyz", &state, &config);
+        assert_eq!(response.status, 503);
+        assert_eq!(response.body, "slot lag 150 is above 100");
+
+        config
+            .status_metrics
+            .update_gauge("slot_lag", &[], 10.0)
+            .await
+            .unwrap();
+        state.datasources[1].set_state(DatasourceState::Failed("closed".to_string()));
+        let response = route("GET", "/readyz", &state, &config);
+        assert_eq!(response.status, 503);
+        assert_eq!(response.body, "datasource grpc has failed: closed");
+
+        state.datasources[1].set_state(DatasourceState::Running);
+        state.set_shutting_down();
+        let response = route("GET", "/readyz", &state, &config);
+        assert_eq!(response.status, 503);
+        assert_eq!(response.body, "pipeline is shutting down");
+    }
+
+    #[tokio::test]
+    async fn test_status() {
+        let (state, config) = admin(&["rpc"]);
+        let metrics = &config.status_metrics;
+        for (update_type, value) in [("account", 3), ("transaction", 4)] {
+            metrics
+                .increment_counter(
+                    "datasource_updates_received",
+                    &[("datasource", "rpc"), ("update_type", update_type)],
+                    value,
+                )
+                .await
+                .unwrap();
+        }
+        metrics
+            .increment_counter("datasource_updates_dropped", &[("datasource", "rpc")], 2)
+            .await
+            .unwrap();
+        metrics
+            .update_gauge("datasource_latest_slot", &[("datasource", "rpc")], 42.0)
+            .await
+            .unwrap();
+        metrics
+            .increment_counter("updates_processed", &[], 7)
+            .await
+            .unwrap();
+
+        let response = route("GET", "/status", &state, &config);
+        assert_eq!(response.status, 200);
+        assert_eq!(response.content_type, "application/json");
+
+        let status: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(status["updates"]["processed"], 7);
+        assert_eq!(status["datasources"][0]["name"], "rpc");
+        assert_eq!(status["datasources"][0]["state"], "running");
+        assert_eq!(status["datasources"][0]["latest_slot"], 42);
+        assert_eq!(status["datasources"][0]["updates_received"], 7);
+        assert_eq!(status["datasources"][0]["updates_dropped"], 2);
+    }
+
+    #[test]
+    fn test_pause_and_resume() {
+        let (state, config) = admin(&["rpc", "grpc"]);
+
+        let response = route("POST", "/datasources/grpc/pause", &state, &config);
+        assert_eq!(response.status, 200);
+        assert!(!state.datasources[0].is_paused());
+        assert!(state.datasources[1].is_paused());
+        assert_eq!(
+            route("GET", "/readyz", &state, &config).body,
+            "datasource grpc is paused"
+        );
+
+        let status = state.status(&config);
+        assert_eq!(status["datasources"][1]["state"], "paused");
+
+        assert_eq!(
+            route("POST", "/datasources/pause", &state, &config).body,
+            "paused 2 datasource(s)"
+        );
+        assert!(state
+            .datasources
+            .iter()
+            .all(|datasource| datasource.is_paused()));
+
+        assert_eq!(
+            route("POST", "/datasources/resume", &state, &config).body,
+            "resumed 2 datasource(s)"
+        );
+        assert!(state
+            .datasources
+            .iter()
+            .all(|datasource| !datasource.is_paused()));
+        assert_eq!(route("GET", "/readyz", &state, &config).status, 200);
+
+        assert_eq!(
+            route("POST", "/datasources/unknown/pause", &state, &config).status,
+            404
+        );
+        assert_eq!(
+            route("GET", "/datasources/pause", &state, &config).status,
+            405
+        );
+    }
+
+    #[test]
+    fn test_shutdown() {
+        let (state, config) = admin(&["rpc"]);
+        assert_eq!(route("POST", "/shutdown", &state, &config).status, 202);
+        assert!(state.shutdown.is_cancelled());
+    }
+
+    async fn connect(state: Arc<AdminState>, config: AdminConfig) -> (TcpStream, JoinHandle<()>) {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let _ = handle_connection(stream, &state, &config).await;
+        });
+
+        (TcpStream::connect(address).await.unwrap(), server)
+    }
+
+    #[tokio::test]
+    async fn test_handle_connection() {
+        let (state, config) = admin(&["rpc"]);
+        let (mut stream, server) = connect(Arc::new(state), config).await;
+
+        stream
+            .write_all(b"GET /readyz?verbose=1 HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        server.await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Length: 5\r\n"));
+        assert!(response.ends_with("\r\n\r\nready"));
+    }
+
+    #[tokio::test]
+    async fn test_handle_connection_read_timeout() {
+        let (state, config) = admin(&["rpc"]);
+        let (mut stream, server) = connect(Arc::new(state), config).await;
+
+        // A request head that is never finished.
+        stream.write_all(b"GET /status HTTP/1.1\r\n").await.unwrap();
+
+        tokio::time::timeout(Duration::from_secs(5), server)
+            .await
+            .expect("the connection should be closed after the read timeout")
+            .unwrap();
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).await.unwrap();
+        assert!(response.is_empty());
+    }
+}
@@ -34,7 +34,10 @@
 //!   data and sending updates to the pipeline.
 
 use {
-    crate::{error::CarbonResult, metrics::MetricsCollection},
+    crate::{
+        error::CarbonResult,
+        metrics::{self, MetricsCollection},
+    },
     async_trait::async_trait,
     solana_account::Account,
     solana_pubkey::Pubkey,
@@ -61,6 +64,11 @@ use {
 /// - `update_types`: Returns a list of `UpdateType` variants indicating the
 ///   types of updates the datasource can provide.
 ///
+/// # Provided Methods
+///
+/// - `name`: The name used for the `datasource` label of the pipeline's
+///   metrics. Defaults to the implementing type's name.
+///
 /// # Example
 ///
 /// ```rust
@@ -97,6 +105,10 @@ pub trait Datasource: Send + Sync {
     ) -> CarbonResult<()>;
 
     fn update_types(&self) -> Vec<UpdateType>;
+
+    fn name(&self) -> &'static str {
+        metrics::short_type_name(std::any::type_name::<Self>())
+    }
 }
 
 /// Represents a data update in the `carbon-core` pipeline, encompassing
@@ -113,6 +125,26 @@ pub enum Update {
     AccountDeletion(AccountDeletion),
 }
 
+impl Update {
+    /// Returns the value used for the `update_type` metrics label.
+    pub fn type_label(&self) -> &'static str {
+        match self {
+            Update::Account(_) => "account",
+            Update::Transaction(_) => "transaction",
+            Update::AccountDeletion(_) => "account_deletion",
+        }
+    }
+
+    /// Returns the slot the update was observed at.
+    pub fn slot(&self) -> u64 {
+        match self {
+            Update::Account(account_update) => account_update.slot,
+            Update::Transaction(transaction_update) => transaction_update.slot,
+            Update::AccountDeletion(account_deletion) => account_deletion.slot,
+        }
+    }
+}
+
 /// Enumerates the types of updates a datasource can provide.
 ///
 /// The `UpdateType` enum categorizes updates into three types:
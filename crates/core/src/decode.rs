@@ -0,0 +1,308 @@
+//! Structured outcomes for decoding accounts and instructions.
+//!
+//! An `AccountDecoder` or `InstructionDecoder` returns `None` both for data
+//! that belongs to another program and for data of its own program that it
+//! cannot read. The second case usually means the program was upgraded with a
+//! new or changed layout, and is worth knowing about. This module describes
+//! why decoding failed, so the pipeline can tell the two cases apart.
+//!
+//! ## Key Components
+//!
+//! - **`DecodeOutcome`**: The result of running a decoder on an update: the
+//!   decoded value, data that is not owned by the decoder's program, or a
+//!   failure.
+//! - **`DecodeFailure`**: Why data owned by the decoder's program could not be
+//!   decoded: an unknown discriminator, or a layout that does not deserialize,
+//!   together with the offset where reading stopped.
+//! - **`DecodeFailureSampler`**: Writes the raw bytes of failed updates to a
+//!   file as NDJSON, a few per program and failure, to inspect IDL upgrades.
+//!
+//! ## Pipeline Integration
+//!
+//! Account and instruction pipes call `decode_account_outcome` and
+//! `decode_instruction_outcome`, and count failures under the
+//! `pipe_decode_failures` series, labeled with `pipe`, `decoder`,
+//! `program_id` and `reason`. A sampler is configured with
+//! `PipelineBuilder::decode_failure_sampler`.
+//!
+//! ## Notes
+//!
+//! - Failures are only reported by decoders that override `program_id`.
+//!   Without it, data the decoder cannot read is treated as not owned, as
+//!   before.
+//! - The System Program decoder does not override `program_id` for accounts,
+//!   since most System-owned accounts are wallets without data.
+//! - Decoders generated by the CLI override `diagnose_account` and
+//!   `diagnose_instruction` with `diagnose_decode_failure!`, which tells an
+//!   unknown discriminator apart from a deserialize error.
+
+use {
+    crate::error::{CarbonResult, Error},
+    serde_json::{Map, Value},
+    std::{
+        collections::HashMap,
+        fmt,
+        fs::OpenOptions,
+        io::{self, Write},
+        path::Path,
+        sync::Mutex,
+    },
+};
+
+/// The number of leading bytes reported as the discriminator when the
+/// decoder does not know the discriminator length.
+pub const DEFAULT_DISCRIMINATOR_LEN: usize = 8;
+
+/// The result of decoding an account or instruction.
+///
+/// # Variants
+///
+/// - `Decoded`: The data was decoded.
+/// - `NotOwned`: The data belongs to a program the decoder does not handle.
+/// - `Failed`: The data belongs to the decoder's program but could not be
+///   decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeOutcome<T> {
+    Decoded(T),
+    NotOwned,
+    Failed(DecodeFailure),
+}
+
+impl<T> DecodeOutcome<T> {
+    /// Returns the decoded value, discarding why decoding failed.
+    pub fn into_decoded(self) -> Option<T> {
+        match self {
+            DecodeOutcome::Decoded(decoded) => Some(decoded),
+            DecodeOutcome::NotOwned | DecodeOutcome::Failed(_) => None,
+        }
+    }
+
+    /// Returns the failure, if the data was owned but could not be decoded.
+    pub fn failure(&self) -> Option<&DecodeFailure> {
+        match self {
+            DecodeOutcome::Failed(failure) => Some(failure),
+            DecodeOutcome::Decoded(_) | DecodeOutcome::NotOwned => None,
+        }
+    }
+}
+
+/// Why data owned by a decoder's program could not be decoded.
+///
+/// # Variants
+///
+/// - `UnknownDiscriminator`: No known layout starts with the data's
+///   discriminator. `discriminator` holds the leading bytes of the data.
+/// - `Deserialize`: The discriminator of `type_name` matched, but the rest of
+///   the data did not deserialize. `offset` is the byte offset into the data,
+///   discriminator included, where reading stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeFailure {
+    UnknownDiscriminator {
+        discriminator: Vec<u8>,
+    },
+    Deserialize {
+        type_name: &'static str,
+        offset: usize,
+        message: String,
+    },
+}
+
+impl DecodeFailure {
+    /// Creates an `UnknownDiscriminator` failure holding the first `length`
+    /// bytes of `data`, or all of it if it is shorter.
+    pub fn unknown_discriminator(data: &[u8], length: usize) -> Self {
+        DecodeFailure::UnknownDiscriminator {
+            discriminator: data[..length.min(data.len())].to_vec(),
+        }
+    }
+
+    /// The value of the `reason` label of the `pipe_decode_failures` series.
+    pub fn reason(&self) -> &'static str {
+        match self {
+            DecodeFailure::UnknownDiscriminator { .. } => "unknown_discriminator",
+            DecodeFailure::Deserialize { .. } => "deserialize_error",
+        }
+    }
+
+    /// Returns whichever of `self` and `other` says more about the data.
+    ///
+    /// A deserialize error, where a discriminator matched, is preferred over
+    /// an unknown discriminator. Between two deserialize errors, the one that
+    /// read further into the data is preferred.
+    pub fn most_specific(self, other: DecodeFailure) -> DecodeFailure {
+        match (&self, &other) {
+            (
+                DecodeFailure::Deserialize { offset, .. },
+                DecodeFailure::Deserialize {
+                    offset: other_offset,
+                    ..
+                },
+            ) if other_offset > offset => other,
+            (DecodeFailure::Deserialize { .. }, _) => self,
+            (_, DecodeFailure::Deserialize { .. }) => other,
+            (
+                DecodeFailure::UnknownDiscriminator { discriminator },
+                DecodeFailure::UnknownDiscriminator {
+                    discriminator: other_discriminator,
+                },
+            ) if other_discriminator.len() > discriminator.len() => other,
+            _ => self,
+        }
+    }
+}
+
+impl fmt::Display for DecodeFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeFailure::UnknownDiscriminator { discriminator } => {
+                write!(f, "unknown discriminator 0x{}", to_hex(discriminator))
+            }
+            DecodeFailure::Deserialize {
+                type_name,
+                offset,
+                message,
+            } => write!(
+                f,
+                "failed to deserialize {type_name} at offset {offset}: {message}"
+            ),
+        }
+    }
+}
+
+/// Writes the raw data of failed decodes to a file as NDJSON.
+///
+/// Each line holds the labels of the failing pipe, the slot, the failure and
+/// the data as a hex string. At most `max_samples` lines are written for each
+/// pipe, program and failure, where failures count as distinct per
+/// discriminator or per type that did not deserialize, so a new layout shows
+/// up even after older failures filled their share.
+///
+/// # Example
+///
+/// ```ignore
+/// carbon_core::pipeline::Pipeline::builder()
+///     .datasource(datasource)
+///     .instruction(PumpfunDecoder, PumpfunProcessor)
+///     .decode_failure_sampler(
+///         DecodeFailureSampler::file("decode_failures.ndjson")?.max_samples(5),
+///     )
+///     .build()?
+///     .run()
+///     .await?;
+/// ```
+pub struct DecodeFailureSampler {
+    writer: Mutex<Box<dyn Write + Send + Sync>>,
+    max_samples: usize,
+    sampled: Mutex<HashMap<String, usize>>,
+}
+
+impl DecodeFailureSampler {
+    /// Creates a sampler that appends to the file at `path`, creating it if it
+    /// does not exist.
+    pub fn file(path: impl AsRef<Path>) -> CarbonResult<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path.as_ref())
+            .map_err(|err| {
+                Error::Custom(format!("Failed to open {}: {err}", path.as_ref().display()))
+            })?;
+
+        Ok(Self::new(Box::new(io::BufWriter::new(file))))
+    }
+
+    /// Creates a sampler that writes to any writer.
+    pub fn new(writer: Box<dyn Write + Send + Sync>) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+            max_samples: 10,
+            sampled: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets how many samples are written for each pipe, program and failure.
+    /// Defaults to 10.
+    pub fn max_samples(mut self, max_samples: usize) -> Self {
+        self.max_samples = max_samples;
+        self
+    }
+
+    /// Writes a sample of `data` unless enough samples of the same failure
+    /// were already written for `labels`.
+    ///
+    /// # Parameters
+    ///
+    /// - `labels`: The labels of the pipe's series, written as fields of the
+    ///   sample.
+    /// - `slot`: The slot of the update that failed to decode.
+    /// - `failure`: Why decoding failed.
+    /// - `data`: The raw account or instruction data.
+    pub fn sample(
+        &self,
+        labels: &[(&str, &str)],
+        slot: u64,
+        failure: &DecodeFailure,
+        data: &[u8],
+    ) -> CarbonResult<()> {
+        let detail = match failure {
+            DecodeFailure::UnknownDiscriminator { discriminator } => to_hex(discriminator),
+            DecodeFailure::Deserialize { type_name, .. } => type_name.to_string(),
+        };
+        let key = format!(
+            "{}/{detail}",
+            crate::metrics::series_key(failure.reason(), labels)
+        );
+
+        {
+            let mut sampled = self
+                .sampled
+                .lock()
+                .map_err(|_| Error::Custom("Decode failure sampler poisoned".to_string()))?;
+            let count = sampled.entry(key).or_insert(0);
+            if *count >= self.max_samples {
+                return Ok(());
+            }
+            *count += 1;
+        }
+
+        let mut record = Map::new();
+        for (key, value) in labels {
+            record.insert(key.to_string(), Value::from(*value));
+        }
+        record.insert("slot".to_string(), Value::from(slot));
+        record.insert("reason".to_string(), Value::from(failure.reason()));
+        match failure {
+            DecodeFailure::UnknownDiscriminator { discriminator } => {
+                record.insert(
+                    "discriminator".to_string(),
+                    Value::from(to_hex(discriminator)),
+                );
+            }
+            DecodeFailure::Deserialize {
+                type_name,
+                offset,
+                message,
+            } => {
+                record.insert("type_name".to_string(), Value::from(*type_name));
+                record.insert("offset".to_string(), Value::from(*offset));
+                record.insert("message".to_string(), Value::from(message.as_str()));
+            }
+        }
+        record.insert("data".to_string(), Value::from(to_hex(data)));
+
+        let mut writer = self
+            .writer
+            .lock()
+            .map_err(|_| Error::Custom("Decode failure sampler poisoned".to_string()))?;
+        serde_json::to_writer(&mut *writer, &record)
+            .map_err(|err| Error::Custom(format!("Failed to write decode failure: {err}")))?;
+        writer
+            .write_all(b"\n")
+            .and_then(|_| writer.flush())
+            .map_err(|err| Error::Custom(format!("Failed to write decode failure: {err}")))
+    }
+}
+
+fn to_hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -9,10 +9,13 @@
 //! # Overview
 //!
 //! - **`CarbonDeserialize`**: A trait for custom deserialization of data
-//!   structures from byte slices.
+//!   structures from byte slices, with `try_deserialize` reporting why
+//!   deserialization failed.
 //! - **`extract_discriminator`**: A function that separates a discriminator
 //!   from the rest of a byte slice, used for parsing data with prefixed
 //!   discriminators.
+//! - **`deserialize_with_trailing_bytes`**: A lenient deserializer that
+//!   ignores bytes left after the last field, for version-aware decoders.
 //! - **`ArrangeAccounts`**: A trait that allows for defining a specific
 //!   arrangement of accounts, suitable for handling Solana account metadata in
 //!   a customized way.
@@ -26,9 +29,12 @@
 //! - Implement `ArrangeAccounts` when you need to access account metadata for
 //!   Solana instructions.
 
-use std::{
-    io::{Error, ErrorKind, Read, Result},
-    ops::Deref,
+use {
+    crate::decode::DecodeFailure,
+    std::{
+        io::{Error, ErrorKind, Read, Result},
+        ops::Deref,
+    },
 };
 /// A trait for custom deserialization of types from byte slices.
 ///
@@ -44,11 +50,21 @@ use std::{
 ///   which is useful for processing raw blockchain data.
 /// - Ensure the data slice passed to `deserialize` is valid and of appropriate
 ///   length to avoid errors.
+/// - `try_deserialize` reports why the data could not be deserialized. The
+///   `CarbonDeserialize` derive tells an unknown discriminator apart from a
+///   layout that does not match. The default implementation reports every
+///   failure as an unknown discriminator.
 pub trait CarbonDeserialize
 where
     Self: Sized + crate::borsh::BorshDeserialize,
 {
     fn deserialize(data: &[u8]) -> Option<Self>;
+
+    fn try_deserialize(data: &[u8]) -> std::result::Result<Self, DecodeFailure> {
+        <Self as CarbonDeserialize>::deserialize(data).ok_or_else(|| {
+            DecodeFailure::unknown_discriminator(data, crate::decode::DEFAULT_DISCRIMINATOR_LEN)
+        })
+    }
 }
 
 /// Extracts a discriminator from the beginning of a byte slice and returns the
@@ -91,6 +107,32 @@ pub fn extract_discriminator(length: usize, data: &[u8]) -> Option<(&[u8], &[u8]
     Some((&data[..length], &data[length..]))
 }
 
+/// Deserializes a value that follows `discriminator` in `data`, ignoring any
+/// bytes left after the last field.
+///
+/// `CarbonDeserialize` rejects data with trailing bytes, which is what makes
+/// layouts distinguishable from one another. This function is the lenient
+/// counterpart, used by version-aware decoders to read data written by a newer
+/// program version that appended fields to a layout.
+///
+/// # Parameters
+///
+/// - `discriminator`: The expected discriminator prefix of `data`.
+/// - `data`: The full data slice, including the discriminator.
+///
+/// # Returns
+///
+/// Returns `None` if the discriminator does not match or if `data` is too
+/// short for the layout of `T`.
+pub fn deserialize_with_trailing_bytes<T: crate::borsh::BorshDeserialize>(
+    discriminator: &[u8],
+    data: &[u8],
+) -> Option<T> {
+    let mut rest = data.strip_prefix(discriminator)?;
+
+    T::deserialize(&mut rest).ok()
+}
+
 /// A trait for defining a custom arrangement of Solana account metadata.
 ///
 /// The `ArrangeAccounts` trait provides an interface for structuring account
@@ -201,3 +243,49 @@ impl crate::borsh::BorshDeserialize for U64PrefixString {
         })?))
     }
 }
+
+/// A wrapper type for vectors that are prefixed with a `u64` length, as
+/// bincode serializes them.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+pub struct U64PrefixVec<T>(pub Vec<T>);
+
+impl<T> Default for U64PrefixVec<T> {
+    fn default() -> Self {
+        Self(Vec::default())
+    }
+}
+
+impl<T> Deref for U64PrefixVec<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<U64PrefixVec<T>> for Vec<T> {
+    fn from(val: U64PrefixVec<T>) -> Self {
+        val.0
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for U64PrefixVec<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}", self.0))
+    }
+}
+
+/// Implements the `CarbonDeserialize` trait for `U64PrefixVec`.
+impl<T: crate::borsh::BorshDeserialize> crate::borsh::BorshDeserialize for U64PrefixVec<T> {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        // read the length of the Vec
+        let length = u64::deserialize_reader(reader)?;
+        let mut items = Vec::new();
+        for _ in 0..length {
+            items.push(T::deserialize_reader(reader)?);
+        }
+
+        Ok(Self(items))
+    }
+}
@@ -21,14 +21,19 @@
 
 use {
     crate::{
-        error::CarbonResult, metrics::MetricsCollection, processor::Processor,
+        decode::{DecodeFailure, DecodeOutcome, DEFAULT_DISCRIMINATOR_LEN},
+        error::CarbonResult,
+        latency::{LatencyMonitor, PipeLatency},
+        metrics::{self, MetricsCollection},
+        processor::Processor,
+        telemetry::{traced, traced_sync},
         transaction::TransactionMetadata,
     },
     async_trait::async_trait,
     serde::Deserialize,
     solana_instruction::AccountMeta,
     solana_pubkey::Pubkey,
-    std::{ops::Deref, sync::Arc},
+    std::{ops::Deref, sync::Arc, time::Instant},
 };
 
 /// Metadata associated with a specific instruction, including transaction-level
@@ -97,6 +102,23 @@ pub struct DecodedInstruction<T> {
 ///
 /// - `decode_instruction`: Decodes a raw Solana `Instruction` into a
 ///   `DecodedInstruction`.
+///
+/// # Provided Methods
+///
+/// - `decode_instruction_at_slot`: Decodes an instruction executed at a given
+///   slot. Decoders whose layouts changed over the program's lifetime override
+///   it to prefer the layout that was live at that slot. By default the slot
+///   is ignored.
+/// - `program_id`: The program whose instructions the decoder handles.
+///   Instructions of it that fail to decode are reported as failures rather
+///   than ignored. Defaults to `None`.
+/// - `diagnose_instruction`: Explains why an instruction of `program_id` could
+///   not be decoded. Defaults to an unknown discriminator.
+/// - `decode_instruction_outcome`: Decodes an instruction executed at a given
+///   slot into a `DecodeOutcome`, which the `InstructionPipe` uses to count
+///   failures.
+/// - `name`: The name used for the `decoder` label of the pipe's metrics.
+///   Defaults to the implementing type's name.
 pub trait InstructionDecoder<'a> {
     type InstructionType;
 
@@ -104,6 +126,46 @@ pub trait InstructionDecoder<'a> {
         &self,
         instruction: &'a solana_instruction::Instruction,
     ) -> Option<DecodedInstruction<Self::InstructionType>>;
+
+    fn decode_instruction_at_slot(
+        &self,
+        instruction: &'a solana_instruction::Instruction,
+        _slot: u64,
+    ) -> Option<DecodedInstruction<Self::InstructionType>> {
+        self.decode_instruction(instruction)
+    }
+
+    fn program_id(&self) -> Option<Pubkey> {
+        None
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &'a solana_instruction::Instruction,
+    ) -> DecodeFailure {
+        DecodeFailure::unknown_discriminator(&instruction.data, DEFAULT_DISCRIMINATOR_LEN)
+    }
+
+    fn decode_instruction_outcome(
+        &self,
+        instruction: &'a solana_instruction::Instruction,
+        slot: u64,
+    ) -> DecodeOutcome<DecodedInstruction<Self::InstructionType>> {
+        if let Some(decoded_instruction) = self.decode_instruction_at_slot(instruction, slot) {
+            return DecodeOutcome::Decoded(decoded_instruction);
+        }
+
+        match self.program_id() {
+            Some(program_id) if program_id == instruction.program_id => {
+                DecodeOutcome::Failed(self.diagnose_instruction(instruction))
+            }
+            _ => DecodeOutcome::NotOwned,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        metrics::short_type_name(std::any::type_name::<Self>())
+    }
 }
 
 /// The input type for the instruction processor.
@@ -129,11 +191,13 @@ pub type InstructionProcessorInputType<T> = (
 ///
 /// - `decoder`: The decoder used for parsing instructions.
 /// - `processor`: The processor that handles decoded instructions.
+/// - `latency`: The pipe's latency totals, set when the pipeline is built.
 pub struct InstructionPipe<T: Send> {
     pub decoder:
         Box<dyn for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static>,
     pub processor:
         Box<dyn Processor<InputType = InstructionProcessorInputType<T>> + Send + Sync + 'static>,
+    pub latency: Option<Arc<PipeLatency>>,
 }
 
 /// An async trait for processing instructions within nested contexts.
@@ -145,7 +209,8 @@ pub struct InstructionPipe<T: Send> {
 /// # Required Methods
 ///
 /// - `run`: Processes a `NestedInstruction`, recursively processing any inner
-///   instructions.
+///   instructions. `InstructionPipe` records the `pipe_*` series labeled with
+///   its processor, its decoder and the instruction's program ID.
 #[async_trait]
 pub trait InstructionPipes<'a>: Send + Sync {
     async fn run(
@@ -153,10 +218,23 @@ pub trait InstructionPipes<'a>: Send + Sync {
         nested_instruction: &NestedInstruction,
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
+
+    /// Registers the pipe with the pipeline's latency monitor, so its runs
+    /// are checked against its processor's budget. Called once when the
+    /// pipeline is built.
+    fn register_latency(&mut self, _latency: &LatencyMonitor) {}
 }
 
 #[async_trait]
 impl<T: Send + 'static> InstructionPipes<'_> for InstructionPipe<T> {
+    fn register_latency(&mut self, latency: &LatencyMonitor) {
+        self.latency = Some(latency.register(&[
+            ("pipe", "instruction"),
+            ("processor", self.processor.name()),
+            ("decoder", self.decoder.name()),
+        ]));
+    }
+
     async fn run(
         &mut self,
         nested_instruction: &NestedInstruction,
@@ -167,20 +245,83 @@ impl<T: Send + 'static> InstructionPipes<'_> for InstructionPipe<T> {
             nested_instruction,
         );
 
-        if let Some(decoded_instruction) = self
-            .decoder
-            .decode_instruction(&nested_instruction.instruction)
-        {
-            self.processor
-                .process(
-                    (
-                        nested_instruction.metadata.clone(),
-                        decoded_instruction,
-                        nested_instruction.inner_instructions.clone(),
+        let outcome = traced_sync!(
+            self.decoder.decode_instruction_outcome(
+                &nested_instruction.instruction,
+                nested_instruction.metadata.transaction_metadata.slot,
+            ),
+            "decode",
+            decoder = self.decoder.name()
+        );
+
+        match outcome {
+            DecodeOutcome::Decoded(decoded_instruction) => {
+                let program_id = decoded_instruction.program_id.to_string();
+                let labels = [
+                    ("pipe", "instruction"),
+                    ("processor", self.processor.name()),
+                    ("decoder", self.decoder.name()),
+                    ("program_id", program_id.as_str()),
+                ];
+
+                metrics
+                    .increment_counter("pipe_updates_decoded", &labels, 1)
+                    .await?;
+
+                let start = Instant::now();
+                let result = traced!(
+                    self.processor.process(
+                        (
+                            nested_instruction.metadata.clone(),
+                            decoded_instruction,
+                            nested_instruction.inner_instructions.clone(),
+                        ),
+                        metrics.clone(),
                     ),
-                    metrics.clone(),
+                    "process",
+                    decoder = self.decoder.name(),
+                    program_id = program_id.as_str(),
+                    slot = nested_instruction.metadata.transaction_metadata.slot,
+                    signature = %nested_instruction.metadata.transaction_metadata.signature
                 )
-                .await?;
+                .await;
+
+                metrics
+                    .record_pipe_run(
+                        &labels,
+                        self.latency.as_deref(),
+                        &nested_instruction.metadata.transaction_metadata.signature,
+                        start,
+                        result,
+                    )
+                    .await?;
+            }
+            DecodeOutcome::Failed(failure) => {
+                let program_id = nested_instruction.instruction.program_id.to_string();
+                let labels = [
+                    ("pipe", "instruction"),
+                    ("decoder", self.decoder.name()),
+                    ("program_id", program_id.as_str()),
+                ];
+
+                log::debug!(
+                    "{} could not decode instruction {} of {}: {}",
+                    self.decoder.name(),
+                    nested_instruction.metadata.index,
+                    nested_instruction.metadata.transaction_metadata.signature,
+                    failure
+                );
+
+                metrics
+                    .record_decode_failure(
+                        &labels,
+                        nested_instruction.metadata.transaction_metadata.slot,
+                        &failure,
+                        &nested_instruction.instruction.data,
+                    )
+                    .await?;
+            }
+            DecodeOutcome::NotOwned => {}
         }
 
         for nested_inner_instruction in nested_instruction.inner_instructions.iter() {
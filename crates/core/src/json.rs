@@ -0,0 +1,813 @@
+//! JSON output for decoded accounts and instructions.
+//!
+//! Generated decoder types derive `serde::Serialize`, but their default
+//! representation is tuned for round-tripping rather than for reading: a
+//! `Pubkey` serializes as an array of 32 numbers, and `u128` values don't fit
+//! the number range most JSON consumers support. This module serializes any
+//! `Serialize` value with those types rendered as strings, and provides a
+//! processor that streams decoded data as newline-delimited JSON (NDJSON).
+//!
+//! ## Key Components
+//!
+//! - **`JsonOptions`**: Controls how numbers are rendered.
+//! - **`to_writer`** and **`to_value`**: Serialize a value with pubkeys as
+//!   base58 strings and `u128`/`i128` as decimal strings.
+//! - **`JsonProcessor`**: A processor that writes one JSON record per decoded
+//!   account or instruction to stdout or to a file.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! carbon_core::pipeline::Pipeline::builder()
+//!     .datasource(datasource)
+//!     .account(PumpfunDecoder, JsonProcessor::stdout())
+//!     .instruction(PumpfunDecoder, JsonProcessor::file("pumpfun.ndjson")?)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! ## Notes
+//!
+//! - Pubkeys are recognized by the newtype name `Pubkey` that their `Serialize`
+//!   implementation reports, so any type embedding a `Pubkey` is covered
+//!   without field attributes.
+//! - `u64` values are written as numbers unless `JsonOptions::u64_as_string`
+//!   is set, for consumers that parse numbers as 64-bit floats.
+
+use {
+    crate::{
+        account::AccountProcessorInputType,
+        error::{CarbonResult, Error},
+        instruction::InstructionProcessorInputType,
+        metrics::MetricsCollection,
+        processor::Processor,
+    },
+    async_trait::async_trait,
+    serde::{
+        ser::{
+            SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+            SerializeTupleStruct, SerializeTupleVariant,
+        },
+        Serialize, Serializer,
+    },
+    solana_pubkey::Pubkey,
+    std::{
+        fs::OpenOptions,
+        io::{self, Write},
+        marker::PhantomData,
+        path::Path,
+        sync::Arc,
+    },
+};
+
+/// Options for rendering decoded data as JSON.
+///
+/// # Fields
+///
+/// - `u64_as_string`: Writes `u64` values as decimal strings instead of
+///   numbers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonOptions {
+    pub u64_as_string: bool,
+}
+
+/// Serializes `value` as JSON into `writer`, with pubkeys as base58 strings and
+/// `u128`/`i128` values as decimal strings.
+pub fn to_writer<W: Write, T: Serialize + ?Sized>(
+    writer: W,
+    value: &T,
+    options: JsonOptions,
+) -> serde_json::Result<()> {
+    let mut serializer = serde_json::Serializer::new(writer);
+
+    value.serialize(JsonSerializer {
+        inner: &mut serializer,
+        options,
+    })
+}
+
+/// Converts `value` into a `serde_json::Value`, with pubkeys as base58 strings
+/// and `u128`/`i128` values as decimal strings.
+pub fn to_value<T: Serialize + ?Sized>(
+    value: &T,
+    options: JsonOptions,
+) -> serde_json::Result<serde_json::Value> {
+    value.serialize(JsonSerializer {
+        inner: serde_json::value::Serializer,
+        options,
+    })
+}
+
+/// A processor that writes decoded accounts or instructions as NDJSON.
+///
+/// Every processed item becomes one line. Account lines carry the account's
+/// slot, pubkey, owner and lamports next to the decoded `data`; instruction
+/// lines carry the transaction signature, slot, instruction position, program
+/// ID and account keys.
+///
+/// # Type Parameters
+///
+/// - `T`: The processor input type, either `AccountProcessorInputType<_>` or
+///   `InstructionProcessorInputType<_>`. It is inferred from the pipe the
+///   processor is added to.
+pub struct JsonProcessor<T> {
+    writer: Box<dyn Write + Send + Sync>,
+    options: JsonOptions,
+    _input: PhantomData<fn() -> T>,
+}
+
+impl<T> JsonProcessor<T> {
+    /// Creates a processor that writes to stdout.
+    pub fn stdout() -> Self {
+        Self::new(Box::new(io::stdout()))
+    }
+
+    /// Creates a processor that appends to the file at `path`, creating it if
+    /// it does not exist.
+    pub fn file(path: impl AsRef<Path>) -> CarbonResult<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path.as_ref())
+            .map_err(|err| {
+                Error::Custom(format!("Failed to open {}: {err}", path.as_ref().display()))
+            })?;
+
+        Ok(Self::new(Box::new(io::BufWriter::new(file))))
+    }
+
+    /// Creates a processor that writes to any writer.
+    pub fn new(writer: Box<dyn Write + Send + Sync>) -> Self {
+        Self {
+            writer,
+            options: JsonOptions::default(),
+            _input: PhantomData,
+        }
+    }
+
+    pub fn with_options(mut self, options: JsonOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+#[derive(Serialize)]
+struct AccountRecord<'a, T> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    slot: u64,
+    pubkey: String,
+    owner: String,
+    lamports: u64,
+    executable: bool,
+    data: &'a T,
+}
+
+#[derive(Serialize)]
+struct InstructionRecord<'a, T> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    signature: String,
+    slot: u64,
+    index: u32,
+    stack_height: u32,
+    program_id: String,
+    accounts: Vec<String>,
+    data: &'a T,
+}
+
+/// Processor inputs that [`JsonProcessor`] can write as a JSON record.
+pub trait JsonRecord {
+    fn write_json_record<W: Write>(
+        &self,
+        writer: W,
+        options: JsonOptions,
+    ) -> serde_json::Result<()>;
+}
+
+impl<T: Serialize> JsonRecord for AccountProcessorInputType<T> {
+    fn write_json_record<W: Write>(
+        &self,
+        writer: W,
+        options: JsonOptions,
+    ) -> serde_json::Result<()> {
+        let (metadata, account) = self;
+
+        to_writer(
+            writer,
+            &AccountRecord {
+                kind: "account",
+                slot: metadata.slot,
+                pubkey: metadata.pubkey.to_string(),
+                owner: account.owner.to_string(),
+                lamports: account.lamports,
+                executable: account.executable,
+                data: &account.data,
+            },
+            options,
+        )
+    }
+}
+
+impl<T: Serialize> JsonRecord for InstructionProcessorInputType<T> {
+    fn write_json_record<W: Write>(
+        &self,
+        writer: W,
+        options: JsonOptions,
+    ) -> serde_json::Result<()> {
+        let (metadata, instruction, _nested_instructions) = self;
+
+        to_writer(
+            writer,
+            &InstructionRecord {
+                kind: "instruction",
+                signature: metadata.transaction_metadata.signature.to_string(),
+                slot: metadata.transaction_metadata.slot,
+                index: metadata.index,
+                stack_height: metadata.stack_height,
+                program_id: instruction.program_id.to_string(),
+                accounts: instruction
+                    .accounts
+                    .iter()
+                    .map(|account| account.pubkey.to_string())
+                    .collect(),
+                data: &instruction.data,
+            },
+            options,
+        )
+    }
+}
+
+#[async_trait]
+impl<T: JsonRecord + Send + Sync + 'static> Processor for JsonProcessor<T> {
+    type InputType = T;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        data.write_json_record(&mut self.writer, self.options)
+            .map_err(|err| Error::Custom(format!("Failed to serialize JSON record: {err}")))?;
+
+        self.writer
+            .write_all(b"\n")
+            .and_then(|_| self.writer.flush())
+            .map_err(|err| Error::Custom(format!("Failed to write JSON record: {err}")))
+    }
+}
+
+/// Returns the base58 form of a value serialized as a `Pubkey` newtype.
+fn pubkey_string<T: Serialize + ?Sized>(value: &T) -> Option<String> {
+    let bytes = serde_json::to_value(value)
+        .ok()?
+        .as_array()?
+        .iter()
+        .map(|byte| byte.as_u64().and_then(|byte| u8::try_from(byte).ok()))
+        .collect::<Option<Vec<u8>>>()?;
+
+    Some(Pubkey::new_from_array(bytes.try_into().ok()?).to_string())
+}
+
+/// A serializer adapter that renders pubkeys and wide integers as strings and
+/// forwards everything else to the wrapped serializer.
+struct JsonSerializer<S> {
+    inner: S,
+    options: JsonOptions,
+}
+
+struct Wrapped<'a, T: ?Sized> {
+    value: &'a T,
+    options: JsonOptions,
+}
+
+impl<T: Serialize + ?Sized> Serialize for Wrapped<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(JsonSerializer {
+            inner: serializer,
+            options: self.options,
+        })
+    }
+}
+
+struct Compound<C> {
+    inner: C,
+    options: JsonOptions,
+}
+
+impl<C> Compound<C> {
+    fn wrap<'a, T: ?Sized>(&self, value: &'a T) -> Wrapped<'a, T> {
+        Wrapped {
+            value,
+            options: self.options,
+        }
+    }
+}
+
+impl<S: Serializer> Serializer for JsonSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Compound<S::SerializeSeq>;
+    type SerializeTuple = Compound<S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<S::SerializeTupleVariant>;
+    type SerializeMap = Compound<S::SerializeMap>;
+    type SerializeStruct = Compound<S::SerializeStruct>;
+    type SerializeStructVariant = Compound<S::SerializeStructVariant>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_bool(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_i8(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_i16(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_i32(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_i64(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_str(&v.to_string())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_u8(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_u16(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_u32(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        if self.options.u64_as_string {
+            self.inner.serialize_str(&v.to_string())
+        } else {
+            self.inner.serialize_u64(v)
+        }
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_str(&v.to_string())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_f32(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_f64(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_char(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_str(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_bytes(v)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_none()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_some(&Wrapped {
+            value,
+            options: self.options,
+        })
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_unit()
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_unit_struct(name)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner
+            .serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        if name == "Pubkey" {
+            if let Some(pubkey) = pubkey_string(value) {
+                return self.inner.serialize_str(&pubkey);
+            }
+        }
+
+        self.inner.serialize_newtype_struct(
+            name,
+            &Wrapped {
+                value,
+                options: self.options,
+            },
+        )
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_newtype_variant(
+            name,
+            variant_index,
+            variant,
+            &Wrapped {
+                value,
+                options: self.options,
+            },
+        )
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_seq(len)?,
+            options: self.options,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_tuple(len)?,
+            options: self.options,
+        })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_tuple_struct(name, len)?,
+            options: self.options,
+        })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(Compound {
+            inner: self
+                .inner
+                .serialize_tuple_variant(name, variant_index, variant, len)?,
+            options: self.options,
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_map(len)?,
+            options: self.options,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_struct(name, len)?,
+            options: self.options,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(Compound {
+            inner: self
+                .inner
+                .serialize_struct_variant(name, variant_index, variant, len)?,
+            options: self.options,
+        })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+impl<C: SerializeSeq> SerializeSeq for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTuple> SerializeTuple for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTupleStruct> SerializeTupleStruct for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTupleVariant> SerializeTupleVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeMap> SerializeMap for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        let key = self.wrap(key);
+        self.inner.serialize_key(&key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_value(&value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeStruct> SerializeStruct for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_field(key, &value)
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeStructVariant> SerializeStructVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_field(key, &value)
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::account::{AccountMetadata, DecodedAccount},
+        serde_json::json,
+        std::sync::Mutex,
+    };
+
+    /// The shape of a generated account: pubkeys at different depths and
+    /// arrays longer than serde's 32-element limit.
+    #[derive(Serialize)]
+    struct Pool {
+        authority: Pubkey,
+        mints: Vec<Pubkey>,
+        fee_recipient: Option<Pubkey>,
+        #[serde(with = "serde_big_array::BigArray")]
+        padding: [u8; 64],
+        #[serde(with = "serde_big_array::BigArray")]
+        observations: [Observation; 40],
+        liquidity: u128,
+        reserve: u64,
+    }
+
+    #[derive(Serialize, Clone, Copy)]
+    struct Observation {
+        timestamp: i64,
+        oracle: Pubkey,
+    }
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn pubkey(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
+
+    fn pool() -> Pool {
+        let mut padding = [0; 64];
+        padding[63] = 255;
+
+        Pool {
+            authority: pubkey(1),
+            mints: vec![pubkey(2), pubkey(3)],
+            fee_recipient: Some(pubkey(4)),
+            padding,
+            observations: std::array::from_fn(|i| Observation {
+                timestamp: i as i64,
+                oracle: pubkey(i as u8),
+            }),
+            liquidity: u128::MAX,
+            reserve: u64::MAX,
+        }
+    }
+
+    #[test]
+    fn test_pubkeys_as_base58() {
+        // Arrange
+        let pool = pool();
+
+        // Act
+        let value = to_value(&pool, JsonOptions::default()).unwrap();
+
+        // Assert
+        assert_eq!(value["authority"], json!(pubkey(1).to_string()));
+        assert_eq!(
+            value["mints"],
+            json!([pubkey(2).to_string(), pubkey(3).to_string()])
+        );
+        assert_eq!(value["fee_recipient"], json!(pubkey(4).to_string()));
+        assert_eq!(
+            value["observations"][39]["oracle"],
+            json!(pubkey(39).to_string())
+        );
+    }
+
+    #[test]
+    fn test_big_arrays_in_full() {
+        // Arrange
+        let pool = pool();
+
+        // Act
+        let value = to_value(&pool, JsonOptions::default()).unwrap();
+
+        // Assert
+        let padding = value["padding"].as_array().unwrap();
+        assert_eq!(padding.len(), 64);
+        assert_eq!(padding[63], json!(255));
+        let observations = value["observations"].as_array().unwrap();
+        assert_eq!(observations.len(), 40);
+        assert_eq!(observations[39]["timestamp"], json!(39));
+    }
+
+    #[test]
+    fn test_wide_integers() {
+        // Arrange
+        let pool = pool();
+
+        // Act
+        let value = to_value(&pool, JsonOptions::default()).unwrap();
+        let u64_as_string = to_value(
+            &pool,
+            JsonOptions {
+                u64_as_string: true,
+            },
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(value["liquidity"], json!(u128::MAX.to_string()));
+        assert_eq!(value["reserve"], json!(u64::MAX));
+        assert_eq!(u64_as_string["reserve"], json!(u64::MAX.to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_process_account() {
+        // Arrange
+        let buffer = SharedBuffer::default();
+        let mut processor =
+            JsonProcessor::<AccountProcessorInputType<Pool>>::new(Box::new(buffer.clone()));
+        let account = (
+            AccountMetadata {
+                slot: 42,
+                pubkey: pubkey(9),
+            },
+            DecodedAccount {
+                lamports: 1_000,
+                data: pool(),
+                owner: pubkey(8),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        // Act
+        processor
+            .process(account, Arc::new(MetricsCollection::new(vec![])))
+            .await
+            .unwrap();
+
+        // Assert
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(output.ends_with('\n'));
+        let record: serde_json::Value = serde_json::from_str(output.trim_end()).unwrap();
+        assert_eq!(record["type"], json!("account"));
+        assert_eq!(record["slot"], json!(42));
+        assert_eq!(record["pubkey"], json!(pubkey(9).to_string()));
+        assert_eq!(record["owner"], json!(pubkey(8).to_string()));
+        assert_eq!(record["data"]["authority"], json!(pubkey(1).to_string()));
+        assert_eq!(record["data"]["padding"].as_array().unwrap().len(), 64);
+    }
+}
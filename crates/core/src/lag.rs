@@ -0,0 +1,422 @@
+//! Tracks how far the pipeline is behind the chain.
+//!
+//! The `LagMonitor` records the latest slot each datasource has delivered, when
+//! the datasources last delivered anything, and the highest slot and block
+//! time the pipeline has processed. The lag is checked on the pipeline's
+//! metrics interval rather than per update, so a feed that stalls completely
+//! still crosses the thresholds and fires the warning hook.
+//!
+//! ## Series
+//!
+//! - `datasource_latest_slot` (gauge, labeled with `datasource`): The latest
+//!   slot received from each datasource.
+//! - `highest_slot_processed` (gauge): The highest slot processed so far.
+//! - `slot_lag` (gauge): The latest slot seen by any datasource minus the
+//!   highest slot processed.
+//! - `update_slot_lag` (histogram): The latest slot seen minus the slot of
+//!   each processed update.
+//! - `block_time_lag_seconds` (gauge): The time since the block time of the
+//!   latest transaction processed.
+//! - `transaction_block_time_lag_seconds` (histogram): The time between a
+//!   transaction's block time and the moment it was processed.
+//! - `seconds_since_last_update` (gauge): The time since any datasource last
+//!   delivered an update.
+//!
+//! ## Notes
+//!
+//! - The warning hook fires once when the lag exceeds a threshold, and is armed
+//!   again after the pipeline has caught up.
+//! - Block times have second precision and are not returned by every
+//!   datasource, so `block_time_lag_seconds` is only recorded once a
+//!   transaction that carries one was processed. Use the stall threshold to
+//!   watch datasources without block times.
+
+use {
+    crate::{datasource::Update, error::CarbonResult, metrics::MetricsCollection},
+    std::{
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc,
+        },
+        time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+    },
+};
+
+/// Describes the lag that triggered a warning.
+///
+/// # Fields
+///
+/// - `highest_slot_processed`: The highest slot processed so far.
+/// - `latest_slot_seen`: The latest slot received from any datasource.
+/// - `slot_lag`: `latest_slot_seen` minus `highest_slot_processed`.
+/// - `block_time_lag`: The time since the block time of the latest
+///   transaction processed, if any carried one.
+/// - `since_last_update`: The time since any datasource last delivered an
+///   update, or since the pipeline started if none has.
+#[derive(Debug, Clone)]
+pub struct LagWarning {
+    pub highest_slot_processed: u64,
+    pub latest_slot_seen: u64,
+    pub slot_lag: u64,
+    pub block_time_lag: Option<Duration>,
+    pub since_last_update: Duration,
+}
+
+/// A callback invoked when the pipeline falls behind its lag thresholds.
+pub type LagWarningHook = Arc<dyn Fn(&LagWarning) + Send + Sync>;
+
+/// The latest slot and the time of the latest update received by the
+/// datasource forwarders.
+#[derive(Debug)]
+pub(crate) struct ReceivedUpdates {
+    started: Instant,
+    latest_slot: AtomicU64,
+    /// Milliseconds from `started` to the latest update, plus one. Zero until
+    /// the first update.
+    last_received: AtomicU64,
+}
+
+impl ReceivedUpdates {
+    fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            latest_slot: AtomicU64::new(0),
+            last_received: AtomicU64::new(0),
+        }
+    }
+
+    /// Records an update received at `slot`.
+    pub(crate) fn record(&self, slot: u64) {
+        self.latest_slot.fetch_max(slot, Ordering::Relaxed);
+        self.last_received.fetch_max(
+            self.started.elapsed().as_millis() as u64 + 1,
+            Ordering::Relaxed,
+        );
+    }
+
+    fn latest_slot(&self) -> u64 {
+        self.latest_slot.load(Ordering::Relaxed)
+    }
+
+    fn since_last_update(&self) -> Duration {
+        let last_received =
+            Duration::from_millis(self.last_received.load(Ordering::Relaxed).saturating_sub(1));
+
+        self.started.elapsed().saturating_sub(last_received)
+    }
+}
+
+/// Measures slot and wall-clock lag and raises warnings past the thresholds.
+///
+/// # Fields
+///
+/// - `slot_lag_threshold`: The slot lag above which the hook fires.
+/// - `block_time_lag_threshold`: The block time lag above which the hook
+///   fires.
+/// - `stall_threshold`: How long the datasources may deliver nothing before
+///   the hook fires.
+/// - `warning_hook`: The callback to invoke. Defaults to logging a warning.
+pub struct LagMonitor {
+    pub slot_lag_threshold: Option<u64>,
+    pub block_time_lag_threshold: Option<Duration>,
+    pub stall_threshold: Option<Duration>,
+    pub warning_hook: LagWarningHook,
+    received: Arc<ReceivedUpdates>,
+    highest_slot_processed: u64,
+    latest_block_time: Option<i64>,
+    lagging: bool,
+}
+
+impl Default for LagMonitor {
+    fn default() -> Self {
+        Self {
+            slot_lag_threshold: None,
+            block_time_lag_threshold: None,
+            stall_threshold: None,
+            warning_hook: Arc::new(|warning: &LagWarning| {
+                log::warn!(
+                    "pipeline is lagging behind: slot {} is {} slots behind the latest slot {}, block time lag: {:?}, last update {:?} ago",
+                    warning.highest_slot_processed,
+                    warning.slot_lag,
+                    warning.latest_slot_seen,
+                    warning.block_time_lag,
+                    warning.since_last_update,
+                );
+            }),
+            received: Arc::new(ReceivedUpdates::new()),
+            highest_slot_processed: 0,
+            latest_block_time: None,
+            lagging: false,
+        }
+    }
+}
+
+impl LagMonitor {
+    /// Returns the tracker shared with the datasource forwarders, which record
+    /// every update they receive.
+    pub(crate) fn received_updates(&self) -> Arc<ReceivedUpdates> {
+        Arc::clone(&self.received)
+    }
+
+    /// Records the lag of an update the pipeline has just processed.
+    pub(crate) async fn record_processed(
+        &mut self,
+        update: &Update,
+        metrics: &MetricsCollection,
+    ) -> CarbonResult<()> {
+        let slot = update.slot();
+        self.highest_slot_processed = self.highest_slot_processed.max(slot);
+
+        let latest_slot_seen = self.received.latest_slot().max(self.highest_slot_processed);
+        metrics
+            .record_histogram(
+                "update_slot_lag",
+                &[],
+                latest_slot_seen.saturating_sub(slot) as f64,
+            )
+            .await?;
+
+        if let Update::Transaction(transaction_update) = update {
+            if let Some(block_time) = transaction_update.block_time {
+                self.latest_block_time = self.latest_block_time.max(Some(block_time));
+
+                metrics
+                    .record_histogram(
+                        "transaction_block_time_lag_seconds",
+                        &[],
+                        block_time_lag(block_time).as_secs_f64(),
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reports the current lag and fires the warning hook if a threshold is
+    /// crossed. Called on every metrics interval.
+    pub(crate) async fn check(&mut self, metrics: &MetricsCollection) -> CarbonResult<()> {
+        let latest_slot_seen = self.received.latest_slot().max(self.highest_slot_processed);
+        let slot_lag = latest_slot_seen - self.highest_slot_processed;
+        let block_time_lag = self.latest_block_time.map(block_time_lag);
+        let since_last_update = self.received.since_last_update();
+
+        metrics
+            .update_gauge(
+                "highest_slot_processed",
+                &[],
+                self.highest_slot_processed as f64,
+            )
+            .await?;
+        metrics
+            .update_gauge("slot_lag", &[], slot_lag as f64)
+            .await?;
+        if let Some(block_time_lag) = block_time_lag {
+            metrics
+                .update_gauge("block_time_lag_seconds", &[], block_time_lag.as_secs_f64())
+                .await?;
+        }
+        metrics
+            .update_gauge(
+                "seconds_since_last_update",
+                &[],
+                since_last_update.as_secs_f64(),
+            )
+            .await?;
+
+        let exceeds_slot_threshold = self
+            .slot_lag_threshold
+            .is_some_and(|threshold| slot_lag > threshold);
+        let exceeds_block_time_threshold = self
+            .block_time_lag_threshold
+            .zip(block_time_lag)
+            .is_some_and(|(threshold, lag)| lag > threshold);
+        let exceeds_stall_threshold = self
+            .stall_threshold
+            .is_some_and(|threshold| since_last_update > threshold);
+
+        if exceeds_slot_threshold || exceeds_block_time_threshold || exceeds_stall_threshold {
+            if !self.lagging {
+                self.lagging = true;
+                (self.warning_hook)(&LagWarning {
+                    highest_slot_processed: self.highest_slot_processed,
+                    latest_slot_seen,
+                    slot_lag,
+                    block_time_lag,
+                    since_last_update,
+                });
+            }
+        } else if self.lagging {
+            self.lagging = false;
+            log::info!("pipeline caught up at slot {}", self.highest_slot_processed);
+        }
+
+        Ok(())
+    }
+}
+
+fn block_time_lag(block_time: i64) -> Duration {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+
+    now.saturating_sub(Duration::from_secs(block_time.max(0) as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::datasource::{AccountDeletion, TransactionUpdate},
+        std::sync::Mutex,
+    };
+
+    fn monitor() -> (LagMonitor, Arc<Mutex<Vec<LagWarning>>>) {
+        let warnings = Arc::new(Mutex::new(Vec::new()));
+        let warnings_clone = Arc::clone(&warnings);
+        let monitor = LagMonitor {
+            warning_hook: Arc::new(move |warning: &LagWarning| {
+                warnings_clone.lock().unwrap().push(warning.clone());
+            }),
+            ..Default::default()
+        };
+
+        (monitor, warnings)
+    }
+
+    fn account_deletion(slot: u64) -> Update {
+        Update::AccountDeletion(AccountDeletion {
+            pubkey: Default::default(),
+            slot,
+        })
+    }
+
+    fn transaction(slot: u64, block_time: i64) -> Update {
+        Update::Transaction(Box::new(TransactionUpdate {
+            signature: Default::default(),
+            transaction: Default::default(),
+            meta: Default::default(),
+            is_vote: false,
+            slot,
+            block_time: Some(block_time),
+        }))
+    }
+
+    fn unix_time() -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64
+    }
+
+    #[tokio::test]
+    async fn test_slot_lag_threshold() {
+        let metrics = MetricsCollection::new(vec![]);
+        let (mut monitor, warnings) = monitor();
+        monitor.slot_lag_threshold = Some(10);
+        let received = monitor.received_updates();
+
+        received.record(100);
+        monitor
+            .record_processed(&account_deletion(100), &metrics)
+            .await
+            .unwrap();
+        received.record(110);
+        monitor.check(&metrics).await.unwrap();
+        assert!(warnings.lock().unwrap().is_empty());
+
+        received.record(111);
+        monitor.check(&metrics).await.unwrap();
+        monitor.check(&metrics).await.unwrap();
+        {
+            let warnings = warnings.lock().unwrap();
+            assert_eq!(warnings.len(), 1);
+            assert_eq!(warnings[0].highest_slot_processed, 100);
+            assert_eq!(warnings[0].latest_slot_seen, 111);
+            assert_eq!(warnings[0].slot_lag, 11);
+            assert!(warnings[0].block_time_lag.is_none());
+        }
+
+        // Catching up arms the hook again.
+        monitor
+            .record_processed(&account_deletion(111), &metrics)
+            .await
+            .unwrap();
+        monitor.check(&metrics).await.unwrap();
+        received.record(200);
+        monitor.check(&metrics).await.unwrap();
+        assert_eq!(warnings.lock().unwrap().len(), 2);
+        assert_eq!(warnings.lock().unwrap()[1].slot_lag, 89);
+    }
+
+    #[tokio::test]
+    async fn test_block_time_lag_threshold() {
+        let metrics = MetricsCollection::new(vec![]);
+        let (mut monitor, warnings) = monitor();
+        monitor.block_time_lag_threshold = Some(Duration::from_secs(60));
+
+        // Nothing processed with a block time yet.
+        monitor.check(&metrics).await.unwrap();
+        assert!(warnings.lock().unwrap().is_empty());
+
+        monitor
+            .record_processed(&transaction(100, unix_time() - 5), &metrics)
+            .await
+            .unwrap();
+        monitor.check(&metrics).await.unwrap();
+        assert!(warnings.lock().unwrap().is_empty());
+
+        // An older transaction does not lower the latest block time.
+        monitor
+            .record_processed(&transaction(90, unix_time() - 600), &metrics)
+            .await
+            .unwrap();
+        monitor.check(&metrics).await.unwrap();
+        assert!(warnings.lock().unwrap().is_empty());
+
+        // The lag grows while no new transactions arrive.
+        monitor.latest_block_time = monitor.latest_block_time.map(|time| time - 120);
+        monitor.check(&metrics).await.unwrap();
+        let warnings = warnings.lock().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].block_time_lag.unwrap() > Duration::from_secs(120));
+        assert_eq!(warnings[0].slot_lag, 0);
+    }
+
+    #[tokio::test]
+    async fn test_stall_threshold() {
+        let metrics = MetricsCollection::new(vec![]);
+        let (mut monitor, warnings) = monitor();
+        monitor.stall_threshold = Some(Duration::from_millis(50));
+        let received = monitor.received_updates();
+
+        received.record(100);
+        monitor.check(&metrics).await.unwrap();
+        assert!(warnings.lock().unwrap().is_empty());
+
+        // A stalled feed fires the hook without any update being processed.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        monitor.check(&metrics).await.unwrap();
+        {
+            let warnings = warnings.lock().unwrap();
+            assert_eq!(warnings.len(), 1);
+            assert!(warnings[0].since_last_update >= Duration::from_millis(100));
+            assert_eq!(warnings[0].latest_slot_seen, 100);
+        }
+
+        received.record(101);
+        monitor.check(&metrics).await.unwrap();
+        assert_eq!(warnings.lock().unwrap().len(), 1);
+        assert!(!monitor.lagging);
+    }
+
+    #[test]
+    fn test_since_last_update_without_updates() {
+        let received = ReceivedUpdates::new();
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert!(received.since_last_update() >= Duration::from_millis(20));
+        received.record(1);
+        assert!(received.since_last_update() < Duration::from_millis(20));
+    }
+}
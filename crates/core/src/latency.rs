@@ -0,0 +1,331 @@
+//! Tracks how long each pipe takes to process updates.
+//!
+//! Every pipe run is timed and recorded under the
+//! `pipe_process_time_nanoseconds` histogram, labeled with the pipe, its
+//! processor and, for account and instruction pipes, its decoder and program
+//! ID. The `LatencyMonitor` additionally keeps running totals per pipe, checks
+//! each run against the latency budget of its processor, and logs the updates
+//! that exceed it.
+//!
+//! ## Series
+//!
+//! - `pipe_budget_exceeded` (counter): The runs that took longer than the
+//!   processor's budget, with the same labels as the pipe's other series.
+//!
+//! ## Notes
+//!
+//! - Budgets are keyed by the processor's `name()`, which defaults to the
+//!   processor's type name.
+//! - Slow updates are logged at the `warn` level with the signature or pubkey
+//!   of the update, so they can be replayed.
+//! - A summary of the slowest pipes, by mean run time, is logged at the `info`
+//!   level when the pipeline's metrics are shut down.
+//! - Pipes register with the `LatencyMonitor` when the pipeline is built and
+//!   get a `PipeLatency` holding their series key and budget, so timing a run
+//!   only updates atomic counters. The totals are kept per pipe, processor and
+//!   decoder.
+
+use {
+    crate::metrics,
+    std::{
+        collections::HashMap,
+        fmt,
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc, Mutex,
+        },
+        time::Duration,
+    },
+};
+
+/// Running totals of a pipe's processing time.
+///
+/// # Fields
+///
+/// - `runs`: The number of timed runs.
+/// - `total`: The time spent in all runs.
+/// - `max`: The longest run.
+/// - `over_budget`: The number of runs that exceeded the processor's budget.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PipeTiming {
+    pub runs: u64,
+    pub total: Duration,
+    pub max: Duration,
+    pub over_budget: u64,
+}
+
+impl PipeTiming {
+    /// The mean run time, or zero before the first run.
+    pub fn mean(&self) -> Duration {
+        if self.runs == 0 {
+            return Duration::ZERO;
+        }
+
+        Duration::from_nanos((self.total.as_nanos() / self.runs as u128) as u64)
+    }
+}
+
+/// The latency totals and budget of one pipe, handed out by
+/// `LatencyMonitor::register`.
+#[derive(Debug)]
+pub struct PipeLatency {
+    series_key: String,
+    budget: Option<Duration>,
+    runs: AtomicU64,
+    total_nanoseconds: AtomicU64,
+    max_nanoseconds: AtomicU64,
+    over_budget: AtomicU64,
+}
+
+impl PipeLatency {
+    fn new(series_key: String, budget: Option<Duration>) -> Self {
+        Self {
+            series_key,
+            budget,
+            runs: AtomicU64::new(0),
+            total_nanoseconds: AtomicU64::new(0),
+            max_nanoseconds: AtomicU64::new(0),
+            over_budget: AtomicU64::new(0),
+        }
+    }
+
+    /// The key of the pipe's totals, e.g.
+    /// `pipe{pipe="transaction",processor="SwapProcessor"}`.
+    pub fn series_key(&self) -> &str {
+        &self.series_key
+    }
+
+    /// The budget of the pipe's processor, if any.
+    pub fn budget(&self) -> Option<Duration> {
+        self.budget
+    }
+
+    /// Records a pipe run and returns the budget it exceeded, if any.
+    ///
+    /// # Parameters
+    ///
+    /// - `elapsed`: How long the run took.
+    /// - `update`: The signature or pubkey of the update, for the slow-update
+    ///   log.
+    pub fn record(&self, elapsed: Duration, update: &dyn fmt::Display) -> Option<Duration> {
+        let elapsed_nanoseconds = elapsed.as_nanos().min(u64::MAX as u128) as u64;
+        self.runs.fetch_add(1, Ordering::Relaxed);
+        self.total_nanoseconds
+            .fetch_add(elapsed_nanoseconds, Ordering::Relaxed);
+        self.max_nanoseconds
+            .fetch_max(elapsed_nanoseconds, Ordering::Relaxed);
+
+        let budget = self.budget.filter(|budget| elapsed > *budget)?;
+        self.over_budget.fetch_add(1, Ordering::Relaxed);
+
+        log::warn!(
+            "slow update {}: {} took {:?}, over its budget of {:?}",
+            update,
+            self.series_key,
+            elapsed,
+            budget
+        );
+
+        Some(budget)
+    }
+
+    /// Returns the pipe's running totals.
+    pub fn timing(&self) -> PipeTiming {
+        PipeTiming {
+            runs: self.runs.load(Ordering::Relaxed),
+            total: Duration::from_nanos(self.total_nanoseconds.load(Ordering::Relaxed)),
+            max: Duration::from_nanos(self.max_nanoseconds.load(Ordering::Relaxed)),
+            over_budget: self.over_budget.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Keeps per-pipe timings and enforces per-processor latency budgets.
+///
+/// The pipeline owns one `LatencyMonitor`, configured through
+/// `PipelineBuilder::latency_budget`, `PipelineBuilder::default_latency_budget`
+/// and `PipelineBuilder::slowest_pipes_summary`.
+pub struct LatencyMonitor {
+    budgets: HashMap<String, Duration>,
+    default_budget: Option<Duration>,
+    summary_size: usize,
+    pipes: Mutex<Vec<Arc<PipeLatency>>>,
+}
+
+impl Default for LatencyMonitor {
+    fn default() -> Self {
+        Self {
+            budgets: HashMap::new(),
+            default_budget: None,
+            summary_size: 10,
+            pipes: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl LatencyMonitor {
+    /// Sets the budget for every pipe whose processor is named `processor`.
+    pub fn set_budget(&mut self, processor: impl Into<String>, budget: Duration) {
+        self.budgets.insert(processor.into(), budget);
+    }
+
+    /// Sets the budget for processors without a budget of their own.
+    pub fn set_default_budget(&mut self, budget: Duration) {
+        self.default_budget = Some(budget);
+    }
+
+    /// Sets how many pipes the shutdown summary lists. `0` disables it.
+    pub fn set_summary_size(&mut self, summary_size: usize) {
+        self.summary_size = summary_size;
+    }
+
+    /// Returns the budget that applies to the processor named `processor`.
+    pub fn budget(&self, processor: &str) -> Option<Duration> {
+        self.budgets.get(processor).copied().or(self.default_budget)
+    }
+
+    /// Registers a pipe and returns the `PipeLatency` its runs are recorded
+    /// in. Pipes with the same labels share their totals.
+    ///
+    /// The budget is looked up by the value of the `processor` label when the
+    /// pipe registers, so budgets must be set first. The pipeline registers
+    /// its pipes when it is built.
+    ///
+    /// # Parameters
+    ///
+    /// - `labels`: The labels identifying the pipe.
+    pub fn register(&self, labels: &[(&str, &str)]) -> Arc<PipeLatency> {
+        let series_key = metrics::series_key("pipe", labels);
+        let mut pipes = self.pipes.lock().unwrap();
+
+        if let Some(pipe) = pipes.iter().find(|pipe| pipe.series_key == series_key) {
+            return Arc::clone(pipe);
+        }
+
+        let budget = labels
+            .iter()
+            .find(|(key, _)| *key == "processor")
+            .and_then(|(_, processor)| self.budget(processor));
+        let pipe = Arc::new(PipeLatency::new(series_key, budget));
+        pipes.push(Arc::clone(&pipe));
+        pipe
+    }
+
+    /// Returns up to `count` pipes with the highest mean run time, slowest
+    /// first, keyed by their series, e.g.
+    /// `pipe{pipe="transaction",processor="SwapProcessor"}`.
+    pub fn slowest_pipes(&self, count: usize) -> Vec<(String, PipeTiming)> {
+        let mut timings = match self.pipes.lock() {
+            Ok(pipes) => pipes
+                .iter()
+                .map(|pipe| (pipe.series_key.clone(), pipe.timing()))
+                .filter(|(_, timing)| timing.runs > 0)
+                .collect::<Vec<_>>(),
+            Err(_) => return Vec::new(),
+        };
+
+        timings.sort_by_key(|(_, timing)| std::cmp::Reverse(timing.mean()));
+        timings.truncate(count);
+        timings
+    }
+
+    /// Logs the slowest pipes, as configured by `set_summary_size`.
+    pub fn log_summary(&self) {
+        let slowest_pipes = self.slowest_pipes(self.summary_size);
+        if slowest_pipes.is_empty() {
+            return;
+        }
+
+        let mut summary = String::from("slowest pipes by mean run time:");
+        for (index, (pipe, timing)) in slowest_pipes.iter().enumerate() {
+            summary.push_str(&format!(
+                "\n  {}. {}: mean {:?}, max {:?}, {} runs, {} over budget",
+                index + 1,
+                pipe,
+                timing.mean(),
+                timing.max,
+                timing.runs,
+                timing.over_budget
+            ));
+        }
+
+        log::info!("{summary}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_resolves_budget() {
+        let mut monitor = LatencyMonitor::default();
+        monitor.set_budget("SlowProcessor", Duration::from_millis(5));
+        monitor.set_default_budget(Duration::from_millis(50));
+
+        let slow = monitor.register(&[("pipe", "account"), ("processor", "SlowProcessor")]);
+        let other = monitor.register(&[("pipe", "account"), ("processor", "OtherProcessor")]);
+        let deletions = monitor.register(&[("pipe", "account_deletion")]);
+
+        assert_eq!(
+            slow.series_key(),
+            r#"pipe{pipe="account",processor="SlowProcessor"}"#
+        );
+        assert_eq!(slow.budget(), Some(Duration::from_millis(5)));
+        assert_eq!(other.budget(), Some(Duration::from_millis(50)));
+        assert_eq!(deletions.budget(), None);
+    }
+
+    #[test]
+    fn test_record() {
+        let mut monitor = LatencyMonitor::default();
+        monitor.set_budget("SlowProcessor", Duration::from_millis(5));
+        let labels = [("pipe", "account"), ("processor", "SlowProcessor")];
+        let pipe = monitor.register(&labels);
+
+        assert_eq!(pipe.record(Duration::from_millis(2), &"update-1"), None);
+        assert_eq!(
+            pipe.record(Duration::from_millis(8), &"update-2"),
+            Some(Duration::from_millis(5))
+        );
+
+        // Pipes with the same labels share their totals.
+        let same_pipe = monitor.register(&labels);
+        same_pipe.record(Duration::from_millis(5), &"update-3");
+
+        assert_eq!(
+            pipe.timing(),
+            PipeTiming {
+                runs: 3,
+                total: Duration::from_millis(15),
+                max: Duration::from_millis(8),
+                over_budget: 1,
+            }
+        );
+        assert_eq!(pipe.timing().mean(), Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_slowest_pipes() {
+        let monitor = LatencyMonitor::default();
+        let fast = monitor.register(&[("pipe", "account"), ("processor", "Fast")]);
+        let slow = monitor.register(&[("pipe", "account"), ("processor", "Slow")]);
+        monitor.register(&[("pipe", "account"), ("processor", "Idle")]);
+
+        fast.record(Duration::from_millis(1), &"update");
+        slow.record(Duration::from_millis(10), &"update");
+
+        let slowest_pipes = monitor.slowest_pipes(5);
+        assert_eq!(
+            slowest_pipes
+                .iter()
+                .map(|(pipe, _)| pipe.as_str())
+                .collect::<Vec<_>>(),
+            [
+                r#"pipe{pipe="account",processor="Slow"}"#,
+                r#"pipe{pipe="account",processor="Fast"}"#,
+            ]
+        );
+        assert_eq!(monitor.slowest_pipes(1).len(), 1);
+    }
+}
@@ -19,6 +19,9 @@
 //! - **[`account_deletion`]**: Handles the deletion of accounts and processes
 //!   these events in the pipeline.
 //!
+//! - **[`admin`]**: Serves optional health, readiness and status endpoints for
+//!   a running pipeline, with controls to pause datasources or shut down.
+//!
 //! - **[`collection`]**: Defines collections for instruction decoding, allowing
 //!   for customized instruction parsers that handle specific instruction sets.
 //!
@@ -26,6 +29,9 @@
 //!   integration of external data sources into the pipeline. Supports
 //!   Solana-specific data structures.
 //!
+//! - **[`decode`]**: Describes why a decoder could not decode data owned by
+//!   its program, and samples the raw data of such failures to a file.
+//!
 //! - **[`deserialize`]**: Contains utilities for data deserialization,
 //!   including helper functions for parsing Solana transactions and other
 //!   binary data formats.
@@ -37,15 +43,40 @@
 //!   transactions. This module includes structures and traits for decoding and
 //!   handling transaction instructions.
 //!
+//! - **[`json`]**: Serializes decoded data as JSON with pubkeys as base58
+//!   strings, and provides a processor that streams decoded accounts and
+//!   instructions as NDJSON.
+//!
+//! - **[`lag`]**: Measures how far the pipeline is behind the chain, in slots
+//!   and wall-clock time, and raises warnings past configured thresholds.
+//!
+//! - **[`latency`]**: Keeps per-pipe processing times, logs updates that
+//!   exceed a processor's latency budget, and summarizes the slowest pipes at
+//!   shutdown.
+//!
+//! - **[`lookup_table`]**: Caches Address Lookup Tables from account updates
+//!   to resolve v0 transactions whose metadata lacks loaded addresses.
+//!
 //! - **[`metrics`]**: Facilitates performance monitoring and metric recording
 //!   within the pipeline. Metrics can be customized and are recorded at each
 //!   processing stage for monitoring and debugging purposes.
 //!
+//! - **[`pda`]**: Describes an instruction account found at the wrong
+//!   address by a generated `verify_pdas` check.
+//!
 //! - **[`pipeline`]**: Represents the core of the framework, defining the main
 //!   pipeline structure that manages data flow and processing. The pipeline
 //!   integrates data sources, processing pipes, and metrics to provide a
 //!   complete data processing solution.
 //!
+//! - **[`program_error`]**: Resolves failed transactions to the failing
+//!   instruction and decodes custom program errors through a registry of
+//!   decoders.
+//!
+//! - **[`priority_fee`]**: Derives a transaction's compute unit limit and
+//!   price from its Compute Budget instructions, and splits its fee into the
+//!   base and priority fees.
+//!
 //! - **[`processor`]**: Contains traits and implementations for processing data
 //!   in the pipeline. This module allows for the creation of custom data
 //!   processors that can be integrated into various stages of the pipeline.
@@ -55,6 +86,10 @@
 //!   Supports complex nested instruction matching for comprehensive transaction
 //!   analysis.
 //!
+//! - **[`telemetry`]**: Emits `tracing` spans for updates, pipes, decoders and
+//!   processors behind the `tracing` feature, and exports them over OTLP with
+//!   the `otlp` feature.
+//!
 //! - **[`transaction`]**: Manages transaction data, including metadata
 //!   extraction and parsing. This module supports transaction validation and
 //!   processing, enabling detailed transaction insights.
@@ -113,15 +148,25 @@
 
 pub mod account;
 pub mod account_deletion;
+pub mod admin;
 pub mod collection;
 pub mod datasource;
+pub mod decode;
 pub mod deserialize;
 pub mod error;
 pub mod instruction;
+pub mod json;
+pub mod lag;
+pub mod latency;
+pub mod lookup_table;
 pub mod metrics;
+pub mod pda;
 pub mod pipeline;
+pub mod priority_fee;
 pub mod processor;
+pub mod program_error;
 pub mod schema;
+pub mod telemetry;
 pub mod transaction;
 pub mod transformers;
 pub use borsh;
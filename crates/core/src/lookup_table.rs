@@ -0,0 +1,198 @@
+//! Keeps the addresses of Address Lookup Tables in memory to resolve v0
+//! transactions whose status metadata lacks loaded addresses.
+//!
+//! A v0 message refers to accounts stored in lookup tables by index, through
+//! its `MessageAddressTableLookup`s. Most datasources report the resolved
+//! addresses in `meta.loaded_addresses`, but some, such as those reading raw
+//! entries or blocks without metadata, leave it empty. Without those
+//! addresses the instructions of such transactions can't be resolved.
+//!
+//! ## Key Components
+//!
+//! - **`AddressLookupTableCache`**: The addresses of each known lookup table,
+//!   kept current from the account updates of the Address Lookup Table
+//!   program, and able to resolve a message's table lookups.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! let lookup_table_cache = Arc::new(AddressLookupTableCache::new());
+//!
+//! carbon_core::pipeline::Pipeline::builder()
+//!     .datasource(datasource)
+//!     .lookup_table_cache(lookup_table_cache.clone())
+//!     .instruction(TestProgramDecoder, TestProgramProcessor)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! ## Notes
+//!
+//! - The cache only learns of a table when the pipeline receives an update of
+//!   its account, so the datasource must also subscribe to the accounts of
+//!   the Address Lookup Table program, or the tables must be inserted up
+//!   front.
+//! - Addresses loaded from `meta.loaded_addresses` always take precedence
+//!   over the cache.
+
+use {
+    solana_account::Account,
+    solana_program::message::v0::{LoadedAddresses, MessageAddressTableLookup},
+    solana_pubkey::Pubkey,
+    std::{collections::HashMap, sync::RwLock},
+};
+
+/// The address of the Address Lookup Table program.
+pub const ADDRESS_LOOKUP_TABLE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("AddressLookupTab1e1111111111111111111111111");
+
+/// The size of a lookup table's header, which precedes its addresses.
+pub const LOOKUP_TABLE_META_SIZE: usize = 56;
+
+/// The type tag of an initialized lookup table account.
+const LOOKUP_TABLE_TYPE: u32 = 1;
+
+/// An in-memory cache of Address Lookup Table addresses.
+///
+/// The cache can be shared between the pipeline, which keeps it current from
+/// account updates, and code that inserts tables fetched elsewhere, for
+/// example over RPC at startup.
+#[derive(Debug, Default)]
+pub struct AddressLookupTableCache {
+    tables: RwLock<HashMap<Pubkey, Vec<Pubkey>>>,
+}
+
+impl AddressLookupTableCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores the addresses of a lookup table, replacing any previous ones.
+    pub fn insert(&self, lookup_table: Pubkey, addresses: Vec<Pubkey>) {
+        log::trace!(
+            "AddressLookupTableCache::insert(lookup_table: {:?}, addresses: {})",
+            lookup_table,
+            addresses.len()
+        );
+        if let Ok(mut tables) = self.tables.write() {
+            tables.insert(lookup_table, addresses);
+        }
+    }
+
+    /// Forgets a lookup table, returning whether it was cached.
+    pub fn remove(&self, lookup_table: &Pubkey) -> bool {
+        log::trace!(
+            "AddressLookupTableCache::remove(lookup_table: {:?})",
+            lookup_table
+        );
+        self.tables
+            .write()
+            .map(|mut tables| tables.remove(lookup_table).is_some())
+            .unwrap_or(false)
+    }
+
+    /// Returns the addresses of a cached lookup table.
+    pub fn get(&self, lookup_table: &Pubkey) -> Option<Vec<Pubkey>> {
+        self.tables.read().ok()?.get(lookup_table).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tables.read().map(|tables| tables.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Updates the cache from an account update.
+    ///
+    /// A table's addresses are stored, and a closed table, whose account has
+    /// no lamports left, is forgotten. Other accounts are ignored.
+    ///
+    /// # Returns
+    ///
+    /// Whether the account is a lookup table the cache now holds.
+    pub fn update(&self, lookup_table: Pubkey, account: &Account) -> bool {
+        if account.lamports == 0 {
+            self.remove(&lookup_table);
+            return false;
+        }
+
+        if account.owner != ADDRESS_LOOKUP_TABLE_PROGRAM_ID {
+            return false;
+        }
+
+        match lookup_table_addresses(&account.data) {
+            Some(addresses) => {
+                self.insert(lookup_table, addresses);
+                true
+            }
+            None => {
+                self.remove(&lookup_table);
+                false
+            }
+        }
+    }
+
+    /// Resolves a message's table lookups to the addresses they load.
+    ///
+    /// As in the runtime, the writable addresses of all tables come before
+    /// the readonly ones, each in the order of the lookups.
+    ///
+    /// # Returns
+    ///
+    /// `None` if a table isn't cached or an index is out of its bounds.
+    pub fn resolve(
+        &self,
+        address_table_lookups: &[MessageAddressTableLookup],
+    ) -> Option<LoadedAddresses> {
+        let tables = self.tables.read().ok()?;
+        let mut loaded_addresses = LoadedAddresses::default();
+
+        for lookup in address_table_lookups {
+            let addresses = tables.get(&lookup.account_key)?;
+
+            for index in &lookup.writable_indexes {
+                loaded_addresses
+                    .writable
+                    .push(*addresses.get(*index as usize)?);
+            }
+            for index in &lookup.readonly_indexes {
+                loaded_addresses
+                    .readonly
+                    .push(*addresses.get(*index as usize)?);
+            }
+        }
+
+        Some(loaded_addresses)
+    }
+}
+
+/// Reads the addresses of a lookup table from its account data.
+///
+/// # Returns
+///
+/// `None` if the data isn't an initialized lookup table.
+pub fn lookup_table_addresses(data: &[u8]) -> Option<Vec<Pubkey>> {
+    let type_tag = u32::from_le_bytes(data.get(..4)?.try_into().ok()?);
+    if type_tag != LOOKUP_TABLE_TYPE {
+        return None;
+    }
+
+    let addresses = data.get(LOOKUP_TABLE_META_SIZE..)?;
+    if addresses.len() % 32 != 0 {
+        return None;
+    }
+
+    Some(
+        addresses
+            .chunks_exact(32)
+            .map(|address| {
+                let mut bytes = [0u8; 32];
+                bytes.copy_from_slice(address);
+                Pubkey::new_from_array(bytes)
+            })
+            .collect(),
+    )
+}
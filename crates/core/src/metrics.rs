@@ -15,6 +15,9 @@
 //!   successful or failed update processing.
 //! - **Histograms**: Measure the distribution of values, such as processing
 //!   times, allowing insights into latency or response times.
+//! - **Labels**: Every metric accepts a list of `(key, value)` pairs that
+//!   split it into separate series, e.g. `decoder="PumpfunDecoder"`. Pass an
+//!   empty slice for a global series.
 //!
 //! ## Implementing the Trait
 //!
@@ -23,8 +26,59 @@
 //! visualization and alerting. The trait requires `async` functions, allowing
 //! implementations to perform non-blocking I/O operations, such as network
 //! requests or database writes.
+//!
+//! ## Pipeline Series
+//!
+//! Besides the global `updates_*` series, the pipeline records:
+//!
+//! - `datasource_updates_received`, labeled with `datasource` and
+//!   `update_type`.
+//! - `datasource_updates_queued` and `datasource_errors`, labeled with
+//!   `datasource`.
+//! - `pipe_updates_decoded`, `pipe_updates_processed`, `pipe_updates_failed`,
+//!   `pipe_process_time_nanoseconds` and `pipe_budget_exceeded`, labeled with
+//!   `pipe`, `processor` and, for account and instruction pipes, `decoder` and
+//!   `program_id`.
+//! - `pipe_decode_failures`, labeled with `pipe`, `decoder`, `program_id` and
+//!   `reason`, for data of a decoder's program that it could not decode.
+//!
+//! ## Datasource Series
+//!
+//! The bundled datasources record their own series under the `datasource_`
+//! prefix, labeled with `datasource` and, where it varies, `kind` (`account`,
+//! `account_deletion`, `transaction` or `block`):
+//!
+//! - `datasource_updates_emitted`, labeled with `datasource` and `kind`, for
+//!   updates successfully sent to the pipeline. It differs from the pipeline's
+//!   `datasource_updates_received` by the updates dropped on a full channel
+//!   and those still queued.
+//! - `datasource_update_process_time_nanoseconds`, labeled with `datasource`
+//!   and `kind`, for the time spent turning a raw message into an update.
+//! - `datasource_fetch_time_milliseconds`, labeled with `datasource` and
+//!   `kind`, for RPC requests of the crawlers.
+//! - `datasource_blocks_fetched`, `datasource_blocks_skipped`,
+//!   `datasource_blocks_received`, `datasource_blocks_processed`,
+//!   `datasource_signatures_fetched`, `datasource_transactions_fetched` and
+//!   `datasource_clock_process_time_nanoseconds`, labeled with `datasource`.
+//!
+//! These replace the unlabeled per-datasource names such as
+//! `yellowstone_grpc_account_updates_received` and
+//! `block_crawler_transactions_processed`. The latter counted every
+//! transaction the block crawler converted, including those it then failed to
+//! send, and is now `datasource_updates_emitted{kind="transaction"}`, which
+//! only counts sent ones. `block_crawler_blocks_received` and
+//! `block_crawler_blocks_processed` are `datasource_blocks_received` and
+//! `datasource_blocks_processed`.
 
-use {crate::error::CarbonResult, async_trait::async_trait, std::sync::Arc};
+use {
+    crate::{
+        decode::{DecodeFailure, DecodeFailureSampler},
+        error::CarbonResult,
+        latency::{LatencyMonitor, PipeLatency},
+    },
+    async_trait::async_trait,
+    std::{fmt, sync::Arc, time::Instant},
+};
 
 #[async_trait]
 pub trait Metrics: Send + Sync {
@@ -42,35 +96,59 @@ pub trait Metrics: Send + Sync {
     /// # Parameters
     ///
     /// - `name`: The name of the gauge metric to update.
+    /// - `labels`: The labels identifying the series, as `(key, value)` pairs.
     /// - `value`: The current value of the gauge metric.
-    async fn update_gauge(&self, name: &str, value: f64) -> CarbonResult<()>;
+    async fn update_gauge(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()>;
 
     /// Increments a counter metric by a specified value.
     ///
     /// # Parameters
     ///
     /// - `name`: The name of the counter metric to increment.
+    /// - `labels`: The labels identifying the series, as `(key, value)` pairs.
     /// - `value`: The amount by which to increment the counter.
-    async fn increment_counter(&self, name: &str, value: u64) -> CarbonResult<()>;
+    async fn increment_counter(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: u64,
+    ) -> CarbonResult<()>;
 
     /// Records a value in a histogram metric, representing distribution data.
     ///
     /// # Parameters
     ///
     /// - `name`: The name of the histogram metric to record.
+    /// - `labels`: The labels identifying the series, as `(key, value)` pairs.
     /// - `value`: The value to add to the histogram, typically representing
     ///   time or size.
-    async fn record_histogram(&self, name: &str, value: f64) -> CarbonResult<()>;
+    async fn record_histogram(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()>;
 }
 
 #[derive(Default)]
 pub struct MetricsCollection {
     pub metrics: Vec<Arc<dyn Metrics>>,
+    pub decode_failure_sampler: Option<Arc<DecodeFailureSampler>>,
+    pub latency: LatencyMonitor,
 }
 
 impl MetricsCollection {
     pub fn new(metrics: Vec<Arc<dyn Metrics>>) -> Self {
-        Self { metrics }
+        Self {
+            metrics,
+            decode_failure_sampler: None,
+            latency: LatencyMonitor::default(),
+        }
     }
 
     pub async fn initialize_metrics(&self) -> CarbonResult<()> {
@@ -81,6 +159,8 @@ impl MetricsCollection {
     }
 
     pub async fn shutdown_metrics(&self) -> CarbonResult<()> {
+        self.latency.log_summary();
+
         for metric in &self.metrics {
             metric.shutdown().await?;
         }
@@ -94,24 +174,151 @@ impl MetricsCollection {
         Ok(())
     }
 
-    pub async fn update_gauge(&self, name: &str, value: f64) -> CarbonResult<()> {
+    pub async fn update_gauge(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
         for metric in &self.metrics {
-            metric.update_gauge(name, value).await?;
+            metric.update_gauge(name, labels, value).await?;
         }
         Ok(())
     }
 
-    pub async fn increment_counter(&self, name: &str, value: u64) -> CarbonResult<()> {
+    pub async fn increment_counter(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: u64,
+    ) -> CarbonResult<()> {
         for metric in &self.metrics {
-            metric.increment_counter(name, value).await?;
+            metric.increment_counter(name, labels, value).await?;
         }
         Ok(())
     }
 
-    pub async fn record_histogram(&self, name: &str, value: f64) -> CarbonResult<()> {
+    /// Records the duration and outcome of a pipe run under the
+    /// `pipe_process_time_nanoseconds`, `pipe_updates_processed` and
+    /// `pipe_updates_failed` series, adds it to the pipe's latency totals and
+    /// checks it against the processor's budget, then hands back the run's
+    /// result.
+    ///
+    /// # Parameters
+    ///
+    /// - `labels`: The labels of the pipe's series.
+    /// - `latency`: The pipe's latency totals from `LatencyMonitor::register`.
+    ///   Runs without them are not checked against a budget.
+    /// - `update`: The signature or pubkey of the update, logged when the run
+    ///   exceeds its budget.
+    /// - `start`: When the run started.
+    /// - `result`: The result of the run.
+    pub async fn record_pipe_run(
+        &self,
+        labels: &[(&str, &str)],
+        latency: Option<&PipeLatency>,
+        update: &(dyn fmt::Display + Sync),
+        start: Instant,
+        result: CarbonResult<()>,
+    ) -> CarbonResult<()> {
+        let elapsed = start.elapsed();
+
+        self.record_histogram(
+            "pipe_process_time_nanoseconds",
+            labels,
+            elapsed.as_nanos() as f64,
+        )
+        .await?;
+
+        let exceeded_budget = latency.and_then(|latency| latency.record(elapsed, update));
+        if exceeded_budget.is_some() {
+            self.increment_counter("pipe_budget_exceeded", labels, 1)
+                .await?;
+        }
+
+        match result {
+            Ok(()) => {
+                self.increment_counter("pipe_updates_processed", labels, 1)
+                    .await
+            }
+            Err(error) => {
+                self.increment_counter("pipe_updates_failed", labels, 1)
+                    .await?;
+                Err(error)
+            }
+        }
+    }
+
+    /// Counts a decode failure under the `pipe_decode_failures` series, with
+    /// the failure's `reason` added to `labels`, and hands the raw data to the
+    /// decode failure sampler, if one is configured.
+    ///
+    /// Sampling errors are logged rather than returned, so a full disk does
+    /// not stop the pipeline.
+    pub async fn record_decode_failure(
+        &self,
+        labels: &[(&str, &str)],
+        slot: u64,
+        failure: &DecodeFailure,
+        data: &[u8],
+    ) -> CarbonResult<()> {
+        let mut failure_labels = labels.to_vec();
+        failure_labels.push(("reason", failure.reason()));
+
+        self.increment_counter("pipe_decode_failures", &failure_labels, 1)
+            .await?;
+
+        if let Some(sampler) = &self.decode_failure_sampler {
+            if let Err(err) = sampler.sample(labels, slot, failure, data) {
+                log::warn!("Failed to sample decode failure: {err}");
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn record_histogram(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
         for metric in &self.metrics {
-            metric.record_histogram(name, value).await?;
+            metric.record_histogram(name, labels, value).await?;
         }
         Ok(())
     }
 }
+
+/// Returns the last path segment of a type name, without generic arguments.
+///
+/// Used to derive short label values such as `PumpfunDecoder` from
+/// [`std::any::type_name`] output like
+/// `carbon_pumpfun_decoder::PumpfunDecoder`.
+pub fn short_type_name(type_name: &str) -> &str {
+    let without_generics = type_name
+        .split_once('<')
+        .map_or(type_name, |(path, _)| path);
+
+    without_generics
+        .rsplit_once("::")
+        .map_or(without_generics, |(_, name)| name)
+}
+
+/// Formats a metric name and its labels as a single series identifier, e.g.
+/// `pipe_updates_failed{pipe="instruction",decoder="PumpfunDecoder"}`.
+///
+/// Returns the bare name when `labels` is empty.
+pub fn series_key(name: &str, labels: &[(&str, &str)]) -> String {
+    if labels.is_empty() {
+        return name.to_string();
+    }
+
+    let labels = labels
+        .iter()
+        .map(|(key, value)| format!("{key}=\"{value}\""))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!("{name}{{{labels}}}")
+}
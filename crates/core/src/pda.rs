@@ -0,0 +1,32 @@
+//! The outcome of checking an instruction's PDA accounts against the
+//! addresses derived from their seeds.
+//!
+//! The CLI generates a `verify_pdas` function for every instruction whose IDL
+//! declares `pda.seeds` for its accounts. It returns `None` when none of the
+//! PDAs can be derived from the instruction's accounts and args alone, for
+//! example when a seed reads the data of another account, and otherwise the
+//! first account found at the wrong address as a [`PdaMismatch`]. The
+//! accounts it can't check are listed in the generated
+//! `UNCHECKED_PDA_ACCOUNTS` constant.
+
+use std::fmt;
+
+/// An instruction account that isn't at the address derived from its PDA
+/// seeds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PdaMismatch {
+    /// The name of the account, as arranged by the instruction.
+    pub account: &'static str,
+}
+
+impl fmt::Display for PdaMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "account `{}` is not at the address derived from its PDA seeds",
+            self.account
+        )
+    }
+}
+
+impl std::error::Error for PdaMismatch {}
@@ -40,6 +40,27 @@
 //!   on performance data.
 //! - **metrics_flush_interval**: Specifies how frequently metrics are flushed.
 //!   Defaults to 5 seconds if unset.
+//! - **admin_server**: An optional HTTP server exposing health, readiness and
+//!   status endpoints, and controls to pause datasources or shut down.
+//! - **decode_failure_sampler**: An optional sink for the raw data of
+//!   accounts and instructions that their decoder's program owns but that
+//!   fail to decode.
+//! - **latency budgets**: Optional per-processor limits on the time a pipe
+//!   may spend on one update. Slower updates are logged, and the slowest
+//!   pipes are summarized at shutdown.
+//! - **drain_timeout**: How long a `ProcessPending` shutdown may take to
+//!   process the queued updates before the pipeline stops anyway.
+//! - **lookup_table_cache**: An optional cache of Address Lookup Tables, kept
+//!   current from account updates, that resolves the loaded addresses of v0
+//!   transactions whose metadata lacks them.
+//!
+//! ## Shutdown
+//!
+//! The pipeline shuts down on SIGINT, SIGTERM or SIGHUP, on a request through
+//! the admin server, or through the [`PipelineHandle`] returned by
+//! [`Pipeline::spawn`]. With `stop_when_datasources_finish`, it also stops
+//! once every datasource has finished and the queued updates have been
+//! processed. Otherwise it keeps running until it is shut down.
 //!
 //! ## Notes
 //!
@@ -56,22 +77,36 @@ use {
             AccountDecoder, AccountMetadata, AccountPipe, AccountPipes, AccountProcessorInputType,
         },
         account_deletion::{AccountDeletionPipe, AccountDeletionPipes},
+        admin::{AdminServer, AdminState, DatasourceControl, DatasourceState},
         collection::InstructionDecoderCollection,
         datasource::{AccountDeletion, Datasource, Update},
-        error::CarbonResult,
+        decode::DecodeFailureSampler,
+        error::{CarbonResult, Error},
         instruction::{
             InstructionDecoder, InstructionPipe, InstructionPipes, InstructionProcessorInputType,
             InstructionsWithMetadata, NestedInstructions,
         },
+        lag::{LagMonitor, LagWarning},
+        lookup_table::AddressLookupTableCache,
         metrics::{Metrics, MetricsCollection},
         processor::Processor,
         schema::TransactionSchema,
+        telemetry::{traced, UpdateSpan},
         transaction::{TransactionPipe, TransactionPipes, TransactionProcessorInputType},
         transformers,
     },
     core::time,
     serde::de::DeserializeOwned,
-    std::{convert::TryInto, sync::Arc, time::Instant},
+    std::{
+        convert::TryInto,
+        future::Future,
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc, Mutex,
+        },
+        time::{Duration, Instant},
+    },
+    tokio::task::JoinHandle,
     tokio_util::sync::CancellationToken,
 };
 
@@ -97,7 +132,9 @@ use {
 ///
 /// - `ProcessPending` is the default variant, enabling the pipeline to ensure
 ///   that no updates are lost during shutdown.
-#[derive(Default, PartialEq, Debug)]
+/// - With a `drain_timeout`, `ProcessPending` stops processing once the
+///   timeout has passed, even if updates are still queued.
+#[derive(Default, PartialEq, Debug, Clone, Copy)]
 pub enum ShutdownStrategy {
     /// Stop the whole pipeline immediately.
     Immediate,
@@ -115,6 +152,11 @@ pub enum ShutdownStrategy {
 /// The default size is 10,000 updates, which provides a reasonable balance
 pub const DEFAULT_CHANNEL_BUFFER_SIZE: usize = 1_000;
 
+/// The size of the channel between a datasource and the task that forwards
+/// its updates to the pipeline's channel. Updates queue up in the pipeline's
+/// channel, so this only absorbs bursts while the forwarder records metrics.
+const DATASOURCE_CHANNEL_BUFFER_SIZE: usize = 64;
+
 /// Represents the primary data processing pipeline in the `carbon-core`
 /// framework.
 ///
@@ -167,6 +209,16 @@ pub const DEFAULT_CHANNEL_BUFFER_SIZE: usize = 1_000;
 ///   used.
 /// - `channel_buffer_size`: The size of the channel buffer for the pipeline.
 ///   If not set, a default size of 10_000 will be used.
+/// - `lag_monitor`: Tracks slot and block time lag and stalled datasources,
+///   and warns when the configured thresholds are exceeded.
+/// - `admin_server`: An optional HTTP server for health checks, status and
+///   control of the running pipeline.
+/// - `drain_timeout`: The maximum time a `ProcessPending` shutdown may spend
+///   processing queued updates. If `None`, it waits until the queue is empty.
+/// - `stop_when_datasources_finish`: Whether the pipeline stops once every
+///   datasource has finished and the queued updates have been processed.
+/// - `lookup_table_cache`: An optional cache of Address Lookup Tables used to
+///   resolve v0 transactions whose metadata lacks loaded addresses.
 ///
 /// ## Example
 ///
@@ -212,6 +264,11 @@ pub struct Pipeline {
     pub datasource_cancellation_token: Option<CancellationToken>,
     pub shutdown_strategy: ShutdownStrategy,
     pub channel_buffer_size: usize,
+    pub lag_monitor: LagMonitor,
+    pub admin_server: Option<AdminServer>,
+    pub drain_timeout: Option<Duration>,
+    pub stop_when_datasources_finish: bool,
+    pub lookup_table_cache: Option<Arc<AddressLookupTableCache>>,
 }
 
 impl Pipeline {
@@ -255,6 +312,11 @@ impl Pipeline {
             datasource_cancellation_token: None,
             shutdown_strategy: ShutdownStrategy::default(),
             channel_buffer_size: DEFAULT_CHANNEL_BUFFER_SIZE,
+            lag_monitor: LagMonitor::default(),
+            admin_server: None,
+            drain_timeout: None,
+            stop_when_datasources_finish: false,
+            lookup_table_cache: None,
         }
     }
 
@@ -315,7 +377,70 @@ impl Pipeline {
     ///   `metrics_flush_interval`.
     /// - The `run` method operates in an infinite loop, handling updates until
     ///   a termination condition occurs.
+    /// - Use [`Pipeline::spawn`] instead to stop the pipeline from code.
     pub async fn run(&mut self) -> CarbonResult<()> {
+        self.run_with_control(Arc::new(PipelineControl::new()), shutdown_signal)
+            .await
+    }
+
+    /// Runs the `Pipeline` on a new Tokio task and returns a handle to control
+    /// it.
+    ///
+    /// The pipeline behaves as with [`Pipeline::run`], and can additionally be
+    /// shut down through the returned [`PipelineHandle`], which also reports
+    /// its progress and completion.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let handle = Pipeline::builder()
+    ///     .datasource(MyDatasource::new())
+    ///     .instruction(MyDecoder, MyProcessor)
+    ///     .drain_timeout(Duration::from_secs(20))
+    ///     .build()?
+    ///     .spawn();
+    ///
+    /// // ...
+    ///
+    /// handle.shutdown(ShutdownStrategy::ProcessPending);
+    /// let stats = handle.wait().await?;
+    /// log::info!("processed {} updates", stats.updates_processed);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Must be called from within a Tokio runtime.
+    /// - Dropping the handle does not stop the pipeline.
+    pub fn spawn(self) -> PipelineHandle {
+        log::trace!("spawn(self)");
+        self.spawn_with_signal(shutdown_signal)
+    }
+
+    /// Spawns the pipeline, listening for shutdown signals through
+    /// `next_signal` instead of the process' signals.
+    fn spawn_with_signal<S, F>(mut self, next_signal: S) -> PipelineHandle
+    where
+        S: Fn() -> F + Send + 'static,
+        F: Future<Output = &'static str> + Send,
+    {
+        let control = Arc::new(PipelineControl::new());
+        let control_clone = Arc::clone(&control);
+
+        let task =
+            tokio::spawn(async move { self.run_with_control(control_clone, next_signal).await });
+
+        PipelineHandle { control, task }
+    }
+
+    async fn run_with_control<S, F>(
+        &mut self,
+        control: Arc<PipelineControl>,
+        next_signal: S,
+    ) -> CarbonResult<()>
+    where
+        S: Fn() -> F,
+        F: Future<Output = &'static str>,
+    {
         log::info!("starting pipeline. num_datasources: {}, num_metrics: {}, num_account_pipes: {}, num_account_deletion_pipes: {}, num_instruction_pipes: {}, num_transaction_pipes: {}",
             self.datasources.len(),
             self.metrics.metrics.len(),
@@ -329,98 +454,248 @@ impl Pipeline {
 
         self.metrics.initialize_metrics().await?;
         let (update_sender, mut update_receiver) =
-            tokio::sync::mpsc::channel::<Update>(self.channel_buffer_size);
+            tokio::sync::mpsc::channel::<(Update, UpdateSpan)>(self.channel_buffer_size);
 
         let datasource_cancellation_token = self
             .datasource_cancellation_token
             .clone()
             .unwrap_or_default();
+        let mut datasource_controls = Vec::with_capacity(self.datasources.len());
 
         for datasource in &self.datasources {
             let datasource_cancellation_token_clone = datasource_cancellation_token.clone();
-            let sender_clone = update_sender.clone();
             let datasource_clone = Arc::clone(datasource);
             let metrics_collection = self.metrics.clone();
+            let datasource_name = datasource.name();
+            let control = Arc::new(DatasourceControl::new(datasource_name));
+            let control_clone = Arc::clone(&control);
+            let (datasource_sender, mut datasource_receiver) = tokio::sync::mpsc::channel::<Update>(
+                DATASOURCE_CHANNEL_BUFFER_SIZE.min(self.channel_buffer_size),
+            );
 
             tokio::spawn(async move {
+                // Datasources may keep sending from their own tasks after
+                // `consume` returns, so a datasource only counts as finished
+                // once its channel closes.
                 if let Err(e) = datasource_clone
                     .consume(
-                        &sender_clone,
+                        &datasource_sender,
                         datasource_cancellation_token_clone,
-                        metrics_collection,
+                        metrics_collection.clone(),
                     )
                     .await
                 {
                     log::error!("error consuming datasource: {:?}", e);
+                    control_clone.set_state(DatasourceState::Failed(e.to_string()));
+                    metrics_collection
+                        .increment_counter(
+                            "datasource_errors",
+                            &[("datasource", datasource_name)],
+                            1,
+                        )
+                        .await
+                        .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
+                }
+            });
+
+            // Forwards the datasource's updates to the pipeline, counting them
+            // and tracking the latest slot per datasource on the way. While the
+            // datasource is paused, its updates are dropped.
+            let sender_clone = update_sender.clone();
+            let metrics_collection = self.metrics.clone();
+            let received_updates = self.lag_monitor.received_updates();
+            let control_clone = Arc::clone(&control);
+            datasource_controls.push(control);
+
+            tokio::spawn(async move {
+                while let Some(update) = datasource_receiver.recv().await {
+                    if control_clone.is_paused() {
+                        metrics_collection
+                            .increment_counter(
+                                "datasource_updates_dropped",
+                                &[("datasource", datasource_name)],
+                                1,
+                            )
+                            .await
+                            .unwrap_or_else(|value| {
+                                log::error!("Error recording metric: {}", value)
+                            });
+                        continue;
+                    }
+
+                    metrics_collection
+                        .increment_counter(
+                            "datasource_updates_received",
+                            &[
+                                ("datasource", datasource_name),
+                                ("update_type", update.type_label()),
+                            ],
+                            1,
+                        )
+                        .await
+                        .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
+
+                    let slot = update.slot();
+                    received_updates.record(slot);
+                    metrics_collection
+                        .update_gauge(
+                            "datasource_latest_slot",
+                            &[("datasource", datasource_name)],
+                            slot as f64,
+                        )
+                        .await
+                        .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
+                    metrics_collection
+                        .update_gauge(
+                            "datasource_updates_queued",
+                            &[("datasource", datasource_name)],
+                            datasource_receiver.len() as f64,
+                        )
+                        .await
+                        .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
+
+                    let span = UpdateSpan::new(datasource_name, &update);
+
+                    if sender_clone.send((update, span)).await.is_err() {
+                        break;
+                    }
                 }
+
+                control_clone.mark_finished();
             });
         }
 
+        // Once this sender is dropped, only the forwarders hold senders, so the
+        // channel closes after every datasource has finished. Unless the
+        // pipeline should stop then, it is kept until a shutdown starts.
+        let mut idle_update_sender = (!self.stop_when_datasources_finish).then_some(update_sender);
+
+        let admin_state = Arc::new(AdminState::new(
+            datasource_controls,
+            control.shutdown.clone(),
+        ));
+        let _admin_server_task = match &self.admin_server {
+            Some(admin_server) => Some(admin_server.start(Arc::clone(&admin_state)).await?),
+            None => None,
+        };
+
         let mut interval = tokio::time::interval(time::Duration::from_secs(
             self.metrics_flush_interval.unwrap_or(5),
         ));
+        let signal = next_signal();
+        tokio::pin!(signal);
+        let mut shutting_down = false;
+        let mut drain_deadline: Option<tokio::time::Instant> = None;
 
         loop {
+            admin_state.heartbeat();
+
+            // Biased, so signals, shutdown requests and the drain timeout take
+            // effect before the next queued update is processed.
             tokio::select! {
-                _ = tokio::signal::ctrl_c() => {
-                    log::trace!("received SIGINT, shutting down.");
+                biased;
+
+                signal_name = &mut signal => {
+                    // A shutdown may have been requested without being started
+                    // yet, e.g. by a first signal just before this one.
+                    if control.shutdown.is_cancelled() {
+                        log::warn!("received {} while shutting down, shutting down immediately.", signal_name);
+                        self.metrics.flush_metrics().await?;
+                        self.metrics.shutdown_metrics().await?;
+                        break;
+                    }
+
+                    log::info!("received {}, shutting down.", signal_name);
+                    signal.set(next_signal());
+                    control.shutdown.cancel();
+                }
+                _ = control.shutdown.cancelled(), if !shutting_down => {
+                    shutting_down = true;
+                    admin_state.set_shutting_down();
+                    admin_state.resume_datasources();
                     datasource_cancellation_token.cancel();
+                    idle_update_sender.take();
+
+                    let shutdown_strategy = control
+                        .requested_strategy()
+                        .unwrap_or(self.shutdown_strategy);
 
-                    if self.shutdown_strategy == ShutdownStrategy::Immediate {
+                    if shutdown_strategy == ShutdownStrategy::Immediate {
                         log::info!("shutting down the pipeline immediately.");
                         self.metrics.flush_metrics().await?;
                         self.metrics.shutdown_metrics().await?;
                         break;
                     } else {
                         log::info!("shutting down the pipeline after processing pending updates.");
+                        drain_deadline = self
+                            .drain_timeout
+                            .map(|timeout| tokio::time::Instant::now() + timeout);
                     }
                 }
+                _ = tokio::time::sleep_until(drain_deadline.unwrap_or_else(tokio::time::Instant::now)), if drain_deadline.is_some() => {
+                    log::warn!(
+                        "drain timeout of {:?} exceeded, shutting down with {} updates still queued.",
+                        self.drain_timeout.unwrap_or_default(),
+                        update_receiver.len(),
+                    );
+                    self.metrics.flush_metrics().await?;
+                    self.metrics.shutdown_metrics().await?;
+                    break;
+                }
                 _ = interval.tick() => {
+                    self.lag_monitor.check(&self.metrics).await?;
                     self.metrics.flush_metrics().await?;
                 }
                 update = update_receiver.recv() => {
                     match update {
-                        Some(update) => {
+                        Some((update, span)) => {
                             self
-                                .metrics.increment_counter("updates_received", 1)
+                                .metrics.increment_counter("updates_received", &[], 1)
                                 .await?;
+                            control.stats.updates_received.fetch_add(1, Ordering::Relaxed);
 
                             let start = Instant::now();
-                            let process_result = self.process(update.clone()).await;
+                            let process_result = span.instrument(self.process(update.clone())).await;
                             let time_taken_nanoseconds = start.elapsed().as_nanos();
                             let time_taken_milliseconds = time_taken_nanoseconds / 1_000_000;
 
+                            self.lag_monitor.record_processed(&update, &self.metrics).await?;
+
                             self
                                 .metrics
-                                .record_histogram("updates_process_time_nanoseconds", time_taken_nanoseconds as f64)
+                                .record_histogram("updates_process_time_nanoseconds", &[], time_taken_nanoseconds as f64)
                                 .await?;
 
                             self
                                 .metrics
-                                .record_histogram("updates_process_time_milliseconds", time_taken_milliseconds as f64)
+                                .record_histogram("updates_process_time_milliseconds", &[], time_taken_milliseconds as f64)
                                 .await?;
 
                             match process_result {
                                 Ok(_) => {
                                     self
-                                        .metrics.increment_counter("updates_successful", 1)
+                                        .metrics.increment_counter("updates_successful", &[], 1)
                                         .await?;
+                                    control.stats.updates_successful.fetch_add(1, Ordering::Relaxed);
 
                                     log::trace!("processed update")
                                 }
                                 Err(error) => {
                                     log::error!("error processing update ({:?}): {:?}", update, error);
-                                    self.metrics.increment_counter("updates_failed", 1).await?;
+                                    self.metrics.increment_counter("updates_failed", &[], 1).await?;
+                                    control.stats.updates_failed.fetch_add(1, Ordering::Relaxed);
                                 }
                             };
 
                             self
-                                .metrics.increment_counter("updates_processed", 1)
+                                .metrics.increment_counter("updates_processed", &[], 1)
                                 .await?;
 
                             self
-                                .metrics.update_gauge("updates_queued", update_receiver.len() as f64)
+                                .metrics.update_gauge("updates_queued", &[], update_receiver.len() as f64)
                                 .await?;
+
+                            control.stats.record_processed(update.slot(), update_receiver.len());
                         }
                         None => {
                             log::info!("update_receiver closed, shutting down.");
@@ -496,19 +771,38 @@ impl Pipeline {
                     pubkey: account_update.pubkey,
                 };
 
+                if let Some(lookup_table_cache) = &self.lookup_table_cache {
+                    lookup_table_cache.update(account_update.pubkey, &account_update.account);
+                }
+
                 for pipe in self.account_pipes.iter_mut() {
-                    pipe.run(
-                        (account_metadata.clone(), account_update.account.clone()),
-                        self.metrics.clone(),
+                    traced!(
+                        pipe.run(
+                            (account_metadata.clone(), account_update.account.clone()),
+                            self.metrics.clone(),
+                        ),
+                        "pipe",
+                        pipe = "account"
                     )
                     .await?;
                 }
 
                 self.metrics
-                    .increment_counter("account_updates_processed", 1)
+                    .increment_counter("account_updates_processed", &[], 1)
                     .await?;
             }
-            Update::Transaction(transaction_update) => {
+            Update::Transaction(mut transaction_update) => {
+                if let Some(lookup_table_cache) = &self.lookup_table_cache {
+                    if transformers::resolve_loaded_addresses(
+                        &mut transaction_update,
+                        lookup_table_cache,
+                    ) {
+                        self.metrics
+                            .increment_counter("lookup_table_resolutions", &[], 1)
+                            .await?;
+                    }
+                }
+
                 let transaction_metadata = &(*transaction_update).clone().try_into()?;
 
                 let instructions_with_metadata: InstructionsWithMetadata =
@@ -521,31 +815,48 @@ impl Pipeline {
 
                 for pipe in self.instruction_pipes.iter_mut() {
                     for nested_instruction in nested_instructions.iter() {
-                        pipe.run(nested_instruction, self.metrics.clone()).await?;
+                        traced!(
+                            pipe.run(nested_instruction, self.metrics.clone()),
+                            "pipe",
+                            pipe = "instruction"
+                        )
+                        .await?;
                     }
                 }
 
                 for pipe in self.transaction_pipes.iter_mut() {
-                    pipe.run(
-                        transaction_metadata.clone(),
-                        &nested_instructions,
-                        self.metrics.clone(),
+                    traced!(
+                        pipe.run(
+                            transaction_metadata.clone(),
+                            &nested_instructions,
+                            self.metrics.clone(),
+                        ),
+                        "pipe",
+                        pipe = "transaction"
                     )
                     .await?;
                 }
 
                 self.metrics
-                    .increment_counter("transaction_updates_processed", 1)
+                    .increment_counter("transaction_updates_processed", &[], 1)
                     .await?;
             }
             Update::AccountDeletion(account_deletion) => {
+                if let Some(lookup_table_cache) = &self.lookup_table_cache {
+                    lookup_table_cache.remove(&account_deletion.pubkey);
+                }
+
                 for pipe in self.account_deletion_pipes.iter_mut() {
-                    pipe.run(account_deletion.clone(), self.metrics.clone())
-                        .await?;
+                    traced!(
+                        pipe.run(account_deletion.clone(), self.metrics.clone()),
+                        "pipe",
+                        pipe = "account_deletion"
+                    )
+                    .await?;
                 }
 
                 self.metrics
-                    .increment_counter("account_deletions_processed", 1)
+                    .increment_counter("account_deletions_processed", &[], 1)
                     .await?;
             }
         };
@@ -554,6 +865,181 @@ impl Pipeline {
     }
 }
 
+/// Resolves when the process receives SIGINT, SIGTERM or SIGHUP, returning
+/// the signal's name.
+async fn shutdown_signal() -> &'static str {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match (
+            signal(SignalKind::terminate()),
+            signal(SignalKind::hangup()),
+        ) {
+            (Ok(mut terminate), Ok(mut hangup)) => {
+                return tokio::select! {
+                    _ = tokio::signal::ctrl_c() => "SIGINT",
+                    _ = terminate.recv() => "SIGTERM",
+                    _ = hangup.recv() => "SIGHUP",
+                };
+            }
+            (Err(err), _) | (_, Err(err)) => {
+                log::error!(
+                    "failed to listen for SIGTERM and SIGHUP, only SIGINT shuts down the pipeline: {}",
+                    err
+                );
+            }
+        }
+    }
+
+    if let Err(err) = tokio::signal::ctrl_c().await {
+        log::error!("failed to listen for SIGINT: {}", err);
+        std::future::pending::<()>().await;
+    }
+
+    "SIGINT"
+}
+
+/// The state shared between a running pipeline and its `PipelineHandle`.
+struct PipelineControl {
+    shutdown: CancellationToken,
+    shutdown_strategy: Mutex<Option<ShutdownStrategy>>,
+    stats: PipelineCounters,
+}
+
+impl PipelineControl {
+    fn new() -> Self {
+        Self {
+            shutdown: CancellationToken::new(),
+            shutdown_strategy: Mutex::new(None),
+            stats: PipelineCounters::new(),
+        }
+    }
+
+    fn request_shutdown(&self, shutdown_strategy: ShutdownStrategy) {
+        self.shutdown_strategy
+            .lock()
+            .unwrap()
+            .get_or_insert(shutdown_strategy);
+        self.shutdown.cancel();
+    }
+
+    fn requested_strategy(&self) -> Option<ShutdownStrategy> {
+        *self.shutdown_strategy.lock().unwrap()
+    }
+}
+
+struct PipelineCounters {
+    started: Instant,
+    updates_received: AtomicU64,
+    updates_processed: AtomicU64,
+    updates_successful: AtomicU64,
+    updates_failed: AtomicU64,
+    updates_queued: AtomicU64,
+    highest_slot_processed: AtomicU64,
+}
+
+impl PipelineCounters {
+    fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            updates_received: AtomicU64::new(0),
+            updates_processed: AtomicU64::new(0),
+            updates_successful: AtomicU64::new(0),
+            updates_failed: AtomicU64::new(0),
+            updates_queued: AtomicU64::new(0),
+            highest_slot_processed: AtomicU64::new(0),
+        }
+    }
+
+    fn record_processed(&self, slot: u64, queued: usize) {
+        self.updates_processed.fetch_add(1, Ordering::Relaxed);
+        self.updates_queued.store(queued as u64, Ordering::Relaxed);
+        self.highest_slot_processed
+            .fetch_max(slot, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> PipelineStats {
+        PipelineStats {
+            uptime: self.started.elapsed(),
+            updates_received: self.updates_received.load(Ordering::Relaxed),
+            updates_processed: self.updates_processed.load(Ordering::Relaxed),
+            updates_successful: self.updates_successful.load(Ordering::Relaxed),
+            updates_failed: self.updates_failed.load(Ordering::Relaxed),
+            updates_queued: self.updates_queued.load(Ordering::Relaxed),
+            highest_slot_processed: self.highest_slot_processed.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A snapshot of a pipeline's progress.
+///
+/// # Fields
+///
+/// - `uptime`: The time since the pipeline was started.
+/// - `updates_received`: The updates taken from the queue.
+/// - `updates_processed`: The updates that went through all pipes.
+/// - `updates_successful`: The processed updates without a pipe error.
+/// - `updates_failed`: The processed updates with a pipe error.
+/// - `updates_queued`: The updates waiting in the queue after the last
+///   processed one.
+/// - `highest_slot_processed`: The highest slot of a processed update.
+#[derive(Debug, Clone, Default)]
+pub struct PipelineStats {
+    pub uptime: Duration,
+    pub updates_received: u64,
+    pub updates_processed: u64,
+    pub updates_successful: u64,
+    pub updates_failed: u64,
+    pub updates_queued: u64,
+    pub highest_slot_processed: u64,
+}
+
+/// A handle to a pipeline started with [`Pipeline::spawn`].
+///
+/// The handle requests shutdowns, reports the pipeline's progress and waits
+/// for it to finish. Dropping the handle leaves the pipeline running.
+pub struct PipelineHandle {
+    control: Arc<PipelineControl>,
+    task: JoinHandle<CarbonResult<()>>,
+}
+
+impl PipelineHandle {
+    /// Requests the pipeline to shut down with `shutdown_strategy`, overriding
+    /// the strategy it was built with.
+    ///
+    /// Only the first request's strategy applies. The pipeline stops
+    /// asynchronously; use [`PipelineHandle::wait`] to wait for it.
+    pub fn shutdown(&self, shutdown_strategy: ShutdownStrategy) {
+        log::trace!("shutdown(self, shutdown_strategy: {:?})", shutdown_strategy);
+        self.control.request_shutdown(shutdown_strategy);
+    }
+
+    /// Returns a snapshot of the pipeline's progress.
+    pub fn stats(&self) -> PipelineStats {
+        self.control.stats.snapshot()
+    }
+
+    /// Returns `true` once the pipeline has stopped.
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Waits for the pipeline to stop and returns its final stats.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error the pipeline stopped with, or an error if its task
+    /// panicked.
+    pub async fn wait(self) -> CarbonResult<PipelineStats> {
+        self.task
+            .await
+            .map_err(|err| Error::Custom(format!("Pipeline task failed: {err}")))??;
+
+        Ok(self.control.stats.snapshot())
+    }
+}
+
 /// A builder for constructing a `Pipeline` instance with customized data
 /// sources, processing pipes, and metrics.
 ///
@@ -615,6 +1101,14 @@ impl Pipeline {
 ///   canceling datasource. If not set, a default `CancellationToken` will be used.
 /// - `channel_buffer_size`: The size of the channel buffer for the pipeline.
 ///   If not set, a default size of 10_000 will be used.
+/// - `lag_monitor`: Tracks slot and block time lag and stalled datasources,
+///   and warns when the configured thresholds are exceeded.
+/// - `admin_server`: An optional HTTP server for health checks, status and
+///   control of the running pipeline.
+/// - `drain_timeout`: The maximum time a `ProcessPending` shutdown may spend
+///   processing queued updates. If not set, it waits until the queue is empty.
+/// - `stop_when_datasources_finish`: Whether the pipeline stops once every
+///   datasource has finished. Defaults to `false`.
 ///
 /// # Returns
 ///
@@ -640,6 +1134,11 @@ pub struct PipelineBuilder {
     pub datasource_cancellation_token: Option<CancellationToken>,
     pub shutdown_strategy: ShutdownStrategy,
     pub channel_buffer_size: usize,
+    pub lag_monitor: LagMonitor,
+    pub admin_server: Option<AdminServer>,
+    pub drain_timeout: Option<Duration>,
+    pub stop_when_datasources_finish: bool,
+    pub lookup_table_cache: Option<Arc<AddressLookupTableCache>>,
 }
 
 impl PipelineBuilder {
@@ -747,6 +1246,7 @@ impl PipelineBuilder {
         self.account_pipes.push(Box::new(AccountPipe {
             decoder: Box::new(decoder),
             processor: Box::new(processor),
+            latency: None,
         }));
         self
     }
@@ -777,6 +1277,7 @@ impl PipelineBuilder {
         self.account_deletion_pipes
             .push(Box::new(AccountDeletionPipe {
                 processor: Box::new(processor),
+                latency: None,
             }));
         self
     }
@@ -811,6 +1312,7 @@ impl PipelineBuilder {
         self.instruction_pipes.push(Box::new(InstructionPipe {
             decoder: Box::new(decoder),
             processor: Box::new(processor),
+            latency: None,
         }));
         self
     }
@@ -855,6 +1357,25 @@ impl PipelineBuilder {
         self
     }
 
+    /// Adds a custom transaction pipe.
+    ///
+    /// Unlike [`PipelineBuilder::transaction`], which decodes instructions
+    /// through an `InstructionDecoderCollection`, this takes any
+    /// `TransactionPipes` implementation, such as ready-made pipes that
+    /// derive their own records from a transaction's instructions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .transaction_pipe(SwapPipe::new(MySwapProcessor));
+    /// ```
+    pub fn transaction_pipe(mut self, pipe: impl for<'a> TransactionPipes<'a> + 'static) -> Self {
+        log::trace!("transaction_pipe(self, pipe: {:?})", stringify!(pipe));
+        self.transaction_pipes.push(Box::new(pipe));
+        self
+    }
+
     /// Adds a metrics component to the pipeline for performance tracking.
     ///
     /// This component collects and reports on pipeline metrics, providing
@@ -944,6 +1465,278 @@ impl PipelineBuilder {
         self
     }
 
+    /// Sets the slot lag above which the lag warning hook fires.
+    ///
+    /// The slot lag is the latest slot received from any datasource minus the
+    /// highest slot the pipeline has processed.
+    ///
+    /// # Parameters
+    ///
+    /// - `threshold`: The maximum tolerated slot lag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .slot_lag_threshold(150);
+    /// ```
+    pub fn slot_lag_threshold(mut self, threshold: u64) -> Self {
+        log::trace!("slot_lag_threshold(self, threshold: {:?})", threshold);
+        self.lag_monitor.slot_lag_threshold = Some(threshold);
+        self
+    }
+
+    /// Sets the time since the block time of the latest transaction processed
+    /// above which the lag warning hook fires.
+    ///
+    /// The lag is checked on every metrics flush interval, so it keeps growing
+    /// while no transactions arrive.
+    ///
+    /// # Parameters
+    ///
+    /// - `threshold`: The maximum tolerated block time lag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .block_time_lag_threshold(Duration::from_secs(60));
+    /// ```
+    pub fn block_time_lag_threshold(mut self, threshold: Duration) -> Self {
+        log::trace!("block_time_lag_threshold(self, threshold: {:?})", threshold);
+        self.lag_monitor.block_time_lag_threshold = Some(threshold);
+        self
+    }
+
+    /// Sets how long the datasources may deliver no updates before the lag
+    /// warning hook fires.
+    ///
+    /// Use this for datasources that do not return block times, or for feeds
+    /// that should never go quiet.
+    ///
+    /// # Parameters
+    ///
+    /// - `threshold`: The maximum tolerated time without updates.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .stall_threshold(Duration::from_secs(30));
+    /// ```
+    pub fn stall_threshold(mut self, threshold: Duration) -> Self {
+        log::trace!("stall_threshold(self, threshold: {:?})", threshold);
+        self.lag_monitor.stall_threshold = Some(threshold);
+        self
+    }
+
+    /// Sets the callback invoked when the pipeline falls behind a lag
+    /// threshold, replacing the default warning log.
+    ///
+    /// The hook fires once per lagging period and is armed again after the
+    /// pipeline has caught up.
+    ///
+    /// # Parameters
+    ///
+    /// - `hook`: The callback receiving the `LagWarning`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .slot_lag_threshold(150)
+    ///     .lag_warning_hook(|warning| alert(format!("{} slots behind", warning.slot_lag)));
+    /// ```
+    pub fn lag_warning_hook(mut self, hook: impl Fn(&LagWarning) + Send + Sync + 'static) -> Self {
+        log::trace!("lag_warning_hook(self, hook: {:?})", stringify!(hook));
+        self.lag_monitor.warning_hook = Arc::new(hook);
+        self
+    }
+
+    /// Serves health, readiness and status endpoints, and controls to pause
+    /// datasources or shut down the pipeline, while the pipeline runs.
+    ///
+    /// See the [`admin`](crate::admin) module for the available endpoints.
+    ///
+    /// # Parameters
+    ///
+    /// - `admin_server`: The admin server configuration.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .admin_server(AdminServer::new(([0, 0, 0, 0], 8080)).max_slot_lag(150));
+    /// ```
+    pub fn admin_server(mut self, admin_server: AdminServer) -> Self {
+        log::trace!("admin_server(self, address: {:?})", admin_server.address);
+        self.admin_server = Some(admin_server);
+        self
+    }
+
+    /// Sets the maximum time a `ProcessPending` shutdown may spend processing
+    /// the queued updates.
+    ///
+    /// When the timeout passes, the pipeline stops with the remaining updates
+    /// unprocessed. Set it below your orchestrator's grace period, e.g. the
+    /// Kubernetes `terminationGracePeriodSeconds`, so metrics are flushed
+    /// before the process is killed.
+    ///
+    /// # Parameters
+    ///
+    /// - `timeout`: The maximum drain duration.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .drain_timeout(Duration::from_secs(20));
+    /// ```
+    pub fn drain_timeout(mut self, timeout: Duration) -> Self {
+        log::trace!("drain_timeout(self, timeout: {:?})", timeout);
+        self.drain_timeout = Some(timeout);
+        self
+    }
+
+    /// Stops the pipeline once every datasource has finished and the queued
+    /// updates have been processed.
+    ///
+    /// Without it, the pipeline keeps running after its datasources finish
+    /// until it is shut down by a signal, the admin server or a
+    /// [`PipelineHandle`]. Use it for datasources that end on their own, such
+    /// as a crawler over a fixed slot range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .datasource(MyDatasource::new())
+    ///     .stop_when_datasources_finish();
+    /// ```
+    pub fn stop_when_datasources_finish(mut self) -> Self {
+        log::trace!("stop_when_datasources_finish(self)");
+        self.stop_when_datasources_finish = true;
+        self
+    }
+
+    /// Resolves the loaded addresses of v0 transactions whose metadata lacks
+    /// them from a cache of Address Lookup Tables.
+    ///
+    /// The pipeline keeps the cache current from the account updates and
+    /// deletions it receives, so the datasource should also subscribe to the
+    /// accounts of the Address Lookup Table program. Tables can also be
+    /// inserted into the cache up front.
+    ///
+    /// See the [`lookup_table`](crate::lookup_table) module.
+    ///
+    /// # Parameters
+    ///
+    /// - `lookup_table_cache`: The cache, which may be shared with other code.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .lookup_table_cache(Arc::new(AddressLookupTableCache::new()));
+    /// ```
+    pub fn lookup_table_cache(mut self, lookup_table_cache: Arc<AddressLookupTableCache>) -> Self {
+        log::trace!(
+            "lookup_table_cache(self, tables: {})",
+            lookup_table_cache.len()
+        );
+        self.lookup_table_cache = Some(lookup_table_cache);
+        self
+    }
+
+    /// Writes the raw data of accounts and instructions that their decoder's
+    /// program owns but that fail to decode to the given sampler.
+    ///
+    /// Failures are counted under `pipe_decode_failures` whether or not a
+    /// sampler is set. The samples help to spot program upgrades that changed
+    /// a layout or added a discriminator.
+    ///
+    /// # Parameters
+    ///
+    /// - `sampler`: The `DecodeFailureSampler` that writes the samples.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .decode_failure_sampler(DecodeFailureSampler::file("decode_failures.ndjson")?);
+    /// ```
+    pub fn decode_failure_sampler(mut self, sampler: DecodeFailureSampler) -> Self {
+        log::trace!("decode_failure_sampler(self, sampler)");
+        self.metrics.decode_failure_sampler = Some(Arc::new(sampler));
+        self
+    }
+
+    /// Sets the latency budget of a processor.
+    ///
+    /// Every pipe run of the processor that takes longer than `budget` is
+    /// logged with the signature or pubkey of the update and counted under
+    /// `pipe_budget_exceeded`.
+    ///
+    /// # Parameters
+    ///
+    /// - `processor`: The processor's `name()`, which defaults to its type
+    ///   name.
+    /// - `budget`: The longest acceptable run time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .latency_budget("PostgresProcessor", Duration::from_millis(50));
+    /// ```
+    pub fn latency_budget(mut self, processor: impl Into<String>, budget: Duration) -> Self {
+        let processor = processor.into();
+        log::trace!(
+            "latency_budget(self, processor: {:?}, budget: {:?})",
+            processor,
+            budget
+        );
+        self.metrics.latency.set_budget(processor, budget);
+        self
+    }
+
+    /// Sets the latency budget of processors without a budget of their own.
+    ///
+    /// # Parameters
+    ///
+    /// - `budget`: The longest acceptable run time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .default_latency_budget(Duration::from_millis(200));
+    /// ```
+    pub fn default_latency_budget(mut self, budget: Duration) -> Self {
+        log::trace!("default_latency_budget(self, budget: {:?})", budget);
+        self.metrics.latency.set_default_budget(budget);
+        self
+    }
+
+    /// Sets how many of the slowest pipes are listed in the summary logged at
+    /// shutdown. Defaults to 10; `0` disables the summary.
+    ///
+    /// # Parameters
+    ///
+    /// - `count`: The number of pipes to list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .slowest_pipes_summary(5);
+    /// ```
+    pub fn slowest_pipes_summary(mut self, count: usize) -> Self {
+        log::trace!("slowest_pipes_summary(self, count: {:?})", count);
+        self.metrics.latency.set_summary_size(count);
+        self
+    }
+
     /// Builds and returns a `Pipeline` configured with the specified
     /// components.
     ///
@@ -976,8 +1769,29 @@ impl PipelineBuilder {
     /// .channel_buffer_size(1000)
     /// .build()?
     /// ```
-    pub fn build(self) -> CarbonResult<Pipeline> {
+    pub fn build(mut self) -> CarbonResult<Pipeline> {
         log::trace!("build(self)");
+
+        if let Some(admin_server) = &self.admin_server {
+            self.metrics.metrics.push(admin_server.status_metrics());
+        }
+
+        // Budgets may be set after the pipes are added, so the pipes register
+        // with the latency monitor once everything is configured.
+        let latency = &self.metrics.latency;
+        for pipe in &mut self.account_pipes {
+            pipe.register_latency(latency);
+        }
+        for pipe in &mut self.account_deletion_pipes {
+            pipe.register_latency(latency);
+        }
+        for pipe in &mut self.instruction_pipes {
+            pipe.register_latency(latency);
+        }
+        for pipe in &mut self.transaction_pipes {
+            pipe.register_latency(latency);
+        }
+
         Ok(Pipeline {
             datasources: self.datasources,
             account_pipes: self.account_pipes,
@@ -989,6 +1803,239 @@ impl PipelineBuilder {
             metrics_flush_interval: self.metrics_flush_interval,
             datasource_cancellation_token: self.datasource_cancellation_token,
             channel_buffer_size: self.channel_buffer_size,
+            lag_monitor: self.lag_monitor,
+            admin_server: self.admin_server,
+            drain_timeout: self.drain_timeout,
+            stop_when_datasources_finish: self.stop_when_datasources_finish,
+            lookup_table_cache: self.lookup_table_cache,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::datasource::{AccountUpdate, UpdateType},
+        async_trait::async_trait,
+        solana_pubkey::Pubkey,
+        tokio::sync::Semaphore,
+    };
+
+    /// Sends `updates` account updates, then waits to be cancelled unless it
+    /// `finishes`.
+    struct TestDatasource {
+        updates: u64,
+        finishes: bool,
+    }
+
+    #[async_trait]
+    impl Datasource for TestDatasource {
+        async fn consume(
+            &self,
+            sender: &tokio::sync::mpsc::Sender<Update>,
+            cancellation_token: CancellationToken,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            for slot in 1..=self.updates {
+                let update = Update::Account(AccountUpdate {
+                    pubkey: Pubkey::default(),
+                    account: solana_account::Account::default(),
+                    slot,
+                });
+                if sender.send(update).await.is_err() {
+                    return Ok(());
+                }
+            }
+
+            if !self.finishes {
+                cancellation_token.cancelled().await;
+            }
+
+            Ok(())
+        }
+
+        fn update_types(&self) -> Vec<UpdateType> {
+            vec![UpdateType::AccountUpdate]
+        }
+    }
+
+    /// Takes `delay` to process each account update.
+    struct SlowPipe {
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl AccountPipes for SlowPipe {
+        async fn run(
+            &mut self,
+            _account_with_metadata: (AccountMetadata, solana_account::Account),
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            tokio::time::sleep(self.delay).await;
+            Ok(())
+        }
+    }
+
+    fn pipeline(updates: u64, finishes: bool, delay: Duration) -> PipelineBuilder {
+        let mut builder = Pipeline::builder().datasource(TestDatasource { updates, finishes });
+        builder.account_pipes.push(Box::new(SlowPipe { delay }));
+        builder
+    }
+
+    /// Returns a signal source that fires once for each permit added to
+    /// `signals`.
+    fn test_signal(
+        signals: &Arc<Semaphore>,
+    ) -> impl Fn() -> std::pin::Pin<Box<dyn Future<Output = &'static str> + Send>> + Send + 'static
+    {
+        let signals = Arc::clone(signals);
+        move || {
+            let signals = Arc::clone(&signals);
+            Box::pin(async move {
+                signals.acquire().await.unwrap().forget();
+                "SIGTERM"
+            })
+        }
+    }
+
+    /// Waits until the pipeline has processed `updates` updates.
+    async fn wait_for_processed(handle: &PipelineHandle, updates: u64) {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while handle.stats().updates_processed < updates {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
         })
+        .await
+        .expect("the pipeline should process the updates");
+    }
+
+    #[tokio::test]
+    async fn test_stop_when_datasources_finish() {
+        let handle = pipeline(3, true, Duration::ZERO)
+            .stop_when_datasources_finish()
+            .build()
+            .unwrap()
+            .spawn_with_signal(test_signal(&Arc::new(Semaphore::new(0))));
+
+        let stats = tokio::time::timeout(Duration::from_secs(5), handle.wait())
+            .await
+            .expect("the pipeline should stop once its datasource finishes")
+            .unwrap();
+
+        assert_eq!(stats.updates_received, 3);
+        assert_eq!(stats.updates_processed, 3);
+        assert_eq!(stats.updates_successful, 3);
+        assert_eq!(stats.highest_slot_processed, 3);
+    }
+
+    #[tokio::test]
+    async fn test_keeps_running_after_datasources_finish() {
+        let handle = pipeline(3, true, Duration::ZERO)
+            .build()
+            .unwrap()
+            .spawn_with_signal(test_signal(&Arc::new(Semaphore::new(0))));
+
+        wait_for_processed(&handle, 3).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!handle.is_finished());
+
+        handle.shutdown(ShutdownStrategy::ProcessPending);
+        let stats = tokio::time::timeout(Duration::from_secs(5), handle.wait())
+            .await
+            .expect("the pipeline should stop after a shutdown request")
+            .unwrap();
+        assert_eq!(stats.updates_processed, 3);
+    }
+
+    #[tokio::test]
+    async fn test_handle_shutdown_process_pending() {
+        let handle = pipeline(10, false, Duration::from_millis(10))
+            .build()
+            .unwrap()
+            .spawn_with_signal(test_signal(&Arc::new(Semaphore::new(0))));
+
+        wait_for_processed(&handle, 1).await;
+        handle.shutdown(ShutdownStrategy::ProcessPending);
+
+        let stats = handle.wait().await.unwrap();
+        assert_eq!(stats.updates_processed, 10);
+        assert_eq!(stats.updates_queued, 0);
+    }
+
+    #[tokio::test]
+    async fn test_handle_shutdown_immediate() {
+        let handle = pipeline(10, false, Duration::from_millis(50))
+            .build()
+            .unwrap()
+            .spawn_with_signal(test_signal(&Arc::new(Semaphore::new(0))));
+
+        wait_for_processed(&handle, 1).await;
+        // Only the first request's strategy applies.
+        handle.shutdown(ShutdownStrategy::Immediate);
+        handle.shutdown(ShutdownStrategy::ProcessPending);
+
+        let stats = handle.wait().await.unwrap();
+        assert!(stats.updates_processed < 10);
+    }
+
+    // The clock is paused and only advances when every task waits on a
+    // timer, so the drain time is exact.
+    #[tokio::test(start_paused = true)]
+    async fn test_drain_timeout() {
+        let handle = pipeline(20, false, Duration::from_millis(20))
+            .drain_timeout(Duration::from_millis(100))
+            .build()
+            .unwrap()
+            .spawn_with_signal(test_signal(&Arc::new(Semaphore::new(0))));
+
+        wait_for_processed(&handle, 1).await;
+        let shutdown_started = tokio::time::Instant::now();
+        handle.shutdown(ShutdownStrategy::ProcessPending);
+
+        let stats = handle.wait().await.unwrap();
+        // The deadline is checked between updates, so the update in progress
+        // when it passes still finishes.
+        let drain_time = shutdown_started.elapsed();
+        assert!(drain_time >= Duration::from_millis(100));
+        assert!(drain_time <= Duration::from_millis(120));
+        assert!(stats.updates_processed < 20);
+        assert!(stats.updates_queued > 0);
+    }
+
+    #[tokio::test]
+    async fn test_signal_processes_pending_updates() {
+        let signals = Arc::new(Semaphore::new(0));
+        let handle = pipeline(10, false, Duration::from_millis(10))
+            .build()
+            .unwrap()
+            .spawn_with_signal(test_signal(&signals));
+
+        wait_for_processed(&handle, 1).await;
+        signals.add_permits(1);
+
+        let stats = tokio::time::timeout(Duration::from_secs(5), handle.wait())
+            .await
+            .expect("the pipeline should stop after a signal")
+            .unwrap();
+        assert_eq!(stats.updates_processed, 10);
+    }
+
+    #[tokio::test]
+    async fn test_second_signal_stops_immediately() {
+        let signals = Arc::new(Semaphore::new(0));
+        let handle = pipeline(10, false, Duration::from_millis(50))
+            .build()
+            .unwrap()
+            .spawn_with_signal(test_signal(&signals));
+
+        wait_for_processed(&handle, 1).await;
+        signals.add_permits(2);
+
+        let stats = tokio::time::timeout(Duration::from_secs(5), handle.wait())
+            .await
+            .expect("the pipeline should stop after a second signal")
+            .unwrap();
+        assert!(stats.updates_processed < 10);
     }
 }
@@ -0,0 +1,197 @@
+//! Derives the compute budget and fee breakdown of a transaction.
+//!
+//! A transaction pays a base fee per signature and, optionally, a priority fee
+//! set with the Compute Budget program: the compute unit price, in
+//! micro-lamports, times the compute unit limit it requests. This module reads
+//! those instructions from the transaction's message and combines them with
+//! the fee and compute units consumed reported in its status metadata.
+//!
+//! ## Key Components
+//!
+//! - **`PriorityFeeDetails`**: The compute unit limit and price, the compute
+//!   units consumed, and the split of the transaction fee into its base and
+//!   priority parts.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! let details = PriorityFeeDetails::from_transaction(&transaction_metadata);
+//! log::info!(
+//!     "{}: {} micro-lamports/CU, {} lamports priority fee, {:?} CU used of {}",
+//!     transaction_metadata.signature,
+//!     details.compute_unit_price,
+//!     details.priority_fee,
+//!     details.compute_units_consumed,
+//!     details.compute_unit_limit,
+//! );
+//! ```
+//!
+//! ## Notes
+//!
+//! - Without `SetComputeUnitLimit`, the limit is the runtime default of
+//!   200,000 compute units per instruction other than Compute Budget
+//!   instructions, capped at 1,400,000.
+//! - The base fee is the part of the transaction fee that is not priority fee,
+//!   so it includes the fees of precompile signatures.
+
+use {
+    crate::transaction::TransactionMetadata,
+    serde::{Deserialize, Serialize},
+    solana_pubkey::Pubkey,
+};
+
+/// The address of the Compute Budget program.
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("ComputeBudget111111111111111111111111111111");
+
+/// The compute unit limit of each instruction without `SetComputeUnitLimit`.
+pub const DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT: u32 = 200_000;
+
+/// The highest compute unit limit a transaction can request.
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// The number of micro-lamports in a lamport, the unit of compute unit prices.
+pub const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+
+/// The compute budget and fee breakdown of a transaction.
+///
+/// # Fields
+///
+/// - `compute_unit_limit`: The requested compute unit limit, or the default
+///   one when the transaction does not set it.
+/// - `requested_compute_unit_limit`: The limit set with
+///   `SetComputeUnitLimit`, if any.
+/// - `compute_unit_price`: The price set with `SetComputeUnitPrice`, in
+///   micro-lamports per compute unit, or `0`.
+/// - `loaded_accounts_data_size_limit`: The limit set with
+///   `SetLoadedAccountsDataSizeLimit`, if any.
+/// - `heap_frame_bytes`: The heap size requested with `RequestHeapFrame`, if
+///   any.
+/// - `compute_units_consumed`: The compute units the transaction consumed, if
+///   the status metadata reports it.
+/// - `fee`: The total fee paid, in lamports.
+/// - `priority_fee`: The part of `fee` paid for priority, in lamports.
+/// - `base_fee`: The rest of `fee`, in lamports.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriorityFeeDetails {
+    pub compute_unit_limit: u32,
+    pub requested_compute_unit_limit: Option<u32>,
+    pub compute_unit_price: u64,
+    pub loaded_accounts_data_size_limit: Option<u32>,
+    pub heap_frame_bytes: Option<u32>,
+    pub compute_units_consumed: Option<u64>,
+    pub fee: u64,
+    pub priority_fee: u64,
+    pub base_fee: u64,
+}
+
+impl PriorityFeeDetails {
+    /// Reads the Compute Budget instructions of a transaction and splits its
+    /// fee.
+    ///
+    /// When an instruction appears more than once, which the runtime rejects,
+    /// the first one is used.
+    pub fn from_transaction(transaction_metadata: &TransactionMetadata) -> Self {
+        log::trace!(
+            "PriorityFeeDetails::from_transaction(signature: {:?})",
+            transaction_metadata.signature
+        );
+
+        let account_keys = transaction_metadata.message.static_account_keys();
+        let mut details = PriorityFeeDetails::default();
+        let mut compute_unit_price = None;
+        let mut other_instructions: u32 = 0;
+
+        for instruction in transaction_metadata.message.instructions() {
+            let is_compute_budget = account_keys
+                .get(instruction.program_id_index as usize)
+                .is_some_and(|program_id| *program_id == COMPUTE_BUDGET_PROGRAM_ID);
+            if !is_compute_budget {
+                other_instructions += 1;
+                continue;
+            }
+
+            let data = instruction.data.as_slice();
+            match data.first() {
+                Some(1) => details.heap_frame_bytes = details.heap_frame_bytes.or(read_u32(data)),
+                Some(2) => {
+                    details.requested_compute_unit_limit =
+                        details.requested_compute_unit_limit.or(read_u32(data))
+                }
+                Some(3) => compute_unit_price = compute_unit_price.or(read_u64(data)),
+                Some(4) => {
+                    details.loaded_accounts_data_size_limit =
+                        details.loaded_accounts_data_size_limit.or(read_u32(data))
+                }
+                _ => {}
+            }
+        }
+
+        details.compute_unit_limit = details
+            .requested_compute_unit_limit
+            .unwrap_or_else(|| {
+                other_instructions.saturating_mul(DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT)
+            })
+            .min(MAX_COMPUTE_UNIT_LIMIT);
+
+        details.compute_unit_price = compute_unit_price.unwrap_or(0);
+        details.compute_units_consumed = transaction_metadata.meta.compute_units_consumed;
+        details.fee = transaction_metadata.meta.fee;
+        details.priority_fee = priority_fee(details.compute_unit_price, details.compute_unit_limit);
+        details.base_fee = details.fee.saturating_sub(details.priority_fee);
+
+        details
+    }
+
+    /// The share of the compute unit limit the transaction consumed, between
+    /// `0.0` and `1.0`.
+    pub fn compute_unit_utilization(&self) -> Option<f64> {
+        if self.compute_unit_limit == 0 {
+            return None;
+        }
+
+        self.compute_units_consumed
+            .map(|consumed| consumed as f64 / self.compute_unit_limit as f64)
+    }
+
+    /// The priority fee per compute unit actually consumed, in
+    /// micro-lamports. Higher than `compute_unit_price` when the transaction
+    /// requests more compute units than it uses.
+    pub fn effective_compute_unit_price(&self) -> Option<u64> {
+        let consumed = self
+            .compute_units_consumed
+            .filter(|consumed| *consumed > 0)?;
+
+        Some(
+            (self.priority_fee as u128 * MICRO_LAMPORTS_PER_LAMPORT / consumed as u128)
+                .min(u64::MAX as u128) as u64,
+        )
+    }
+}
+
+impl TransactionMetadata {
+    /// Returns the transaction's compute budget and fee breakdown.
+    ///
+    /// See [`PriorityFeeDetails::from_transaction`].
+    pub fn priority_fee_details(&self) -> PriorityFeeDetails {
+        PriorityFeeDetails::from_transaction(self)
+    }
+}
+
+/// Returns the priority fee, in lamports, of `compute_unit_limit` compute
+/// units at `compute_unit_price` micro-lamports each, rounded up.
+pub fn priority_fee(compute_unit_price: u64, compute_unit_limit: u32) -> u64 {
+    let micro_lamports = compute_unit_price as u128 * compute_unit_limit as u128;
+
+    micro_lamports
+        .div_ceil(MICRO_LAMPORTS_PER_LAMPORT)
+        .min(u64::MAX as u128) as u64
+}
+
+fn read_u32(data: &[u8]) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(1..5)?.try_into().ok()?))
+}
+
+fn read_u64(data: &[u8]) -> Option<u64> {
+    Some(u64::from_le_bytes(data.get(1..9)?.try_into().ok()?))
+}
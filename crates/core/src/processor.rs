@@ -52,7 +52,10 @@
 //!   enable monitoring and alerting on key performance indicators.
 
 use {
-    crate::{error::CarbonResult, metrics::MetricsCollection},
+    crate::{
+        error::CarbonResult,
+        metrics::{self, MetricsCollection},
+    },
     async_trait::async_trait,
     std::sync::Arc,
 };
@@ -76,6 +79,12 @@ use {
 /// - `process`: Processes the specified `InputType` data asynchronously,
 ///   optionally updating associated metrics.
 ///
+/// # Provided Methods
+///
+/// - `name`: The name used for the `processor` label of the pipe's metrics
+///   and to look up the processor's latency budget. Defaults to the
+///   implementing type's name.
+///
 /// # Example
 ///
 /// ```rust
@@ -93,9 +102,9 @@ use {
 ///         // Perform data processing logic
 ///
 ///         // Optionally, update metrics
-///         for metric in &metrics {
-///             metric.increment_counter("processed_items", 1).await?;
-///         }
+///         metrics
+///             .increment_counter("processed_items", &[("processor", "custom")], 1)
+///             .await?;
 ///
 ///         Ok(())
 ///     }
@@ -110,4 +119,8 @@ pub trait Processor {
         data: Self::InputType,
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
+
+    fn name(&self) -> &'static str {
+        metrics::short_type_name(std::any::type_name::<Self>())
+    }
 }
@@ -0,0 +1,434 @@
+//! Resolves failed transactions to the failing instruction and a decoded
+//! program error.
+//!
+//! When a transaction fails with `InstructionError::Custom(code)`, the status
+//! stored in `TransactionStatusMeta` only carries the raw error code. This
+//! module lets decoders map those codes back to the program's own error enum,
+//! so processors can report `SlippageExceeded` instead of `6001`.
+//!
+//! ## Key Components
+//!
+//! - **`ProgramErrorDecoder`**: A trait implemented by decoders that know the
+//!   error codes of their program. The CLI generates an implementation from
+//!   the IDL `errors` section.
+//! - **`ProgramErrorRegistry`**: A lookup of program error decoders by program
+//!   ID.
+//! - **`TransactionFailure`**: The failing instruction of a transaction, with
+//!   the program error decoded when the program is registered.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! let mut registry = ProgramErrorRegistry::new();
+//! registry.register(PUMPFUN_PROGRAM_ID, PumpfunDecoder);
+//!
+//! if let Some(failure) = registry.decode_transaction_error(&transaction_metadata) {
+//!     log::warn!(
+//!         "instruction {} failed: {:?}",
+//!         failure.instruction_index,
+//!         failure.program_error
+//!     );
+//! }
+//! ```
+
+use {
+    crate::transaction::TransactionMetadata,
+    serde::Serialize,
+    solana_pubkey::Pubkey,
+    solana_sdk::{instruction::InstructionError, transaction::TransactionError},
+    std::{collections::HashMap, str::FromStr},
+};
+
+/// A program error resolved from a custom error code.
+///
+/// # Fields
+///
+/// - `code`: The raw custom error code.
+/// - `name`: The name of the error variant, as declared in the IDL.
+/// - `message`: The error message, or an empty string if the IDL declares none.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DecodedProgramError {
+    pub code: u32,
+    pub name: String,
+    pub message: String,
+}
+
+/// A trait for decoding the custom error codes of a program.
+///
+/// Implementations return `None` for codes the program does not declare,
+/// for example Anchor framework errors below `6000`.
+pub trait ProgramErrorDecoder {
+    fn decode_program_error(&self, code: u32) -> Option<DecodedProgramError>;
+}
+
+/// Describes why a transaction failed.
+///
+/// # Fields
+///
+/// - `instruction_index`: The index of the failing top-level instruction.
+/// - `program_id`: The program that failed. When the failure happened in a
+///   CPI, this is the innermost failing program found in the log messages,
+///   otherwise it is the program of the top-level instruction.
+/// - `error`: The instruction error reported in the transaction status.
+/// - `program_error`: The decoded program error, if `error` is a custom error
+///   and the failing program is registered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionFailure {
+    pub instruction_index: u8,
+    pub program_id: Option<Pubkey>,
+    pub error: InstructionError,
+    pub program_error: Option<DecodedProgramError>,
+}
+
+/// A registry of program error decoders, keyed by program ID.
+#[derive(Default)]
+pub struct ProgramErrorRegistry {
+    decoders: HashMap<Pubkey, Box<dyn ProgramErrorDecoder + Send + Sync>>,
+}
+
+impl ProgramErrorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the error decoder of a program, replacing any decoder
+    /// previously registered for the same program ID.
+    pub fn register(
+        &mut self,
+        program_id: Pubkey,
+        decoder: impl ProgramErrorDecoder + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.decoders.insert(program_id, Box::new(decoder));
+        self
+    }
+
+    /// Decodes a custom error code returned by `program_id`.
+    pub fn decode(&self, program_id: &Pubkey, code: u32) -> Option<DecodedProgramError> {
+        self.decoders.get(program_id)?.decode_program_error(code)
+    }
+
+    /// Resolves the failure of a transaction.
+    ///
+    /// # Parameters
+    ///
+    /// - `transaction_metadata`: The metadata of the transaction to inspect.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the transaction succeeded or failed outside of an
+    /// instruction (for example with `InsufficientFundsForFee`), otherwise the
+    /// `TransactionFailure` describing the failing instruction.
+    pub fn decode_transaction_error(
+        &self,
+        transaction_metadata: &TransactionMetadata,
+    ) -> Option<TransactionFailure> {
+        let mut failure = failed_instruction(transaction_metadata)?;
+
+        if let (InstructionError::Custom(code), Some(program_id)) =
+            (&failure.error, &failure.program_id)
+        {
+            failure.program_error = self.decode(program_id, *code);
+        }
+
+        Some(failure)
+    }
+}
+
+/// Finds the failing instruction of a transaction without decoding the
+/// program error.
+///
+/// # Parameters
+///
+/// - `transaction_metadata`: The metadata of the transaction to inspect.
+///
+/// # Returns
+///
+/// A `TransactionFailure` with `program_error` set to `None`, or `None` if the
+/// transaction did not fail in an instruction.
+pub fn failed_instruction(
+    transaction_metadata: &TransactionMetadata,
+) -> Option<TransactionFailure> {
+    let Err(TransactionError::InstructionError(instruction_index, error)) =
+        &transaction_metadata.meta.status
+    else {
+        return None;
+    };
+
+    let program_id = transaction_metadata
+        .meta
+        .log_messages
+        .as_deref()
+        .and_then(failed_program_from_logs)
+        .or_else(|| {
+            let instruction = transaction_metadata
+                .message
+                .instructions()
+                .get(*instruction_index as usize)?;
+            transaction_metadata
+                .message
+                .static_account_keys()
+                .get(instruction.program_id_index as usize)
+                .copied()
+        });
+
+    Some(TransactionFailure {
+        instruction_index: *instruction_index,
+        program_id,
+        error: error.clone(),
+        program_error: None,
+    })
+}
+
+/// Returns the first program logging `Program <id> failed: ...`, which is
+/// the innermost failing program since the runtime logs failures while
+/// unwinding the CPI stack.
+fn failed_program_from_logs(log_messages: &[String]) -> Option<Pubkey> {
+    log_messages.iter().find_map(|log| {
+        let rest = log.strip_prefix("Program ")?;
+        let (program_id, outcome) = rest.split_once(' ')?;
+        if !outcome.starts_with("failed") {
+            return None;
+        }
+        Pubkey::from_str(program_id).ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_sdk::{
+            instruction::CompiledInstruction,
+            message::{Message, VersionedMessage},
+            signature::Signature,
+        },
+        solana_transaction_status::TransactionStatusMeta,
+    };
+
+    const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGWPBuVt8fqCqyo6Hs7sQ6J";
+
+    struct SwapErrorDecoder;
+
+    impl ProgramErrorDecoder for SwapErrorDecoder {
+        fn decode_program_error(&self, code: u32) -> Option<DecodedProgramError> {
+            let (name, message) = match code {
+                6000 => ("SlippageExceeded", "Slippage tolerance exceeded"),
+                6001 => ("InvalidMint", ""),
+                _ => return None,
+            };
+
+            Some(DecodedProgramError {
+                code,
+                name: name.to_string(),
+                message: message.to_string(),
+            })
+        }
+    }
+
+    fn registry(program_id: Pubkey) -> ProgramErrorRegistry {
+        let mut registry = ProgramErrorRegistry::new();
+        registry.register(program_id, SwapErrorDecoder);
+        registry
+    }
+
+    fn failed_transaction(
+        program_id: Pubkey,
+        error: InstructionError,
+        log_messages: Option<Vec<String>>,
+    ) -> TransactionMetadata {
+        let fee_payer = Pubkey::new_unique();
+        let message = Message {
+            account_keys: vec![fee_payer, program_id],
+            instructions: vec![CompiledInstruction {
+                program_id_index: 1,
+                accounts: vec![0],
+                data: vec![],
+            }],
+            ..Message::default()
+        };
+
+        TransactionMetadata {
+            slot: 0,
+            signature: Signature::default(),
+            fee_payer,
+            meta: TransactionStatusMeta {
+                status: Err(TransactionError::InstructionError(0, error)),
+                log_messages,
+                ..TransactionStatusMeta::default()
+            },
+            message: VersionedMessage::Legacy(message),
+            block_time: None,
+        }
+    }
+
+    #[test]
+    fn test_decode_known_code() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let registry = registry(program_id);
+
+        // Act
+        let decoded = registry.decode(&program_id, 6000);
+
+        // Assert
+        assert_eq!(
+            decoded,
+            Some(DecodedProgramError {
+                code: 6000,
+                name: "SlippageExceeded".to_string(),
+                message: "Slippage tolerance exceeded".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_unknown_code() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let registry = registry(program_id);
+
+        // Act
+        let anchor_error = registry.decode(&program_id, 3012);
+        let undeclared_error = registry.decode(&program_id, 6002);
+        let unregistered_program = registry.decode(&Pubkey::new_unique(), 6000);
+
+        // Assert
+        assert_eq!(anchor_error, None);
+        assert_eq!(undeclared_error, None);
+        assert_eq!(unregistered_program, None);
+    }
+
+    #[test]
+    fn test_failed_program_from_logs() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let token_program_id = Pubkey::from_str(TOKEN_PROGRAM_ID).unwrap();
+        let log_messages = vec![
+            format!("Program {program_id} invoke [1]"),
+            format!("Program {token_program_id} invoke [2]"),
+            "Program log: Error: insufficient funds".to_string(),
+            format!("Program {token_program_id} consumed 4381 of 195000 compute units"),
+            format!("Program {token_program_id} failed: custom program error: 0x1"),
+            format!("Program {program_id} consumed 9000 of 200000 compute units"),
+            format!("Program {program_id} failed: custom program error: 0x1"),
+        ];
+
+        // Act
+        let failed_program = failed_program_from_logs(&log_messages);
+
+        // Assert
+        assert_eq!(failed_program, Some(token_program_id));
+    }
+
+    #[test]
+    fn test_failed_program_from_logs_without_failure() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let log_messages = vec![
+            format!("Program {program_id} invoke [1]"),
+            "Program log: Instruction: Swap".to_string(),
+            "Program log: failed to find a route".to_string(),
+            format!("Program {program_id} consumed 9000 of 200000 compute units"),
+            format!("Program {program_id} success"),
+        ];
+
+        // Act
+        let failed_program = failed_program_from_logs(&log_messages);
+
+        // Assert
+        assert_eq!(failed_program, None);
+    }
+
+    #[test]
+    fn test_decode_transaction_error() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let registry = registry(program_id);
+        let transaction_metadata = failed_transaction(
+            program_id,
+            InstructionError::Custom(6000),
+            Some(vec![
+                format!("Program {program_id} invoke [1]"),
+                format!("Program {program_id} failed: custom program error: 0x1770"),
+            ]),
+        );
+
+        // Act
+        let failure = registry.decode_transaction_error(&transaction_metadata);
+
+        // Assert
+        assert_eq!(
+            failure,
+            Some(TransactionFailure {
+                instruction_index: 0,
+                program_id: Some(program_id),
+                error: InstructionError::Custom(6000),
+                program_error: registry.decode(&program_id, 6000),
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_transaction_error_without_failed_program_logs() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let registry = registry(program_id);
+        let transaction_metadata =
+            failed_transaction(program_id, InstructionError::Custom(6001), None);
+
+        // Act
+        let failure = registry
+            .decode_transaction_error(&transaction_metadata)
+            .unwrap();
+
+        // Assert
+        assert_eq!(failure.program_id, Some(program_id));
+        assert_eq!(
+            failure.program_error.map(|error| error.name),
+            Some("InvalidMint".to_string())
+        );
+    }
+
+    #[test]
+    fn test_decode_transaction_error_from_cpi() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let token_program_id = Pubkey::from_str(TOKEN_PROGRAM_ID).unwrap();
+        let registry = registry(program_id);
+        let transaction_metadata = failed_transaction(
+            program_id,
+            InstructionError::Custom(6000),
+            Some(vec![
+                format!("Program {program_id} invoke [1]"),
+                format!("Program {token_program_id} invoke [2]"),
+                format!("Program {token_program_id} failed: custom program error: 0x1770"),
+                format!("Program {program_id} failed: custom program error: 0x1770"),
+            ]),
+        );
+
+        // Act
+        let failure = registry
+            .decode_transaction_error(&transaction_metadata)
+            .unwrap();
+
+        // Assert
+        assert_eq!(failure.program_id, Some(token_program_id));
+        assert_eq!(failure.program_error, None);
+    }
+
+    #[test]
+    fn test_decode_successful_transaction() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let registry = registry(program_id);
+        let mut transaction_metadata =
+            failed_transaction(program_id, InstructionError::Custom(6000), None);
+        transaction_metadata.meta.status = Ok(());
+
+        // Act
+        let failure = registry.decode_transaction_error(&transaction_metadata);
+
+        // Assert
+        assert_eq!(failure, None);
+    }
+}
@@ -0,0 +1,194 @@
+//! Structured tracing spans for the pipeline stages.
+//!
+//! With the `tracing` feature enabled, the pipeline emits spans through the
+//! [`tracing`](https://docs.rs/tracing) crate:
+//!
+//! - `update`: Opened when a datasource delivers an update and closed once all
+//!   pipes have handled it, so it includes the time spent in the queue. Carries
+//!   `datasource`, `update_type`, `slot` and, for transactions, `signature`.
+//! - `pipe`: One per pipe run, with the pipe kind.
+//! - `decode`: The decoder call of account and instruction pipes, with the
+//!   decoder name.
+//! - `process`: The processor call, with the decoder, `program_id`, `slot` and,
+//!   for instructions, `signature`.
+//!
+//! Without the feature the helpers compile to nothing.
+//!
+//! The `otlp` feature adds [`init_otlp_tracing`], which exports the spans to
+//! an OpenTelemetry collector over gRPC.
+//!
+//! # Example
+//!
+//! ```ignore
+//! // Keep the guard alive for the lifetime of the pipeline; dropping it
+//! // flushes the remaining spans.
+//! let _guard = carbon_core::telemetry::init_otlp_tracing(
+//!     "my-indexer",
+//!     "http://localhost:4317",
+//! )?;
+//!
+//! carbon_core::pipeline::Pipeline::builder()
+//!     .datasource(datasource)
+//!     .instruction(MyDecoder, MyProcessor)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+
+use {crate::datasource::Update, std::future::Future};
+
+/// Wraps a future in a span built from the remaining `tracing::info_span!`
+/// arguments. The span fields are evaluated before the future, so they may
+/// refer to values the future takes ownership of. Without the `tracing`
+/// feature the future is returned unchanged.
+#[cfg(feature = "tracing")]
+macro_rules! traced {
+    ($future:expr, $($span:tt)+) => {{
+        let span = tracing::info_span!($($span)+);
+        tracing::Instrument::instrument($future, span)
+    }};
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! traced {
+    ($future:expr, $($span:tt)+) => {
+        $future
+    };
+}
+
+/// Evaluates an expression inside a span built from the remaining
+/// `tracing::info_span!` arguments.
+#[cfg(feature = "tracing")]
+macro_rules! traced_sync {
+    ($expr:expr, $($span:tt)+) => {
+        tracing::info_span!($($span)+).in_scope(|| $expr)
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! traced_sync {
+    ($expr:expr, $($span:tt)+) => {
+        $expr
+    };
+}
+
+pub(crate) use {traced, traced_sync};
+
+/// The span of a single update, opened when its datasource delivers it.
+#[derive(Clone)]
+pub(crate) struct UpdateSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+impl UpdateSpan {
+    pub(crate) fn new(datasource: &str, update: &Update) -> Self {
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::info_span!(
+                "update",
+                datasource,
+                update_type = update.type_label(),
+                slot = update.slot(),
+                signature = tracing::field::Empty,
+            );
+
+            if let Update::Transaction(transaction_update) = update {
+                span.record(
+                    "signature",
+                    tracing::field::display(&transaction_update.signature),
+                );
+            }
+
+            Self { span }
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        {
+            let _ = (datasource, update);
+            Self {}
+        }
+    }
+
+    /// Runs `future` inside the update's span.
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> impl Future<Output = F::Output> {
+        #[cfg(feature = "tracing")]
+        {
+            tracing::Instrument::instrument(future, self.span.clone())
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        {
+            future
+        }
+    }
+}
+
+/// Flushes and shuts down the OTLP exporter when dropped.
+#[cfg(feature = "otlp")]
+pub struct OtlpGuard {
+    provider: opentelemetry_sdk::trace::TracerProvider,
+}
+
+#[cfg(feature = "otlp")]
+impl Drop for OtlpGuard {
+    fn drop(&mut self) {
+        if let Err(err) = self.provider.shutdown() {
+            log::error!("Failed to shut down the OTLP exporter: {}", err);
+        }
+    }
+}
+
+/// Installs a global `tracing` subscriber that exports spans to an
+/// OpenTelemetry collector.
+///
+/// # Parameters
+///
+/// - `service_name`: The `service.name` resource attribute of the exported
+///   spans.
+/// - `endpoint`: The collector's OTLP gRPC endpoint, e.g.
+///   `http://localhost:4317`.
+///
+/// # Returns
+///
+/// An [`OtlpGuard`] that flushes the remaining spans when dropped. Spans are
+/// filtered with `RUST_LOG`, defaulting to `info`.
+///
+/// # Errors
+///
+/// Returns an error if the exporter cannot be created or a global subscriber
+/// is already installed.
+#[cfg(feature = "otlp")]
+pub fn init_otlp_tracing(
+    service_name: &str,
+    endpoint: &str,
+) -> crate::error::CarbonResult<OtlpGuard> {
+    use {
+        crate::error::Error,
+        opentelemetry::trace::TracerProvider as _,
+        opentelemetry_otlp::WithExportConfig,
+        tracing_subscriber::{layer::SubscriberExt, EnvFilter},
+    };
+
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()
+        .map_err(|err| Error::Custom(format!("Failed to create OTLP exporter: {err}")))?;
+
+    let provider = opentelemetry_sdk::trace::TracerProvider::builder()
+        .with_batch_exporter(exporter, opentelemetry_sdk::runtime::Tokio)
+        .with_resource(opentelemetry_sdk::Resource::new(vec![
+            opentelemetry::KeyValue::new("service.name", service_name.to_string()),
+        ]))
+        .build();
+
+    let subscriber = tracing_subscriber::registry()
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("carbon-core")));
+
+    tracing::subscriber::set_global_default(subscriber)
+        .map_err(|err| Error::Custom(format!("Failed to install tracing subscriber: {err}")))?;
+
+    Ok(OtlpGuard { provider })
+}
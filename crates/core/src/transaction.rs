@@ -34,6 +34,7 @@ use {
         collection::InstructionDecoderCollection,
         error::CarbonResult,
         instruction::{DecodedInstruction, InstructionMetadata, NestedInstruction},
+        latency::{LatencyMonitor, PipeLatency},
         metrics::MetricsCollection,
         processor::Processor,
         schema::{ParsedInstruction, TransactionSchema},
@@ -43,7 +44,7 @@ use {
     serde::de::DeserializeOwned,
     solana_pubkey::Pubkey,
     solana_signature::Signature,
-    std::{convert::TryFrom, sync::Arc},
+    std::{convert::TryFrom, sync::Arc, time::Instant},
 };
 /// Contains metadata about a transaction, including its slot, signature, fee
 /// payer, transaction status metadata, the version transaction message and its
@@ -134,6 +135,7 @@ pub type TransactionProcessorInputType<T, U = ()> = (
 pub struct TransactionPipe<T: InstructionDecoderCollection, U> {
     schema: Option<TransactionSchema<T>>,
     processor: Box<dyn Processor<InputType = TransactionProcessorInputType<T, U>> + Send + Sync>,
+    latency: Option<Arc<PipeLatency>>,
 }
 
 /// Represents a parsed transaction, including its metadata and parsed
@@ -170,6 +172,7 @@ impl<T: InstructionDecoderCollection, U> TransactionPipe<T, U> {
         Self {
             schema,
             processor: Box::new(processor),
+            latency: None,
         }
     }
 
@@ -243,7 +246,8 @@ pub trait TransactionPipes<'a>: Send + Sync {
     /// Runs the transaction pipe with the provided instructions and metrics.
     ///
     /// The method parses the instructions, matches them against the schema, and
-    /// processes the matched data asynchronously.
+    /// processes the matched data asynchronously. `TransactionPipe` records the
+    /// `pipe_*` series labeled with its processor.
     ///
     /// # Parameters
     ///
@@ -260,6 +264,11 @@ pub trait TransactionPipes<'a>: Send + Sync {
         instructions: &[NestedInstruction],
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
+
+    /// Registers the pipe with the pipeline's latency monitor, so its runs
+    /// are checked against its processor's budget. Called once when the
+    /// pipeline is built.
+    fn register_latency(&mut self, _latency: &LatencyMonitor) {}
 }
 
 #[async_trait]
@@ -268,6 +277,13 @@ where
     T: InstructionDecoderCollection + Sync + 'static,
     U: DeserializeOwned + Send + Sync + 'static,
 {
+    fn register_latency(&mut self, latency: &LatencyMonitor) {
+        self.latency = Some(latency.register(&[
+            ("pipe", "transaction"),
+            ("processor", self.processor.name()),
+        ]));
+    }
+
     async fn run(
         &mut self,
         transaction_metadata: TransactionMetadata,
@@ -279,6 +295,8 @@ where
             instructions,
         );
 
+        let start = Instant::now();
+        let signature = transaction_metadata.signature;
         let parsed_instructions = self.parse_instructions(instructions);
 
         let matched_data = self.matches_schema(&parsed_instructions);
@@ -289,13 +307,25 @@ where
             0,
         );
 
-        self.processor
+        let result = self
+            .processor
             .process(
                 (transaction_metadata, unnested_instructions, matched_data),
-                metrics,
+                metrics.clone(),
             )
-            .await?;
+            .await;
 
-        Ok(())
+        metrics
+            .record_pipe_run(
+                &[
+                    ("pipe", "transaction"),
+                    ("processor", self.processor.name()),
+                ],
+                self.latency.as_deref(),
+                &signature,
+                start,
+                result,
+            )
+            .await
     }
 }
@@ -19,6 +19,9 @@
 //!
 //! - The module supports both legacy and v0 transactions, including handling of
 //!   loaded addresses and inner instructions.
+//! - When a v0 transaction's metadata lacks its loaded addresses, they can be
+//!   resolved from an [`AddressLookupTableCache`] with
+//!   `resolve_loaded_addresses`.
 
 use {
     crate::{
@@ -26,6 +29,7 @@ use {
         datasource::TransactionUpdate,
         error::{CarbonResult, Error},
         instruction::{DecodedInstruction, InstructionMetadata},
+        lookup_table::AddressLookupTableCache,
         schema::ParsedInstruction,
         transaction::TransactionMetadata,
     },
@@ -269,6 +273,49 @@ pub fn extract_instructions_with_metadata(
     Ok(instructions_with_metadata)
 }
 
+/// Fills in the loaded addresses of a v0 transaction from a lookup table
+/// cache when its status metadata lacks them.
+///
+/// Transactions that are legacy, that have no table lookups or whose
+/// metadata already reports loaded addresses are left unchanged.
+///
+/// # Parameters
+///
+/// - `transaction_update`: The transaction whose `meta.loaded_addresses` to
+///   fill in.
+/// - `lookup_table_cache`: The cache holding the addresses of the lookup
+///   tables the transaction refers to.
+///
+/// # Returns
+///
+/// Whether the loaded addresses were filled in. `false` for a transaction
+/// that needs them means one of its tables isn't cached, and its accounts
+/// past the static keys can't be resolved.
+pub fn resolve_loaded_addresses(
+    transaction_update: &mut TransactionUpdate,
+    lookup_table_cache: &AddressLookupTableCache,
+) -> bool {
+    log::trace!(
+        "resolve_loaded_addresses(signature: {:?})",
+        transaction_update.signature
+    );
+    let VersionedMessage::V0(v0) = &transaction_update.transaction.message else {
+        return false;
+    };
+
+    if v0.address_table_lookups.is_empty() || !transaction_update.meta.loaded_addresses.is_empty() {
+        return false;
+    }
+
+    match lookup_table_cache.resolve(&v0.address_table_lookups) {
+        Some(loaded_addresses) => {
+            transaction_update.meta.loaded_addresses = loaded_addresses;
+            true
+        }
+        None => false,
+    }
+}
+
 /// Extracts account metadata from a compiled instruction and transaction
 /// message.
 ///
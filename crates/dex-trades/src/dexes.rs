@@ -0,0 +1,1434 @@
+//! Recognizes the swap instructions of each DEX, and the pools and vaults
+//! they trade against.
+
+use {
+    crate::swap::{Dex, Swap, SwapFee, NATIVE_MINT},
+    carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionDecoder},
+    solana_sdk::{instruction::Instruction, pubkey::Pubkey},
+};
+
+/// A pool and the two token accounts holding its reserves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PoolVaults {
+    pub pool: Pubkey,
+    pub vaults: [Pubkey; 2],
+}
+
+/// A decoded swap instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DecodedSwap {
+    pub dex: Dex,
+    pub trader: Pubkey,
+    /// The pools traded against, in order. Only multi-hop instructions have
+    /// more than one.
+    pub pools: Vec<PoolVaults>,
+    /// The mint the DEX charges its fees in, for DEXes that report fees in a
+    /// fixed mint of the pool.
+    pub fee_mint: Option<Pubkey>,
+}
+
+impl DecodedSwap {
+    fn new(dex: Dex, trader: Pubkey, pool: Pubkey, vaults: [Pubkey; 2]) -> Self {
+        DecodedSwap {
+            dex,
+            trader,
+            pools: vec![PoolVaults { pool, vaults }],
+            fee_mint: None,
+        }
+    }
+}
+
+/// Decodes `instruction` if it is the swap instruction of a supported DEX.
+pub(crate) fn decode_swap(instruction: &Instruction) -> Option<DecodedSwap> {
+    match instruction.program_id {
+        carbon_raydium_amm_v4_decoder::PROGRAM_ID => raydium_amm_v4(instruction),
+        carbon_raydium_clmm_decoder::PROGRAM_ID => raydium_clmm(instruction),
+        carbon_raydium_cpmm_decoder::PROGRAM_ID => raydium_cpmm(instruction),
+        carbon_orca_whirlpool_decoder::PROGRAM_ID => orca_whirlpool(instruction),
+        carbon_meteora_dlmm_decoder::PROGRAM_ID => meteora_dlmm(instruction),
+        carbon_meteora_pools_decoder::PROGRAM_ID => meteora_pools(instruction),
+        carbon_lifinity_amm_v2_decoder::PROGRAM_ID => lifinity_amm_v2(instruction),
+        carbon_fluxbeam_decoder::PROGRAM_ID => fluxbeam(instruction),
+        carbon_pumpfun_decoder::PROGRAM_ID => pumpfun(instruction),
+        carbon_pump_swap_decoder::PROGRAM_ID => pump_swap(instruction),
+        carbon_stabble_stable_swap_decoder::PROGRAM_ID => stabble_stable_swap(instruction),
+        carbon_stabble_weighted_swap_decoder::PROGRAM_ID => stabble_weighted_swap(instruction),
+        carbon_phoenix_v1_decoder::PROGRAM_ID => phoenix_v1(instruction),
+        carbon_openbook_v2_decoder::PROGRAM_ID => openbook_v2(instruction),
+        _ => None,
+    }
+}
+
+/// Returns the fee logged by the swap event among the swap's inner
+/// instructions, for DEXes that emit events through self-invocations.
+pub(crate) fn event_fee(
+    decoded: &DecodedSwap,
+    inner_instructions: &[&Instruction],
+    mint_in: Pubkey,
+) -> Option<SwapFee> {
+    let program_id = decoded.dex.program_id();
+    inner_instructions
+        .iter()
+        .filter(|inner_instruction| inner_instruction.program_id == program_id)
+        .find_map(|inner_instruction| match decoded.dex {
+            Dex::MeteoraDlmm => {
+                use carbon_meteora_dlmm_decoder::{
+                    instructions::MeteoraDlmmInstruction, MeteoraDlmmDecoder,
+                };
+
+                match MeteoraDlmmDecoder
+                    .decode_instruction(inner_instruction)?
+                    .data
+                {
+                    MeteoraDlmmInstruction::SwapEvent(event) => Some(SwapFee {
+                        mint: mint_in,
+                        amount: event.fee,
+                    }),
+                    _ => None,
+                }
+            }
+            Dex::MeteoraPools => {
+                use carbon_meteora_pools_decoder::{
+                    instructions::MeteoraPoolsProgramInstruction, MeteoraPoolsDecoder,
+                };
+
+                match MeteoraPoolsDecoder
+                    .decode_instruction(inner_instruction)?
+                    .data
+                {
+                    MeteoraPoolsProgramInstruction::SwapEvent(event) => Some(SwapFee {
+                        mint: mint_in,
+                        amount: event.trade_fee.saturating_add(event.protocol_fee),
+                    }),
+                    _ => None,
+                }
+            }
+            Dex::PumpSwap => {
+                use carbon_pump_swap_decoder::{
+                    instructions::PumpSwapInstruction, PumpSwapDecoder,
+                };
+
+                let (lp_fee, protocol_fee) =
+                    match PumpSwapDecoder.decode_instruction(inner_instruction)?.data {
+                        PumpSwapInstruction::BuyEvent(event) => (event.lp_fee, event.protocol_fee),
+                        PumpSwapInstruction::SellEvent(event) => (event.lp_fee, event.protocol_fee),
+                        _ => return None,
+                    };
+                Some(SwapFee {
+                    mint: decoded.fee_mint?,
+                    amount: lp_fee.saturating_add(protocol_fee),
+                })
+            }
+            _ => None,
+        })
+}
+
+/// Returns the swaps logged by Pump.fun trade events among the swap's inner
+/// instructions.
+///
+/// Pump.fun bonding curves hold SOL as lamports and sells debit them
+/// directly, without a transfer, so amounts come from the events rather
+/// than from transfers.
+pub(crate) fn pumpfun_swaps(
+    decoded: &DecodedSwap,
+    inner_instructions: &[&Instruction],
+) -> Vec<Swap> {
+    use carbon_pumpfun_decoder::{instructions::PumpfunInstruction, PumpfunDecoder};
+
+    let Some(bonding_curve) = decoded.pools.first() else {
+        return Vec::new();
+    };
+
+    inner_instructions
+        .iter()
+        .filter(|inner_instruction| {
+            inner_instruction.program_id == carbon_pumpfun_decoder::PROGRAM_ID
+        })
+        .filter_map(|inner_instruction| {
+            let PumpfunInstruction::TradeEvent(event) =
+                PumpfunDecoder.decode_instruction(inner_instruction)?.data
+            else {
+                return None;
+            };
+
+            let (mint_in, amount_in, mint_out, amount_out) = if event.is_buy {
+                (
+                    NATIVE_MINT,
+                    event.sol_amount,
+                    event.mint,
+                    event.token_amount,
+                )
+            } else {
+                (
+                    event.mint,
+                    event.token_amount,
+                    NATIVE_MINT,
+                    event.sol_amount,
+                )
+            };
+
+            Some(Swap {
+                dex: Dex::Pumpfun,
+                pool: bonding_curve.pool,
+                trader: event.user,
+                mint_in,
+                amount_in,
+                mint_out,
+                amount_out,
+                fee: None,
+            })
+        })
+        .collect()
+}
+
+fn raydium_amm_v4(instruction: &Instruction) -> Option<DecodedSwap> {
+    use carbon_raydium_amm_v4_decoder::{
+        instructions::{swap_base_in, swap_base_out, RaydiumAmmV4Instruction},
+        RaydiumAmmV4Decoder,
+    };
+
+    let decoded = RaydiumAmmV4Decoder.decode_instruction(instruction)?;
+    match decoded.data {
+        RaydiumAmmV4Instruction::SwapBaseIn(_) => {
+            let accounts = swap_base_in::SwapBaseIn::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::RaydiumAmmV4,
+                accounts.user_source_owner,
+                accounts.amm,
+                [
+                    accounts.pool_coin_token_account,
+                    accounts.pool_pc_token_account,
+                ],
+            ))
+        }
+        RaydiumAmmV4Instruction::SwapBaseOut(_) => {
+            let accounts = swap_base_out::SwapBaseOut::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::RaydiumAmmV4,
+                accounts.user_source_owner,
+                accounts.amm,
+                [
+                    accounts.pool_coin_token_account,
+                    accounts.pool_pc_token_account,
+                ],
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn raydium_clmm(instruction: &Instruction) -> Option<DecodedSwap> {
+    use carbon_raydium_clmm_decoder::{
+        instructions::{swap, swap_v2, RaydiumClmmInstruction},
+        RaydiumClmmDecoder,
+    };
+
+    let decoded = RaydiumClmmDecoder.decode_instruction(instruction)?;
+    match decoded.data {
+        RaydiumClmmInstruction::Swap(_) => {
+            let accounts = swap::Swap::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::RaydiumClmm,
+                accounts.payer,
+                accounts.pool_state,
+                [accounts.input_vault, accounts.output_vault],
+            ))
+        }
+        RaydiumClmmInstruction::SwapV2(_) => {
+            let accounts = swap_v2::SwapV2::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::RaydiumClmm,
+                accounts.payer,
+                accounts.pool_state,
+                [accounts.input_vault, accounts.output_vault],
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn raydium_cpmm(instruction: &Instruction) -> Option<DecodedSwap> {
+    use carbon_raydium_cpmm_decoder::{
+        instructions::{swap_base_input, swap_base_output, RaydiumCpmmInstruction},
+        RaydiumCpmmDecoder,
+    };
+
+    let decoded = RaydiumCpmmDecoder.decode_instruction(instruction)?;
+    match decoded.data {
+        RaydiumCpmmInstruction::SwapBaseInput(_) => {
+            let accounts = swap_base_input::SwapBaseInput::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::RaydiumCpmm,
+                accounts.payer,
+                accounts.pool_state,
+                [accounts.input_vault, accounts.output_vault],
+            ))
+        }
+        RaydiumCpmmInstruction::SwapBaseOutput(_) => {
+            let accounts = swap_base_output::SwapBaseOutput::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::RaydiumCpmm,
+                accounts.payer,
+                accounts.pool_state,
+                [accounts.input_vault, accounts.output_vault],
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn orca_whirlpool(instruction: &Instruction) -> Option<DecodedSwap> {
+    use carbon_orca_whirlpool_decoder::{
+        instructions::{swap, swap_v2, two_hop_swap, two_hop_swap_v2, OrcaWhirlpoolInstruction},
+        OrcaWhirlpoolDecoder,
+    };
+
+    let decoded = OrcaWhirlpoolDecoder.decode_instruction(instruction)?;
+    match decoded.data {
+        OrcaWhirlpoolInstruction::Swap(_) => {
+            let accounts = swap::Swap::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::OrcaWhirlpool,
+                accounts.token_authority,
+                accounts.whirlpool,
+                [accounts.token_vault_a, accounts.token_vault_b],
+            ))
+        }
+        OrcaWhirlpoolInstruction::SwapV2(_) => {
+            let accounts = swap_v2::SwapV2::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::OrcaWhirlpool,
+                accounts.token_authority,
+                accounts.whirlpool,
+                [accounts.token_vault_a, accounts.token_vault_b],
+            ))
+        }
+        OrcaWhirlpoolInstruction::TwoHopSwap(_) => {
+            let accounts = two_hop_swap::TwoHopSwap::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap {
+                dex: Dex::OrcaWhirlpool,
+                trader: accounts.token_authority,
+                pools: vec![
+                    PoolVaults {
+                        pool: accounts.whirlpool_one,
+                        vaults: [accounts.token_vault_one_a, accounts.token_vault_one_b],
+                    },
+                    PoolVaults {
+                        pool: accounts.whirlpool_two,
+                        vaults: [accounts.token_vault_two_a, accounts.token_vault_two_b],
+                    },
+                ],
+                fee_mint: None,
+            })
+        }
+        OrcaWhirlpoolInstruction::TwoHopSwapV2(_) => {
+            let accounts = two_hop_swap_v2::TwoHopSwapV2::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap {
+                dex: Dex::OrcaWhirlpool,
+                trader: accounts.token_authority,
+                pools: vec![
+                    PoolVaults {
+                        pool: accounts.whirlpool_one,
+                        vaults: [
+                            accounts.token_vault_one_input,
+                            accounts.token_vault_one_intermediate,
+                        ],
+                    },
+                    PoolVaults {
+                        pool: accounts.whirlpool_two,
+                        vaults: [
+                            accounts.token_vault_two_intermediate,
+                            accounts.token_vault_two_output,
+                        ],
+                    },
+                ],
+                fee_mint: None,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn meteora_dlmm(instruction: &Instruction) -> Option<DecodedSwap> {
+    use carbon_meteora_dlmm_decoder::{
+        instructions::{swap, swap_exact_out, swap_with_price_impact, MeteoraDlmmInstruction},
+        MeteoraDlmmDecoder,
+    };
+
+    let decoded = MeteoraDlmmDecoder.decode_instruction(instruction)?;
+    match decoded.data {
+        MeteoraDlmmInstruction::Swap(_) => {
+            let accounts = swap::Swap::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::MeteoraDlmm,
+                accounts.user,
+                accounts.lb_pair,
+                [accounts.reserve_x, accounts.reserve_y],
+            ))
+        }
+        MeteoraDlmmInstruction::SwapExactOut(_) => {
+            let accounts = swap_exact_out::SwapExactOut::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::MeteoraDlmm,
+                accounts.user,
+                accounts.lb_pair,
+                [accounts.reserve_x, accounts.reserve_y],
+            ))
+        }
+        MeteoraDlmmInstruction::SwapWithPriceImpact(_) => {
+            let accounts =
+                swap_with_price_impact::SwapWithPriceImpact::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::MeteoraDlmm,
+                accounts.user,
+                accounts.lb_pair,
+                [accounts.reserve_x, accounts.reserve_y],
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn meteora_pools(instruction: &Instruction) -> Option<DecodedSwap> {
+    use carbon_meteora_pools_decoder::{
+        instructions::{swap, MeteoraPoolsProgramInstruction},
+        MeteoraPoolsDecoder,
+    };
+
+    let decoded = MeteoraPoolsDecoder.decode_instruction(instruction)?;
+    match decoded.data {
+        MeteoraPoolsProgramInstruction::Swap(_) => {
+            let accounts = swap::Swap::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::MeteoraPools,
+                accounts.user,
+                accounts.pool,
+                [accounts.a_token_vault, accounts.b_token_vault],
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn lifinity_amm_v2(instruction: &Instruction) -> Option<DecodedSwap> {
+    use carbon_lifinity_amm_v2_decoder::{
+        instructions::{swap, LifinityAmmV2Instruction},
+        LifinityAmmV2Decoder,
+    };
+
+    let decoded = LifinityAmmV2Decoder.decode_instruction(instruction)?;
+    match decoded.data {
+        LifinityAmmV2Instruction::Swap(_) => {
+            let accounts = swap::Swap::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::LifinityAmmV2,
+                accounts.user_transfer_authority,
+                accounts.amm,
+                [accounts.swap_source, accounts.swap_destination],
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn fluxbeam(instruction: &Instruction) -> Option<DecodedSwap> {
+    use carbon_fluxbeam_decoder::{
+        instructions::{swap, FluxbeamInstruction},
+        FluxbeamDecoder,
+    };
+
+    let decoded = FluxbeamDecoder.decode_instruction(instruction)?;
+    match decoded.data {
+        FluxbeamInstruction::Swap(_) => {
+            let accounts = swap::Swap::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::Fluxbeam,
+                accounts.user_transfer_authority,
+                accounts.swap,
+                [accounts.swap_source, accounts.swap_destination],
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn pumpfun(instruction: &Instruction) -> Option<DecodedSwap> {
+    use carbon_pumpfun_decoder::{
+        instructions::{buy, sell, PumpfunInstruction},
+        PumpfunDecoder,
+    };
+
+    let decoded = PumpfunDecoder.decode_instruction(instruction)?;
+    match decoded.data {
+        PumpfunInstruction::Buy(_) => {
+            let accounts = buy::Buy::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::Pumpfun,
+                accounts.user,
+                accounts.bonding_curve,
+                [accounts.bonding_curve, accounts.associated_bonding_curve],
+            ))
+        }
+        PumpfunInstruction::Sell(_) => {
+            let accounts = sell::Sell::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::Pumpfun,
+                accounts.user,
+                accounts.bonding_curve,
+                [accounts.bonding_curve, accounts.associated_bonding_curve],
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn pump_swap(instruction: &Instruction) -> Option<DecodedSwap> {
+    use carbon_pump_swap_decoder::{
+        instructions::{buy, sell, PumpSwapInstruction},
+        PumpSwapDecoder,
+    };
+
+    let decoded = PumpSwapDecoder.decode_instruction(instruction)?;
+    let (trader, pool, vaults, quote_mint) = match decoded.data {
+        PumpSwapInstruction::Buy(_) => {
+            let accounts = buy::Buy::arrange_accounts(&decoded.accounts)?;
+            (
+                accounts.user,
+                accounts.pool,
+                [
+                    accounts.pool_base_token_account,
+                    accounts.pool_quote_token_account,
+                ],
+                accounts.quote_mint,
+            )
+        }
+        PumpSwapInstruction::Sell(_) => {
+            let accounts = sell::Sell::arrange_accounts(&decoded.accounts)?;
+            (
+                accounts.user,
+                accounts.pool,
+                [
+                    accounts.pool_base_token_account,
+                    accounts.pool_quote_token_account,
+                ],
+                accounts.quote_mint,
+            )
+        }
+        _ => return None,
+    };
+
+    Some(DecodedSwap {
+        fee_mint: Some(quote_mint),
+        ..DecodedSwap::new(Dex::PumpSwap, trader, pool, vaults)
+    })
+}
+
+fn stabble_stable_swap(instruction: &Instruction) -> Option<DecodedSwap> {
+    use carbon_stabble_stable_swap_decoder::{
+        instructions::{swap, swap_v2, StableSwapInstruction},
+        StableSwapDecoder,
+    };
+
+    let decoded = StableSwapDecoder.decode_instruction(instruction)?;
+    match decoded.data {
+        StableSwapInstruction::Swap(_) => {
+            let accounts = swap::Swap::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::StabbleStableSwap,
+                accounts.user,
+                accounts.pool,
+                [accounts.vault_token_in, accounts.vault_token_out],
+            ))
+        }
+        StableSwapInstruction::SwapV2(_) => {
+            let accounts = swap_v2::SwapV2::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::StabbleStableSwap,
+                accounts.user,
+                accounts.pool,
+                [accounts.vault_token_in, accounts.vault_token_out],
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn stabble_weighted_swap(instruction: &Instruction) -> Option<DecodedSwap> {
+    use carbon_stabble_weighted_swap_decoder::{
+        instructions::{swap, swap_v2, WeightedSwapInstruction},
+        WeightedSwapDecoder,
+    };
+
+    let decoded = WeightedSwapDecoder.decode_instruction(instruction)?;
+    match decoded.data {
+        WeightedSwapInstruction::Swap(_) => {
+            let accounts = swap::Swap::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::StabbleWeightedSwap,
+                accounts.user,
+                accounts.pool,
+                [accounts.vault_token_in, accounts.vault_token_out],
+            ))
+        }
+        WeightedSwapInstruction::SwapV2(_) => {
+            let accounts = swap_v2::SwapV2::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::StabbleWeightedSwap,
+                accounts.user,
+                accounts.pool,
+                [accounts.vault_token_in, accounts.vault_token_out],
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn phoenix_v1(instruction: &Instruction) -> Option<DecodedSwap> {
+    use carbon_phoenix_v1_decoder::{
+        instructions::{swap, PhoenixInstruction},
+        PhoenixDecoder,
+    };
+
+    let decoded = PhoenixDecoder.decode_instruction(instruction)?;
+    match decoded.data {
+        PhoenixInstruction::Swap(_) => {
+            let accounts = swap::Swap::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::PhoenixV1,
+                accounts.trader,
+                accounts.market,
+                [accounts.base_vault, accounts.quote_vault],
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn openbook_v2(instruction: &Instruction) -> Option<DecodedSwap> {
+    use carbon_openbook_v2_decoder::{
+        instructions::{place_take_order, OpenbookV2Instruction},
+        OpenbookV2Decoder,
+    };
+
+    let decoded = OpenbookV2Decoder.decode_instruction(instruction)?;
+    match decoded.data {
+        OpenbookV2Instruction::PlaceTakeOrder(_) => {
+            let accounts = place_take_order::PlaceTakeOrder::arrange_accounts(&decoded.accounts)?;
+            Some(DecodedSwap::new(
+                Dex::OpenbookV2,
+                accounts.signer,
+                accounts.market,
+                [accounts.market_base_vault, accounts.market_quote_vault],
+            ))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{fixtures::*, swap::instruction_swaps},
+        carbon_core::instruction::NestedInstruction,
+        solana_sdk::pubkey,
+        std::collections::HashMap,
+    };
+
+    const AMOUNT_IN: u64 = 1_000_000;
+    const AMOUNT_OUT: u64 = 2_500_000;
+    const TOKEN_2022_PROGRAM_ID: Pubkey = carbon_token_2022_decoder::PROGRAM_ID;
+
+    /// Returns the data of a swap whose arguments are the amount in and the
+    /// minimum amount out.
+    fn amount_in_data(discriminator: &[u8]) -> Vec<u8> {
+        data(
+            discriminator,
+            &[&AMOUNT_IN.to_le_bytes(), &0u64.to_le_bytes()],
+        )
+    }
+
+    #[test]
+    fn test_raydium_amm_v4_swap() {
+        // Arrange
+        let swap = SwapAccounts::new();
+        let other = Pubkey::new_unique();
+        let instruction = nested(
+            instruction(
+                carbon_raydium_amm_v4_decoder::PROGRAM_ID,
+                amount_in_data(&[9]),
+                &[
+                    TOKEN_PROGRAM_ID,
+                    swap.pool,
+                    swap.pool_authority,
+                    other,
+                    other,
+                    swap.vault_a,
+                    swap.vault_b,
+                    other,
+                    other,
+                    other,
+                    other,
+                    other,
+                    other,
+                    other,
+                    other,
+                    swap.user_a,
+                    swap.user_b,
+                    swap.trader,
+                ],
+            ),
+            swap.transfers(AMOUNT_IN, AMOUNT_OUT),
+        );
+
+        // Act
+        let swaps = instruction_swaps(&instruction, &swap.token_mints());
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![swap.swap(Dex::RaydiumAmmV4, AMOUNT_IN, AMOUNT_OUT)]
+        );
+    }
+
+    #[test]
+    fn test_raydium_clmm_swap_v2() {
+        // Arrange
+        let swap = SwapAccounts::new();
+        let other = Pubkey::new_unique();
+        let instruction = nested(
+            instruction(
+                carbon_raydium_clmm_decoder::PROGRAM_ID,
+                data(
+                    &0x2b04ed0b1ac91e62u64.to_be_bytes(),
+                    &[
+                        &AMOUNT_IN.to_le_bytes(),
+                        &0u64.to_le_bytes(),
+                        &0u128.to_le_bytes(),
+                        &[1],
+                    ],
+                ),
+                &[
+                    swap.trader,
+                    other,
+                    swap.pool,
+                    swap.user_a,
+                    swap.user_b,
+                    swap.vault_a,
+                    swap.vault_b,
+                    other,
+                    TOKEN_PROGRAM_ID,
+                    TOKEN_2022_PROGRAM_ID,
+                    other,
+                    swap.mint_a,
+                    swap.mint_b,
+                ],
+            ),
+            vec![
+                transfer_checked(
+                    TOKEN_PROGRAM_ID,
+                    swap.user_a,
+                    swap.mint_a,
+                    swap.vault_a,
+                    swap.trader,
+                    AMOUNT_IN,
+                ),
+                transfer_checked(
+                    TOKEN_2022_PROGRAM_ID,
+                    swap.vault_b,
+                    swap.mint_b,
+                    swap.user_b,
+                    swap.pool,
+                    AMOUNT_OUT,
+                ),
+            ],
+        );
+
+        // Act
+        // Checked transfers name their mints, so no balances are needed.
+        let swaps = instruction_swaps(&instruction, &HashMap::new());
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![swap.swap(Dex::RaydiumClmm, AMOUNT_IN, AMOUNT_OUT)]
+        );
+    }
+
+    #[test]
+    fn test_raydium_cpmm_swap_base_input() {
+        // Arrange
+        let swap = SwapAccounts::new();
+        let instruction = raydium_cpmm_swap(&swap, AMOUNT_IN, AMOUNT_OUT);
+
+        // Act
+        let swaps = instruction_swaps(&instruction, &swap.token_mints());
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![swap.swap(Dex::RaydiumCpmm, AMOUNT_IN, AMOUNT_OUT)]
+        );
+    }
+
+    #[test]
+    fn test_orca_whirlpool_swap() {
+        // Arrange
+        let swap = SwapAccounts::new();
+        let other = Pubkey::new_unique();
+        let instruction = nested(
+            instruction(
+                carbon_orca_whirlpool_decoder::PROGRAM_ID,
+                data(
+                    &0xf8c69e91e17587c8u64.to_be_bytes(),
+                    &[
+                        &AMOUNT_IN.to_le_bytes(),
+                        &0u64.to_le_bytes(),
+                        &0u128.to_le_bytes(),
+                        &[1],
+                        &[1],
+                    ],
+                ),
+                &[
+                    TOKEN_PROGRAM_ID,
+                    swap.trader,
+                    swap.pool,
+                    swap.user_a,
+                    swap.vault_a,
+                    swap.user_b,
+                    swap.vault_b,
+                    other,
+                    other,
+                    other,
+                    other,
+                ],
+            ),
+            swap.transfers(AMOUNT_IN, AMOUNT_OUT),
+        );
+
+        // Act
+        let swaps = instruction_swaps(&instruction, &swap.token_mints());
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![swap.swap(Dex::OrcaWhirlpool, AMOUNT_IN, AMOUNT_OUT)]
+        );
+    }
+
+    #[test]
+    fn test_meteora_dlmm_swap() {
+        // Arrange
+        let swap = SwapAccounts::new();
+        let other = Pubkey::new_unique();
+        let program_id = carbon_meteora_dlmm_decoder::PROGRAM_ID;
+        let swap_event = instruction(
+            program_id,
+            data(
+                &0xe445a52e51cb9a1d516ce3becdd00ac4u128.to_be_bytes(),
+                &[
+                    &swap.pool.to_bytes(),
+                    &swap.trader.to_bytes(),
+                    &10i32.to_le_bytes(),
+                    &12i32.to_le_bytes(),
+                    &AMOUNT_IN.to_le_bytes(),
+                    &AMOUNT_OUT.to_le_bytes(),
+                    &[1],
+                    &2_500u64.to_le_bytes(),
+                    &125u64.to_le_bytes(),
+                    &25u128.to_le_bytes(),
+                    &0u64.to_le_bytes(),
+                ],
+            ),
+            &[other],
+        );
+        let mut inner_instructions = swap.transfers(AMOUNT_IN, AMOUNT_OUT);
+        inner_instructions.push(nested(swap_event, Vec::new()));
+        let instruction = nested(
+            instruction(
+                program_id,
+                amount_in_data(&0xf8c69e91e17587c8u64.to_be_bytes()),
+                &[
+                    swap.pool,
+                    other,
+                    swap.vault_a,
+                    swap.vault_b,
+                    swap.user_a,
+                    swap.user_b,
+                    swap.mint_a,
+                    swap.mint_b,
+                    other,
+                    other,
+                    swap.trader,
+                    TOKEN_PROGRAM_ID,
+                    TOKEN_PROGRAM_ID,
+                    other,
+                    program_id,
+                ],
+            ),
+            inner_instructions,
+        );
+
+        // Act
+        let swaps = instruction_swaps(&instruction, &swap.token_mints());
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![Swap {
+                fee: Some(SwapFee {
+                    mint: swap.mint_a,
+                    amount: 2_500,
+                }),
+                ..swap.swap(Dex::MeteoraDlmm, AMOUNT_IN, AMOUNT_OUT)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_meteora_pools_swap() {
+        // Arrange
+        let swap = SwapAccounts::new();
+        let other = Pubkey::new_unique();
+        let vault_program = Pubkey::new_unique();
+        let protocol_fee_account = Pubkey::new_unique();
+        let program_id = carbon_meteora_pools_decoder::PROGRAM_ID;
+        let [deposit, withdraw] = swap.transfers(AMOUNT_IN, AMOUNT_OUT).try_into().unwrap();
+        let swap_event = instruction(
+            program_id,
+            data(
+                &0xe445a52e51cb9a1d516ce3becdd00ac4u128.to_be_bytes(),
+                &[
+                    &AMOUNT_IN.to_le_bytes(),
+                    &AMOUNT_OUT.to_le_bytes(),
+                    &2_000u64.to_le_bytes(),
+                    &500u64.to_le_bytes(),
+                    &0u64.to_le_bytes(),
+                ],
+            ),
+            &[other],
+        );
+        let instruction = nested(
+            instruction(
+                program_id,
+                amount_in_data(&0xf8c69e91e17587c8u64.to_be_bytes()),
+                &[
+                    swap.pool,
+                    swap.user_a,
+                    swap.user_b,
+                    other,
+                    other,
+                    swap.vault_a,
+                    swap.vault_b,
+                    other,
+                    other,
+                    other,
+                    other,
+                    protocol_fee_account,
+                    swap.trader,
+                    vault_program,
+                    TOKEN_PROGRAM_ID,
+                ],
+            ),
+            vec![
+                // The protocol fee is paid to the pool's fee account, not its
+                // vaults, so it isn't part of the amount in.
+                transfer(swap.user_a, protocol_fee_account, swap.trader, 500),
+                nested(instruction(vault_program, Vec::new(), &[]), vec![deposit]),
+                nested(instruction(vault_program, Vec::new(), &[]), vec![withdraw]),
+                nested(swap_event, Vec::new()),
+            ],
+        );
+
+        // Act
+        let swaps = instruction_swaps(&instruction, &swap.token_mints());
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![Swap {
+                fee: Some(SwapFee {
+                    mint: swap.mint_a,
+                    amount: 2_500,
+                }),
+                ..swap.swap(Dex::MeteoraPools, AMOUNT_IN, AMOUNT_OUT)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lifinity_amm_v2_swap() {
+        // Arrange
+        let instruction =
+            carbon_test_utils::read_instruction("tests/fixtures/lifinity_amm_v2_swap_ix.json")
+                .expect("read fixture");
+        let swap = SwapAccounts {
+            trader: pubkey!("CapuXNQoDviLvU1PxFiizLgPNQCxrsag1uMeyk6zLVps"),
+            pool: pubkey!("EiEAydLqSKFqRPpuwYoVxEJ6h9UZh9tsTaHgs4f8b8Z5"),
+            pool_authority: pubkey!("Hc3DCcYyN7m2Wasf4Zhe8FMQ9qf9PiBiMJ9vqwSqB42h"),
+            vault_a: pubkey!("D8F3PPxSuykAgyPPKwQdXDGGoRnUXzxowaheVJw5ATDC"),
+            vault_b: pubkey!("GUicRosQyLJCYG8hjYcbiGKAVAmT1puQTVmJjFxJmdMK"),
+            user_a: pubkey!("GQuvMWcBF1M2wgh2sbxkonq7FtBc6UNurtHjREMRAL1x"),
+            user_b: pubkey!("91bUbswo6Di8235jAPwim1At4cPZLbG2pkpneyqKg4NQ"),
+            mint_a: Pubkey::new_unique(),
+            mint_b: Pubkey::new_unique(),
+        };
+        let instruction = nested(instruction, swap.transfers(99_140, 2_017));
+
+        // Act
+        let swaps = instruction_swaps(&instruction, &swap.token_mints());
+
+        // Assert
+        assert_eq!(swaps, vec![swap.swap(Dex::LifinityAmmV2, 99_140, 2_017)]);
+    }
+
+    #[test]
+    fn test_fluxbeam_swap() {
+        // Arrange
+        let instruction =
+            carbon_test_utils::read_instruction("tests/fixtures/fluxbeam_swap_ix.json")
+                .expect("read fixture");
+        let swap = SwapAccounts {
+            trader: pubkey!("AB1daTZcHAySAexN1SpacinrwRixNP7nLd31TVnNXMLx"),
+            pool: pubkey!("6bJUX2XqmGp6nZGrnEoZh3mAt8M73G1AZbgUhT4hooAC"),
+            pool_authority: pubkey!("2CQ6cW8RzowMEcdEiRRgEWzaYjpLWaHv1WoVyWfF8nsY"),
+            vault_a: pubkey!("jM5cFHP9iPj9en1fJFJLfRpLt68Y81UdWfXHv9an3HK"),
+            vault_b: pubkey!("8a4WD4hbfuPyiistrVU8qcpwMcJmf3RBuw1s1tvVYJ1Q"),
+            user_a: pubkey!("DX1mX7WN7jQJXzaiiQR6W1G69xHg3kXjDtrEyYXxgZAm"),
+            user_b: pubkey!("Ew1Aj2Mm82KCN9dtMNnhVXZDjUfiiu18CNj9Qx6Vystk"),
+            mint_a: NATIVE_MINT,
+            mint_b: pubkey!("3YkBR2w1ttpWKzdP5XQtzXqsGFS9i1mGg9pDrqn4e9j6"),
+        };
+        // The output mint is a Token-2022 mint, paid out with a checked
+        // transfer.
+        let instruction = nested(
+            instruction,
+            vec![
+                transfer(swap.user_a, swap.vault_a, swap.trader, 800_000_000),
+                transfer_checked(
+                    TOKEN_2022_PROGRAM_ID,
+                    swap.vault_b,
+                    swap.mint_b,
+                    swap.user_b,
+                    swap.pool_authority,
+                    41_375_226_511,
+                ),
+            ],
+        );
+        let token_mints = HashMap::from([(swap.user_a, NATIVE_MINT)]);
+
+        // Act
+        let swaps = instruction_swaps(&instruction, &token_mints);
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![swap.swap(Dex::Fluxbeam, 800_000_000, 41_375_226_511)]
+        );
+    }
+
+    /// Returns a Pump.fun trade event self-invocation.
+    fn trade_event(
+        mint: Pubkey,
+        sol_amount: u64,
+        token_amount: u64,
+        is_buy: bool,
+        user: Pubkey,
+    ) -> NestedInstruction {
+        let event = instruction(
+            carbon_pumpfun_decoder::PROGRAM_ID,
+            data(
+                &0xe445a52e51cb9a1dbddb7fd34ee661eeu128.to_be_bytes(),
+                &[
+                    &mint.to_bytes(),
+                    &sol_amount.to_le_bytes(),
+                    &token_amount.to_le_bytes(),
+                    &[is_buy as u8],
+                    &user.to_bytes(),
+                    &1_733_000_000i64.to_le_bytes(),
+                    &0u64.to_le_bytes(),
+                    &0u64.to_le_bytes(),
+                    &0u64.to_le_bytes(),
+                    &0u64.to_le_bytes(),
+                ],
+            ),
+            &[pubkey!("Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1")],
+        );
+        nested(event, Vec::new())
+    }
+
+    #[test]
+    fn test_pumpfun_buy() {
+        // Arrange
+        let instruction = carbon_test_utils::read_instruction("tests/fixtures/pumpfun_buy_ix.json")
+            .expect("read fixture");
+        let mint = pubkey!("9p1PMtto471A7GvnRJVmDcuqUz3xDd1Lhu8vzrmpump");
+        let bonding_curve = pubkey!("HWxwYxr4AV5ytUyT8pvjCEiUrXhwpbx365VpvQ6Bd6MZ");
+        let user = pubkey!("5ztadiszGPmBeGVcvmtPyqiHRA8SpU8mqNzPV1WeV88F");
+        let instruction = nested(
+            instruction,
+            vec![
+                transfer(
+                    pubkey!("AUfg9aTAix7YarkHXSBMUyQPCTq55Gg1Z2NTe6utwwzG"),
+                    pubkey!("4FLYmjhLuUb5ofNBo1PA9enF7HrPUSYUA1t55tUSFYa5"),
+                    bonding_curve,
+                    2_712_969_161_192,
+                ),
+                trade_event(mint, 200_000_000, 2_712_969_161_192, true, user),
+            ],
+        );
+
+        // Act
+        let swaps = instruction_swaps(&instruction, &HashMap::new());
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![Swap {
+                dex: Dex::Pumpfun,
+                pool: bonding_curve,
+                trader: user,
+                mint_in: NATIVE_MINT,
+                amount_in: 200_000_000,
+                mint_out: mint,
+                amount_out: 2_712_969_161_192,
+                fee: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_pumpfun_sell() {
+        // Arrange
+        let instruction =
+            carbon_test_utils::read_instruction("tests/fixtures/pumpfun_sell_ix.json")
+                .expect("read fixture");
+        let mint = pubkey!("HXfFC4G1aJJo17KW56jJ2iaDLFXq6T8XZjPbQfhspump");
+        let bonding_curve = pubkey!("8f12Y6z6CkMmcBqduvThRG2V873CP3eu2iBydqKGDX6y");
+        let user = pubkey!("3bApZNQrP3T6Q1GvK1n1nUPHHnpnsbrEmdGyQyYLEbkP");
+        // Sells pay SOL out of the bonding curve's lamports, without a
+        // transfer, so only the event has the amount out.
+        let instruction = nested(
+            instruction,
+            vec![
+                transfer(
+                    pubkey!("Bi6H7WPrZoJmqSauP38NuBaEttGraZkceR4p17ekoTwh"),
+                    pubkey!("GkSscwZJBhcFeB6hpWrnfrE73e5SawPmMuT55U1W4uqz"),
+                    user,
+                    26_705_394_300,
+                ),
+                trade_event(mint, 730_000, 26_705_394_300, false, user),
+            ],
+        );
+
+        // Act
+        let swaps = instruction_swaps(&instruction, &HashMap::new());
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![Swap {
+                dex: Dex::Pumpfun,
+                pool: bonding_curve,
+                trader: user,
+                mint_in: mint,
+                amount_in: 26_705_394_300,
+                mint_out: NATIVE_MINT,
+                amount_out: 730_000,
+                fee: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_pump_swap_buy() {
+        // Arrange
+        // Buys pay the quote mint, `mint_a`, for the base mint, `mint_b`.
+        let swap = SwapAccounts::new();
+        let other = Pubkey::new_unique();
+        let protocol_fee_account = Pubkey::new_unique();
+        let program_id = carbon_pump_swap_decoder::PROGRAM_ID;
+        let mut fields = vec![1_733_000_000u64, AMOUNT_OUT, AMOUNT_IN, 0, 0, 0, 0];
+        // The quote amount in, the LP fee and the protocol fee with their
+        // basis points, the quote amount with the LP fee and the user's
+        // quote amount in.
+        fields.extend([
+            AMOUNT_IN - 2_500,
+            20,
+            2_000,
+            5,
+            500,
+            AMOUNT_IN - 500,
+            AMOUNT_IN,
+        ]);
+        let mut event_fields: Vec<Vec<u8>> = fields
+            .iter()
+            .map(|field| field.to_le_bytes().to_vec())
+            .collect();
+        event_fields.extend(
+            [
+                swap.pool,
+                swap.trader,
+                swap.user_b,
+                swap.user_a,
+                other,
+                protocol_fee_account,
+            ]
+            .map(|account| account.to_bytes().to_vec()),
+        );
+        let buy_event = instruction(
+            program_id,
+            data(
+                &0xe445a52e51cb9a1d67f4521f2cf57777u128.to_be_bytes(),
+                &event_fields.iter().map(Vec::as_slice).collect::<Vec<_>>(),
+            ),
+            &[other],
+        );
+        let mut inner_instructions = swap.transfers(AMOUNT_IN - 500, AMOUNT_OUT);
+        inner_instructions.push(transfer(
+            swap.user_a,
+            protocol_fee_account,
+            swap.trader,
+            500,
+        ));
+        inner_instructions.push(nested(buy_event, Vec::new()));
+        let instruction = nested(
+            instruction(
+                program_id,
+                data(
+                    &0x66063d1201daebeau64.to_be_bytes(),
+                    &[&AMOUNT_OUT.to_le_bytes(), &AMOUNT_IN.to_le_bytes()],
+                ),
+                &[
+                    swap.pool,
+                    swap.trader,
+                    other,
+                    swap.mint_b,
+                    swap.mint_a,
+                    swap.user_b,
+                    swap.user_a,
+                    swap.vault_b,
+                    swap.vault_a,
+                    other,
+                    protocol_fee_account,
+                    TOKEN_PROGRAM_ID,
+                    TOKEN_PROGRAM_ID,
+                    other,
+                    other,
+                    other,
+                    program_id,
+                ],
+            ),
+            inner_instructions,
+        );
+
+        // Act
+        let swaps = instruction_swaps(&instruction, &swap.token_mints());
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![Swap {
+                fee: Some(SwapFee {
+                    mint: swap.mint_a,
+                    amount: 2_500,
+                }),
+                ..swap.swap(Dex::PumpSwap, AMOUNT_IN - 500, AMOUNT_OUT)
+            }]
+        );
+    }
+
+    /// Returns the accounts of a Stabble `swap`, which both of its pool
+    /// programs share.
+    fn stabble_accounts(swap: &SwapAccounts) -> [Pubkey; 12] {
+        let other = Pubkey::new_unique();
+        [
+            swap.trader,
+            swap.user_a,
+            swap.user_b,
+            swap.vault_a,
+            swap.vault_b,
+            other,
+            swap.pool,
+            other,
+            other,
+            swap.pool_authority,
+            other,
+            TOKEN_PROGRAM_ID,
+        ]
+    }
+
+    #[test]
+    fn test_stabble_stable_swap() {
+        // Arrange
+        let swap = SwapAccounts::new();
+        let instruction = nested(
+            instruction(
+                carbon_stabble_stable_swap_decoder::PROGRAM_ID,
+                data(
+                    &0xf8c69e91e17587c8u64.to_be_bytes(),
+                    &[&[1], &AMOUNT_IN.to_le_bytes(), &0u64.to_le_bytes()],
+                ),
+                &stabble_accounts(&swap),
+            ),
+            swap.transfers(AMOUNT_IN, AMOUNT_OUT),
+        );
+
+        // Act
+        let swaps = instruction_swaps(&instruction, &swap.token_mints());
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![swap.swap(Dex::StabbleStableSwap, AMOUNT_IN, AMOUNT_OUT)]
+        );
+    }
+
+    #[test]
+    fn test_stabble_weighted_swap() {
+        // Arrange
+        // Without an amount in, the pool swaps the user's whole balance.
+        let swap = SwapAccounts::new();
+        let instruction = nested(
+            instruction(
+                carbon_stabble_weighted_swap_decoder::PROGRAM_ID,
+                data(
+                    &0xf8c69e91e17587c8u64.to_be_bytes(),
+                    &[&[0], &0u64.to_le_bytes()],
+                ),
+                &stabble_accounts(&swap),
+            ),
+            swap.transfers(AMOUNT_IN, AMOUNT_OUT),
+        );
+
+        // Act
+        let swaps = instruction_swaps(&instruction, &swap.token_mints());
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![swap.swap(Dex::StabbleWeightedSwap, AMOUNT_IN, AMOUNT_OUT)]
+        );
+    }
+
+    #[test]
+    fn test_phoenix_v1_swap() {
+        // Arrange
+        // An immediate-or-cancel ask, selling the base mint, `mint_a`, for
+        // the quote mint, `mint_b`.
+        let swap = SwapAccounts::new();
+        let program_id = carbon_phoenix_v1_decoder::PROGRAM_ID;
+        let order_packet: &[&[u8]] = &[
+            &[2],
+            &[1],
+            &[0],
+            &1_000u64.to_le_bytes(),
+            &0u64.to_le_bytes(),
+            &0u64.to_le_bytes(),
+            &0u64.to_le_bytes(),
+            &[0],
+            &[0],
+            &0u128.to_le_bytes(),
+            &[0],
+            &[0],
+            &[0],
+        ];
+        let instruction = nested(
+            instruction(
+                program_id,
+                data(&[0], order_packet),
+                &[
+                    program_id,
+                    Pubkey::new_unique(),
+                    swap.pool,
+                    swap.trader,
+                    swap.user_a,
+                    swap.user_b,
+                    swap.vault_a,
+                    swap.vault_b,
+                    TOKEN_PROGRAM_ID,
+                ],
+            ),
+            swap.transfers(AMOUNT_IN, AMOUNT_OUT),
+        );
+
+        // Act
+        let swaps = instruction_swaps(&instruction, &swap.token_mints());
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![swap.swap(Dex::PhoenixV1, AMOUNT_IN, AMOUNT_OUT)]
+        );
+    }
+
+    #[test]
+    fn test_openbook_v2_place_take_order() {
+        // Arrange
+        // An immediate-or-cancel ask, selling the base mint, `mint_a`, for
+        // the quote mint, `mint_b`.
+        let swap = SwapAccounts::new();
+        let other = Pubkey::new_unique();
+        let instruction = nested(
+            instruction(
+                carbon_openbook_v2_decoder::PROGRAM_ID,
+                data(
+                    &0x032c47031ac7cb55u64.to_be_bytes(),
+                    &[
+                        &[1],
+                        &1_000i64.to_le_bytes(),
+                        &1_000i64.to_le_bytes(),
+                        &i64::MAX.to_le_bytes(),
+                        &[1],
+                        &[50],
+                    ],
+                ),
+                &[
+                    swap.trader,
+                    other,
+                    swap.pool,
+                    swap.pool_authority,
+                    other,
+                    other,
+                    swap.vault_a,
+                    swap.vault_b,
+                    other,
+                    swap.user_a,
+                    swap.user_b,
+                    other,
+                    other,
+                    TOKEN_PROGRAM_ID,
+                    other,
+                    other,
+                ],
+            ),
+            swap.transfers(AMOUNT_IN, AMOUNT_OUT),
+        );
+
+        // Act
+        let swaps = instruction_swaps(&instruction, &swap.token_mints());
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![swap.swap(Dex::OpenbookV2, AMOUNT_IN, AMOUNT_OUT)]
+        );
+    }
+
+    #[test]
+    fn test_decode_swap_ignores_other_instructions() {
+        // Arrange
+        let swap = SwapAccounts::new();
+        let instruction = instruction(
+            carbon_raydium_cpmm_decoder::PROGRAM_ID,
+            vec![0; 8],
+            &[swap.trader, swap.pool],
+        );
+
+        // Act
+        let decoded = decode_swap(&instruction);
+
+        // Assert
+        assert_eq!(decoded, None);
+    }
+}
@@ -0,0 +1,232 @@
+//! Builders for the instructions and transactions the tests extract swaps
+//! from.
+
+use {
+    crate::swap::{Dex, Swap},
+    carbon_core::{
+        instruction::{InstructionMetadata, NestedInstruction},
+        transaction::TransactionMetadata,
+    },
+    solana_account_decoder::parse_token::UiTokenAmount,
+    solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        message::{Message, VersionedMessage},
+        pubkey,
+        pubkey::Pubkey,
+        signature::Signature,
+    },
+    solana_transaction_status::{TransactionStatusMeta, TransactionTokenBalance},
+    std::collections::HashMap,
+};
+
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// Returns an instruction with `accounts` as writable non-signers.
+pub fn instruction(program_id: Pubkey, data: Vec<u8>, accounts: &[Pubkey]) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: accounts
+            .iter()
+            .map(|account| AccountMeta::new(*account, false))
+            .collect(),
+        data,
+    }
+}
+
+/// Returns instruction data of `discriminator` followed by the serialized
+/// `fields`.
+pub fn data(discriminator: &[u8], fields: &[&[u8]]) -> Vec<u8> {
+    let mut data = discriminator.to_vec();
+    for field in fields {
+        data.extend_from_slice(field);
+    }
+    data
+}
+
+pub fn nested(
+    instruction: Instruction,
+    inner_instructions: Vec<NestedInstruction>,
+) -> NestedInstruction {
+    NestedInstruction {
+        metadata: InstructionMetadata {
+            transaction_metadata: transaction_metadata(Vec::new(), Vec::new(), Vec::new()),
+            stack_height: 1,
+            index: 0,
+        },
+        instruction,
+        inner_instructions,
+    }
+}
+
+/// Returns a Token program `Transfer`, which doesn't name its mint.
+pub fn transfer(
+    source: Pubkey,
+    destination: Pubkey,
+    authority: Pubkey,
+    amount: u64,
+) -> NestedInstruction {
+    nested(
+        instruction(
+            TOKEN_PROGRAM_ID,
+            data(&[3], &[&amount.to_le_bytes()]),
+            &[source, destination, authority],
+        ),
+        Vec::new(),
+    )
+}
+
+/// Returns a `TransferChecked` of the Token or Token-2022 program.
+pub fn transfer_checked(
+    program_id: Pubkey,
+    source: Pubkey,
+    mint: Pubkey,
+    destination: Pubkey,
+    authority: Pubkey,
+    amount: u64,
+) -> NestedInstruction {
+    nested(
+        instruction(
+            program_id,
+            data(&[12], &[&amount.to_le_bytes(), &[6]]),
+            &[source, mint, destination, authority],
+        ),
+        Vec::new(),
+    )
+}
+
+pub fn token_balance(account_index: u8, mint: Pubkey) -> TransactionTokenBalance {
+    TransactionTokenBalance {
+        account_index,
+        mint: mint.to_string(),
+        ui_token_amount: UiTokenAmount {
+            ui_amount: None,
+            decimals: 6,
+            amount: "0".to_string(),
+            ui_amount_string: "0".to_string(),
+        },
+        owner: Pubkey::default().to_string(),
+        program_id: TOKEN_PROGRAM_ID.to_string(),
+    }
+}
+
+/// Returns the metadata of a successful legacy transaction.
+pub fn transaction_metadata(
+    account_keys: Vec<Pubkey>,
+    pre_token_balances: Vec<TransactionTokenBalance>,
+    post_token_balances: Vec<TransactionTokenBalance>,
+) -> TransactionMetadata {
+    TransactionMetadata {
+        slot: 0,
+        signature: Signature::default(),
+        fee_payer: account_keys.first().copied().unwrap_or_default(),
+        meta: TransactionStatusMeta {
+            pre_token_balances: Some(pre_token_balances),
+            post_token_balances: Some(post_token_balances),
+            ..Default::default()
+        },
+        message: VersionedMessage::Legacy(Message {
+            account_keys,
+            ..Default::default()
+        }),
+        block_time: None,
+    }
+}
+
+/// The accounts of a swap of `mint_a` for `mint_b` against a pool whose
+/// reserves are held in `vault_a` and `vault_b`.
+pub struct SwapAccounts {
+    pub trader: Pubkey,
+    pub pool: Pubkey,
+    pub pool_authority: Pubkey,
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+    pub user_a: Pubkey,
+    pub user_b: Pubkey,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+}
+
+impl SwapAccounts {
+    pub fn new() -> Self {
+        SwapAccounts {
+            trader: Pubkey::new_unique(),
+            pool: Pubkey::new_unique(),
+            pool_authority: Pubkey::new_unique(),
+            vault_a: Pubkey::new_unique(),
+            vault_b: Pubkey::new_unique(),
+            user_a: Pubkey::new_unique(),
+            user_b: Pubkey::new_unique(),
+            mint_a: Pubkey::new_unique(),
+            mint_b: Pubkey::new_unique(),
+        }
+    }
+
+    /// Returns the transfers of the trader paying `amount_in` of `mint_a`
+    /// into the pool and receiving `amount_out` of `mint_b`.
+    pub fn transfers(&self, amount_in: u64, amount_out: u64) -> Vec<NestedInstruction> {
+        vec![
+            transfer(self.user_a, self.vault_a, self.trader, amount_in),
+            transfer(self.vault_b, self.user_b, self.pool_authority, amount_out),
+        ]
+    }
+
+    /// Maps the token accounts of the swap to their mints.
+    pub fn token_mints(&self) -> HashMap<Pubkey, Pubkey> {
+        HashMap::from([
+            (self.vault_a, self.mint_a),
+            (self.vault_b, self.mint_b),
+            (self.user_a, self.mint_a),
+            (self.user_b, self.mint_b),
+        ])
+    }
+
+    /// Returns the swap of `amount_in` of `mint_a` for `amount_out` of
+    /// `mint_b`, without a fee.
+    pub fn swap(&self, dex: Dex, amount_in: u64, amount_out: u64) -> Swap {
+        Swap {
+            dex,
+            pool: self.pool,
+            trader: self.trader,
+            mint_in: self.mint_a,
+            amount_in,
+            mint_out: self.mint_b,
+            amount_out,
+            fee: None,
+        }
+    }
+}
+
+/// Returns a Raydium CPMM `swap_base_input` of `amount_in` for
+/// `amount_out`, with its transfers.
+pub fn raydium_cpmm_swap(
+    swap: &SwapAccounts,
+    amount_in: u64,
+    amount_out: u64,
+) -> NestedInstruction {
+    let other = Pubkey::new_unique();
+    nested(
+        instruction(
+            carbon_raydium_cpmm_decoder::PROGRAM_ID,
+            data(
+                &0x8fbe5adac41e33deu64.to_be_bytes(),
+                &[&amount_in.to_le_bytes(), &0u64.to_le_bytes()],
+            ),
+            &[
+                swap.trader,
+                swap.pool_authority,
+                other,
+                swap.pool,
+                swap.user_a,
+                swap.user_b,
+                swap.vault_a,
+                swap.vault_b,
+                TOKEN_PROGRAM_ID,
+                TOKEN_PROGRAM_ID,
+                swap.mint_a,
+                swap.mint_b,
+                other,
+            ],
+        ),
+        swap.transfers(amount_in, amount_out),
+    )
+}
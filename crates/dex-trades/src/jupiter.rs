@@ -0,0 +1,866 @@
+//! Reconstructs Jupiter routes from their instructions and the swap events
+//! they log.
+
+use {
+    crate::{
+        dexes,
+        swap::{Dex, SwapFee},
+    },
+    carbon_core::{
+        deserialize::ArrangeAccounts,
+        instruction::{InstructionDecoder, NestedInstruction},
+        transaction::TransactionMetadata,
+    },
+    carbon_jupiter_swap_decoder::{
+        instructions::{
+            exact_out_route::ExactOutRoute, route::Route,
+            route_with_token_ledger::RouteWithTokenLedger,
+            shared_accounts_exact_out_route::SharedAccountsExactOutRoute,
+            shared_accounts_route::SharedAccountsRoute,
+            shared_accounts_route_with_token_ledger::SharedAccountsRouteWithTokenLedger,
+            JupiterSwapInstruction,
+        },
+        types::RoutePlanStep,
+        JupiterSwapDecoder,
+    },
+    solana_sdk::pubkey::Pubkey,
+};
+
+/// The Jupiter instruction a trade was made with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum JupiterRouteKind {
+    Route,
+    RouteWithTokenLedger,
+    SharedAccountsRoute,
+    SharedAccountsRouteWithTokenLedger,
+    ExactOutRoute,
+    SharedAccountsExactOutRoute,
+}
+
+impl JupiterRouteKind {
+    /// Whether the route fixes its output amount rather than its input.
+    pub fn is_exact_out(&self) -> bool {
+        matches!(
+            self,
+            JupiterRouteKind::ExactOutRoute | JupiterRouteKind::SharedAccountsExactOutRoute
+        )
+    }
+}
+
+/// One swap of a Jupiter route, as logged by its `SwapEvent`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct JupiterHop {
+    /// The program of the AMM swapped through.
+    pub amm: Pubkey,
+    /// The DEX of the AMM, if Carbon has a decoder for it.
+    pub dex: Option<Dex>,
+    /// The pool swapped against, if the DEX's swap instruction was
+    /// recognized.
+    pub pool: Option<Pubkey>,
+    /// The step of the route plan the hop executed, when the route logged
+    /// one event per step.
+    pub route_plan_step: Option<RoutePlanStep>,
+    pub mint_in: Pubkey,
+    pub amount_in: u64,
+    pub mint_out: Pubkey,
+    pub amount_out: u64,
+}
+
+/// A trade routed through Jupiter.
+///
+/// `amount_in` is what the trader paid and `amount_out` what they received,
+/// so the platform fee is part of `amount_in` when charged in the input mint
+/// and not part of `amount_out` when charged in the output mint.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct JupiterTrade {
+    pub kind: JupiterRouteKind,
+    /// The signer that authorized the trade.
+    pub trader: Pubkey,
+    pub mint_in: Pubkey,
+    pub amount_in: u64,
+    pub mint_out: Pubkey,
+    pub amount_out: u64,
+    /// The quoted output amount of exact-in routes, or the quoted input
+    /// amount of exact-out routes.
+    pub quoted_amount: u64,
+    /// The slippage the trader accepted against `quoted_amount`.
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
+    /// The platform fee, as logged by the route's `FeeEvent`.
+    pub platform_fee: Option<SwapFee>,
+    /// The swaps of the route, in execution order.
+    pub hops: Vec<JupiterHop>,
+}
+
+impl JupiterTrade {
+    /// Rebuilds the trade of a Jupiter route instruction from its inner
+    /// instructions.
+    ///
+    /// Returns `None` for other instructions, and for routes that logged no
+    /// swap events.
+    pub fn from_instruction(instruction: &NestedInstruction) -> Option<Self> {
+        let decoded = JupiterSwapDecoder.decode_instruction(&instruction.instruction)?;
+        let route = RouteInstruction::new(decoded.data, &decoded.accounts)?;
+
+        let mut hops = Vec::new();
+        let mut platform_fee = None;
+        let mut dex_swap = None;
+        for inner_instruction in &instruction.inner_instructions {
+            let inner_instruction = &inner_instruction.instruction;
+            if let Some(decoded_swap) = dexes::decode_swap(inner_instruction) {
+                dex_swap = Some(decoded_swap);
+                continue;
+            }
+
+            match JupiterSwapDecoder
+                .decode_instruction(inner_instruction)
+                .map(|decoded| decoded.data)
+            {
+                Some(JupiterSwapInstruction::SwapEvent(event)) => {
+                    let dex = Dex::from_program_id(&event.amm);
+                    let pool = dex_swap
+                        .take()
+                        .filter(|decoded_swap| Some(decoded_swap.dex) == dex)
+                        .and_then(|decoded_swap| match decoded_swap.pools.as_slice() {
+                            [pool] => Some(pool.pool),
+                            _ => None,
+                        });
+                    hops.push(JupiterHop {
+                        amm: event.amm,
+                        dex,
+                        pool,
+                        route_plan_step: None,
+                        mint_in: event.input_mint,
+                        amount_in: event.input_amount,
+                        mint_out: event.output_mint,
+                        amount_out: event.output_amount,
+                    });
+                }
+                Some(JupiterSwapInstruction::FeeEvent(event)) => {
+                    platform_fee = Some(SwapFee {
+                        mint: event.mint,
+                        amount: event.amount,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        if hops.is_empty() {
+            return None;
+        }
+        if hops.len() == route.route_plan.len() {
+            for (hop, step) in hops.iter_mut().zip(route.route_plan) {
+                hop.route_plan_step = Some(step);
+            }
+        }
+
+        let mint_in = route.source_mint.unwrap_or(hops[0].mint_in);
+        let mint_out = route.destination_mint;
+        let (mut amount_in, mut amount_out) = route_amounts(&hops, mint_in, mint_out);
+        if let Some(fee) = platform_fee {
+            if fee.mint == mint_out {
+                amount_out = amount_out.saturating_sub(fee.amount);
+            } else if fee.mint == mint_in {
+                amount_in = amount_in.saturating_add(fee.amount);
+            }
+        }
+
+        Some(JupiterTrade {
+            kind: route.kind,
+            trader: route.trader,
+            mint_in,
+            amount_in,
+            mint_out,
+            amount_out,
+            quoted_amount: route.quoted_amount,
+            slippage_bps: route.slippage_bps,
+            platform_fee_bps: route.platform_fee_bps,
+            platform_fee,
+            hops,
+        })
+    }
+
+    /// Returns how much worse than quoted the trade executed, in basis
+    /// points of `quoted_amount`: the output shortfall of exact-in routes or
+    /// the input excess of exact-out routes. Negative when the trade beat
+    /// its quote.
+    pub fn realized_slippage_bps(&self) -> Option<f64> {
+        if self.quoted_amount == 0 {
+            return None;
+        }
+        let difference = if self.kind.is_exact_out() {
+            self.amount_in as f64 - self.quoted_amount as f64
+        } else {
+            self.quoted_amount as f64 - self.amount_out as f64
+        };
+        Some(difference / self.quoted_amount as f64 * 10_000.0)
+    }
+}
+
+/// Returns the Jupiter trades of a transaction, in execution order.
+///
+/// Route instructions are recognized at any depth. Failed transactions have
+/// no trades.
+pub fn jupiter_trades(
+    transaction_metadata: &TransactionMetadata,
+    instructions: &[NestedInstruction],
+) -> Vec<JupiterTrade> {
+    if transaction_metadata.meta.status.is_err() {
+        return Vec::new();
+    }
+
+    let mut trades = Vec::new();
+    collect_trades(instructions, &mut trades);
+    trades
+}
+
+fn collect_trades(instructions: &[NestedInstruction], trades: &mut Vec<JupiterTrade>) {
+    for instruction in instructions {
+        match JupiterTrade::from_instruction(instruction) {
+            Some(trade) => trades.push(trade),
+            None => collect_trades(&instruction.inner_instructions, trades),
+        }
+    }
+}
+
+/// Sums what the route's first hops took in and its last hops paid out.
+///
+/// With the route plan, these are the hops reading the route's first token
+/// account and writing its last one, which also holds for routes that start
+/// and end in the same mint. Without it, they are the hops trading the
+/// route's input and output mints.
+fn route_amounts(hops: &[JupiterHop], mint_in: Pubkey, mint_out: Pubkey) -> (u64, u64) {
+    let last_index = hops
+        .iter()
+        .filter_map(|hop| hop.route_plan_step.as_ref())
+        .map(|step| step.output_index)
+        .max();
+
+    let mut amount_in: u64 = 0;
+    let mut amount_out: u64 = 0;
+    for hop in hops {
+        let (first, last) = match (&hop.route_plan_step, last_index) {
+            (Some(step), Some(last_index)) => {
+                (step.input_index == 0, step.output_index == last_index)
+            }
+            _ => (hop.mint_in == mint_in, hop.mint_out == mint_out),
+        };
+        if first {
+            amount_in = amount_in.saturating_add(hop.amount_in);
+        }
+        if last {
+            amount_out = amount_out.saturating_add(hop.amount_out);
+        }
+    }
+    (amount_in, amount_out)
+}
+
+/// The parameters shared by Jupiter's route instructions.
+struct RouteInstruction {
+    kind: JupiterRouteKind,
+    trader: Pubkey,
+    /// The input mint, for the instructions that name it.
+    source_mint: Option<Pubkey>,
+    destination_mint: Pubkey,
+    route_plan: Vec<RoutePlanStep>,
+    quoted_amount: u64,
+    slippage_bps: u16,
+    platform_fee_bps: u8,
+}
+
+impl RouteInstruction {
+    fn new(
+        instruction: JupiterSwapInstruction,
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self> {
+        Some(match instruction {
+            JupiterSwapInstruction::Route(route) => {
+                let accounts = Route::arrange_accounts(accounts)?;
+                RouteInstruction {
+                    kind: JupiterRouteKind::Route,
+                    trader: accounts.user_transfer_authority,
+                    source_mint: None,
+                    destination_mint: accounts.destination_mint,
+                    route_plan: route.route_plan,
+                    quoted_amount: route.quoted_out_amount,
+                    slippage_bps: route.slippage_bps,
+                    platform_fee_bps: route.platform_fee_bps,
+                }
+            }
+            JupiterSwapInstruction::RouteWithTokenLedger(route) => {
+                let accounts = RouteWithTokenLedger::arrange_accounts(accounts)?;
+                RouteInstruction {
+                    kind: JupiterRouteKind::RouteWithTokenLedger,
+                    trader: accounts.user_transfer_authority,
+                    source_mint: None,
+                    destination_mint: accounts.destination_mint,
+                    route_plan: route.route_plan,
+                    quoted_amount: route.quoted_out_amount,
+                    slippage_bps: route.slippage_bps,
+                    platform_fee_bps: route.platform_fee_bps,
+                }
+            }
+            JupiterSwapInstruction::SharedAccountsRoute(route) => {
+                let accounts = SharedAccountsRoute::arrange_accounts(accounts)?;
+                RouteInstruction {
+                    kind: JupiterRouteKind::SharedAccountsRoute,
+                    trader: accounts.user_transfer_authority,
+                    source_mint: Some(accounts.source_mint),
+                    destination_mint: accounts.destination_mint,
+                    route_plan: route.route_plan,
+                    quoted_amount: route.quoted_out_amount,
+                    slippage_bps: route.slippage_bps,
+                    platform_fee_bps: route.platform_fee_bps,
+                }
+            }
+            JupiterSwapInstruction::SharedAccountsRouteWithTokenLedger(route) => {
+                let accounts = SharedAccountsRouteWithTokenLedger::arrange_accounts(accounts)?;
+                RouteInstruction {
+                    kind: JupiterRouteKind::SharedAccountsRouteWithTokenLedger,
+                    trader: accounts.user_transfer_authority,
+                    source_mint: Some(accounts.source_mint),
+                    destination_mint: accounts.destination_mint,
+                    route_plan: route.route_plan,
+                    quoted_amount: route.quoted_out_amount,
+                    slippage_bps: route.slippage_bps,
+                    platform_fee_bps: route.platform_fee_bps,
+                }
+            }
+            JupiterSwapInstruction::ExactOutRoute(route) => {
+                let accounts = ExactOutRoute::arrange_accounts(accounts)?;
+                RouteInstruction {
+                    kind: JupiterRouteKind::ExactOutRoute,
+                    trader: accounts.user_transfer_authority,
+                    source_mint: Some(accounts.source_mint),
+                    destination_mint: accounts.destination_mint,
+                    route_plan: route.route_plan,
+                    quoted_amount: route.quoted_in_amount,
+                    slippage_bps: route.slippage_bps,
+                    platform_fee_bps: route.platform_fee_bps,
+                }
+            }
+            JupiterSwapInstruction::SharedAccountsExactOutRoute(route) => {
+                let accounts = SharedAccountsExactOutRoute::arrange_accounts(accounts)?;
+                RouteInstruction {
+                    kind: JupiterRouteKind::SharedAccountsExactOutRoute,
+                    trader: accounts.user_transfer_authority,
+                    source_mint: Some(accounts.source_mint),
+                    destination_mint: accounts.destination_mint,
+                    route_plan: route.route_plan,
+                    quoted_amount: route.quoted_in_amount,
+                    slippage_bps: route.slippage_bps,
+                    platform_fee_bps: route.platform_fee_bps,
+                }
+            }
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::fixtures::*,
+        carbon_jupiter_swap_decoder::{types::Swap, PROGRAM_ID as JUPITER_PROGRAM_ID},
+        solana_sdk::transaction::TransactionError,
+    };
+
+    const ROUTE: u64 = 0xe517cb977ae3ad2a;
+    const SHARED_ACCOUNTS_ROUTE: u64 = 0xc1209b3341d69c81;
+    const EXACT_OUT_ROUTE: u64 = 0xd033ef977b2bed5c;
+    const SHARED_ACCOUNTS_EXACT_OUT_ROUTE: u64 = 0xb0d169a89a7d453e;
+    const SWAP_EVENT: u128 = 0xe445a52e51cb9a1d40c6cde8260871e2;
+    const FEE_EVENT: u128 = 0xe445a52e51cb9a1d494f4e7fb8d50ddc;
+
+    /// The index of `Swap::RaydiumCP` in Jupiter's `Swap` enum.
+    const RAYDIUM_CP: u8 = 46;
+
+    /// The parameters of a route instruction.
+    struct RouteParams {
+        steps: Vec<(u8, u8, u8)>,
+        amount: u64,
+        quoted_amount: u64,
+        slippage_bps: u16,
+        platform_fee_bps: u8,
+    }
+
+    fn route_plan_step(percent: u8, input_index: u8, output_index: u8) -> RoutePlanStep {
+        RoutePlanStep {
+            swap: Swap::RaydiumCP,
+            percent,
+            input_index,
+            output_index,
+        }
+    }
+
+    /// Returns the data of a route instruction, whose route plan swaps
+    /// through Raydium CPMM at each step. Shared accounts routes start with
+    /// their `id`.
+    fn route_data(discriminator: u64, id: Option<u8>, params: &RouteParams) -> Vec<u8> {
+        let mut route_plan = (params.steps.len() as u32).to_le_bytes().to_vec();
+        for (percent, input_index, output_index) in &params.steps {
+            route_plan.extend_from_slice(&[RAYDIUM_CP, *percent, *input_index, *output_index]);
+        }
+        data(
+            &discriminator.to_be_bytes(),
+            &[
+                id.as_slice(),
+                &route_plan,
+                &params.amount.to_le_bytes(),
+                &params.quoted_amount.to_le_bytes(),
+                &params.slippage_bps.to_le_bytes(),
+                &[params.platform_fee_bps],
+            ],
+        )
+    }
+
+    /// Returns the accounts of a `SharedAccountsRoute` or
+    /// `SharedAccountsExactOutRoute` trading `mint_in` for `mint_out`.
+    fn shared_accounts(trader: Pubkey, mint_in: Pubkey, mint_out: Pubkey) -> Vec<Pubkey> {
+        let mut accounts: Vec<Pubkey> = (0..13).map(|_| Pubkey::new_unique()).collect();
+        accounts[0] = TOKEN_PROGRAM_ID;
+        accounts[2] = trader;
+        accounts[7] = mint_in;
+        accounts[8] = mint_out;
+        accounts[12] = JUPITER_PROGRAM_ID;
+        accounts
+    }
+
+    /// Returns the `SwapEvent` Jupiter logs after swapping through `swap`.
+    fn swap_event(swap: &SwapAccounts, amount_in: u64, amount_out: u64) -> NestedInstruction {
+        nested(
+            instruction(
+                JUPITER_PROGRAM_ID,
+                data(
+                    &SWAP_EVENT.to_be_bytes(),
+                    &[
+                        carbon_raydium_cpmm_decoder::PROGRAM_ID.as_ref(),
+                        swap.mint_a.as_ref(),
+                        &amount_in.to_le_bytes(),
+                        swap.mint_b.as_ref(),
+                        &amount_out.to_le_bytes(),
+                    ],
+                ),
+                &[],
+            ),
+            Vec::new(),
+        )
+    }
+
+    fn fee_event(mint: Pubkey, amount: u64) -> NestedInstruction {
+        nested(
+            instruction(
+                JUPITER_PROGRAM_ID,
+                data(
+                    &FEE_EVENT.to_be_bytes(),
+                    &[
+                        Pubkey::new_unique().as_ref(),
+                        mint.as_ref(),
+                        &amount.to_le_bytes(),
+                    ],
+                ),
+                &[],
+            ),
+            Vec::new(),
+        )
+    }
+
+    /// Returns the Raydium CPMM swap through `swap` and the `SwapEvent` that
+    /// follows it.
+    fn hop_instructions(
+        swap: &SwapAccounts,
+        amount_in: u64,
+        amount_out: u64,
+    ) -> Vec<NestedInstruction> {
+        vec![
+            raydium_cpmm_swap(swap, amount_in, amount_out),
+            swap_event(swap, amount_in, amount_out),
+        ]
+    }
+
+    fn hop(
+        swap: &SwapAccounts,
+        amount_in: u64,
+        amount_out: u64,
+        route_plan_step: Option<RoutePlanStep>,
+    ) -> JupiterHop {
+        JupiterHop {
+            amm: carbon_raydium_cpmm_decoder::PROGRAM_ID,
+            dex: Some(Dex::RaydiumCpmm),
+            pool: Some(swap.pool),
+            route_plan_step,
+            mint_in: swap.mint_a,
+            amount_in,
+            mint_out: swap.mint_b,
+            amount_out,
+        }
+    }
+
+    /// Returns the accounts of a hop from the output of `swap`, or of a
+    /// split of `swap` when `split`.
+    fn next_hop(swap: &SwapAccounts, split: bool) -> SwapAccounts {
+        if split {
+            SwapAccounts {
+                trader: swap.trader,
+                user_a: swap.user_a,
+                user_b: swap.user_b,
+                mint_a: swap.mint_a,
+                mint_b: swap.mint_b,
+                ..SwapAccounts::new()
+            }
+        } else {
+            SwapAccounts {
+                trader: swap.trader,
+                user_a: swap.user_b,
+                mint_a: swap.mint_b,
+                ..SwapAccounts::new()
+            }
+        }
+    }
+
+    #[test]
+    fn test_multi_hop_route_with_output_mint_fee() {
+        // Arrange
+        let first = SwapAccounts::new();
+        let second = next_hop(&first, false);
+        let params = RouteParams {
+            steps: vec![(100, 0, 1), (100, 1, 2)],
+            amount: 1_000_000,
+            quoted_amount: 2_490_000,
+            slippage_bps: 50,
+            platform_fee_bps: 10,
+        };
+        let accounts = [
+            TOKEN_PROGRAM_ID,
+            first.trader,
+            first.user_a,
+            second.user_b,
+            second.user_b,
+            second.mint_b,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            JUPITER_PROGRAM_ID,
+        ];
+        let mut inner_instructions = hop_instructions(&first, 1_000_000, 5_000);
+        inner_instructions.extend(hop_instructions(&second, 5_000, 2_500_000));
+        inner_instructions.push(fee_event(second.mint_b, 2_500));
+        let route = nested(
+            instruction(
+                JUPITER_PROGRAM_ID,
+                route_data(ROUTE, None, &params),
+                &accounts,
+            ),
+            inner_instructions,
+        );
+
+        // Act
+        let trade = JupiterTrade::from_instruction(&route);
+
+        // Assert
+        assert_eq!(
+            trade,
+            Some(JupiterTrade {
+                kind: JupiterRouteKind::Route,
+                trader: first.trader,
+                mint_in: first.mint_a,
+                amount_in: 1_000_000,
+                mint_out: second.mint_b,
+                amount_out: 2_497_500,
+                quoted_amount: 2_490_000,
+                slippage_bps: 50,
+                platform_fee_bps: 10,
+                platform_fee: Some(SwapFee {
+                    mint: second.mint_b,
+                    amount: 2_500,
+                }),
+                hops: vec![
+                    hop(&first, 1_000_000, 5_000, Some(route_plan_step(100, 0, 1))),
+                    hop(&second, 5_000, 2_500_000, Some(route_plan_step(100, 1, 2))),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_shared_accounts_split_route_with_input_mint_fee() {
+        // Arrange
+        let first = SwapAccounts::new();
+        let split = next_hop(&first, true);
+        let last = next_hop(&first, false);
+        let params = RouteParams {
+            steps: vec![(60, 0, 1), (40, 0, 1), (100, 1, 2)],
+            amount: 1_000_000,
+            quoted_amount: 2_500_000,
+            slippage_bps: 100,
+            platform_fee_bps: 100,
+        };
+        let mut inner_instructions = vec![fee_event(first.mint_a, 10_000)];
+        inner_instructions.extend(hop_instructions(&first, 600_000, 3_000));
+        inner_instructions.extend(hop_instructions(&split, 400_000, 2_000));
+        inner_instructions.extend(hop_instructions(&last, 5_000, 2_480_000));
+        let route = nested(
+            instruction(
+                JUPITER_PROGRAM_ID,
+                route_data(SHARED_ACCOUNTS_ROUTE, Some(3), &params),
+                &shared_accounts(first.trader, first.mint_a, last.mint_b),
+            ),
+            inner_instructions,
+        );
+
+        // Act
+        let trade = JupiterTrade::from_instruction(&route).expect("trade");
+
+        // Assert
+        assert_eq!(trade.kind, JupiterRouteKind::SharedAccountsRoute);
+        assert_eq!(trade.trader, first.trader);
+        assert_eq!((trade.mint_in, trade.amount_in), (first.mint_a, 1_010_000));
+        assert_eq!((trade.mint_out, trade.amount_out), (last.mint_b, 2_480_000));
+        assert_eq!(
+            trade.platform_fee,
+            Some(SwapFee {
+                mint: first.mint_a,
+                amount: 10_000,
+            })
+        );
+        assert_eq!(
+            trade.hops,
+            vec![
+                hop(&first, 600_000, 3_000, Some(route_plan_step(60, 0, 1))),
+                hop(&split, 400_000, 2_000, Some(route_plan_step(40, 0, 1))),
+                hop(&last, 5_000, 2_480_000, Some(route_plan_step(100, 1, 2))),
+            ]
+        );
+        assert_eq!(trade.realized_slippage_bps(), Some(80.0));
+    }
+
+    #[test]
+    fn test_exact_out_route_with_input_mint_fee() {
+        // Arrange
+        let swap = SwapAccounts::new();
+        let params = RouteParams {
+            steps: vec![(100, 0, 1)],
+            amount: 2_500_000,
+            quoted_amount: 1_000_000,
+            slippage_bps: 50,
+            platform_fee_bps: 20,
+        };
+        let accounts = [
+            TOKEN_PROGRAM_ID,
+            swap.trader,
+            swap.user_a,
+            swap.user_b,
+            swap.user_b,
+            swap.mint_a,
+            swap.mint_b,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            JUPITER_PROGRAM_ID,
+        ];
+        let mut inner_instructions = vec![fee_event(swap.mint_a, 2_000)];
+        inner_instructions.extend(hop_instructions(&swap, 1_003_000, 2_500_000));
+        let route = nested(
+            instruction(
+                JUPITER_PROGRAM_ID,
+                route_data(EXACT_OUT_ROUTE, None, &params),
+                &accounts,
+            ),
+            inner_instructions,
+        );
+
+        // Act
+        let trade = JupiterTrade::from_instruction(&route).expect("trade");
+
+        // Assert
+        assert_eq!(trade.kind, JupiterRouteKind::ExactOutRoute);
+        assert!(trade.kind.is_exact_out());
+        assert_eq!((trade.mint_in, trade.amount_in), (swap.mint_a, 1_005_000));
+        assert_eq!((trade.mint_out, trade.amount_out), (swap.mint_b, 2_500_000));
+        assert_eq!(trade.quoted_amount, 1_000_000);
+        assert_eq!(trade.realized_slippage_bps(), Some(50.0));
+    }
+
+    #[test]
+    fn test_shared_accounts_exact_out_route_with_output_mint_fee() {
+        // Arrange
+        let first = SwapAccounts::new();
+        let second = next_hop(&first, false);
+        let params = RouteParams {
+            steps: vec![(100, 0, 1), (100, 1, 2)],
+            amount: 2_500_000,
+            quoted_amount: 1_000_000,
+            slippage_bps: 50,
+            platform_fee_bps: 10,
+        };
+        let mut inner_instructions = hop_instructions(&first, 990_000, 5_000);
+        inner_instructions.extend(hop_instructions(&second, 5_000, 2_502_500));
+        inner_instructions.push(fee_event(second.mint_b, 2_500));
+        let route = nested(
+            instruction(
+                JUPITER_PROGRAM_ID,
+                route_data(SHARED_ACCOUNTS_EXACT_OUT_ROUTE, Some(0), &params),
+                &shared_accounts(first.trader, first.mint_a, second.mint_b),
+            ),
+            inner_instructions,
+        );
+
+        // Act
+        let trade = JupiterTrade::from_instruction(&route).expect("trade");
+
+        // Assert
+        assert_eq!(trade.kind, JupiterRouteKind::SharedAccountsExactOutRoute);
+        assert_eq!((trade.mint_in, trade.amount_in), (first.mint_a, 990_000));
+        assert_eq!(
+            (trade.mint_out, trade.amount_out),
+            (second.mint_b, 2_500_000)
+        );
+        assert_eq!(trade.realized_slippage_bps(), Some(-100.0));
+    }
+
+    #[test]
+    fn test_route_amounts_of_circular_route() {
+        // Arrange
+        let first = SwapAccounts::new();
+        let second = SwapAccounts {
+            trader: first.trader,
+            user_a: first.user_b,
+            mint_a: first.mint_b,
+            user_b: first.user_a,
+            mint_b: first.mint_a,
+            ..SwapAccounts::new()
+        };
+        let hops = [
+            hop(&first, 1_000_000, 5_000, Some(route_plan_step(100, 0, 1))),
+            hop(&second, 5_000, 1_010_000, Some(route_plan_step(100, 1, 2))),
+        ];
+
+        // Act
+        let amounts = route_amounts(&hops, first.mint_a, first.mint_a);
+
+        // Assert
+        assert_eq!(amounts, (1_000_000, 1_010_000));
+    }
+
+    #[test]
+    fn test_route_amounts_without_route_plan() {
+        // Arrange
+        let first = SwapAccounts::new();
+        let split = next_hop(&first, true);
+        let last = next_hop(&first, false);
+        let hops = [
+            hop(&first, 600_000, 3_000, None),
+            hop(&split, 400_000, 2_000, None),
+            hop(&last, 5_000, 2_500_000, None),
+        ];
+
+        // Act
+        let amounts = route_amounts(&hops, first.mint_a, last.mint_b);
+
+        // Assert
+        assert_eq!(amounts, (1_000_000, 2_500_000));
+    }
+
+    #[test]
+    fn test_route_plan_not_matching_swap_events() {
+        // Arrange
+        let first = SwapAccounts::new();
+        let second = next_hop(&first, false);
+        let params = RouteParams {
+            steps: vec![(100, 0, 1)],
+            amount: 1_000_000,
+            quoted_amount: 2_500_000,
+            slippage_bps: 50,
+            platform_fee_bps: 0,
+        };
+        let mut inner_instructions = hop_instructions(&first, 1_000_000, 5_000);
+        inner_instructions.extend(hop_instructions(&second, 5_000, 2_500_000));
+        let route = nested(
+            instruction(
+                JUPITER_PROGRAM_ID,
+                route_data(SHARED_ACCOUNTS_ROUTE, Some(0), &params),
+                &shared_accounts(first.trader, first.mint_a, second.mint_b),
+            ),
+            inner_instructions,
+        );
+
+        // Act
+        let trade = JupiterTrade::from_instruction(&route).expect("trade");
+
+        // Assert
+        assert!(trade.hops.iter().all(|hop| hop.route_plan_step.is_none()));
+        assert_eq!((trade.amount_in, trade.amount_out), (1_000_000, 2_500_000));
+    }
+
+    #[test]
+    fn test_route_without_swap_events() {
+        // Arrange
+        let swap = SwapAccounts::new();
+        let params = RouteParams {
+            steps: vec![(100, 0, 1)],
+            amount: 1_000_000,
+            quoted_amount: 2_500_000,
+            slippage_bps: 50,
+            platform_fee_bps: 0,
+        };
+        let route = nested(
+            instruction(
+                JUPITER_PROGRAM_ID,
+                route_data(SHARED_ACCOUNTS_ROUTE, Some(0), &params),
+                &shared_accounts(swap.trader, swap.mint_a, swap.mint_b),
+            ),
+            vec![raydium_cpmm_swap(&swap, 1_000_000, 2_500_000)],
+        );
+
+        // Act
+        let trade = JupiterTrade::from_instruction(&route);
+
+        // Assert
+        assert_eq!(trade, None);
+    }
+
+    #[test]
+    fn test_jupiter_trades_of_nested_routes() {
+        // Arrange
+        let swap = SwapAccounts::new();
+        let params = RouteParams {
+            steps: vec![(100, 0, 1)],
+            amount: 1_000_000,
+            quoted_amount: 2_500_000,
+            slippage_bps: 50,
+            platform_fee_bps: 0,
+        };
+        let route = nested(
+            instruction(
+                JUPITER_PROGRAM_ID,
+                route_data(SHARED_ACCOUNTS_ROUTE, Some(0), &params),
+                &shared_accounts(swap.trader, swap.mint_a, swap.mint_b),
+            ),
+            hop_instructions(&swap, 1_000_000, 2_500_000),
+        );
+        let instructions = vec![nested(
+            instruction(Pubkey::new_unique(), Vec::new(), &[]),
+            vec![route],
+        )];
+        let mut transaction_metadata = transaction_metadata(Vec::new(), Vec::new(), Vec::new());
+
+        // Act
+        let trades = jupiter_trades(&transaction_metadata, &instructions);
+        transaction_metadata.meta.status = Err(TransactionError::AccountNotFound);
+        let failed_trades = jupiter_trades(&transaction_metadata, &instructions);
+
+        // Assert
+        assert_eq!(trades.len(), 1);
+        assert_eq!(
+            trades[0].hops,
+            vec![hop(
+                &swap,
+                1_000_000,
+                2_500_000,
+                Some(route_plan_step(100, 0, 1))
+            )]
+        );
+        assert_eq!(failed_trades, Vec::new());
+    }
+}
@@ -0,0 +1,71 @@
+//! # Carbon DEX Trades
+//!
+//! `carbon-dex-trades` normalizes the swaps of the DEXes Carbon has decoders
+//! for into one [`Swap`] record per trade against a pool, so indexers don't
+//! need a processor per DEX to track trades.
+//!
+//! Swap instructions are recognized by their decoders, at any depth of the
+//! transaction, and their amounts read from the token transfers into and out
+//! of the pool's vaults among their inner instructions. Transfers that don't
+//! name their mint are resolved through the transaction's pre and post token
+//! balances.
+//!
+//! ## Modules Overview
+//!
+//! - **[`jupiter`]**: Rebuilds the routes of Jupiter swaps into
+//!   [`JupiterTrade`]s, with each hop's AMM, mints and amounts, the platform
+//!   fee and the slippage against the quote.
+//!
+//! - **[`pipe`]**: Provides [`SwapPipe`], a transaction pipe that sends the
+//!   swaps of each transaction to a processor.
+//!
+//! - **[`swap`]**: Defines the [`Swap`] record and extracts swaps from a
+//!   transaction's instructions.
+//!
+//! - **[`transfers`]**: Decodes Token and Token-2022 transfers and maps token
+//!   accounts to their mints.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! use carbon_dex_trades::{SwapPipe, SwapProcessorInputType};
+//!
+//! pub struct SwapProcessor;
+//!
+//! #[async_trait]
+//! impl Processor for SwapProcessor {
+//!     type InputType = SwapProcessorInputType;
+//!
+//!     async fn process(
+//!         &mut self,
+//!         (metadata, swaps): Self::InputType,
+//!         _metrics: Arc<MetricsCollection>,
+//!     ) -> CarbonResult<()> {
+//!         for swap in swaps {
+//!             log::info!("{}: {:?}", metadata.signature, swap);
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! carbon_core::pipeline::Pipeline::builder()
+//!     .datasource(datasource)
+//!     .transaction_pipe(SwapPipe::new(SwapProcessor))
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+
+mod dexes;
+#[cfg(test)]
+mod fixtures;
+pub mod jupiter;
+pub mod pipe;
+pub mod swap;
+pub mod transfers;
+
+pub use {
+    jupiter::{jupiter_trades, JupiterHop, JupiterRouteKind, JupiterTrade},
+    pipe::{SwapPipe, SwapProcessorInputType},
+    swap::{swaps, Dex, Swap, SwapFee, NATIVE_MINT},
+};
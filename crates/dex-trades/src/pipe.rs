@@ -0,0 +1,80 @@
+use {
+    crate::swap::{self, Swap},
+    async_trait::async_trait,
+    carbon_core::{
+        error::CarbonResult,
+        instruction::NestedInstruction,
+        latency::{LatencyMonitor, PipeLatency},
+        metrics::MetricsCollection,
+        processor::Processor,
+        transaction::{TransactionMetadata, TransactionPipes},
+    },
+    std::{sync::Arc, time::Instant},
+};
+
+/// The input passed to a [`SwapPipe`]'s processor: the transaction and the
+/// swaps it performed.
+pub type SwapProcessorInputType = (TransactionMetadata, Vec<Swap>);
+
+/// A transaction pipe that sends the swaps of each transaction to its
+/// processor.
+///
+/// Transactions without swaps, including failed transactions, are not sent
+/// to the processor.
+pub struct SwapPipe {
+    processor: Box<dyn Processor<InputType = SwapProcessorInputType> + Send + Sync>,
+    latency: Option<Arc<PipeLatency>>,
+}
+
+impl SwapPipe {
+    /// Creates a new `SwapPipe` with the given processor.
+    pub fn new(
+        processor: impl Processor<InputType = SwapProcessorInputType> + Send + Sync + 'static,
+    ) -> Self {
+        log::trace!("SwapPipe::new(processor: {:?})", stringify!(processor));
+        Self {
+            processor: Box::new(processor),
+            latency: None,
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionPipes<'_> for SwapPipe {
+    fn register_latency(&mut self, latency: &LatencyMonitor) {
+        self.latency =
+            Some(latency.register(&[("pipe", "swap"), ("processor", self.processor.name())]));
+    }
+
+    async fn run(
+        &mut self,
+        transaction_metadata: TransactionMetadata,
+        instructions: &[NestedInstruction],
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        log::trace!("SwapPipe::run(instructions: {:?}, metrics)", instructions);
+
+        let swaps = swap::swaps(&transaction_metadata, instructions);
+        if swaps.is_empty() {
+            return Ok(());
+        }
+
+        let start = Instant::now();
+        let signature = transaction_metadata.signature;
+
+        let result = self
+            .processor
+            .process((transaction_metadata, swaps), metrics.clone())
+            .await;
+
+        metrics
+            .record_pipe_run(
+                &[("pipe", "swap"), ("processor", self.processor.name())],
+                self.latency.as_deref(),
+                &signature,
+                start,
+                result,
+            )
+            .await
+    }
+}
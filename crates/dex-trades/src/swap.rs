@@ -0,0 +1,417 @@
+use {
+    crate::{
+        dexes,
+        transfers::{self, TokenTransfer},
+    },
+    carbon_core::{instruction::NestedInstruction, transaction::TransactionMetadata},
+    solana_sdk::{instruction::Instruction, pubkey, pubkey::Pubkey},
+    std::collections::HashMap,
+};
+
+/// The mint of wrapped SOL, used as `mint_in` or `mint_out` when a swap
+/// trades native SOL.
+pub const NATIVE_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+
+/// The DEXes whose swaps are recognized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Dex {
+    RaydiumAmmV4,
+    RaydiumClmm,
+    RaydiumCpmm,
+    OrcaWhirlpool,
+    MeteoraDlmm,
+    MeteoraPools,
+    LifinityAmmV2,
+    Fluxbeam,
+    Pumpfun,
+    PumpSwap,
+    StabbleStableSwap,
+    StabbleWeightedSwap,
+    PhoenixV1,
+    OpenbookV2,
+}
+
+impl Dex {
+    pub const ALL: [Dex; 14] = [
+        Dex::RaydiumAmmV4,
+        Dex::RaydiumClmm,
+        Dex::RaydiumCpmm,
+        Dex::OrcaWhirlpool,
+        Dex::MeteoraDlmm,
+        Dex::MeteoraPools,
+        Dex::LifinityAmmV2,
+        Dex::Fluxbeam,
+        Dex::Pumpfun,
+        Dex::PumpSwap,
+        Dex::StabbleStableSwap,
+        Dex::StabbleWeightedSwap,
+        Dex::PhoenixV1,
+        Dex::OpenbookV2,
+    ];
+
+    /// Returns the DEX whose program is `program_id`.
+    pub fn from_program_id(program_id: &Pubkey) -> Option<Dex> {
+        Dex::ALL
+            .into_iter()
+            .find(|dex| dex.program_id() == *program_id)
+    }
+
+    pub fn program_id(&self) -> Pubkey {
+        match self {
+            Dex::RaydiumAmmV4 => carbon_raydium_amm_v4_decoder::PROGRAM_ID,
+            Dex::RaydiumClmm => carbon_raydium_clmm_decoder::PROGRAM_ID,
+            Dex::RaydiumCpmm => carbon_raydium_cpmm_decoder::PROGRAM_ID,
+            Dex::OrcaWhirlpool => carbon_orca_whirlpool_decoder::PROGRAM_ID,
+            Dex::MeteoraDlmm => carbon_meteora_dlmm_decoder::PROGRAM_ID,
+            Dex::MeteoraPools => carbon_meteora_pools_decoder::PROGRAM_ID,
+            Dex::LifinityAmmV2 => carbon_lifinity_amm_v2_decoder::PROGRAM_ID,
+            Dex::Fluxbeam => carbon_fluxbeam_decoder::PROGRAM_ID,
+            Dex::Pumpfun => carbon_pumpfun_decoder::PROGRAM_ID,
+            Dex::PumpSwap => carbon_pump_swap_decoder::PROGRAM_ID,
+            Dex::StabbleStableSwap => carbon_stabble_stable_swap_decoder::PROGRAM_ID,
+            Dex::StabbleWeightedSwap => carbon_stabble_weighted_swap_decoder::PROGRAM_ID,
+            Dex::PhoenixV1 => carbon_phoenix_v1_decoder::PROGRAM_ID,
+            Dex::OpenbookV2 => carbon_openbook_v2_decoder::PROGRAM_ID,
+        }
+    }
+}
+
+/// A fee the DEX reported charging for a swap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct SwapFee {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// One trade against one pool.
+///
+/// `amount_in` is what the pool's vaults received and `amount_out` what they
+/// paid out, so fees the DEX sends elsewhere, such as protocol or host fees,
+/// are not part of `amount_in`. Multi-hop instructions produce one swap per
+/// pool.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Swap {
+    pub dex: Dex,
+    pub pool: Pubkey,
+    /// The signer that authorized the swap: the user, or the program that
+    /// routed it.
+    pub trader: Pubkey,
+    pub mint_in: Pubkey,
+    pub amount_in: u64,
+    pub mint_out: Pubkey,
+    pub amount_out: u64,
+    /// The fee the DEX logged for the swap, for DEXes that log swap events.
+    pub fee: Option<SwapFee>,
+}
+
+/// Returns the swaps of a transaction, in execution order.
+///
+/// Swap instructions are recognized at any depth, so swaps routed through
+/// aggregators are reported as one swap per DEX instruction. Failed
+/// transactions have no swaps.
+pub fn swaps(
+    transaction_metadata: &TransactionMetadata,
+    instructions: &[NestedInstruction],
+) -> Vec<Swap> {
+    if transaction_metadata.meta.status.is_err() {
+        return Vec::new();
+    }
+
+    let token_mints = transfers::token_account_mints(transaction_metadata);
+    let mut swaps = Vec::new();
+    collect_swaps(instructions, &token_mints, &mut swaps);
+    swaps
+}
+
+/// Returns the swaps performed by `instruction`, reading amounts from the
+/// token transfers among its inner instructions.
+///
+/// `token_mints` maps token accounts to their mints, to resolve the mint of
+/// plain `Transfer` instructions; see
+/// [`token_account_mints`](crate::transfers::token_account_mints).
+pub fn instruction_swaps(
+    instruction: &NestedInstruction,
+    token_mints: &HashMap<Pubkey, Pubkey>,
+) -> Vec<Swap> {
+    let Some(decoded) = dexes::decode_swap(&instruction.instruction) else {
+        return Vec::new();
+    };
+
+    let mut inner_instructions = Vec::new();
+    flatten(&instruction.inner_instructions, &mut inner_instructions);
+
+    if decoded.dex == Dex::Pumpfun {
+        return dexes::pumpfun_swaps(&decoded, &inner_instructions);
+    }
+
+    let transfers: Vec<TokenTransfer> = inner_instructions
+        .iter()
+        .filter_map(|inner_instruction| TokenTransfer::from_instruction(inner_instruction))
+        .collect();
+
+    decoded
+        .pools
+        .iter()
+        .filter_map(|pool| {
+            let (mint_in, amount_in, mint_out, amount_out) =
+                pool_amounts(&pool.vaults, &transfers, token_mints)?;
+
+            Some(Swap {
+                dex: decoded.dex,
+                pool: pool.pool,
+                trader: decoded.trader,
+                mint_in,
+                amount_in,
+                mint_out,
+                amount_out,
+                fee: dexes::event_fee(&decoded, &inner_instructions, mint_in),
+            })
+        })
+        .collect()
+}
+
+fn collect_swaps(
+    instructions: &[NestedInstruction],
+    token_mints: &HashMap<Pubkey, Pubkey>,
+    swaps: &mut Vec<Swap>,
+) {
+    for instruction in instructions {
+        swaps.extend(instruction_swaps(instruction, token_mints));
+        collect_swaps(&instruction.inner_instructions, token_mints, swaps);
+    }
+}
+
+fn flatten<'a>(instructions: &'a [NestedInstruction], flattened: &mut Vec<&'a Instruction>) {
+    for instruction in instructions {
+        flattened.push(&instruction.instruction);
+        flatten(&instruction.inner_instructions, flattened);
+    }
+}
+
+/// Nets the transfers into and out of a pool's vaults per mint. The mint the
+/// vaults gained is the one swapped in, the mint they lost the one swapped
+/// out.
+fn pool_amounts(
+    vaults: &[Pubkey],
+    transfers: &[TokenTransfer],
+    token_mints: &HashMap<Pubkey, Pubkey>,
+) -> Option<(Pubkey, u64, Pubkey, u64)> {
+    let mut net_amounts = HashMap::<Pubkey, i128>::new();
+    for transfer in transfers {
+        let into_pool = vaults.contains(&transfer.destination);
+        let out_of_pool = vaults.contains(&transfer.source);
+        if into_pool == out_of_pool {
+            continue;
+        }
+
+        let Some(mint) = transfer.mint(token_mints) else {
+            continue;
+        };
+        let amount = transfer.amount as i128;
+        *net_amounts.entry(mint).or_default() += if into_pool { amount } else { -amount };
+    }
+
+    let (mint_in, amount_in) = net_amounts
+        .iter()
+        .filter(|(_, amount)| **amount > 0)
+        .max_by_key(|(_, amount)| **amount)?;
+    let (mint_out, amount_out) = net_amounts
+        .iter()
+        .filter(|(_, amount)| **amount < 0)
+        .min_by_key(|(_, amount)| **amount)?;
+
+    Some((
+        *mint_in,
+        u64::try_from(*amount_in).ok()?,
+        *mint_out,
+        u64::try_from(amount_out.unsigned_abs()).ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::fixtures::*, solana_sdk::transaction::TransactionError};
+
+    const AMOUNT_IN: u64 = 1_000_000;
+    const AMOUNT_MIDDLE: u64 = 5_000;
+    const AMOUNT_OUT: u64 = 2_500_000;
+
+    /// Returns the metadata of a transaction whose token balances list the
+    /// token accounts of `swaps`.
+    fn transaction_with_balances(swaps: &[&SwapAccounts]) -> TransactionMetadata {
+        let token_mints: Vec<(Pubkey, Pubkey)> =
+            swaps.iter().flat_map(|swap| swap.token_mints()).collect();
+        let balances: Vec<_> = token_mints
+            .iter()
+            .enumerate()
+            .map(|(index, (_, mint))| token_balance(index as u8, *mint))
+            .collect();
+        transaction_metadata(
+            token_mints.iter().map(|(account, _)| *account).collect(),
+            balances.clone(),
+            balances,
+        )
+    }
+
+    /// Returns the accounts of a second hop, from the first hop's output.
+    fn next_hop(swap: &SwapAccounts) -> SwapAccounts {
+        SwapAccounts {
+            trader: swap.trader,
+            user_a: swap.user_b,
+            mint_a: swap.mint_b,
+            ..SwapAccounts::new()
+        }
+    }
+
+    #[test]
+    fn test_dex_from_program_id() {
+        for dex in Dex::ALL {
+            assert_eq!(Dex::from_program_id(&dex.program_id()), Some(dex));
+        }
+        assert_eq!(Dex::from_program_id(&Pubkey::new_unique()), None);
+    }
+
+    #[test]
+    fn test_swaps_routed_through_aggregator() {
+        // Arrange
+        let first = SwapAccounts::new();
+        let second = next_hop(&first);
+        let aggregator = Pubkey::new_unique();
+        let instructions = vec![nested(
+            instruction(aggregator, Vec::new(), &[first.trader]),
+            vec![
+                raydium_cpmm_swap(&first, AMOUNT_IN, AMOUNT_MIDDLE),
+                raydium_cpmm_swap(&second, AMOUNT_MIDDLE, AMOUNT_OUT),
+            ],
+        )];
+        let transaction_metadata = transaction_with_balances(&[&first, &second]);
+
+        // Act
+        let swaps = swaps(&transaction_metadata, &instructions);
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![
+                first.swap(Dex::RaydiumCpmm, AMOUNT_IN, AMOUNT_MIDDLE),
+                second.swap(Dex::RaydiumCpmm, AMOUNT_MIDDLE, AMOUNT_OUT),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_swaps_of_multi_hop_instruction() {
+        // Arrange
+        let first = SwapAccounts::new();
+        let second = next_hop(&first);
+        let other = Pubkey::new_unique();
+        let mut transfers = first.transfers(AMOUNT_IN, AMOUNT_MIDDLE);
+        transfers.extend(second.transfers(AMOUNT_MIDDLE, AMOUNT_OUT));
+        let instructions = vec![nested(
+            instruction(
+                carbon_orca_whirlpool_decoder::PROGRAM_ID,
+                data(
+                    &0xc360ed6c44a2dbe6u64.to_be_bytes(),
+                    &[
+                        &AMOUNT_IN.to_le_bytes(),
+                        &0u64.to_le_bytes(),
+                        &[1],
+                        &[1],
+                        &[1],
+                        &0u128.to_le_bytes(),
+                        &0u128.to_le_bytes(),
+                    ],
+                ),
+                &[
+                    TOKEN_PROGRAM_ID,
+                    first.trader,
+                    first.pool,
+                    second.pool,
+                    first.user_a,
+                    first.vault_a,
+                    first.user_b,
+                    first.vault_b,
+                    second.user_a,
+                    second.vault_a,
+                    second.user_b,
+                    second.vault_b,
+                    other,
+                    other,
+                    other,
+                    other,
+                    other,
+                    other,
+                    other,
+                    other,
+                ],
+            ),
+            transfers,
+        )];
+        let transaction_metadata = transaction_with_balances(&[&first, &second]);
+
+        // Act
+        let swaps = swaps(&transaction_metadata, &instructions);
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![
+                first.swap(Dex::OrcaWhirlpool, AMOUNT_IN, AMOUNT_MIDDLE),
+                second.swap(Dex::OrcaWhirlpool, AMOUNT_MIDDLE, AMOUNT_OUT),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_swaps_resolve_mints_from_pre_and_post_token_balances() {
+        // Arrange
+        // The trader's input account is closed by the transaction and its
+        // output account created by it, so each only has one balance, and
+        // the vaults have none.
+        let swap = SwapAccounts::new();
+        let instructions = vec![raydium_cpmm_swap(&swap, AMOUNT_IN, AMOUNT_OUT)];
+        let transaction_metadata = transaction_metadata(
+            vec![swap.trader, swap.user_a, swap.user_b],
+            vec![token_balance(1, swap.mint_a)],
+            vec![token_balance(2, swap.mint_b)],
+        );
+
+        // Act
+        let swaps = swaps(&transaction_metadata, &instructions);
+
+        // Assert
+        assert_eq!(
+            swaps,
+            vec![swap.swap(Dex::RaydiumCpmm, AMOUNT_IN, AMOUNT_OUT)]
+        );
+    }
+
+    #[test]
+    fn test_swaps_skip_transfers_of_unknown_mints() {
+        // Arrange
+        let swap = SwapAccounts::new();
+        let instructions = vec![raydium_cpmm_swap(&swap, AMOUNT_IN, AMOUNT_OUT)];
+        let transaction_metadata = transaction_metadata(vec![swap.trader], Vec::new(), Vec::new());
+
+        // Act
+        let swaps = swaps(&transaction_metadata, &instructions);
+
+        // Assert
+        assert_eq!(swaps, Vec::new());
+    }
+
+    #[test]
+    fn test_swaps_of_failed_transaction() {
+        // Arrange
+        let swap = SwapAccounts::new();
+        let instructions = vec![raydium_cpmm_swap(&swap, AMOUNT_IN, AMOUNT_OUT)];
+        let mut transaction_metadata = transaction_with_balances(&[&swap]);
+        transaction_metadata.meta.status = Err(TransactionError::AccountNotFound);
+
+        // Act
+        let swaps = swaps(&transaction_metadata, &instructions);
+
+        // Assert
+        assert_eq!(swaps, Vec::new());
+    }
+}
@@ -0,0 +1,350 @@
+use {
+    carbon_core::{
+        deserialize::ArrangeAccounts, instruction::InstructionDecoder,
+        transaction::TransactionMetadata,
+    },
+    carbon_token_2022_decoder::{
+        instructions::{
+            transfer as token_2022_transfer, transfer_checked as token_2022_transfer_checked,
+            transfer_checked_with_fee, Token2022Instruction,
+        },
+        Token2022Decoder,
+    },
+    carbon_token_program_decoder::{
+        instructions::{transfer, transfer_checked, TokenProgramInstruction},
+        TokenProgramDecoder,
+    },
+    solana_sdk::{instruction::Instruction, pubkey::Pubkey},
+    std::{collections::HashMap, str::FromStr},
+};
+
+/// A Token or Token-2022 program transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TokenTransfer {
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub authority: Pubkey,
+    /// The mint, for the checked transfers that name it.
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+}
+
+impl TokenTransfer {
+    /// Decodes `Transfer`, `TransferChecked` and `TransferCheckedWithFee`
+    /// instructions of the Token and Token-2022 programs.
+    pub fn from_instruction(instruction: &Instruction) -> Option<Self> {
+        if let Some(decoded) = TokenProgramDecoder.decode_instruction(instruction) {
+            return match decoded.data {
+                TokenProgramInstruction::Transfer(transfer) => {
+                    let accounts = transfer::Transfer::arrange_accounts(&decoded.accounts)?;
+                    Some(TokenTransfer {
+                        source: accounts.source,
+                        destination: accounts.destination,
+                        authority: accounts.authority,
+                        mint: None,
+                        amount: transfer.amount,
+                    })
+                }
+                TokenProgramInstruction::TransferChecked(transfer) => {
+                    let accounts =
+                        transfer_checked::TransferChecked::arrange_accounts(&decoded.accounts)?;
+                    Some(TokenTransfer {
+                        source: accounts.source,
+                        destination: accounts.destination,
+                        authority: accounts.authority,
+                        mint: Some(accounts.mint),
+                        amount: transfer.amount,
+                    })
+                }
+                _ => None,
+            };
+        }
+
+        let decoded = Token2022Decoder.decode_instruction(instruction)?;
+        match decoded.data {
+            Token2022Instruction::Transfer(transfer) => {
+                let accounts = token_2022_transfer::Transfer::arrange_accounts(&decoded.accounts)?;
+                Some(TokenTransfer {
+                    source: accounts.source,
+                    destination: accounts.destination,
+                    authority: accounts.authority,
+                    mint: None,
+                    amount: transfer.amount,
+                })
+            }
+            Token2022Instruction::TransferChecked(transfer) => {
+                let accounts = token_2022_transfer_checked::TransferChecked::arrange_accounts(
+                    &decoded.accounts,
+                )?;
+                Some(TokenTransfer {
+                    source: accounts.source,
+                    destination: accounts.destination,
+                    authority: accounts.authority,
+                    mint: Some(accounts.mint),
+                    amount: transfer.amount,
+                })
+            }
+            Token2022Instruction::TransferCheckedWithFee(transfer) => {
+                let accounts = transfer_checked_with_fee::TransferCheckedWithFee::arrange_accounts(
+                    &decoded.accounts,
+                )?;
+                Some(TokenTransfer {
+                    source: accounts.source,
+                    destination: accounts.destination,
+                    authority: accounts.authority,
+                    mint: Some(accounts.mint),
+                    amount: transfer.amount,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the transfer's mint, looking up its source or destination in
+    /// `token_mints` when the instruction doesn't name it.
+    pub fn mint(&self, token_mints: &HashMap<Pubkey, Pubkey>) -> Option<Pubkey> {
+        self.mint
+            .or_else(|| token_mints.get(&self.source).copied())
+            .or_else(|| token_mints.get(&self.destination).copied())
+    }
+}
+
+/// Maps the token accounts of a transaction to their mints, from its pre and
+/// post token balances.
+///
+/// Balances refer to accounts by their index in the transaction's account
+/// keys, which for v0 transactions include the addresses loaded from lookup
+/// tables.
+pub fn token_account_mints(transaction_metadata: &TransactionMetadata) -> HashMap<Pubkey, Pubkey> {
+    let meta = &transaction_metadata.meta;
+    let account_keys: Vec<Pubkey> = transaction_metadata
+        .message
+        .static_account_keys()
+        .iter()
+        .chain(meta.loaded_addresses.writable.iter())
+        .chain(meta.loaded_addresses.readonly.iter())
+        .copied()
+        .collect();
+
+    meta.pre_token_balances
+        .iter()
+        .flatten()
+        .chain(meta.post_token_balances.iter().flatten())
+        .filter_map(|balance| {
+            let account = account_keys.get(balance.account_index as usize)?;
+            let mint = Pubkey::from_str(&balance.mint).ok()?;
+            Some((*account, mint))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::fixtures::*,
+        solana_sdk::message::{v0, v0::LoadedAddresses, VersionedMessage},
+    };
+
+    const TOKEN_2022_PROGRAM_ID: Pubkey = carbon_token_2022_decoder::PROGRAM_ID;
+
+    #[test]
+    fn test_transfer() {
+        // Arrange
+        let (source, destination, authority) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let instruction = transfer(source, destination, authority, 1_000).instruction;
+
+        // Act
+        let transfer = TokenTransfer::from_instruction(&instruction);
+
+        // Assert
+        assert_eq!(
+            transfer,
+            Some(TokenTransfer {
+                source,
+                destination,
+                authority,
+                mint: None,
+                amount: 1_000,
+            })
+        );
+    }
+
+    #[test]
+    fn test_transfer_checked() {
+        for program_id in [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID] {
+            // Arrange
+            let (source, mint, destination, authority) = (
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            );
+            let instruction =
+                transfer_checked(program_id, source, mint, destination, authority, 1_000)
+                    .instruction;
+
+            // Act
+            let transfer = TokenTransfer::from_instruction(&instruction);
+
+            // Assert
+            assert_eq!(
+                transfer,
+                Some(TokenTransfer {
+                    source,
+                    destination,
+                    authority,
+                    mint: Some(mint),
+                    amount: 1_000,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_transfer_checked_with_fee() {
+        // Arrange
+        let (source, mint, destination, authority) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let instruction = instruction(
+            TOKEN_2022_PROGRAM_ID,
+            data(
+                &[26, 1],
+                &[&1_000u64.to_le_bytes(), &[6], &10u64.to_le_bytes()],
+            ),
+            &[source, mint, destination, authority],
+        );
+
+        // Act
+        let transfer = TokenTransfer::from_instruction(&instruction);
+
+        // Assert
+        assert_eq!(
+            transfer,
+            Some(TokenTransfer {
+                source,
+                destination,
+                authority,
+                mint: Some(mint),
+                amount: 1_000,
+            })
+        );
+    }
+
+    #[test]
+    fn test_other_instructions_are_not_transfers() {
+        let accounts = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        // A Token program `CloseAccount`.
+        let close_account = instruction(TOKEN_PROGRAM_ID, vec![9], &accounts);
+        // A `Transfer` layout sent to another program.
+        let mut other_program = transfer(accounts[0], accounts[1], accounts[2], 1_000).instruction;
+        other_program.program_id = Pubkey::new_unique();
+
+        assert_eq!(TokenTransfer::from_instruction(&close_account), None);
+        assert_eq!(TokenTransfer::from_instruction(&other_program), None);
+    }
+
+    #[test]
+    fn test_mint() {
+        let (source, destination, source_mint, destination_mint, checked_mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let transfer = TokenTransfer {
+            source,
+            destination,
+            authority: Pubkey::new_unique(),
+            mint: None,
+            amount: 1_000,
+        };
+        let both = HashMap::from([(source, source_mint), (destination, destination_mint)]);
+        let destination_only = HashMap::from([(destination, destination_mint)]);
+
+        assert_eq!(transfer.mint(&both), Some(source_mint));
+        assert_eq!(transfer.mint(&destination_only), Some(destination_mint));
+        assert_eq!(transfer.mint(&HashMap::new()), None);
+        assert_eq!(
+            TokenTransfer {
+                mint: Some(checked_mint),
+                ..transfer
+            }
+            .mint(&both),
+            Some(checked_mint)
+        );
+    }
+
+    #[test]
+    fn test_token_account_mints() {
+        // Arrange
+        let (closed, created, mint_a, mint_b) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let transaction_metadata = transaction_metadata(
+            vec![Pubkey::new_unique(), closed, created],
+            vec![token_balance(1, mint_a)],
+            vec![token_balance(2, mint_b), token_balance(7, mint_b)],
+        );
+
+        // Act
+        let token_mints = token_account_mints(&transaction_metadata);
+
+        // Assert
+        assert_eq!(
+            token_mints,
+            HashMap::from([(closed, mint_a), (created, mint_b)])
+        );
+    }
+
+    #[test]
+    fn test_token_account_mints_of_loaded_addresses() {
+        // Arrange
+        // Loaded addresses follow the static keys, writable ones first.
+        let (fee_payer, writable, readonly, mint_a, mint_b) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut transaction_metadata = transaction_metadata(
+            Vec::new(),
+            vec![token_balance(1, mint_a), token_balance(2, mint_b)],
+            Vec::new(),
+        );
+        transaction_metadata.message = VersionedMessage::V0(v0::Message {
+            account_keys: vec![fee_payer],
+            ..Default::default()
+        });
+        transaction_metadata.meta.loaded_addresses = LoadedAddresses {
+            writable: vec![writable],
+            readonly: vec![readonly],
+        };
+
+        // Act
+        let token_mints = token_account_mints(&transaction_metadata);
+
+        // Assert
+        assert_eq!(
+            token_mints,
+            HashMap::from([(writable, mint_a), (readonly, mint_b)])
+        );
+    }
+}
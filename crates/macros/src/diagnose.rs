@@ -0,0 +1,70 @@
+//! # Decode Failure Diagnosis Module
+//!
+//! The `diagnose` module provides the `diagnose_decode_failure!` macro, which
+//! explains why data owned by a program could not be decoded into any of the
+//! program's account or instruction types.
+//!
+//! ## Usage
+//!
+//! Decoders call the macro from `diagnose_account` or `diagnose_instruction`
+//! with the same types they try when decoding. Each type reports either an
+//! unknown discriminator or a deserialize error, and the most specific report
+//! is returned.
+
+/// Explains why data could not be deserialized into any of the given types.
+///
+/// The `diagnose_decode_failure!` macro calls `try_deserialize` for each type
+/// and returns the most specific `DecodeFailure`. A type whose discriminator
+/// matched but whose layout did not is preferred over unknown discriminators,
+/// which points at the type that changed.
+///
+/// # Syntax
+///
+/// ```ignore
+/// diagnose_decode_failure!(data, TypeA, TypeB, ...);
+/// ```
+///
+/// # Example
+///
+/// ```ignore
+/// fn diagnose_instruction(&self, instruction: &Instruction) -> DecodeFailure {
+///     carbon_core::diagnose_decode_failure!(instruction.data.as_slice(),
+///         buy::Buy,
+///         sell::Sell,
+///     )
+/// }
+/// ```
+///
+/// # Parameters
+///
+/// - `$data`: The raw data, as a byte slice.
+/// - `$ty`: The types the data was decoded into, each implementing
+///   `CarbonDeserialize`.
+///
+/// # Returns
+///
+/// Returns a `DecodeFailure`. If every type deserializes the data, which only
+/// happens when the macro is called for data that did decode, an unknown
+/// discriminator holding the leading bytes is returned.
+#[macro_export]
+macro_rules! diagnose_decode_failure {
+    ($data:expr, $($ty:ty),+ $(,)?) => {{
+        use carbon_core::deserialize::CarbonDeserialize;
+        let data: &[u8] = $data;
+        let mut failure: Option<carbon_core::decode::DecodeFailure> = None;
+        $(
+            if let Err(candidate) = <$ty>::try_deserialize(data) {
+                failure = Some(match failure {
+                    Some(failure) => failure.most_specific(candidate),
+                    None => candidate,
+                });
+            }
+        )+
+        failure.unwrap_or_else(|| {
+            carbon_core::decode::DecodeFailure::unknown_discriminator(
+                data,
+                carbon_core::decode::DEFAULT_DISCRIMINATOR_LEN,
+            )
+        })
+    }};
+}
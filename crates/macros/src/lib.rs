@@ -1,8 +1,11 @@
 //! # Carbon Macros
 //!
 //! This crate provides powerful macros for building and processing transaction
-//! schemas and decoding instructions dynamically. It includes two main modules:
+//! schemas and decoding instructions dynamically. It includes three main
+//! modules:
 //!
+//! - **`diagnose`**: Includes the `diagnose_decode_failure!` macro, explaining
+//!   why owned account or instruction data could not be decoded.
 //! - **`schema`**: Offers the `schema!` macro to construct hierarchical
 //!   transaction schemas with flexible node types, ideal for organizing and
 //!   validating complex transaction structures.
@@ -20,8 +23,10 @@
 //!
 //! ## Modules
 //!
+//! - **`diagnose`**: For reporting decode failures.
 //! - **`schema`**: For building transaction schemas.
 //! - **`try_decode_ix`**: For decoding instructions dynamically.
 
+pub mod diagnose;
 pub mod schemas;
 pub mod try_decode_ixs;
@@ -121,6 +121,11 @@ use {
 /// - The macro will return `None` during deserialization if the data is shorter
 ///   than the discriminator or if there is a mismatch between the provided and
 ///   expected discriminators.
+/// - `try_deserialize` reports these cases as an unknown discriminator, and
+///   data that does not match the layout as a deserialize error with the
+///   offset where reading stopped. Building that report allocates, so
+///   `deserialize` does not use it; decoders only call `try_deserialize` to
+///   diagnose data they failed to decode.
 #[proc_macro_derive(CarbonDeserialize, attributes(carbon))]
 pub fn carbon_deserialize_derive(input_token_stream: TokenStream) -> TokenStream {
     let derive_input = input_token_stream.clone();
@@ -137,17 +142,40 @@ pub fn carbon_deserialize_derive(input_token_stream: TokenStream) -> TokenStream
         impl carbon_core::deserialize::CarbonDeserialize for #name {
             fn deserialize(data: &[u8]) -> Option<Self> {
                 let discriminator: &[u8] = #discriminator;
-                if data.len() < discriminator.len() {
+                if !data.starts_with(discriminator) {
                     return None;
                 }
 
+                carbon_core::borsh::BorshDeserialize::try_from_slice(&data[discriminator.len()..]).ok()
+            }
 
-                let (disc, rest) = data.split_at(discriminator.len());
-                if disc != discriminator {
-                    return None;
+            fn try_deserialize(data: &[u8]) -> ::core::result::Result<Self, carbon_core::decode::DecodeFailure> {
+                let discriminator: &[u8] = #discriminator;
+                if !data.starts_with(discriminator) {
+                    return Err(carbon_core::decode::DecodeFailure::unknown_discriminator(
+                        data,
+                        discriminator.len(),
+                    ));
                 }
 
-                 carbon_core::borsh::BorshDeserialize::try_from_slice(rest).ok()
+                let mut rest = &data[discriminator.len()..];
+                let result: std::io::Result<Self> =
+                    carbon_core::borsh::BorshDeserialize::deserialize(&mut rest);
+                let offset = data.len() - rest.len();
+
+                match result {
+                    Ok(value) if rest.is_empty() => Ok(value),
+                    Ok(_) => Err(carbon_core::decode::DecodeFailure::Deserialize {
+                        type_name: stringify!(#name),
+                        offset,
+                        message: format!("{} unexpected trailing bytes", rest.len()),
+                    }),
+                    Err(err) => Err(carbon_core::decode::DecodeFailure::Deserialize {
+                        type_name: stringify!(#name),
+                        offset,
+                        message: err.to_string(),
+                    }),
+                }
             }
         }
     };
@@ -102,6 +102,7 @@ impl Datasource for HeliusWebsocket {
         }
 
         let mut reconnection_attempts = 0;
+        let datasource_name = self.name();
 
         loop {
             if cancellation_token.is_cancelled() {
@@ -229,7 +230,8 @@ impl Datasource for HeliusWebsocket {
 
                                                 metrics_clone
                                                     .record_histogram(
-                                                        "helius_atlas_ws_clock_process_time_nanoseconds",
+                                                        "datasource_clock_process_time_nanoseconds",
+                                                        &[("datasource", datasource_name)],
                                                         last_clock_update.elapsed().as_nanos() as f64
                                                     )
                                                     .await
@@ -313,10 +315,7 @@ impl Datasource for HeliusWebsocket {
                                                             slot: acc_event.context.slot,
                                                         };
 
-                                                        metrics.record_histogram("helius_atlas_ws_account_deletion_process_time_nanoseconds", start_time.elapsed().as_nanos() as f64).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
-
-                                                        metrics.increment_counter("helius_atlas_ws_account_deletions_received", 1).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
-
+                                                        metrics.record_histogram("datasource_update_process_time_nanoseconds", &[("datasource", datasource_name), ("kind", "account_deletion")], start_time.elapsed().as_nanos() as f64).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
 
                                                         if let Err(err) = sender_clone.try_send(
                                                             Update::AccountDeletion(account_deletion),
@@ -324,6 +323,8 @@ impl Datasource for HeliusWebsocket {
                                                             log::error!("Error sending account update: {:?}", err);
                                                             break;
                                                         }
+
+                                                        metrics.increment_counter("datasource_updates_emitted", &[("datasource", datasource_name), ("kind", "account_deletion")], 1).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
                                                     }
                                                 } else {
                                                     let update = Update::Account(AccountUpdate {
@@ -332,15 +333,14 @@ impl Datasource for HeliusWebsocket {
                                                         slot: acc_event.context.slot,
                                                     });
 
-                                                    metrics.record_histogram("helius_atlas_ws_account_process_time_nanoseconds", start_time.elapsed().as_nanos() as f64).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
-
-                                                    metrics.increment_counter("helius_atlas_ws_account_updates_received", 1).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
-
+                                                    metrics.record_histogram("datasource_update_process_time_nanoseconds", &[("datasource", datasource_name), ("kind", "account")], start_time.elapsed().as_nanos() as f64).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
 
                                                     if let Err(err) = sender_clone.try_send(update) {
                                                         log::error!("Error sending account update: {:?}", err);
                                                         break;
                                                     }
+
+                                                    metrics.increment_counter("datasource_updates_emitted", &[("datasource", datasource_name), ("kind", "account")], 1).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
                                                 }
                                             },
                                             None => {
@@ -586,19 +586,19 @@ impl Datasource for HeliusWebsocket {
 
                                             metrics
                                                     .record_histogram(
-                                                        "helius_atlas_ws_transaction_process_time_nanoseconds",
+                                                        "datasource_update_process_time_nanoseconds",
+                                                        &[("datasource", datasource_name), ("kind", "transaction")],
                                                         start_time.elapsed().as_nanos() as f64
                                                     )
                                                     .await
                                                     .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
 
-                                            metrics.increment_counter("helius_atlas_ws_transaction_updates_received", 1).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
-
-
                                             if let Err(err) = sender_clone.try_send(update) {
                                                 log::error!("Error sending transaction update: {:?}", err);
                                                 break;
                                             }
+
+                                            metrics.increment_counter("datasource_updates_emitted", &[("datasource", datasource_name), ("kind", "transaction")], 1).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
                                         },
                                         None => {
                                             log::info!("Helius WS Accounts stream has been closed");
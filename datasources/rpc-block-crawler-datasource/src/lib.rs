@@ -87,6 +87,7 @@ impl Datasource for RpcBlockCrawler {
             self.max_concurrent_requests,
             cancellation_token.clone(),
             metrics.clone(),
+            self.name(),
         );
 
         let task_processor = task_processor(
@@ -94,6 +95,7 @@ impl Datasource for RpcBlockCrawler {
             sender,
             cancellation_token.clone(),
             metrics.clone(),
+            self.name(),
         );
 
         tokio::spawn(async move {
@@ -122,6 +124,7 @@ fn block_fetcher(
     max_concurrent_requests: usize,
     cancellation_token: CancellationToken,
     metrics: Arc<MetricsCollection>,
+    datasource_name: &'static str,
 ) -> JoinHandle<()> {
     let rpc_client_clone = rpc_client.clone();
     tokio::spawn(async move {
@@ -182,7 +185,8 @@ fn block_fetcher(
                                 let time_taken = start.elapsed().as_millis();
                                 metrics
                                     .record_histogram(
-                                        "block_crawler_blocks_fetch_times_milliseconds",
+                                        "datasource_fetch_time_milliseconds",
+                                        &[("datasource", datasource_name), ("kind", "block")],
                                         time_taken as f64,
                                     )
                                     .await
@@ -191,7 +195,11 @@ fn block_fetcher(
                                     });
 
                                 metrics
-                                    .increment_counter("block_crawler_blocks_fetched", 1)
+                                    .increment_counter(
+                                        "datasource_blocks_fetched",
+                                        &[("datasource", datasource_name)],
+                                        1,
+                                    )
                                     .await
                                     .unwrap_or_else(|value| {
                                         log::error!("Error recording metric: {}", value)
@@ -210,7 +218,11 @@ fn block_fetcher(
                                     || e.to_string().contains("-32007")
                                 {
                                     metrics
-                                        .increment_counter("block_crawler_blocks_skipped", 1)
+                                        .increment_counter(
+                                            "datasource_blocks_skipped",
+                                            &[("datasource", datasource_name)],
+                                            1,
+                                        )
                                         .await
                                         .unwrap_or_else(|value| {
                                             log::error!("Error recording metric: {}", value)
@@ -249,6 +261,7 @@ fn task_processor(
     sender: Sender<Update>,
     cancellation_token: CancellationToken,
     metrics: Arc<MetricsCollection>,
+    datasource_name: &'static str,
 ) -> JoinHandle<()> {
     let mut block_receiver = block_receiver;
     let sender = sender.clone();
@@ -262,7 +275,7 @@ fn task_processor(
                 }
                 Some((slot, block)) = block_receiver.recv() => {
                     metrics
-                        .increment_counter("block_crawler_blocks_received", 1)
+                        .increment_counter("datasource_blocks_received", &[("datasource", datasource_name)], 1)
                         .await
                         .unwrap_or_else(|value| {
                             log::error!("Error recording metric: {}", value)
@@ -303,31 +316,33 @@ fn task_processor(
 
                             metrics
                                 .record_histogram(
-                                    "block_crawler_transaction_process_time_nanoseconds",
+                                    "datasource_update_process_time_nanoseconds",
+                                    &[("datasource", datasource_name), ("kind", "transaction")],
                                     start_time.elapsed().as_nanos() as f64
                                 )
                                 .await
                                 .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
 
-                            metrics.increment_counter("block_crawler_transactions_processed", 1)
-                                .await
-                                .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
-
                             if let Err(err) = sender.try_send(update) {
                                 log::error!("Error sending transaction update: {:?}", err);
                                 break;
                             }
+
+                            metrics.increment_counter("datasource_updates_emitted", &[("datasource", datasource_name), ("kind", "transaction")], 1)
+                                .await
+                                .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
                         }
                     }
                     metrics
                         .record_histogram(
-                            "block_crawler_block_process_time_nanoseconds",
+                            "datasource_update_process_time_nanoseconds",
+                            &[("datasource", datasource_name), ("kind", "block")],
                             block_start_time.elapsed().as_nanos() as f64
                         ).await
                         .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
 
                     metrics
-                        .increment_counter("block_crawler_blocks_processed", 1)
+                        .increment_counter("datasource_blocks_processed", &[("datasource", datasource_name)], 1)
                         .await
                         .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
                 }
@@ -366,6 +381,7 @@ mod tests {
             1,
             cancellation_token.clone(),
             Arc::new(MetricsCollection::new(vec![])),
+            "RpcBlockCrawler",
         );
 
         // Create a task to receive blocks
@@ -447,6 +463,7 @@ mod tests {
             2,
             cancellation_token.clone(),
             Arc::new(MetricsCollection::new(vec![])),
+            "RpcBlockCrawler",
         );
 
         // Create a task to receive blocks
@@ -61,6 +61,7 @@ impl Datasource for RpcBlockSubscribe {
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()> {
         let mut reconnection_attempts = 0;
+        let datasource_name = self.name();
 
         loop {
             if cancellation_token.is_cancelled() {
@@ -156,32 +157,34 @@ impl Datasource for RpcBlockSubscribe {
 
                                             metrics
                                                 .record_histogram(
-                                                    "block_subscribe_transaction_process_time_nanoseconds",
+                                                    "datasource_update_process_time_nanoseconds",
+                                                    &[("datasource", datasource_name), ("kind", "transaction")],
                                                     start_time.elapsed().as_nanos() as f64
                                                 )
                                                 .await
                                                 .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
 
-                                            metrics.increment_counter("block_subscribe_transactions_processed", 1)
-                                                .await
-                                                .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
-
                                             if let Err(err) = sender_clone.try_send(update) {
                                                 log::error!("Error sending transaction update: {:?}", err);
                                                 break;
                                             }
+
+                                            metrics.increment_counter("datasource_updates_emitted", &[("datasource", datasource_name), ("kind", "transaction")], 1)
+                                                .await
+                                                .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
                                         }
                                     }
 
                                     metrics
                                         .record_histogram(
-                                            "block_subscribe_block_process_time_nanoseconds",
+                                            "datasource_update_process_time_nanoseconds",
+                                            &[("datasource", datasource_name), ("kind", "block")],
                                             block_start_time.elapsed().as_nanos() as f64
                                         )
                                         .await
                                         .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
 
-                                    metrics.increment_counter("block_subscribe_blocks_received", 1)
+                                    metrics.increment_counter("datasource_blocks_processed", &[("datasource", datasource_name)], 1)
                                         .await
                                         .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
                                 }
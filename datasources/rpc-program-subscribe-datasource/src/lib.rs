@@ -56,6 +56,7 @@ impl Datasource for RpcProgramSubscribe {
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()> {
         let mut reconnection_attempts = 0;
+        let datasource_name = self.name();
 
         loop {
             if cancellation_token.is_cancelled() {
@@ -134,20 +135,21 @@ impl Datasource for RpcProgramSubscribe {
 
                                 metrics
                                     .record_histogram(
-                                        "program_subscribe_account_process_time_nanoseconds",
+                                        "datasource_update_process_time_nanoseconds",
+                                        &[("datasource", datasource_name), ("kind", "account")],
                                         start_time.elapsed().as_nanos() as f64
                                     )
                                     .await
                                     .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
 
-                                metrics.increment_counter("program_subscribe_accounts_processed", 1)
-                                    .await
-                                    .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
-
                                 if let Err(err) = sender_clone.try_send(update) {
                                     log::error!("Error sending account update: {:?}", err);
                                     break;
                                 }
+
+                                metrics.increment_counter("datasource_updates_emitted", &[("datasource", datasource_name), ("kind", "account")], 1)
+                                    .await
+                                    .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
                             }
                             None => {
                                 log::warn!("Program accounts stream has been closed, attempting to reconnect...");
@@ -96,6 +96,7 @@ impl Datasource for RpcTransactionCrawler {
         let sender = sender.clone();
         let commitment = self.commitment;
         let max_concurrent_requests = self.max_concurrent_requests;
+        let datasource_name = self.name();
 
         let (signature_sender, signature_receiver) = mpsc::channel(1000);
         let (transaction_sender, transaction_receiver) = mpsc::channel(1000);
@@ -110,6 +111,7 @@ impl Datasource for RpcTransactionCrawler {
             commitment,
             cancellation_token.clone(),
             metrics.clone(),
+            datasource_name,
         );
 
         let transaction_fetcher = transaction_fetcher(
@@ -120,6 +122,7 @@ impl Datasource for RpcTransactionCrawler {
             max_concurrent_requests,
             cancellation_token.clone(),
             metrics.clone(),
+            datasource_name,
         );
 
         let task_processor = task_processor(
@@ -128,6 +131,7 @@ impl Datasource for RpcTransactionCrawler {
             filters,
             cancellation_token.clone(),
             metrics.clone(),
+            datasource_name,
         );
 
         tokio::spawn(async move {
@@ -157,6 +161,7 @@ fn signature_fetcher(
     commitment: Option<CommitmentConfig>,
     cancellation_token: CancellationToken,
     metrics: Arc<MetricsCollection>,
+    datasource_name: &'static str,
 ) -> JoinHandle<()> {
     let rpc_client = Arc::clone(&rpc_client);
     let filters = filters.clone();
@@ -210,10 +215,10 @@ fn signature_fetcher(
 
                             let time_taken = start.elapsed().as_millis();
 
-                            metrics.record_histogram("transaction_crawler_signatures_fetch_times_milliseconds",   time_taken as f64)
+                            metrics.record_histogram("datasource_fetch_time_milliseconds", &[("datasource", datasource_name), ("kind", "signatures")], time_taken as f64)
                                 .await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
 
-                            metrics.increment_counter("transaction_crawler_signatures_fetched", signatures.len() as u64).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
+                            metrics.increment_counter("datasource_signatures_fetched", &[("datasource", datasource_name)], signatures.len() as u64).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
 
                         }
                         Err(e) => {
@@ -227,6 +232,7 @@ fn signature_fetcher(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn transaction_fetcher(
     rpc_client: Arc<RpcClient>,
     signature_receiver: Receiver<Signature>,
@@ -235,6 +241,7 @@ fn transaction_fetcher(
     max_concurrent_requests: usize,
     cancellation_token: CancellationToken,
     metrics: Arc<MetricsCollection>,
+    datasource_name: &'static str,
 ) -> JoinHandle<()> {
     let rpc_client = Arc::clone(&rpc_client);
     let transaction_sender = transaction_sender.clone();
@@ -273,7 +280,8 @@ fn transaction_fetcher(
 
                                 metrics
                                     .record_histogram(
-                                        "transaction_crawler_transaction_fetch_times_milliseconds",
+                                        "datasource_fetch_time_milliseconds",
+                                        &[("datasource", datasource_name), ("kind", "transaction")],
                                         time_taken as f64,
                                     )
                                     .await
@@ -290,12 +298,18 @@ fn transaction_fetcher(
                 })
                 .buffer_unordered(max_concurrent_requests)
                 .for_each(|result| async {
-                    metrics
-                        .increment_counter("transaction_crawler_transactions_fetched", 1)
-                        .await
-                        .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
-
                     if let Some((signature, fetched_transaction)) = result {
+                        metrics
+                            .increment_counter(
+                                "datasource_transactions_fetched",
+                                &[("datasource", datasource_name)],
+                                1,
+                            )
+                            .await
+                            .unwrap_or_else(|value| {
+                                log::error!("Error recording metric: {}", value)
+                            });
+
                         if let Err(e) = transaction_sender
                             .send((signature, fetched_transaction))
                             .await
@@ -322,6 +336,7 @@ fn task_processor(
     filters: Filters,
     cancellation_token: CancellationToken,
     metrics: Arc<MetricsCollection>,
+    datasource_name: &'static str,
 ) -> JoinHandle<()> {
     let mut transaction_receiver = transaction_receiver;
     let sender = sender.clone();
@@ -409,8 +424,9 @@ fn task_processor(
 
                     metrics
                             .record_histogram(
-                                "transaction_crawler_transaction_process_time_milliseconds",
-                                start.elapsed().as_millis() as f64
+                                "datasource_update_process_time_nanoseconds",
+                                &[("datasource", datasource_name), ("kind", "transaction")],
+                                start.elapsed().as_nanos() as f64
                             )
                             .await
                             .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
@@ -420,6 +436,15 @@ fn task_processor(
                         log::error!("Failed to send update: {:?}", e);
                         continue;
                     }
+
+                    metrics
+                        .increment_counter(
+                            "datasource_updates_emitted",
+                            &[("datasource", datasource_name), ("kind", "transaction")],
+                            1,
+                        )
+                        .await
+                        .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
                 }
             }
         }
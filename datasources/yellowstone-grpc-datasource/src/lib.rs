@@ -74,6 +74,7 @@ impl Datasource for YellowstoneGrpcGeyserClient {
         let account_filters = self.account_filters.clone();
         let transaction_filters = self.transaction_filters.clone();
         let account_deletions_tracked = self.account_deletions_tracked.clone();
+        let datasource_name = self.name();
 
         let mut geyser_client = GeyserGrpcClient::build_from_shared(endpoint)
             .map_err(|err| carbon_core::error::Error::FailedToConsumeDatasource(err.to_string()))?
@@ -117,9 +118,6 @@ impl Datasource for YellowstoneGrpcGeyserClient {
                                             Some(UpdateOneof::Account(account_update)) => {
                                                 let start_time = std::time::Instant::now();
 
-                                                metrics.increment_counter("yellowstone_grpc_account_updates_received", 1).await.unwrap();
-
-
                                                 if let Some(account_info) = account_update.account {
                                                     let Ok(account_pubkey) =
                                                         Pubkey::try_from(account_info.pubkey)
@@ -157,6 +155,8 @@ impl Datasource for YellowstoneGrpcGeyserClient {
                                                                 Update::AccountDeletion(account_deletion),
                                                             ) {
                                                                 log::error!("Failed to send account deletion update for pubkey {:?} at slot {}: {:?}", account_pubkey, account_update.slot, e);
+                                                            } else {
+                                                                metrics.increment_counter("datasource_updates_emitted", &[("datasource", datasource_name), ("kind", "account_deletion")], 1).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
                                                             }
                                                         }
                                                     } else {
@@ -168,18 +168,19 @@ impl Datasource for YellowstoneGrpcGeyserClient {
 
                                                         if let Err(e) = sender.try_send(update) {
                                                             log::error!("Failed to send account update for pubkey {:?} at slot {}: {:?}", account_pubkey, account_update.slot, e);
+                                                        } else {
+                                                            metrics.increment_counter("datasource_updates_emitted", &[("datasource", datasource_name), ("kind", "account")], 1).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
                                                         }
                                                     }
 
                                                     metrics
                                                             .record_histogram(
-                                                                "yellowstone_grpc_account_process_time_nanoseconds",
+                                                                "datasource_update_process_time_nanoseconds",
+                                                                &[("datasource", datasource_name), ("kind", "account")],
                                                                 start_time.elapsed().as_nanos() as f64
                                                             )
                                                             .await
-                                                            .unwrap();
-
-                                                    metrics.increment_counter("yellowstone_grpc_account_updates_received", 1).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
+                                                            .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
 
                                                 } else {
                                                     log::error!("No account info in UpdateOneof::Account at slot {}", account_update.slot);
@@ -235,20 +236,20 @@ impl Datasource for YellowstoneGrpcGeyserClient {
                                                         log::error!("Failed to send transaction update with signature {:?} at slot {}: {:?}", signature, transaction_update.slot, e);
                                                         continue;
                                                     }
+
+                                                    metrics.increment_counter("datasource_updates_emitted", &[("datasource", datasource_name), ("kind", "transaction")], 1).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
                                                 } else {
                                                     log::error!("No transaction info in `UpdateOneof::Transaction` at slot {}", transaction_update.slot);
                                                 }
 
                                                 metrics
                                                         .record_histogram(
-                                                            "yellowstone_grpc_transaction_process_time_nanoseconds",
+                                                            "datasource_update_process_time_nanoseconds",
+                                                            &[("datasource", datasource_name), ("kind", "transaction")],
                                                             start_time.elapsed().as_nanos() as f64
                                                         )
                                                         .await
-                                                        .unwrap();
-
-                                                metrics.increment_counter("yellowstone_grpc_transaction_updates_received", 1).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
-
+                                                        .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
                                             }
 
                                             Some(UpdateOneof::Ping(_)) => {
@@ -0,0 +1,179 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, decode::DecodeFailure};
+
+/// The size of a lookup table's header, which precedes its addresses.
+pub const LOOKUP_TABLE_META_SIZE: usize = 56;
+
+/// A lookup table account.
+///
+/// Lookup tables are serialized with bincode rather than borsh: the account
+/// starts with a little-endian `u32` type, `1` for an initialized table, and
+/// the header is zero-padded to 56 bytes. The addresses fill the rest of the
+/// account.
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+pub struct AddressLookupTable {
+    pub meta: LookupTableMeta,
+    pub addresses: Vec<solana_sdk::pubkey::Pubkey>,
+}
+
+impl AddressLookupTable {
+    /// Returns the addresses at the given indexes, or `None` if an index is
+    /// out of the table's bounds.
+    pub fn lookup(&self, indexes: &[u8]) -> Option<Vec<solana_sdk::pubkey::Pubkey>> {
+        indexes
+            .iter()
+            .map(|index| self.addresses.get(*index as usize).copied())
+            .collect()
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.meta.authority.is_none()
+    }
+
+    pub fn is_deactivated(&self) -> bool {
+        self.meta.deactivation_slot != u64::MAX
+    }
+}
+
+impl borsh::BorshDeserialize for AddressLookupTable {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let account_type = u32::deserialize_reader(reader)?;
+        if account_type != 1 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Unknown lookup table account type {account_type}"),
+            ));
+        }
+
+        let meta = LookupTableMeta::deserialize_reader(reader)?;
+        if meta.authority.is_none() {
+            let mut padding = [0u8; 32];
+            reader.read_exact(&mut padding)?;
+        }
+
+        let mut addresses = Vec::new();
+        reader.read_to_end(&mut addresses)?;
+        if addresses.len() % 32 != 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "Addresses length {} is not a multiple of 32",
+                    addresses.len()
+                ),
+            ));
+        }
+
+        Ok(AddressLookupTable {
+            meta,
+            addresses: addresses
+                .chunks_exact(32)
+                .map(|address| {
+                    let mut bytes = [0u8; 32];
+                    bytes.copy_from_slice(address);
+                    solana_sdk::pubkey::Pubkey::new_from_array(bytes)
+                })
+                .collect(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::CarbonDeserialize for AddressLookupTable {
+    fn deserialize(data: &[u8]) -> Option<Self> {
+        Self::try_deserialize(data).ok()
+    }
+
+    fn try_deserialize(data: &[u8]) -> Result<Self, DecodeFailure> {
+        if data.get(..4) != Some(&[1u8, 0, 0, 0][..]) {
+            return Err(DecodeFailure::unknown_discriminator(data, 4));
+        }
+
+        let mut rest = data;
+        borsh::BorshDeserialize::deserialize(&mut rest).map_err(|err: std::io::Error| {
+            DecodeFailure::Deserialize {
+                type_name: "AddressLookupTable",
+                offset: data.len() - rest.len(),
+                message: err.to_string(),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, carbon_core::deserialize::CarbonDeserialize, solana_sdk::pubkey::Pubkey};
+
+    fn lookup_table_data(authority: Option<Pubkey>, addresses: &[Pubkey]) -> Vec<u8> {
+        let mut data = Vec::with_capacity(LOOKUP_TABLE_META_SIZE + addresses.len() * 32);
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&u64::MAX.to_le_bytes());
+        data.extend_from_slice(&312_000_000u64.to_le_bytes());
+        data.push(0);
+        match authority {
+            Some(authority) => {
+                data.push(1);
+                data.extend_from_slice(authority.as_ref());
+            }
+            None => data.push(0),
+        }
+        data.resize(LOOKUP_TABLE_META_SIZE, 0);
+        for address in addresses {
+            data.extend_from_slice(address.as_ref());
+        }
+        data
+    }
+
+    #[test]
+    fn test_decode_lookup_table() {
+        let addresses = [
+            Pubkey::new_from_array([3; 32]),
+            Pubkey::new_from_array([4; 32]),
+            Pubkey::new_from_array([5; 32]),
+        ];
+        let data = lookup_table_data(Some(Pubkey::new_from_array([2; 32])), &addresses);
+
+        let table = AddressLookupTable::deserialize(&data).expect("decode lookup table");
+
+        assert_eq!(table.meta.last_extended_slot, 312_000_000);
+        assert_eq!(table.meta.authority, Some(Pubkey::new_from_array([2; 32])));
+        assert_eq!(table.addresses, addresses);
+        assert!(!table.is_frozen());
+        assert!(!table.is_deactivated());
+        assert_eq!(
+            table.lookup(&[2, 0]),
+            Some(vec![addresses[2], addresses[0]])
+        );
+        assert_eq!(table.lookup(&[3]), None);
+    }
+
+    #[test]
+    fn test_decode_frozen_lookup_table() {
+        let addresses = [Pubkey::new_from_array([3; 32])];
+        let data = lookup_table_data(None, &addresses);
+
+        let table = AddressLookupTable::deserialize(&data).expect("decode lookup table");
+
+        assert!(table.is_frozen());
+        assert_eq!(table.addresses, addresses);
+    }
+
+    #[test]
+    fn test_decode_failures() {
+        let mut data = lookup_table_data(None, &[]);
+        data[0] = 0;
+        assert_eq!(
+            AddressLookupTable::try_deserialize(&data),
+            Err(DecodeFailure::unknown_discriminator(&data, 4))
+        );
+
+        let mut data = lookup_table_data(None, &[Pubkey::new_from_array([3; 32])]);
+        data.pop();
+        assert!(matches!(
+            AddressLookupTable::try_deserialize(&data),
+            Err(DecodeFailure::Deserialize {
+                type_name: "AddressLookupTable",
+                ..
+            })
+        ));
+    }
+}
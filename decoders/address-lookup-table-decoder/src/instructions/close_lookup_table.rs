@@ -0,0 +1,32 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x04000000")]
+pub struct CloseLookupTable {}
+
+#[derive(Debug, PartialEq)]
+pub struct CloseLookupTableInstructionAccounts {
+    pub lookup_table: solana_sdk::pubkey::Pubkey,
+    pub authority: solana_sdk::pubkey::Pubkey,
+    pub recipient: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for CloseLookupTable {
+    type ArrangedAccounts = CloseLookupTableInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [lookup_table, authority, recipient, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CloseLookupTableInstructionAccounts {
+            lookup_table: lookup_table.pubkey,
+            authority: authority.pubkey,
+            recipient: recipient.pubkey,
+        })
+    }
+}
@@ -0,0 +1,37 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x00000000")]
+pub struct CreateLookupTable {
+    pub recent_slot: u64,
+    pub bump_seed: u8,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CreateLookupTableInstructionAccounts {
+    pub lookup_table: solana_sdk::pubkey::Pubkey,
+    pub authority: solana_sdk::pubkey::Pubkey,
+    pub payer: solana_sdk::pubkey::Pubkey,
+    pub system_program: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for CreateLookupTable {
+    type ArrangedAccounts = CreateLookupTableInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [lookup_table, authority, payer, system_program, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CreateLookupTableInstructionAccounts {
+            lookup_table: lookup_table.pubkey,
+            authority: authority.pubkey,
+            payer: payer.pubkey,
+            system_program: system_program.pubkey,
+        })
+    }
+}
@@ -0,0 +1,30 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x03000000")]
+pub struct DeactivateLookupTable {}
+
+#[derive(Debug, PartialEq)]
+pub struct DeactivateLookupTableInstructionAccounts {
+    pub lookup_table: solana_sdk::pubkey::Pubkey,
+    pub authority: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for DeactivateLookupTable {
+    type ArrangedAccounts = DeactivateLookupTableInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [lookup_table, authority, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(DeactivateLookupTableInstructionAccounts {
+            lookup_table: lookup_table.pubkey,
+            authority: authority.pubkey,
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use carbon_core::{borsh, deserialize::U64PrefixVec, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x02000000")]
+pub struct ExtendLookupTable {
+    pub new_addresses: U64PrefixVec<solana_sdk::pubkey::Pubkey>,
+}
+
+/// The payer and system program are only passed when the table's account
+/// needs more lamports to stay rent exempt.
+#[derive(Debug, PartialEq)]
+pub struct ExtendLookupTableInstructionAccounts {
+    pub lookup_table: solana_sdk::pubkey::Pubkey,
+    pub authority: solana_sdk::pubkey::Pubkey,
+    pub payer: Option<solana_sdk::pubkey::Pubkey>,
+    pub system_program: Option<solana_sdk::pubkey::Pubkey>,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for ExtendLookupTable {
+    type ArrangedAccounts = ExtendLookupTableInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [lookup_table, authority, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ExtendLookupTableInstructionAccounts {
+            lookup_table: lookup_table.pubkey,
+            authority: authority.pubkey,
+            payer: accounts.get(2).map(|account| account.pubkey),
+            system_program: accounts.get(3).map(|account| account.pubkey),
+        })
+    }
+}
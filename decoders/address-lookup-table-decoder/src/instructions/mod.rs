@@ -0,0 +1,180 @@
+use crate::PROGRAM_ID;
+
+use super::AddressLookupTableDecoder;
+pub mod close_lookup_table;
+pub mod create_lookup_table;
+pub mod deactivate_lookup_table;
+pub mod extend_lookup_table;
+pub mod freeze_lookup_table;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum AddressLookupTableInstruction {
+    CreateLookupTable(create_lookup_table::CreateLookupTable),
+    FreezeLookupTable(freeze_lookup_table::FreezeLookupTable),
+    ExtendLookupTable(extend_lookup_table::ExtendLookupTable),
+    DeactivateLookupTable(deactivate_lookup_table::DeactivateLookupTable),
+    CloseLookupTable(close_lookup_table::CloseLookupTable),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for AddressLookupTableDecoder {
+    type InstructionType = AddressLookupTableInstruction;
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            AddressLookupTableInstruction::CreateLookupTable => create_lookup_table::CreateLookupTable,
+            AddressLookupTableInstruction::FreezeLookupTable => freeze_lookup_table::FreezeLookupTable,
+            AddressLookupTableInstruction::ExtendLookupTable => extend_lookup_table::ExtendLookupTable,
+            AddressLookupTableInstruction::DeactivateLookupTable => deactivate_lookup_table::DeactivateLookupTable,
+            AddressLookupTableInstruction::CloseLookupTable => close_lookup_table::CloseLookupTable,
+        )
+    }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            create_lookup_table::CreateLookupTable,
+            freeze_lookup_table::FreezeLookupTable,
+            extend_lookup_table::ExtendLookupTable,
+            deactivate_lookup_table::DeactivateLookupTable,
+            close_lookup_table::CloseLookupTable,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use carbon_core::{
+        deserialize::{ArrangeAccounts, U64PrefixVec},
+        instruction::InstructionDecoder,
+    };
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_decode_create_lookup_table() {
+        // Arrange
+        let expected_ix = AddressLookupTableInstruction::CreateLookupTable(
+            create_lookup_table::CreateLookupTable {
+                recent_slot: 312_000_000,
+                bump_seed: 254,
+            },
+        );
+        let accounts = vec![
+            AccountMeta::new(Pubkey::new_from_array([1; 32]), false),
+            AccountMeta::new_readonly(Pubkey::new_from_array([2; 32]), true),
+            AccountMeta::new(Pubkey::new_from_array([2; 32]), true),
+            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+        ];
+        let mut data = vec![0, 0, 0, 0];
+        data.extend_from_slice(&312_000_000u64.to_le_bytes());
+        data.push(254);
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts.clone(),
+            data,
+        };
+
+        // Act
+        let decoded = AddressLookupTableDecoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let arranged_accounts =
+            create_lookup_table::CreateLookupTable::arrange_accounts(&instruction.accounts)
+                .expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.accounts, accounts);
+        assert_eq!(
+            arranged_accounts.lookup_table,
+            Pubkey::new_from_array([1; 32])
+        );
+        assert_eq!(arranged_accounts.payer, Pubkey::new_from_array([2; 32]));
+    }
+
+    #[test]
+    fn test_decode_extend_lookup_table() {
+        // Arrange
+        let new_addresses = vec![
+            Pubkey::new_from_array([3; 32]),
+            Pubkey::new_from_array([4; 32]),
+        ];
+        let expected_ix = AddressLookupTableInstruction::ExtendLookupTable(
+            extend_lookup_table::ExtendLookupTable {
+                new_addresses: U64PrefixVec(new_addresses.clone()),
+            },
+        );
+        let mut data = vec![2, 0, 0, 0];
+        data.extend_from_slice(&2u64.to_le_bytes());
+        for address in &new_addresses {
+            data.extend_from_slice(address.as_ref());
+        }
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(Pubkey::new_from_array([1; 32]), false),
+                AccountMeta::new_readonly(Pubkey::new_from_array([2; 32]), true),
+            ],
+            data,
+        };
+
+        // Act
+        let decoded = AddressLookupTableDecoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let arranged_accounts =
+            extend_lookup_table::ExtendLookupTable::arrange_accounts(&instruction.accounts)
+                .expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(arranged_accounts.payer, None);
+    }
+
+    #[test]
+    fn test_decode_close_lookup_table() {
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![],
+            data: vec![4, 0, 0, 0],
+        };
+
+        let decoded = AddressLookupTableDecoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+
+        assert_eq!(
+            decoded.data,
+            AddressLookupTableInstruction::CloseLookupTable(
+                close_lookup_table::CloseLookupTable {}
+            )
+        );
+    }
+}
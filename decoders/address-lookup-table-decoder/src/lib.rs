@@ -0,0 +1,9 @@
+use solana_sdk::{pubkey, pubkey::Pubkey};
+
+pub struct AddressLookupTableDecoder;
+
+pub mod accounts;
+pub mod instructions;
+pub mod types;
+
+pub const PROGRAM_ID: Pubkey = pubkey!("AddressLookupTab1e1111111111111111111111111");
@@ -0,0 +1,16 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+/// The header of a lookup table account.
+///
+/// A `deactivation_slot` of `u64::MAX` means the table is active, and a table
+/// without `authority` is frozen.
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct LookupTableMeta {
+    pub deactivation_slot: u64,
+    pub last_extended_slot: u64,
+    pub last_extended_slot_start_index: u8,
+    pub authority: Option<solana_sdk::pubkey::Pubkey>,
+    pub padding: u16,
+}
@@ -0,0 +1,2 @@
+pub mod lookup_table_meta;
+pub use lookup_table_meta::*;
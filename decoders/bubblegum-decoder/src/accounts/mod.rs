@@ -0,0 +1,80 @@
+use carbon_core::account::AccountDecoder;
+use carbon_core::deserialize::CarbonDeserialize;
+
+use super::BubblegumDecoder;
+use crate::PROGRAM_ID;
+pub mod tree_config;
+pub mod voucher;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub enum BubblegumAccount {
+    TreeConfig(tree_config::TreeConfig),
+    Voucher(voucher::Voucher),
+}
+
+impl AccountDecoder<'_> for BubblegumDecoder {
+    type AccountType = BubblegumAccount;
+    fn decode_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) = tree_config::TreeConfig::deserialize(account.data.as_slice())
+        {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: BubblegumAccount::TreeConfig(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        if let Some(decoded_account) = voucher::Voucher::deserialize(account.data.as_slice()) {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: BubblegumAccount::Voucher(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        // Tree configs are allocated with padding after their fields, which
+        // newer program versions use for new fields.
+        if let Some(decoded_account) =
+            carbon_core::deserialize::deserialize_with_trailing_bytes::<tree_config::TreeConfig>(
+                &[0x7a, 0xf5, 0xaf, 0xf8, 0xab, 0x22, 0x00, 0xcf],
+                account.data.as_slice(),
+            )
+        {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: BubblegumAccount::TreeConfig(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        None
+    }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            tree_config::TreeConfig,
+            voucher::Voucher,
+        )
+    }
+}
@@ -0,0 +1,48 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x746e1d386bdb2a5d")]
+pub struct Burn {
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BurnInstructionAccounts {
+    pub tree_authority: solana_sdk::pubkey::Pubkey,
+    pub leaf_owner: solana_sdk::pubkey::Pubkey,
+    pub leaf_delegate: solana_sdk::pubkey::Pubkey,
+    pub merkle_tree: solana_sdk::pubkey::Pubkey,
+    pub log_wrapper: solana_sdk::pubkey::Pubkey,
+    pub compression_program: solana_sdk::pubkey::Pubkey,
+    pub system_program: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for Burn {
+    type ArrangedAccounts = BurnInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [tree_authority, leaf_owner, leaf_delegate, merkle_tree, log_wrapper, compression_program, system_program, _remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(BurnInstructionAccounts {
+            tree_authority: tree_authority.pubkey,
+            leaf_owner: leaf_owner.pubkey,
+            leaf_delegate: leaf_delegate.pubkey,
+            merkle_tree: merkle_tree.pubkey,
+            log_wrapper: log_wrapper.pubkey,
+            compression_program: compression_program.pubkey,
+            system_program: system_program.pubkey,
+        })
+    }
+}
@@ -0,0 +1,50 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x5a934bb255580489")]
+pub struct Delegate {
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DelegateInstructionAccounts {
+    pub tree_authority: solana_sdk::pubkey::Pubkey,
+    pub leaf_owner: solana_sdk::pubkey::Pubkey,
+    pub previous_leaf_delegate: solana_sdk::pubkey::Pubkey,
+    pub new_leaf_delegate: solana_sdk::pubkey::Pubkey,
+    pub merkle_tree: solana_sdk::pubkey::Pubkey,
+    pub log_wrapper: solana_sdk::pubkey::Pubkey,
+    pub compression_program: solana_sdk::pubkey::Pubkey,
+    pub system_program: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for Delegate {
+    type ArrangedAccounts = DelegateInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [tree_authority, leaf_owner, previous_leaf_delegate, new_leaf_delegate, merkle_tree, log_wrapper, compression_program, system_program, _remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DelegateInstructionAccounts {
+            tree_authority: tree_authority.pubkey,
+            leaf_owner: leaf_owner.pubkey,
+            previous_leaf_delegate: previous_leaf_delegate.pubkey,
+            new_leaf_delegate: new_leaf_delegate.pubkey,
+            merkle_tree: merkle_tree.pubkey,
+            log_wrapper: log_wrapper.pubkey,
+            compression_program: compression_program.pubkey,
+            system_program: system_program.pubkey,
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use {
+    super::super::types::*,
+    carbon_core::{borsh, CarbonDeserialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x9162c076b8937668")]
+pub struct MintV1 {
+    pub message: MetadataArgs,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MintV1InstructionAccounts {
+    pub tree_authority: solana_sdk::pubkey::Pubkey,
+    pub leaf_owner: solana_sdk::pubkey::Pubkey,
+    pub leaf_delegate: solana_sdk::pubkey::Pubkey,
+    pub merkle_tree: solana_sdk::pubkey::Pubkey,
+    pub payer: solana_sdk::pubkey::Pubkey,
+    pub tree_delegate: solana_sdk::pubkey::Pubkey,
+    pub log_wrapper: solana_sdk::pubkey::Pubkey,
+    pub compression_program: solana_sdk::pubkey::Pubkey,
+    pub system_program: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for MintV1 {
+    type ArrangedAccounts = MintV1InstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [tree_authority, leaf_owner, leaf_delegate, merkle_tree, payer, tree_delegate, log_wrapper, compression_program, system_program, _remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(MintV1InstructionAccounts {
+            tree_authority: tree_authority.pubkey,
+            leaf_owner: leaf_owner.pubkey,
+            leaf_delegate: leaf_delegate.pubkey,
+            merkle_tree: merkle_tree.pubkey,
+            payer: payer.pubkey,
+            tree_delegate: tree_delegate.pubkey,
+            log_wrapper: log_wrapper.pubkey,
+            compression_program: compression_program.pubkey,
+            system_program: system_program.pubkey,
+        })
+    }
+}
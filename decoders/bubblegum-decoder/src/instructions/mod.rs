@@ -0,0 +1,169 @@
+use crate::PROGRAM_ID;
+
+use super::BubblegumDecoder;
+pub mod burn;
+pub mod cancel_redeem;
+pub mod create_tree;
+pub mod decompress_v1;
+pub mod delegate;
+pub mod mint_to_collection_v1;
+pub mod mint_v1;
+pub mod redeem;
+pub mod set_and_verify_collection;
+pub mod set_decompressible_state;
+pub mod set_tree_delegate;
+pub mod transfer;
+pub mod unverify_collection;
+pub mod unverify_creator;
+pub mod update_metadata;
+pub mod verify_collection;
+pub mod verify_creator;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum BubblegumInstruction {
+    CreateTree(create_tree::CreateTree),
+    SetTreeDelegate(set_tree_delegate::SetTreeDelegate),
+    SetDecompressibleState(set_decompressible_state::SetDecompressibleState),
+    MintV1(mint_v1::MintV1),
+    MintToCollectionV1(mint_to_collection_v1::MintToCollectionV1),
+    VerifyCreator(verify_creator::VerifyCreator),
+    UnverifyCreator(unverify_creator::UnverifyCreator),
+    VerifyCollection(verify_collection::VerifyCollection),
+    UnverifyCollection(unverify_collection::UnverifyCollection),
+    SetAndVerifyCollection(set_and_verify_collection::SetAndVerifyCollection),
+    Transfer(transfer::Transfer),
+    Delegate(delegate::Delegate),
+    Burn(burn::Burn),
+    Redeem(redeem::Redeem),
+    CancelRedeem(cancel_redeem::CancelRedeem),
+    DecompressV1(decompress_v1::DecompressV1),
+    UpdateMetadata(update_metadata::UpdateMetadata),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for BubblegumDecoder {
+    type InstructionType = BubblegumInstruction;
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            BubblegumInstruction::CreateTree => create_tree::CreateTree,
+            BubblegumInstruction::SetTreeDelegate => set_tree_delegate::SetTreeDelegate,
+            BubblegumInstruction::SetDecompressibleState => set_decompressible_state::SetDecompressibleState,
+            BubblegumInstruction::MintV1 => mint_v1::MintV1,
+            BubblegumInstruction::MintToCollectionV1 => mint_to_collection_v1::MintToCollectionV1,
+            BubblegumInstruction::VerifyCreator => verify_creator::VerifyCreator,
+            BubblegumInstruction::UnverifyCreator => unverify_creator::UnverifyCreator,
+            BubblegumInstruction::VerifyCollection => verify_collection::VerifyCollection,
+            BubblegumInstruction::UnverifyCollection => unverify_collection::UnverifyCollection,
+            BubblegumInstruction::SetAndVerifyCollection => set_and_verify_collection::SetAndVerifyCollection,
+            BubblegumInstruction::Transfer => transfer::Transfer,
+            BubblegumInstruction::Delegate => delegate::Delegate,
+            BubblegumInstruction::Burn => burn::Burn,
+            BubblegumInstruction::Redeem => redeem::Redeem,
+            BubblegumInstruction::CancelRedeem => cancel_redeem::CancelRedeem,
+            BubblegumInstruction::DecompressV1 => decompress_v1::DecompressV1,
+            BubblegumInstruction::UpdateMetadata => update_metadata::UpdateMetadata,
+        )
+    }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            create_tree::CreateTree,
+            set_tree_delegate::SetTreeDelegate,
+            set_decompressible_state::SetDecompressibleState,
+            mint_v1::MintV1,
+            mint_to_collection_v1::MintToCollectionV1,
+            verify_creator::VerifyCreator,
+            unverify_creator::UnverifyCreator,
+            verify_collection::VerifyCollection,
+            unverify_collection::UnverifyCollection,
+            set_and_verify_collection::SetAndVerifyCollection,
+            transfer::Transfer,
+            delegate::Delegate,
+            burn::Burn,
+            redeem::Redeem,
+            cancel_redeem::CancelRedeem,
+            decompress_v1::DecompressV1,
+            update_metadata::UpdateMetadata,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionDecoder};
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_decode_transfer() {
+        // Arrange
+        let expected_ix = BubblegumInstruction::Transfer(transfer::Transfer {
+            root: [1; 32],
+            data_hash: [2; 32],
+            creator_hash: [3; 32],
+            nonce: 42,
+            index: 41,
+        });
+        let accounts: Vec<AccountMeta> = (10..18)
+            .map(|byte| AccountMeta::new_readonly(Pubkey::new_from_array([byte; 32]), false))
+            .collect();
+        let mut data = vec![0xa3, 0x34, 0xc8, 0xe7, 0x8c, 0x03, 0x45, 0xba];
+        data.extend_from_slice(&[1; 32]);
+        data.extend_from_slice(&[2; 32]);
+        data.extend_from_slice(&[3; 32]);
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(&41u32.to_le_bytes());
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts.clone(),
+            data,
+        };
+
+        // Act
+        let decoded = BubblegumDecoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let arranged_accounts =
+            transfer::Transfer::arrange_accounts(&instruction.accounts).expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.accounts, accounts);
+        assert_eq!(
+            arranged_accounts.new_leaf_owner,
+            Pubkey::new_from_array([13; 32])
+        );
+        assert_eq!(
+            arranged_accounts.merkle_tree,
+            Pubkey::new_from_array([14; 32])
+        );
+    }
+}
@@ -0,0 +1,536 @@
+//! Reconstructs the leaf a Bubblegum instruction writes to its tree.
+//!
+//! A compressed NFT only exists as a leaf hash in a concurrent Merkle tree, so
+//! its owner, delegate and creators can't be read from any account. Bubblegum
+//! instead logs each leaf it writes as a `LeafSchemaEvent` through the Noop
+//! program, and the Account Compression program logs the resulting change to
+//! the tree as a `ChangeLogEvent`. Both are inner instructions of the
+//! Bubblegum instruction, which together with its arguments and accounts
+//! describe the asset after the change.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! impl Processor for CompressedNftProcessor {
+//!     type InputType = InstructionProcessorInputType<BubblegumInstruction>;
+//!
+//!     async fn process(&mut self, (_, instruction, inner_instructions): Self::InputType, _: Arc<MetricsCollection>) -> CarbonResult<()> {
+//!         if let Some(update) = leaf_update(&instruction, &inner_instructions) {
+//!             match update.kind {
+//!                 LeafUpdateKind::Burn => self.owners.remove(&update.asset_id),
+//!                 _ => self.owners.insert(update.asset_id, update.owner),
+//!             };
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//! ```
+//!
+//! ## Notes
+//!
+//! - Only leaves of Bubblegum V1 trees, which use the SPL Account Compression
+//!   and Noop programs, are reconstructed.
+//! - Burns log no leaf, so their update has no `leaf`, and its owner and
+//!   delegate are those of the burned asset.
+//! - Creators are only known for instructions that take the asset's metadata
+//!   as an argument: mints, creator and collection verification, and
+//!   metadata updates.
+
+use {
+    crate::{
+        instructions::{
+            burn, delegate, mint_to_collection_v1, mint_v1, set_and_verify_collection, transfer,
+            unverify_collection, unverify_creator, update_metadata, verify_collection,
+            verify_creator, BubblegumInstruction,
+        },
+        types::{Creator, LeafSchema, LeafSchemaEvent},
+        PROGRAM_ID,
+    },
+    carbon_core::{
+        deserialize::{ArrangeAccounts, CarbonDeserialize},
+        instruction::{DecodedInstruction, NestedInstruction},
+    },
+    carbon_spl_account_compression_decoder::types::{AccountCompressionEvent, ChangeLogEventV1},
+    solana_sdk::pubkey::Pubkey,
+};
+
+/// What a Bubblegum instruction did to a leaf.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum LeafUpdateKind {
+    Mint,
+    Transfer,
+    Delegate,
+    Burn,
+    /// A creator or the collection was verified or unverified, or the
+    /// metadata was updated.
+    MetadataUpdate,
+}
+
+/// A compressed NFT after a Bubblegum instruction changed its leaf.
+///
+/// # Fields
+///
+/// - `kind`: What the instruction did to the leaf.
+/// - `asset_id`: The id of the asset, derived from the tree and the nonce.
+/// - `merkle_tree`: The tree holding the leaf.
+/// - `nonce`: The nonce the asset was minted with.
+/// - `leaf_index`: The index of the leaf in the tree.
+/// - `owner`: The owner of the asset.
+/// - `delegate`: The delegate of the asset, the owner when there is none.
+/// - `leaf`: The leaf Bubblegum logged, or `None` for burns.
+/// - `leaf_hash`: The hash of `leaf`.
+/// - `creators`: The asset's creators, when the instruction carries its
+///   metadata.
+/// - `change_log`: The change to the tree the Account Compression program
+///   logged, whose `seq` orders the updates of a tree.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LeafUpdate {
+    pub kind: LeafUpdateKind,
+    pub asset_id: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub nonce: u64,
+    pub leaf_index: u32,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub leaf: Option<LeafSchema>,
+    pub leaf_hash: Option<[u8; 32]>,
+    pub creators: Option<Vec<Creator>>,
+    pub change_log: Option<ChangeLogEventV1>,
+}
+
+impl LeafUpdate {
+    /// The sequence number of the tree after the change.
+    pub fn seq(&self) -> Option<u64> {
+        self.change_log.as_ref().map(|change_log| change_log.seq)
+    }
+}
+
+/// Returns the id of the asset minted with `nonce` in `merkle_tree`.
+pub fn asset_id(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"asset", merkle_tree.as_ref(), &nonce.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+    .0
+}
+
+/// Reconstructs the leaf a Bubblegum instruction wrote from its arguments,
+/// accounts and the events logged by its inner instructions.
+///
+/// # Returns
+///
+/// `None` for instructions that don't change a leaf, such as tree creation,
+/// redemption and decompression, and for mints whose leaf wasn't logged.
+pub fn leaf_update(
+    instruction: &DecodedInstruction<BubblegumInstruction>,
+    inner_instructions: &[NestedInstruction],
+) -> Option<LeafUpdate> {
+    leaf_update_from_events(instruction, &noop_events(inner_instructions))
+}
+
+/// Returns the Account Compression events logged by Noop instructions among
+/// `inner_instructions` and their own inner instructions, in order.
+pub fn noop_events(inner_instructions: &[NestedInstruction]) -> Vec<AccountCompressionEvent> {
+    let mut events = Vec::new();
+    collect_noop_events(inner_instructions, &mut events);
+    events
+}
+
+/// Reconstructs the leaf a Bubblegum instruction wrote from its arguments,
+/// accounts and the Account Compression events its inner instructions
+/// logged.
+///
+/// See [`leaf_update`].
+pub fn leaf_update_from_events(
+    instruction: &DecodedInstruction<BubblegumInstruction>,
+    events: &[AccountCompressionEvent],
+) -> Option<LeafUpdate> {
+    let leaf_event = events
+        .iter()
+        .filter_map(AccountCompressionEvent::application_data)
+        .find_map(LeafSchemaEvent::deserialize);
+    let change_log = events
+        .iter()
+        .find_map(AccountCompressionEvent::change_log)
+        .cloned();
+
+    let accounts = instruction.accounts.as_slice();
+    let (kind, merkle_tree, nonce, index, owner, delegate, creators) = match &instruction.data {
+        BubblegumInstruction::MintV1(mint) => {
+            let accounts = mint_v1::MintV1::arrange_accounts(accounts)?;
+            let leaf = &leaf_event.as_ref()?.schema;
+            (
+                LeafUpdateKind::Mint,
+                accounts.merkle_tree,
+                leaf.nonce(),
+                None,
+                accounts.leaf_owner,
+                accounts.leaf_delegate,
+                Some(mint.message.creators.clone()),
+            )
+        }
+        BubblegumInstruction::MintToCollectionV1(mint) => {
+            let accounts = mint_to_collection_v1::MintToCollectionV1::arrange_accounts(accounts)?;
+            let leaf = &leaf_event.as_ref()?.schema;
+            (
+                LeafUpdateKind::Mint,
+                accounts.merkle_tree,
+                leaf.nonce(),
+                None,
+                accounts.leaf_owner,
+                accounts.leaf_delegate,
+                Some(mint.metadata_args.creators.clone()),
+            )
+        }
+        BubblegumInstruction::Transfer(transfer) => {
+            let accounts = transfer::Transfer::arrange_accounts(accounts)?;
+            (
+                LeafUpdateKind::Transfer,
+                accounts.merkle_tree,
+                transfer.nonce,
+                Some(transfer.index),
+                accounts.new_leaf_owner,
+                accounts.new_leaf_owner,
+                None,
+            )
+        }
+        BubblegumInstruction::Delegate(delegate) => {
+            let accounts = delegate::Delegate::arrange_accounts(accounts)?;
+            (
+                LeafUpdateKind::Delegate,
+                accounts.merkle_tree,
+                delegate.nonce,
+                Some(delegate.index),
+                accounts.leaf_owner,
+                accounts.new_leaf_delegate,
+                None,
+            )
+        }
+        BubblegumInstruction::Burn(burn) => {
+            let accounts = burn::Burn::arrange_accounts(accounts)?;
+            (
+                LeafUpdateKind::Burn,
+                accounts.merkle_tree,
+                burn.nonce,
+                Some(burn.index),
+                accounts.leaf_owner,
+                accounts.leaf_delegate,
+                None,
+            )
+        }
+        BubblegumInstruction::VerifyCreator(verify) => {
+            let accounts = verify_creator::VerifyCreator::arrange_accounts(accounts)?;
+            (
+                LeafUpdateKind::MetadataUpdate,
+                accounts.merkle_tree,
+                verify.nonce,
+                Some(verify.index),
+                accounts.leaf_owner,
+                accounts.leaf_delegate,
+                Some(with_creator_verified(
+                    &verify.message.creators,
+                    &accounts.creator,
+                    true,
+                )),
+            )
+        }
+        BubblegumInstruction::UnverifyCreator(unverify) => {
+            let accounts = unverify_creator::UnverifyCreator::arrange_accounts(accounts)?;
+            (
+                LeafUpdateKind::MetadataUpdate,
+                accounts.merkle_tree,
+                unverify.nonce,
+                Some(unverify.index),
+                accounts.leaf_owner,
+                accounts.leaf_delegate,
+                Some(with_creator_verified(
+                    &unverify.message.creators,
+                    &accounts.creator,
+                    false,
+                )),
+            )
+        }
+        BubblegumInstruction::VerifyCollection(verify) => {
+            let accounts = verify_collection::VerifyCollection::arrange_accounts(accounts)?;
+            (
+                LeafUpdateKind::MetadataUpdate,
+                accounts.merkle_tree,
+                verify.nonce,
+                Some(verify.index),
+                accounts.leaf_owner,
+                accounts.leaf_delegate,
+                Some(verify.message.creators.clone()),
+            )
+        }
+        BubblegumInstruction::UnverifyCollection(unverify) => {
+            let accounts = unverify_collection::UnverifyCollection::arrange_accounts(accounts)?;
+            (
+                LeafUpdateKind::MetadataUpdate,
+                accounts.merkle_tree,
+                unverify.nonce,
+                Some(unverify.index),
+                accounts.leaf_owner,
+                accounts.leaf_delegate,
+                Some(unverify.message.creators.clone()),
+            )
+        }
+        BubblegumInstruction::SetAndVerifyCollection(verify) => {
+            let accounts =
+                set_and_verify_collection::SetAndVerifyCollection::arrange_accounts(accounts)?;
+            (
+                LeafUpdateKind::MetadataUpdate,
+                accounts.merkle_tree,
+                verify.nonce,
+                Some(verify.index),
+                accounts.leaf_owner,
+                accounts.leaf_delegate,
+                Some(verify.message.creators.clone()),
+            )
+        }
+        BubblegumInstruction::UpdateMetadata(update) => {
+            let accounts = update_metadata::UpdateMetadata::arrange_accounts(accounts)?;
+            (
+                LeafUpdateKind::MetadataUpdate,
+                accounts.merkle_tree,
+                update.nonce,
+                Some(update.index),
+                accounts.leaf_owner,
+                accounts.leaf_delegate,
+                Some(
+                    update
+                        .update_args
+                        .creators
+                        .clone()
+                        .unwrap_or_else(|| update.current_metadata.creators.clone()),
+                ),
+            )
+        }
+        _ => return None,
+    };
+
+    let leaf = leaf_event.as_ref().map(|event| event.schema.clone());
+    let leaf_index = change_log
+        .as_ref()
+        .map(|change_log| change_log.index)
+        .or(index)
+        .unwrap_or(nonce as u32);
+
+    Some(LeafUpdate {
+        kind,
+        asset_id: leaf
+            .as_ref()
+            .map(LeafSchema::id)
+            .unwrap_or_else(|| asset_id(&merkle_tree, nonce)),
+        merkle_tree,
+        nonce,
+        leaf_index,
+        owner: leaf.as_ref().map(LeafSchema::owner).unwrap_or(owner),
+        delegate: leaf.as_ref().map(LeafSchema::delegate).unwrap_or(delegate),
+        leaf_hash: leaf_event.as_ref().map(|event| event.leaf_hash),
+        leaf,
+        creators,
+        change_log,
+    })
+}
+
+fn collect_noop_events(
+    inner_instructions: &[NestedInstruction],
+    events: &mut Vec<AccountCompressionEvent>,
+) {
+    for inner_instruction in inner_instructions {
+        if let Some(event) =
+            AccountCompressionEvent::from_noop_instruction(&inner_instruction.instruction)
+        {
+            events.push(event);
+        }
+        collect_noop_events(&inner_instruction.inner_instructions, events);
+    }
+}
+
+fn with_creator_verified(creators: &[Creator], creator: &Pubkey, verified: bool) -> Vec<Creator> {
+    creators
+        .iter()
+        .map(|entry| Creator {
+            verified: if entry.address == *creator {
+                verified
+            } else {
+                entry.verified
+            },
+            ..entry.clone()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            instructions::burn::Burn,
+            types::{MetadataArgs, TokenProgramVersion},
+        },
+        carbon_spl_account_compression_decoder::types::{
+            ApplicationDataEvent, ApplicationDataEventV1, ChangeLogEvent,
+        },
+        solana_sdk::instruction::AccountMeta,
+    };
+
+    fn key(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
+
+    fn accounts(count: u8) -> Vec<AccountMeta> {
+        (1..=count)
+            .map(|byte| AccountMeta::new_readonly(key(byte), false))
+            .collect()
+    }
+
+    fn leaf_event_bytes(schema: &LeafSchema) -> Vec<u8> {
+        let LeafSchema::V1 {
+            id,
+            owner,
+            delegate,
+            nonce,
+            data_hash,
+            creator_hash,
+        } = schema;
+        // `BubblegumEventType::LeafSchemaEvent`, `Version::V1`, `LeafSchema::V1`
+        let mut data = vec![1, 0, 0];
+        for field in [id, owner, delegate] {
+            data.extend_from_slice(field.as_ref());
+        }
+        data.extend_from_slice(&nonce.to_le_bytes());
+        data.extend_from_slice(data_hash);
+        data.extend_from_slice(creator_hash);
+        data.extend_from_slice(&[8; 32]);
+        data
+    }
+
+    fn change_log(index: u32, seq: u64) -> ChangeLogEventV1 {
+        ChangeLogEventV1 {
+            id: key(4),
+            path: vec![],
+            seq,
+            index,
+        }
+    }
+
+    #[test]
+    fn test_mint_leaf_update() {
+        // Arrange
+        let schema = LeafSchema::V1 {
+            id: key(20),
+            owner: key(2),
+            delegate: key(3),
+            nonce: 6,
+            data_hash: [5; 32],
+            creator_hash: [6; 32],
+        };
+        let message = MetadataArgs {
+            name: "cNFT".to_string(),
+            symbol: String::new(),
+            uri: String::new(),
+            seller_fee_basis_points: 500,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: None,
+            collection: None,
+            uses: None,
+            token_program_version: TokenProgramVersion::Original,
+            creators: vec![Creator {
+                address: key(9),
+                verified: false,
+                share: 100,
+            }],
+        };
+        let instruction = DecodedInstruction {
+            program_id: PROGRAM_ID,
+            data: BubblegumInstruction::MintV1(mint_v1::MintV1 { message }),
+            accounts: accounts(9),
+        };
+        let events = vec![
+            AccountCompressionEvent::ApplicationData(ApplicationDataEvent::V1(
+                ApplicationDataEventV1 {
+                    application_data: leaf_event_bytes(&schema),
+                },
+            )),
+            AccountCompressionEvent::ChangeLog(ChangeLogEvent::V1(change_log(6, 7))),
+        ];
+
+        // Act
+        let update = leaf_update_from_events(&instruction, &events).expect("leaf update");
+
+        // Assert
+        assert_eq!(update.kind, LeafUpdateKind::Mint);
+        assert_eq!(update.asset_id, key(20));
+        assert_eq!(update.merkle_tree, key(4));
+        assert_eq!(update.nonce, 6);
+        assert_eq!(update.leaf_index, 6);
+        assert_eq!(update.owner, key(2));
+        assert_eq!(update.delegate, key(3));
+        assert_eq!(update.leaf, Some(schema));
+        assert_eq!(update.leaf_hash, Some([8; 32]));
+        assert_eq!(
+            update.creators.as_ref().expect("creators")[0].address,
+            key(9)
+        );
+        assert_eq!(update.seq(), Some(7));
+    }
+
+    #[test]
+    fn test_burn_leaf_update() {
+        // Arrange
+        let instruction = DecodedInstruction {
+            program_id: PROGRAM_ID,
+            data: BubblegumInstruction::Burn(Burn {
+                root: [0; 32],
+                data_hash: [0; 32],
+                creator_hash: [0; 32],
+                nonce: 11,
+                index: 11,
+            }),
+            accounts: accounts(7),
+        };
+        let events = vec![AccountCompressionEvent::ChangeLog(ChangeLogEvent::V1(
+            change_log(11, 30),
+        ))];
+
+        // Act
+        let update = leaf_update_from_events(&instruction, &events).expect("leaf update");
+
+        // Assert
+        assert_eq!(update.kind, LeafUpdateKind::Burn);
+        assert_eq!(update.asset_id, asset_id(&key(4), 11));
+        assert_eq!(update.owner, key(2));
+        assert_eq!(update.delegate, key(3));
+        assert_eq!(update.leaf, None);
+        assert_eq!(update.seq(), Some(30));
+    }
+
+    #[test]
+    fn test_mint_without_leaf_event() {
+        let instruction = DecodedInstruction {
+            program_id: PROGRAM_ID,
+            data: BubblegumInstruction::MintV1(mint_v1::MintV1 {
+                message: MetadataArgs {
+                    name: String::new(),
+                    symbol: String::new(),
+                    uri: String::new(),
+                    seller_fee_basis_points: 0,
+                    primary_sale_happened: false,
+                    is_mutable: false,
+                    edition_nonce: None,
+                    token_standard: None,
+                    collection: None,
+                    uses: None,
+                    token_program_version: TokenProgramVersion::Original,
+                    creators: vec![],
+                },
+            }),
+            accounts: accounts(9),
+        };
+
+        assert_eq!(leaf_update_from_events(&instruction, &[]), None);
+    }
+}
@@ -0,0 +1,10 @@
+use solana_sdk::{pubkey, pubkey::Pubkey};
+
+pub struct BubblegumDecoder;
+
+pub mod accounts;
+pub mod instructions;
+pub mod leaf;
+pub mod types;
+
+pub const PROGRAM_ID: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
@@ -0,0 +1,9 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub enum BubblegumEventType {
+    Uninitialized,
+    LeafSchemaEvent,
+}
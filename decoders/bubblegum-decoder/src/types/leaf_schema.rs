@@ -0,0 +1,41 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub enum LeafSchema {
+    V1 {
+        id: solana_sdk::pubkey::Pubkey,
+        owner: solana_sdk::pubkey::Pubkey,
+        delegate: solana_sdk::pubkey::Pubkey,
+        nonce: u64,
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+    },
+}
+
+impl LeafSchema {
+    pub fn id(&self) -> solana_sdk::pubkey::Pubkey {
+        match self {
+            LeafSchema::V1 { id, .. } => *id,
+        }
+    }
+
+    pub fn owner(&self) -> solana_sdk::pubkey::Pubkey {
+        match self {
+            LeafSchema::V1 { owner, .. } => *owner,
+        }
+    }
+
+    pub fn delegate(&self) -> solana_sdk::pubkey::Pubkey {
+        match self {
+            LeafSchema::V1 { delegate, .. } => *delegate,
+        }
+    }
+
+    pub fn nonce(&self) -> u64 {
+        match self {
+            LeafSchema::V1 { nonce, .. } => *nonce,
+        }
+    }
+}
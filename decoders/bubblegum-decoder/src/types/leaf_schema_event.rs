@@ -0,0 +1,16 @@
+use {
+    super::*,
+    carbon_core::{borsh, CarbonDeserialize},
+};
+
+/// The leaf Bubblegum logs through the Noop program, wrapped in an
+/// `ApplicationData` event, whenever it writes a leaf to a tree.
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct LeafSchemaEvent {
+    pub event_type: BubblegumEventType,
+    pub version: Version,
+    pub schema: LeafSchema,
+    pub leaf_hash: [u8; 32],
+}
@@ -0,0 +1,26 @@
+pub mod bubblegum_event_type;
+pub use bubblegum_event_type::*;
+pub mod collection;
+pub use collection::*;
+pub mod creator;
+pub use creator::*;
+pub mod decompressible_state;
+pub use decompressible_state::*;
+pub mod leaf_schema;
+pub use leaf_schema::*;
+pub mod leaf_schema_event;
+pub use leaf_schema_event::*;
+pub mod metadata_args;
+pub use metadata_args::*;
+pub mod token_program_version;
+pub use token_program_version::*;
+pub mod token_standard;
+pub use token_standard::*;
+pub mod update_args;
+pub use update_args::*;
+pub mod use_method;
+pub use use_method::*;
+pub mod uses;
+pub use uses::*;
+pub mod version;
+pub use version::*;
@@ -0,0 +1,127 @@
+use crate::PROGRAM_ID;
+
+use super::ComputeBudgetDecoder;
+pub mod request_heap_frame;
+pub mod request_units;
+pub mod set_compute_unit_limit;
+pub mod set_compute_unit_price;
+pub mod set_loaded_accounts_data_size_limit;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum ComputeBudgetInstruction {
+    RequestUnits(request_units::RequestUnits),
+    RequestHeapFrame(request_heap_frame::RequestHeapFrame),
+    SetComputeUnitLimit(set_compute_unit_limit::SetComputeUnitLimit),
+    SetComputeUnitPrice(set_compute_unit_price::SetComputeUnitPrice),
+    SetLoadedAccountsDataSizeLimit(
+        set_loaded_accounts_data_size_limit::SetLoadedAccountsDataSizeLimit,
+    ),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for ComputeBudgetDecoder {
+    type InstructionType = ComputeBudgetInstruction;
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            ComputeBudgetInstruction::RequestUnits => request_units::RequestUnits,
+            ComputeBudgetInstruction::RequestHeapFrame => request_heap_frame::RequestHeapFrame,
+            ComputeBudgetInstruction::SetComputeUnitLimit => set_compute_unit_limit::SetComputeUnitLimit,
+            ComputeBudgetInstruction::SetComputeUnitPrice => set_compute_unit_price::SetComputeUnitPrice,
+            ComputeBudgetInstruction::SetLoadedAccountsDataSizeLimit => set_loaded_accounts_data_size_limit::SetLoadedAccountsDataSizeLimit,
+        )
+    }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            request_units::RequestUnits,
+            request_heap_frame::RequestHeapFrame,
+            set_compute_unit_limit::SetComputeUnitLimit,
+            set_compute_unit_price::SetComputeUnitPrice,
+            set_loaded_accounts_data_size_limit::SetLoadedAccountsDataSizeLimit,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use carbon_core::instruction::InstructionDecoder;
+    use solana_sdk::instruction::Instruction;
+
+    use super::*;
+
+    fn instruction(data: Vec<u8>) -> Instruction {
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![],
+            data,
+        }
+    }
+
+    #[test]
+    fn test_decode_set_compute_unit_limit() {
+        // Arrange
+        let expected_ix = ComputeBudgetInstruction::SetComputeUnitLimit(
+            set_compute_unit_limit::SetComputeUnitLimit { units: 200_000 },
+        );
+
+        // Act
+        let decoded = ComputeBudgetDecoder
+            .decode_instruction(&instruction(vec![2, 0x40, 0x0d, 0x03, 0x00]))
+            .expect("decode instruction");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.program_id, PROGRAM_ID);
+    }
+
+    #[test]
+    fn test_decode_set_compute_unit_price() {
+        // Arrange
+        let expected_ix = ComputeBudgetInstruction::SetComputeUnitPrice(
+            set_compute_unit_price::SetComputeUnitPrice {
+                micro_lamports: 1_000_000,
+            },
+        );
+        let mut data = vec![3];
+        data.extend_from_slice(&1_000_000u64.to_le_bytes());
+
+        // Act
+        let decoded = ComputeBudgetDecoder
+            .decode_instruction(&instruction(data))
+            .expect("decode instruction");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+    }
+
+    #[test]
+    fn test_decode_unknown_instruction() {
+        let decoded = ComputeBudgetDecoder.decode_instruction(&instruction(vec![9, 0, 0, 0, 0]));
+
+        assert!(decoded.is_none());
+    }
+}
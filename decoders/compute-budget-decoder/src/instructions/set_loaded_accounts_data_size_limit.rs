@@ -0,0 +1,21 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x04")]
+pub struct SetLoadedAccountsDataSizeLimit {
+    pub bytes: u32,
+}
+
+pub struct SetLoadedAccountsDataSizeLimitInstructionAccounts {}
+
+impl carbon_core::deserialize::ArrangeAccounts for SetLoadedAccountsDataSizeLimit {
+    type ArrangedAccounts = SetLoadedAccountsDataSizeLimitInstructionAccounts;
+
+    fn arrange_accounts(
+        _accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        Some(SetLoadedAccountsDataSizeLimitInstructionAccounts {})
+    }
+}
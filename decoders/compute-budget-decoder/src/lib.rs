@@ -0,0 +1,7 @@
+use solana_sdk::{pubkey, pubkey::Pubkey};
+
+pub struct ComputeBudgetDecoder;
+
+pub mod instructions;
+
+pub const PROGRAM_ID: Pubkey = pubkey!("ComputeBudget111111111111111111111111111111");
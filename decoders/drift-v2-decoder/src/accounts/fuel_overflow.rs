@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0xb640e7b1e28e453a")]
 pub struct FuelOverflow {
     pub authority: solana_sdk::pubkey::Pubkey,
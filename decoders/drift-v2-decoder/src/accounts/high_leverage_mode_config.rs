@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x03c45abdc140e4ea")]
 pub struct HighLeverageModeConfig {
     pub max_users: u32,
@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x6eca0e2a5f495a5f")]
 pub struct InsuranceFundStake {
     pub authority: solana_sdk::pubkey::Pubkey,
@@ -22,6 +22,7 @@ pub mod state;
 pub mod user;
 pub mod user_stats;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum DriftAccount {
     OpenbookV2FulfillmentConfig(openbook_v2_fulfillment_config::OpenbookV2FulfillmentConfig),
     PhoenixV1FulfillmentConfig(phoenix_v1_fulfillment_config::PhoenixV1FulfillmentConfig),
@@ -262,4 +263,34 @@ impl<'a> AccountDecoder<'a> for DriftDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            openbook_v2_fulfillment_config::OpenbookV2FulfillmentConfig,
+            phoenix_v1_fulfillment_config::PhoenixV1FulfillmentConfig,
+            serum_v3_fulfillment_config::SerumV3FulfillmentConfig,
+            high_leverage_mode_config::HighLeverageModeConfig,
+            insurance_fund_stake::InsuranceFundStake,
+            protocol_if_shares_transfer_config::ProtocolIfSharesTransferConfig,
+            prelaunch_oracle::PrelaunchOracle,
+            perp_market::PerpMarket,
+            protected_maker_mode_config::ProtectedMakerModeConfig,
+            pyth_lazer_oracle::PythLazerOracle,
+            signed_msg_user_orders::SignedMsgUserOrders,
+            spot_market::SpotMarket,
+            state::State,
+            user::User,
+            user_stats::UserStats,
+            referrer_name::ReferrerName,
+            fuel_overflow::FuelOverflow,
+        )
+    }
 }
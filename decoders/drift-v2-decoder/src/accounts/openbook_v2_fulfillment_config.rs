@@ -2,7 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x032b3a6a8384c7ab")]
 pub struct OpenbookV2FulfillmentConfig {
     pub pubkey: solana_sdk::pubkey::Pubkey,
@@ -2,7 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0xe92d3e2823813048")]
 pub struct PhoenixV1FulfillmentConfig {
     pub pubkey: solana_sdk::pubkey::Pubkey,
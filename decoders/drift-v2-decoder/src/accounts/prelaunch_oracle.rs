@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x5c0e8bea48f4441a")]
 pub struct PrelaunchOracle {
     pub price: i64,
@@ -9,5 +9,6 @@ pub struct PrelaunchOracle {
     pub last_update_slot: u64,
     pub amm_last_update_slot: u64,
     pub perp_market_index: u16,
+    #[serde(with = "serde_big_array::BigArray")]
     pub padding: [u8; 70],
 }
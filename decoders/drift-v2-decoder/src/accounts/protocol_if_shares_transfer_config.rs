@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0xbc01d56217941e01")]
 pub struct ProtocolIfSharesTransferConfig {
     pub whitelisted_signers: [solana_sdk::pubkey::Pubkey; 4],
@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x6985aa6e342a1cb6")]
 pub struct ReferrerName {
     pub authority: solana_sdk::pubkey::Pubkey,
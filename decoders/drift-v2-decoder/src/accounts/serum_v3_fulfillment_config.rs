@@ -2,7 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x41a0c570efa867b9")]
 pub struct SerumV3FulfillmentConfig {
     pub pubkey: solana_sdk::pubkey::Pubkey,
@@ -2,7 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x460632f8de018f31")]
 pub struct SignedMsgUserOrders {
     pub authority_pubkey: solana_sdk::pubkey::Pubkey,
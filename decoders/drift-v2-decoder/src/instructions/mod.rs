@@ -674,4 +674,232 @@ impl<'a> carbon_core::instruction::InstructionDecoder<'a> for DriftDecoder {
             DriftInstruction::FuelSeasonRecordEvent => fuel_season_record_event::FuelSeasonRecordEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            initialize_user::InitializeUser,
+            initialize_user_stats::InitializeUserStats,
+            initialize_signed_msg_user_orders::InitializeSignedMsgUserOrders,
+            resize_signed_msg_user_orders::ResizeSignedMsgUserOrders,
+            initialize_fuel_overflow::InitializeFuelOverflow,
+            sweep_fuel::SweepFuel,
+            reset_fuel_season::ResetFuelSeason,
+            initialize_referrer_name::InitializeReferrerName,
+            deposit::Deposit,
+            withdraw::Withdraw,
+            transfer_deposit::TransferDeposit,
+            transfer_pools::TransferPools,
+            place_perp_order::PlacePerpOrder,
+            cancel_order::CancelOrder,
+            cancel_order_by_user_id::CancelOrderByUserId,
+            cancel_orders::CancelOrders,
+            cancel_orders_by_ids::CancelOrdersByIds,
+            modify_order::ModifyOrder,
+            modify_order_by_user_id::ModifyOrderByUserId,
+            place_and_take_perp_order::PlaceAndTakePerpOrder,
+            place_and_make_perp_order::PlaceAndMakePerpOrder,
+            place_and_make_signed_msg_perp_order::PlaceAndMakeSignedMsgPerpOrder,
+            place_signed_msg_taker_order::PlaceSignedMsgTakerOrder,
+            place_spot_order::PlaceSpotOrder,
+            place_and_take_spot_order::PlaceAndTakeSpotOrder,
+            place_and_make_spot_order::PlaceAndMakeSpotOrder,
+            place_orders::PlaceOrders,
+            begin_swap::BeginSwap,
+            end_swap::EndSwap,
+            add_perp_lp_shares::AddPerpLpShares,
+            remove_perp_lp_shares::RemovePerpLpShares,
+            remove_perp_lp_shares_in_expiring_market::RemovePerpLpSharesInExpiringMarket,
+            update_user_name::UpdateUserName,
+            update_user_custom_margin_ratio::UpdateUserCustomMarginRatio,
+            update_user_margin_trading_enabled::UpdateUserMarginTradingEnabled,
+            update_user_pool_id::UpdateUserPoolId,
+            update_user_delegate::UpdateUserDelegate,
+            update_user_reduce_only::UpdateUserReduceOnly,
+            update_user_advanced_lp::UpdateUserAdvancedLp,
+            update_user_protected_maker_orders::UpdateUserProtectedMakerOrders,
+            delete_user::DeleteUser,
+            force_delete_user::ForceDeleteUser,
+            delete_signed_msg_user_orders::DeleteSignedMsgUserOrders,
+            reclaim_rent::ReclaimRent,
+            enable_user_high_leverage_mode::EnableUserHighLeverageMode,
+            fill_perp_order::FillPerpOrder,
+            revert_fill::RevertFill,
+            fill_spot_order::FillSpotOrder,
+            trigger_order::TriggerOrder,
+            force_cancel_orders::ForceCancelOrders,
+            update_user_idle::UpdateUserIdle,
+            log_user_balances::LogUserBalances,
+            disable_user_high_leverage_mode::DisableUserHighLeverageMode,
+            update_user_fuel_bonus::UpdateUserFuelBonus,
+            update_user_stats_referrer_status::UpdateUserStatsReferrerStatus,
+            update_user_open_orders_count::UpdateUserOpenOrdersCount,
+            admin_disable_update_perp_bid_ask_twap::AdminDisableUpdatePerpBidAskTwap,
+            settle_pnl::SettlePnl,
+            settle_multiple_pnls::SettleMultiplePnls,
+            settle_funding_payment::SettleFundingPayment,
+            settle_lp::SettleLp,
+            settle_expired_market::SettleExpiredMarket,
+            liquidate_perp::LiquidatePerp,
+            liquidate_perp_with_fill::LiquidatePerpWithFill,
+            liquidate_spot::LiquidateSpot,
+            liquidate_spot_with_swap_begin::LiquidateSpotWithSwapBegin,
+            liquidate_spot_with_swap_end::LiquidateSpotWithSwapEnd,
+            liquidate_borrow_for_perp_pnl::LiquidateBorrowForPerpPnl,
+            liquidate_perp_pnl_for_deposit::LiquidatePerpPnlForDeposit,
+            set_user_status_to_being_liquidated::SetUserStatusToBeingLiquidated,
+            resolve_perp_pnl_deficit::ResolvePerpPnlDeficit,
+            resolve_perp_bankruptcy::ResolvePerpBankruptcy,
+            resolve_spot_bankruptcy::ResolveSpotBankruptcy,
+            settle_revenue_to_insurance_fund::SettleRevenueToInsuranceFund,
+            update_funding_rate::UpdateFundingRate,
+            update_prelaunch_oracle::UpdatePrelaunchOracle,
+            update_perp_bid_ask_twap::UpdatePerpBidAskTwap,
+            update_spot_market_cumulative_interest::UpdateSpotMarketCumulativeInterest,
+            update_amms::UpdateAmms,
+            update_spot_market_expiry::UpdateSpotMarketExpiry,
+            update_user_quote_asset_insurance_stake::UpdateUserQuoteAssetInsuranceStake,
+            update_user_gov_token_insurance_stake::UpdateUserGovTokenInsuranceStake,
+            update_user_gov_token_insurance_stake_devnet::UpdateUserGovTokenInsuranceStakeDevnet,
+            initialize_insurance_fund_stake::InitializeInsuranceFundStake,
+            add_insurance_fund_stake::AddInsuranceFundStake,
+            request_remove_insurance_fund_stake::RequestRemoveInsuranceFundStake,
+            cancel_request_remove_insurance_fund_stake::CancelRequestRemoveInsuranceFundStake,
+            remove_insurance_fund_stake::RemoveInsuranceFundStake,
+            transfer_protocol_if_shares::TransferProtocolIfShares,
+            update_pyth_pull_oracle::UpdatePythPullOracle,
+            post_pyth_pull_oracle_update_atomic::PostPythPullOracleUpdateAtomic,
+            post_multi_pyth_pull_oracle_updates_atomic::PostMultiPythPullOracleUpdatesAtomic,
+            pause_spot_market_deposit_withdraw::PauseSpotMarketDepositWithdraw,
+            initialize::Initialize,
+            initialize_spot_market::InitializeSpotMarket,
+            delete_initialized_spot_market::DeleteInitializedSpotMarket,
+            initialize_serum_fulfillment_config::InitializeSerumFulfillmentConfig,
+            update_serum_fulfillment_config_status::UpdateSerumFulfillmentConfigStatus,
+            initialize_openbook_v2_fulfillment_config::InitializeOpenbookV2FulfillmentConfig,
+            openbook_v2_fulfillment_config_status::OpenbookV2FulfillmentConfigStatus,
+            initialize_phoenix_fulfillment_config::InitializePhoenixFulfillmentConfig,
+            phoenix_fulfillment_config_status::PhoenixFulfillmentConfigStatus,
+            update_serum_vault::UpdateSerumVault,
+            initialize_perp_market::InitializePerpMarket,
+            initialize_prediction_market::InitializePredictionMarket,
+            delete_initialized_perp_market::DeleteInitializedPerpMarket,
+            move_amm_price::MoveAmmPrice,
+            recenter_perp_market_amm::RecenterPerpMarketAmm,
+            update_perp_market_amm_summary_stats::UpdatePerpMarketAmmSummaryStats,
+            update_perp_market_expiry::UpdatePerpMarketExpiry,
+            settle_expired_market_pools_to_revenue_pool::SettleExpiredMarketPoolsToRevenuePool,
+            deposit_into_perp_market_fee_pool::DepositIntoPerpMarketFeePool,
+            deposit_into_spot_market_vault::DepositIntoSpotMarketVault,
+            deposit_into_spot_market_revenue_pool::DepositIntoSpotMarketRevenuePool,
+            repeg_amm_curve::RepegAmmCurve,
+            update_perp_market_amm_oracle_twap::UpdatePerpMarketAmmOracleTwap,
+            reset_perp_market_amm_oracle_twap::ResetPerpMarketAmmOracleTwap,
+            update_k::UpdateK,
+            update_perp_market_margin_ratio::UpdatePerpMarketMarginRatio,
+            update_perp_market_high_leverage_margin_ratio::UpdatePerpMarketHighLeverageMarginRatio,
+            update_perp_market_funding_period::UpdatePerpMarketFundingPeriod,
+            update_perp_market_max_imbalances::UpdatePerpMarketMaxImbalances,
+            update_perp_market_liquidation_fee::UpdatePerpMarketLiquidationFee,
+            update_insurance_fund_unstaking_period::UpdateInsuranceFundUnstakingPeriod,
+            update_spot_market_pool_id::UpdateSpotMarketPoolId,
+            update_spot_market_liquidation_fee::UpdateSpotMarketLiquidationFee,
+            update_withdraw_guard_threshold::UpdateWithdrawGuardThreshold,
+            update_spot_market_if_factor::UpdateSpotMarketIfFactor,
+            update_spot_market_revenue_settle_period::UpdateSpotMarketRevenueSettlePeriod,
+            update_spot_market_status::UpdateSpotMarketStatus,
+            update_spot_market_paused_operations::UpdateSpotMarketPausedOperations,
+            update_spot_market_asset_tier::UpdateSpotMarketAssetTier,
+            update_spot_market_margin_weights::UpdateSpotMarketMarginWeights,
+            update_spot_market_borrow_rate::UpdateSpotMarketBorrowRate,
+            update_spot_market_max_token_deposits::UpdateSpotMarketMaxTokenDeposits,
+            update_spot_market_max_token_borrows::UpdateSpotMarketMaxTokenBorrows,
+            update_spot_market_scale_initial_asset_weight_start::UpdateSpotMarketScaleInitialAssetWeightStart,
+            update_spot_market_oracle::UpdateSpotMarketOracle,
+            update_spot_market_step_size_and_tick_size::UpdateSpotMarketStepSizeAndTickSize,
+            update_spot_market_min_order_size::UpdateSpotMarketMinOrderSize,
+            update_spot_market_orders_enabled::UpdateSpotMarketOrdersEnabled,
+            update_spot_market_if_paused_operations::UpdateSpotMarketIfPausedOperations,
+            update_spot_market_name::UpdateSpotMarketName,
+            update_perp_market_status::UpdatePerpMarketStatus,
+            update_perp_market_paused_operations::UpdatePerpMarketPausedOperations,
+            update_perp_market_contract_tier::UpdatePerpMarketContractTier,
+            update_perp_market_imf_factor::UpdatePerpMarketImfFactor,
+            update_perp_market_unrealized_asset_weight::UpdatePerpMarketUnrealizedAssetWeight,
+            update_perp_market_concentration_coef::UpdatePerpMarketConcentrationCoef,
+            update_perp_market_curve_update_intensity::UpdatePerpMarketCurveUpdateIntensity,
+            update_perp_market_target_base_asset_amount_per_lp::UpdatePerpMarketTargetBaseAssetAmountPerLp,
+            update_perp_market_per_lp_base::UpdatePerpMarketPerLpBase,
+            update_lp_cooldown_time::UpdateLpCooldownTime,
+            update_perp_fee_structure::UpdatePerpFeeStructure,
+            update_spot_fee_structure::UpdateSpotFeeStructure,
+            update_initial_pct_to_liquidate::UpdateInitialPctToLiquidate,
+            update_liquidation_duration::UpdateLiquidationDuration,
+            update_liquidation_margin_buffer_ratio::UpdateLiquidationMarginBufferRatio,
+            update_oracle_guard_rails::UpdateOracleGuardRails,
+            update_state_settlement_duration::UpdateStateSettlementDuration,
+            update_state_max_number_of_sub_accounts::UpdateStateMaxNumberOfSubAccounts,
+            update_state_max_initialize_user_fee::UpdateStateMaxInitializeUserFee,
+            update_perp_market_oracle::UpdatePerpMarketOracle,
+            update_perp_market_base_spread::UpdatePerpMarketBaseSpread,
+            update_amm_jit_intensity::UpdateAmmJitIntensity,
+            update_perp_market_max_spread::UpdatePerpMarketMaxSpread,
+            update_perp_market_step_size_and_tick_size::UpdatePerpMarketStepSizeAndTickSize,
+            update_perp_market_name::UpdatePerpMarketName,
+            update_perp_market_min_order_size::UpdatePerpMarketMinOrderSize,
+            update_perp_market_max_slippage_ratio::UpdatePerpMarketMaxSlippageRatio,
+            update_perp_market_max_fill_reserve_fraction::UpdatePerpMarketMaxFillReserveFraction,
+            update_perp_market_max_open_interest::UpdatePerpMarketMaxOpenInterest,
+            update_perp_market_number_of_users::UpdatePerpMarketNumberOfUsers,
+            update_perp_market_fee_adjustment::UpdatePerpMarketFeeAdjustment,
+            update_spot_market_fee_adjustment::UpdateSpotMarketFeeAdjustment,
+            update_perp_market_fuel::UpdatePerpMarketFuel,
+            update_spot_market_fuel::UpdateSpotMarketFuel,
+            init_user_fuel::InitUserFuel,
+            update_admin::UpdateAdmin,
+            update_whitelist_mint::UpdateWhitelistMint,
+            update_discount_mint::UpdateDiscountMint,
+            update_exchange_status::UpdateExchangeStatus,
+            update_perp_auction_duration::UpdatePerpAuctionDuration,
+            update_spot_auction_duration::UpdateSpotAuctionDuration,
+            initialize_protocol_if_shares_transfer_config::InitializeProtocolIfSharesTransferConfig,
+            update_protocol_if_shares_transfer_config::UpdateProtocolIfSharesTransferConfig,
+            initialize_prelaunch_oracle::InitializePrelaunchOracle,
+            update_prelaunch_oracle_params::UpdatePrelaunchOracleParams,
+            delete_prelaunch_oracle::DeletePrelaunchOracle,
+            initialize_pyth_pull_oracle::InitializePythPullOracle,
+            initialize_pyth_lazer_oracle::InitializePythLazerOracle,
+            post_pyth_lazer_oracle_update::PostPythLazerOracleUpdate,
+            initialize_high_leverage_mode_config::InitializeHighLeverageModeConfig,
+            update_high_leverage_mode_config::UpdateHighLeverageModeConfig,
+            initialize_protected_maker_mode_config::InitializeProtectedMakerModeConfig,
+            update_protected_maker_mode_config::UpdateProtectedMakerModeConfig,
+            new_user_record_event::NewUserRecordEvent,
+            deposit_record_event::DepositRecordEvent,
+            spot_interest_record_event::SpotInterestRecordEvent,
+            funding_payment_record_event::FundingPaymentRecordEvent,
+            funding_rate_record_event::FundingRateRecordEvent,
+            curve_record_event::CurveRecordEvent,
+            signed_msg_order_record_event::SignedMsgOrderRecordEvent,
+            order_record_event::OrderRecordEvent,
+            order_action_record_event::OrderActionRecordEvent,
+            lp_record_event::LpRecordEvent,
+            liquidation_record_event::LiquidationRecordEvent,
+            settle_pnl_record_event::SettlePnlRecordEvent,
+            insurance_fund_record_event::InsuranceFundRecordEvent,
+            insurance_fund_stake_record_event::InsuranceFundStakeRecordEvent,
+            swap_record_event::SwapRecordEvent,
+            spot_market_vault_deposit_record_event::SpotMarketVaultDepositRecordEvent,
+            delete_user_record_event::DeleteUserRecordEvent,
+            fuel_sweep_record_event::FuelSweepRecordEvent,
+            fuel_season_record_event::FuelSeasonRecordEvent,
+        )
+    }
 }
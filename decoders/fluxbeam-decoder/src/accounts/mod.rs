@@ -6,6 +6,7 @@ use crate::PROGRAM_ID;
 use super::FluxbeamDecoder;
 pub mod swap_v1;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum FluxbeamAccount {
     SwapV1(swap_v1::SwapV1),
 }
@@ -32,6 +33,17 @@ impl<'a> AccountDecoder<'a> for FluxbeamDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(account.data.as_slice(), swap_v1::SwapV1,)
+    }
 }
 
 #[cfg(test)]
@@ -51,6 +51,25 @@ impl<'a> carbon_core::instruction::InstructionDecoder<'a> for FluxbeamDecoder {
             FluxbeamInstruction::WithdrawSingleTokenTypeExactAmountOut => withdraw_single_token_type_exact_amount_out::WithdrawSingleTokenTypeExactAmountOut,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            initialize::Initialize,
+            swap::Swap,
+            deposit_all_token_types::DepositAllTokenTypes,
+            withdraw_all_token_types::WithdrawAllTokenTypes,
+            deposit_single_token_type_exact_amount_in::DepositSingleTokenTypeExactAmountIn,
+            withdraw_single_token_type_exact_amount_out::WithdrawSingleTokenTypeExactAmountOut,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -5,6 +5,7 @@ use {
 };
 pub mod dca;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum JupiterDcaAccount {
     Dca(dca::Dca),
 }
@@ -31,6 +32,17 @@ impl AccountDecoder<'_> for JupiterDcaDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(account.data.as_slice(), dca::Dca,)
+    }
 }
 
 #[cfg(test)]
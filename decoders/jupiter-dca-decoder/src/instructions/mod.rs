@@ -83,6 +83,37 @@ impl carbon_core::instruction::InstructionDecoder<'_> for JupiterDcaDecoder {
             JupiterDcaInstruction::DepositEvent => deposit_event::DepositEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            open_dca::OpenDca,
+            open_dca_v2::OpenDcaV2,
+            close_dca::CloseDca,
+            withdraw::Withdraw,
+            deposit::Deposit,
+            withdraw_fees::WithdrawFees,
+            initiate_flash_fill::InitiateFlashFill,
+            fulfill_flash_fill::FulfillFlashFill,
+            initiate_dlmm_fill::InitiateDlmmFill,
+            fulfill_dlmm_fill::FulfillDlmmFill,
+            transfer::Transfer,
+            end_and_close::EndAndClose,
+            collected_fee_event::CollectedFeeEvent,
+            filled_event::FilledEvent,
+            opened_event::OpenedEvent,
+            closed_event::ClosedEvent,
+            withdraw_event::WithdrawEvent,
+            deposit_event::DepositEvent,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -6,6 +6,7 @@ use {
 pub mod fee;
 pub mod order;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum JupiterLimitOrder2Account {
     Order(order::Order),
     Fee(fee::Fee),
@@ -43,4 +44,15 @@ impl AccountDecoder<'_> for JupiterLimitOrder2Decoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(account.data.as_slice(), order::Order, fee::Fee,)
+    }
 }
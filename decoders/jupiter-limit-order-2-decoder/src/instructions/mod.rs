@@ -56,4 +56,26 @@ impl carbon_core::instruction::InstructionDecoder<'_> for JupiterLimitOrder2Deco
             JupiterLimitOrder2Instruction::CreateOrderEvent => create_order_event::CreateOrderEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            update_fee::UpdateFee,
+            withdraw_fee::WithdrawFee,
+            initialize_order::InitializeOrder,
+            cancel_order::CancelOrder,
+            pre_flash_fill_order::PreFlashFillOrder,
+            flash_fill_order::FlashFillOrder,
+            trade_event::TradeEvent,
+            cancel_order_event::CancelOrderEvent,
+            create_order_event::CreateOrderEvent,
+        )
+    }
 }
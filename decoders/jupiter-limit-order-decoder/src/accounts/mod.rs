@@ -6,6 +6,7 @@ use {
 pub mod fee;
 pub mod order;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum JupiterLimitOrderAccount {
     Fee(fee::Fee),
     Order(order::Order),
@@ -43,4 +44,15 @@ impl AccountDecoder<'_> for JupiterLimitOrderDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(account.data.as_slice(), fee::Fee, order::Order,)
+    }
 }
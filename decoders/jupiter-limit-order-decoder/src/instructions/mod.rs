@@ -65,4 +65,29 @@ impl carbon_core::instruction::InstructionDecoder<'_> for JupiterLimitOrderDecod
             JupiterLimitOrderInstruction::CreateOrderEvent => create_order_event::CreateOrderEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            initialize_order::InitializeOrder,
+            fill_order::FillOrder,
+            pre_flash_fill_order::PreFlashFillOrder,
+            flash_fill_order::FlashFillOrder,
+            cancel_order::CancelOrder,
+            cancel_expired_order::CancelExpiredOrder,
+            withdraw_fee::WithdrawFee,
+            init_fee::InitFee,
+            update_fee::UpdateFee,
+            trade_event::TradeEvent,
+            cancel_order_event::CancelOrderEvent,
+            create_order_event::CreateOrderEvent,
+        )
+    }
 }
@@ -11,6 +11,7 @@ pub mod position;
 pub mod position_request;
 pub mod token_ledger;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum PerpetualsAccount {
     Custody(custody::Custody),
     Perpetuals(perpetuals::Perpetuals),
@@ -97,4 +98,23 @@ impl<'a> AccountDecoder<'a> for PerpetualsDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            custody::Custody,
+            perpetuals::Perpetuals,
+            pool::Pool,
+            position_request::PositionRequest,
+            position::Position,
+            token_ledger::TokenLedger,
+        )
+    }
 }
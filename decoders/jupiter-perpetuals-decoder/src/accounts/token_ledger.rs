@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x9cf709bc366c554d")]
 pub struct TokenLedger {
     pub token_account: solana_sdk::pubkey::Pubkey,
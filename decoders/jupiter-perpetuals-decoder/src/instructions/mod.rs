@@ -208,4 +208,72 @@ impl<'a> carbon_core::instruction::InstructionDecoder<'a> for PerpetualsDecoder
             PerpetualsInstruction::InstantDecreasePositionEvent => instant_decrease_position_event::InstantDecreasePositionEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            init::Init,
+            add_pool::AddPool,
+            add_custody::AddCustody,
+            set_custody_config::SetCustodyConfig,
+            set_pool_config::SetPoolConfig,
+            set_perpetuals_config::SetPerpetualsConfig,
+            transfer_admin::TransferAdmin,
+            withdraw_fees2::WithdrawFees2,
+            create_token_metadata::CreateTokenMetadata,
+            create_token_ledger::CreateTokenLedger,
+            operator_set_custody_config::OperatorSetCustodyConfig,
+            operator_set_pool_config::OperatorSetPoolConfig,
+            test_init::TestInit,
+            set_test_time::SetTestTime,
+            set_token_ledger::SetTokenLedger,
+            swap2::Swap2,
+            add_liquidity2::AddLiquidity2,
+            remove_liquidity2::RemoveLiquidity2,
+            create_increase_position_market_request::CreateIncreasePositionMarketRequest,
+            create_decrease_position_request2::CreateDecreasePositionRequest2,
+            create_decrease_position_market_request::CreateDecreasePositionMarketRequest,
+            update_decrease_position_request2::UpdateDecreasePositionRequest2,
+            close_position_request::ClosePositionRequest,
+            increase_position4::IncreasePosition4,
+            increase_position_pre_swap::IncreasePositionPreSwap,
+            increase_position_with_internal_swap::IncreasePositionWithInternalSwap,
+            decrease_position4::DecreasePosition4,
+            decrease_position_with_internal_swap::DecreasePositionWithInternalSwap,
+            liquidate_full_position4::LiquidateFullPosition4,
+            refresh_assets_under_management::RefreshAssetsUnderManagement,
+            instant_create_tpsl::InstantCreateTpsl,
+            instant_create_limit_order::InstantCreateLimitOrder,
+            instant_increase_position::InstantIncreasePosition,
+            instant_decrease_position::InstantDecreasePosition,
+            instant_update_limit_order::InstantUpdateLimitOrder,
+            instant_update_tpsl::InstantUpdateTpsl,
+            get_add_liquidity_amount_and_fee2::GetAddLiquidityAmountAndFee2,
+            get_remove_liquidity_amount_and_fee2::GetRemoveLiquidityAmountAndFee2,
+            get_assets_under_management2::GetAssetsUnderManagement2,
+            create_position_request_event::CreatePositionRequestEvent,
+            instant_create_tpsl_event::InstantCreateTpslEvent,
+            instant_update_tpsl_event::InstantUpdateTpslEvent,
+            close_position_request_event::ClosePositionRequestEvent,
+            increase_position_event::IncreasePositionEvent,
+            increase_position_pre_swap_event::IncreasePositionPreSwapEvent,
+            decrease_position_event::DecreasePositionEvent,
+            decrease_position_post_swap_event::DecreasePositionPostSwapEvent,
+            liquidate_full_position_event::LiquidateFullPositionEvent,
+            pool_swap_event::PoolSwapEvent,
+            pool_swap_exact_out_event::PoolSwapExactOutEvent,
+            add_liquidity_event::AddLiquidityEvent,
+            remove_liquidity_event::RemoveLiquidityEvent,
+            instant_create_limit_order_event::InstantCreateLimitOrderEvent,
+            instant_increase_position_event::InstantIncreasePositionEvent,
+            instant_decrease_position_event::InstantDecreasePositionEvent,
+        )
+    }
 }
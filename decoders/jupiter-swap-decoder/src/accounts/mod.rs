@@ -5,6 +5,7 @@ use {
 };
 pub mod token_ledger;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum JupiterSwapAccount {
     TokenLedger(token_ledger::TokenLedger),
 }
@@ -33,4 +34,15 @@ impl AccountDecoder<'_> for JupiterSwapDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(account.data.as_slice(), token_ledger::TokenLedger,)
+    }
 }
@@ -73,4 +73,31 @@ impl carbon_core::instruction::InstructionDecoder<'_> for JupiterSwapDecoder {
             JupiterSwapInstruction::SwapEvent => swap_event::SwapEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            claim::Claim,
+            claim_token::ClaimToken,
+            create_open_orders::CreateOpenOrders,
+            create_program_open_orders::CreateProgramOpenOrders,
+            create_token_ledger::CreateTokenLedger,
+            exact_out_route::ExactOutRoute,
+            route::Route,
+            route_with_token_ledger::RouteWithTokenLedger,
+            set_token_ledger::SetTokenLedger,
+            shared_accounts_exact_out_route::SharedAccountsExactOutRoute,
+            shared_accounts_route::SharedAccountsRoute,
+            shared_accounts_route_with_token_ledger::SharedAccountsRouteWithTokenLedger,
+            fee_event::FeeEvent,
+            swap_event::SwapEvent,
+        )
+    }
 }
@@ -3,7 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0xc666d84a3f42a3be")]
 pub struct FarmState {
     pub farm_admin: solana_sdk::pubkey::Pubkey,
@@ -42,5 +42,6 @@ pub struct FarmState {
     pub strategy_id: solana_sdk::pubkey::Pubkey,
     pub delegated_rps_admin: solana_sdk::pubkey::Pubkey,
     pub vault_id: solana_sdk::pubkey::Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
     pub padding: [u64; 78],
 }
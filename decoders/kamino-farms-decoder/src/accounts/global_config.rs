@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x95089ccaa0fcb0d9")]
 pub struct GlobalConfig {
     pub global_admin: solana_sdk::pubkey::Pubkey,
@@ -8,5 +8,6 @@ pub struct GlobalConfig {
     pub treasury_vaults_authority: solana_sdk::pubkey::Pubkey,
     pub treasury_vaults_authority_bump: u64,
     pub pending_global_admin: solana_sdk::pubkey::Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
     pub padding1: [u128; 126],
 }
@@ -8,6 +8,7 @@ pub mod global_config;
 pub mod oracle_prices;
 pub mod user_state;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum KaminoFarmsAccount {
     FarmState(farm_state::FarmState),
     GlobalConfig(global_config::GlobalConfig),
@@ -70,4 +71,21 @@ impl<'a> AccountDecoder<'a> for KaminoFarmsDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            farm_state::FarmState,
+            global_config::GlobalConfig,
+            user_state::UserState,
+            oracle_prices::OraclePrices,
+        )
+    }
 }
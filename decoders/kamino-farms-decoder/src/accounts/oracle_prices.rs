@@ -3,9 +3,10 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x598076dd0648b492")]
 pub struct OraclePrices {
     pub oracle_mappings: solana_sdk::pubkey::Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
     pub prices: [DatedPrice; 512],
 }
@@ -101,4 +101,42 @@ impl<'a> carbon_core::instruction::InstructionDecoder<'a> for KaminoFarmsDecoder
             KaminoFarmsInstruction::IdlMissingTypes => idl_missing_types::IdlMissingTypes,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            initialize_global_config::InitializeGlobalConfig,
+            update_global_config::UpdateGlobalConfig,
+            initialize_farm::InitializeFarm,
+            initialize_farm_delegated::InitializeFarmDelegated,
+            initialize_reward::InitializeReward,
+            add_rewards::AddRewards,
+            update_farm_config::UpdateFarmConfig,
+            initialize_user::InitializeUser,
+            transfer_ownership::TransferOwnership,
+            reward_user_once::RewardUserOnce,
+            refresh_farm::RefreshFarm,
+            stake::Stake,
+            set_stake_delegated::SetStakeDelegated,
+            harvest_reward::HarvestReward,
+            unstake::Unstake,
+            refresh_user_state::RefreshUserState,
+            withdraw_unstaked_deposits::WithdrawUnstakedDeposits,
+            withdraw_treasury::WithdrawTreasury,
+            deposit_to_farm_vault::DepositToFarmVault,
+            withdraw_from_farm_vault::WithdrawFromFarmVault,
+            withdraw_slashed_amount::WithdrawSlashedAmount,
+            update_farm_admin::UpdateFarmAdmin,
+            update_global_config_admin::UpdateGlobalConfigAdmin,
+            withdraw_reward::WithdrawReward,
+            idl_missing_types::IdlMissingTypes,
+        )
+    }
 }
@@ -12,6 +12,7 @@ pub mod short_url;
 pub mod user_metadata;
 pub mod user_state;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum KaminoLendingAccount {
     UserState(user_state::UserState),
     LendingMarket(lending_market::LendingMarket),
@@ -124,4 +125,25 @@ impl AccountDecoder<'_> for KaminoLendingDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            user_state::UserState,
+            lending_market::LendingMarket,
+            obligation::Obligation,
+            referrer_state::ReferrerState,
+            referrer_token_state::ReferrerTokenState,
+            short_url::ShortUrl,
+            user_metadata::UserMetadata,
+            reserve::Reserve,
+        )
+    }
 }
@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x270fd04d20c36938")]
 pub struct ReferrerTokenState {
     pub referrer: solana_sdk::pubkey::Pubkey,
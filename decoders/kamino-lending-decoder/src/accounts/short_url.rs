@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x1c59ae19e27c7ed4")]
 pub struct ShortUrl {
     pub referrer: solana_sdk::pubkey::Pubkey,
@@ -134,4 +134,52 @@ impl carbon_core::instruction::InstructionDecoder<'_> for KaminoLendingDecoder {
             KaminoLendingInstruction::IdlMissingTypes => idl_missing_types::IdlMissingTypes,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            init_lending_market::InitLendingMarket,
+            update_lending_market::UpdateLendingMarket,
+            update_lending_market_owner::UpdateLendingMarketOwner,
+            init_reserve::InitReserve,
+            init_farms_for_reserve::InitFarmsForReserve,
+            update_reserve_config::UpdateReserveConfig,
+            redeem_fees::RedeemFees,
+            withdraw_protocol_fee::WithdrawProtocolFee,
+            socialize_loss::SocializeLoss,
+            mark_obligation_for_deleveraging::MarkObligationForDeleveraging,
+            refresh_reserve::RefreshReserve,
+            refresh_reserves_batch::RefreshReservesBatch,
+            deposit_reserve_liquidity::DepositReserveLiquidity,
+            redeem_reserve_collateral::RedeemReserveCollateral,
+            init_obligation::InitObligation,
+            init_obligation_farms_for_reserve::InitObligationFarmsForReserve,
+            refresh_obligation_farms_for_reserve::RefreshObligationFarmsForReserve,
+            refresh_obligation::RefreshObligation,
+            deposit_obligation_collateral::DepositObligationCollateral,
+            withdraw_obligation_collateral::WithdrawObligationCollateral,
+            borrow_obligation_liquidity::BorrowObligationLiquidity,
+            repay_obligation_liquidity::RepayObligationLiquidity,
+            repay_and_withdraw_and_redeem::RepayAndWithdrawAndRedeem,
+            deposit_reserve_liquidity_and_obligation_collateral::DepositReserveLiquidityAndObligationCollateral,
+            withdraw_obligation_collateral_and_redeem_reserve_collateral::WithdrawObligationCollateralAndRedeemReserveCollateral,
+            liquidate_obligation_and_redeem_reserve_collateral::LiquidateObligationAndRedeemReserveCollateral,
+            flash_repay_reserve_liquidity::FlashRepayReserveLiquidity,
+            flash_borrow_reserve_liquidity::FlashBorrowReserveLiquidity,
+            request_elevation_group::RequestElevationGroup,
+            init_referrer_token_state::InitReferrerTokenState,
+            init_user_metadata::InitUserMetadata,
+            withdraw_referrer_fees::WithdrawReferrerFees,
+            init_referrer_state_and_short_url::InitReferrerStateAndShortUrl,
+            delete_referrer_state_and_short_url::DeleteReferrerStateAndShortUrl,
+            idl_missing_types::IdlMissingTypes,
+        )
+    }
 }
@@ -3,6 +3,8 @@ use solana_sdk::{pubkey, pubkey::Pubkey};
 pub struct KaminoLendingDecoder;
 pub mod accounts;
 pub mod instructions;
+#[cfg(feature = "math")]
+pub mod math;
 pub mod types;
 
 pub const PROGRAM_ID: Pubkey = pubkey!("KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD");
@@ -0,0 +1,116 @@
+//! Kamino's fixed point formats.
+//!
+//! A `Fraction` is an unsigned 68.60 fixed point number, stored in the `_sf`
+//! (scaled fraction) fields as a `u128`. A `BigFraction`, stored in the
+//! `_bsf` fields as [`BigFractionBytes`], has the same 60 fractional bits in
+//! a 256-bit integer.
+//!
+//! The arithmetic follows the program's: results round down, and operations
+//! return `None` where the program's would overflow.
+
+use {super::U256, crate::types::BigFractionBytes};
+
+/// The number of bits after the point of `Fraction`s and `BigFraction`s.
+pub const FRACTION_BITS: i32 = 60;
+
+/// Converts an integer to a scaled fraction.
+pub fn fraction_from_int(value: u64) -> u128 {
+    (value as u128) << FRACTION_BITS
+}
+
+/// Converts a percentage to a scaled fraction.
+pub fn fraction_from_percent(percent: u64) -> u128 {
+    fraction_from_int(percent) / 100
+}
+
+/// Multiplies two scaled fractions.
+pub fn fraction_mul(a: u128, b: u128) -> Option<u128> {
+    let product = U256::from(a).checked_mul(U256::from(b))?;
+    u128::try_from(product >> FRACTION_BITS as usize).ok()
+}
+
+/// Divides two scaled fractions, or returns `None` if `b` is zero.
+pub fn fraction_div(a: u128, b: u128) -> Option<u128> {
+    let quotient = (U256::from(a) << FRACTION_BITS as usize).checked_div(U256::from(b))?;
+    u128::try_from(quotient).ok()
+}
+
+/// Divides two big scaled fractions into a scaled fraction, or returns
+/// `None` if `b` is zero.
+pub fn big_fraction_div(a: &BigFractionBytes, b: &BigFractionBytes) -> Option<u128> {
+    let a = U256(a.value);
+    if a.leading_zeros() < FRACTION_BITS as u32 {
+        return None;
+    }
+    let quotient = (a << FRACTION_BITS as usize).checked_div(U256(b.value))?;
+    u128::try_from(quotient).ok()
+}
+
+/// Converts a scaled fraction to a float.
+pub fn fraction_to_f64(sf: u128) -> f64 {
+    sf as f64 / 2f64.powi(FRACTION_BITS)
+}
+
+/// Converts a float to a scaled fraction, saturating at the bounds of the
+/// format.
+pub fn f64_to_fraction(value: f64) -> u128 {
+    (value * 2f64.powi(FRACTION_BITS)) as u128
+}
+
+/// Converts a big scaled fraction to a float.
+pub fn big_fraction_to_f64(bsf: &BigFractionBytes) -> f64 {
+    bsf.value
+        .iter()
+        .enumerate()
+        .map(|(limb, value)| *value as f64 * 2f64.powi(64 * limb as i32 - FRACTION_BITS))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fraction_to_f64() {
+        assert_eq!(fraction_to_f64(0), 0.0);
+        assert_eq!(fraction_to_f64(1 << 60), 1.0);
+        assert_eq!(fraction_to_f64(3 << 59), 1.5);
+        assert_eq!(f64_to_fraction(1.5), 3 << 59);
+        assert_eq!(f64_to_fraction(-1.0), 0);
+    }
+
+    #[test]
+    fn test_fraction_arithmetic() {
+        assert_eq!(fraction_from_int(3), 3 << 60);
+        assert_eq!(fraction_from_percent(75), (3 << 60) / 4);
+        assert_eq!(fraction_mul(3 << 59, 5 << 60), Some(15 << 59));
+        assert_eq!(fraction_mul(u128::MAX, 1 << 61), None);
+        assert_eq!(fraction_div(3 << 60, 1 << 61), Some(3 << 59));
+        // A third is truncated to 60 bits.
+        assert_eq!(fraction_div(1 << 60, 3 << 60), Some((1 << 60) / 3));
+        assert_eq!(fraction_div(1 << 60, 0), None);
+    }
+
+    #[test]
+    fn test_big_fraction_to_f64() {
+        let big_fraction = |value: [u64; 4]| BigFractionBytes {
+            value,
+            padding: [0; 2],
+        };
+
+        assert_eq!(big_fraction_to_f64(&big_fraction([1 << 60, 0, 0, 0])), 1.0);
+        assert_eq!(big_fraction_to_f64(&big_fraction([0, 1, 0, 0])), 16.0);
+        assert_eq!(big_fraction_to_f64(&big_fraction([1 << 59, 1, 0, 0])), 16.5);
+        assert_eq!(
+            big_fraction_div(
+                &big_fraction([1 << 59, 1, 0, 0]),
+                &big_fraction([0, 1, 0, 0])
+            ),
+            Some(33 << 55)
+        );
+        assert_eq!(
+            big_fraction_div(&big_fraction([1, 0, 0, 0]), &big_fraction([0; 4])),
+            None
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! Token amounts, market values, LTV and health of obligations, from the
+//! scaled fractions of obligations and reserves.
+//!
+//! Values are in the quote currency of the reserves' oracle prices, usually
+//! USD. Amounts and values are accumulated as scaled fractions, with the
+//! program's arithmetic, and token amounts are in the token's smallest unit;
+//! floats are only used for display amounts and ratios.
+
+pub mod fraction;
+pub mod obligation;
+
+pub use obligation::{ObligationValuation, PositionValue};
+
+use u256::U256;
+
+mod u256 {
+    // The expansion of `construct_uint!` rounds divisions up by hand.
+    #![allow(clippy::manual_div_ceil)]
+
+    uint::construct_uint! {
+        /// Holds big fractions and the intermediate products of the fraction
+        /// arithmetic.
+        pub struct U256(4);
+    }
+}
@@ -0,0 +1,342 @@
+//! Valuation of obligations against the current state of their reserves.
+
+use {
+    super::fraction::{
+        big_fraction_div, fraction_div, fraction_from_int, fraction_from_percent, fraction_mul,
+        fraction_to_f64,
+    },
+    crate::accounts::{obligation::Obligation, reserve::Reserve},
+    solana_sdk::pubkey::Pubkey,
+    std::collections::HashMap,
+};
+
+/// A deposit or borrow of an obligation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PositionValue {
+    pub reserve: Pubkey,
+    pub mint: Pubkey,
+    /// The amount of the reserve's liquidity token, in its smallest unit, as
+    /// a scaled fraction.
+    pub amount_sf: u128,
+    pub market_value_sf: u128,
+    /// The amount of the reserve's liquidity token, in whole tokens, for
+    /// display.
+    pub ui_amount: f64,
+}
+
+/// The deposits and borrows of an obligation, and the values its health is
+/// computed from, as scaled fractions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObligationValuation {
+    pub deposits: Vec<PositionValue>,
+    pub borrows: Vec<PositionValue>,
+    pub deposited_value_sf: u128,
+    pub borrowed_value_sf: u128,
+    /// The borrowed value, weighted by the reserves' borrow factors.
+    pub borrow_factor_adjusted_debt_value_sf: u128,
+    /// The deposited value, weighted by the reserves' loan to value ratios.
+    pub allowed_borrow_value_sf: u128,
+    /// The deposited value, weighted by the reserves' liquidation
+    /// thresholds.
+    pub unhealthy_borrow_value_sf: u128,
+}
+
+impl ObligationValuation {
+    /// Returns the ratio of the borrowed value to the deposited value, or
+    /// `None` without deposits.
+    pub fn ltv(&self) -> Option<f64> {
+        ratio(self.borrowed_value_sf, self.deposited_value_sf)
+    }
+
+    /// Returns the ratio of the unhealthy borrow value to the borrow factor
+    /// adjusted debt: the obligation can be liquidated below 1. `None`
+    /// without debt.
+    pub fn health_factor(&self) -> Option<f64> {
+        ratio(
+            self.unhealthy_borrow_value_sf,
+            self.borrow_factor_adjusted_debt_value_sf,
+        )
+    }
+}
+
+impl Reserve {
+    /// Returns the liquidity the reserve holds for depositors, lent out
+    /// liquidity included and fees excluded, in the token's smallest unit,
+    /// as a scaled fraction.
+    pub fn total_supply(&self) -> Option<u128> {
+        let liquidity = &self.liquidity;
+        fraction_from_int(liquidity.available_amount)
+            .checked_add(liquidity.borrowed_amount_sf)?
+            .checked_sub(liquidity.accumulated_protocol_fees_sf)?
+            .checked_sub(liquidity.accumulated_referrer_fees_sf)?
+            .checked_sub(liquidity.pending_referrer_fees_sf)
+    }
+
+    /// Returns the amount of collateral tokens each liquidity token mints,
+    /// as a scaled fraction.
+    pub fn collateral_exchange_rate(&self) -> Option<u128> {
+        let total_supply = self.total_supply()?;
+        if self.collateral.mint_total_supply == 0 || total_supply == 0 {
+            Some(fraction_from_int(1))
+        } else {
+            fraction_div(
+                fraction_from_int(self.collateral.mint_total_supply),
+                total_supply,
+            )
+        }
+    }
+
+    /// Returns the oracle price of a whole liquidity token, as of the
+    /// reserve's last refresh, for display.
+    pub fn price(&self) -> f64 {
+        fraction_to_f64(self.liquidity.market_price_sf)
+    }
+
+    fn position(&self, reserve: Pubkey, amount_sf: u128) -> Option<PositionValue> {
+        let decimal_factor = 10u128.checked_pow(self.liquidity.mint_decimals.try_into().ok()?)?;
+        let market_value_sf =
+            fraction_mul(amount_sf, self.liquidity.market_price_sf)? / decimal_factor;
+
+        Some(PositionValue {
+            reserve,
+            mint: self.liquidity.mint_pubkey,
+            amount_sf,
+            market_value_sf,
+            ui_amount: fraction_to_f64(amount_sf) / decimal_factor as f64,
+        })
+    }
+}
+
+impl Obligation {
+    /// Values the obligation's deposits and borrows at the current exchange
+    /// rates, cumulative borrow rates and prices of `reserves`, keyed by
+    /// address, with the program's fraction arithmetic.
+    ///
+    /// Deposits are weighted by the reserves' own loan to value ratios and
+    /// liquidation thresholds, not those of the obligation's elevation
+    /// group. Returns `None` if a reserve of the obligation is missing or a
+    /// value overflows.
+    pub fn valuation(&self, reserves: &HashMap<Pubkey, Reserve>) -> Option<ObligationValuation> {
+        let mut valuation = ObligationValuation {
+            deposits: Vec::new(),
+            borrows: Vec::new(),
+            deposited_value_sf: 0,
+            borrowed_value_sf: 0,
+            borrow_factor_adjusted_debt_value_sf: 0,
+            allowed_borrow_value_sf: 0,
+            unhealthy_borrow_value_sf: 0,
+        };
+
+        for deposit in &self.deposits {
+            if deposit.deposit_reserve == Pubkey::default() {
+                continue;
+            }
+            let reserve = reserves.get(&deposit.deposit_reserve)?;
+            let amount_sf = fraction_div(
+                fraction_from_int(deposit.deposited_amount),
+                reserve.collateral_exchange_rate()?,
+            )?;
+            let position = reserve.position(deposit.deposit_reserve, amount_sf)?;
+            let allowed_borrow_value_sf = fraction_mul(
+                position.market_value_sf,
+                fraction_from_percent(reserve.config.loan_to_value_pct as u64),
+            )?;
+            let unhealthy_borrow_value_sf = fraction_mul(
+                position.market_value_sf,
+                fraction_from_percent(reserve.config.liquidation_threshold_pct as u64),
+            )?;
+
+            valuation.deposited_value_sf = valuation
+                .deposited_value_sf
+                .checked_add(position.market_value_sf)?;
+            valuation.allowed_borrow_value_sf = valuation
+                .allowed_borrow_value_sf
+                .checked_add(allowed_borrow_value_sf)?;
+            valuation.unhealthy_borrow_value_sf = valuation
+                .unhealthy_borrow_value_sf
+                .checked_add(unhealthy_borrow_value_sf)?;
+            valuation.deposits.push(position);
+        }
+
+        for borrow in &self.borrows {
+            if borrow.borrow_reserve == Pubkey::default() {
+                continue;
+            }
+            let reserve = reserves.get(&borrow.borrow_reserve)?;
+
+            // Borrows grow with the reserve's cumulative borrow rate since
+            // the obligation was last refreshed.
+            let amount_sf = match big_fraction_div(
+                &reserve.liquidity.cumulative_borrow_rate_bsf,
+                &borrow.cumulative_borrow_rate_bsf,
+            ) {
+                Some(interest) if interest > fraction_from_int(1) => {
+                    fraction_mul(borrow.borrowed_amount_sf, interest)?
+                }
+                _ => borrow.borrowed_amount_sf,
+            };
+            let position = reserve.position(borrow.borrow_reserve, amount_sf)?;
+            let borrow_factor_adjusted_debt_value_sf = fraction_mul(
+                position.market_value_sf,
+                fraction_from_percent(reserve.config.borrow_factor_pct.max(100)),
+            )?;
+
+            valuation.borrowed_value_sf = valuation
+                .borrowed_value_sf
+                .checked_add(position.market_value_sf)?;
+            valuation.borrow_factor_adjusted_debt_value_sf = valuation
+                .borrow_factor_adjusted_debt_value_sf
+                .checked_add(borrow_factor_adjusted_debt_value_sf)?;
+            valuation.borrows.push(position);
+        }
+
+        Some(valuation)
+    }
+
+    /// Returns the LTV the obligation stored at its last refresh.
+    pub fn ltv(&self) -> Option<f64> {
+        ratio(
+            self.borrowed_assets_market_value_sf,
+            self.deposited_value_sf,
+        )
+    }
+
+    /// Returns the health factor of the values the obligation stored at its
+    /// last refresh, which account for its elevation group.
+    pub fn health_factor(&self) -> Option<f64> {
+        ratio(
+            self.unhealthy_borrow_value_sf,
+            self.borrow_factor_adjusted_debt_value_sf,
+        )
+    }
+}
+
+fn ratio(numerator_sf: u128, denominator_sf: u128) -> Option<f64> {
+    (denominator_sf > 0).then(|| numerator_sf as f64 / denominator_sf as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            accounts::KaminoLendingAccount, math::fraction::f64_to_fraction, KaminoLendingDecoder,
+        },
+        carbon_core::account::AccountDecoder,
+    };
+
+    const SOL_RESERVE: Pubkey = Pubkey::new_from_array([1; 32]);
+    const USDC_RESERVE: Pubkey = Pubkey::new_from_array([2; 32]);
+
+    // The fixtures hold a SOL reserve priced at 150 with 1.2 * 10^12
+    // collateral tokens for 1.5 * 10^12 lamports of liquidity, a USDC reserve
+    // whose cumulative borrow rate is 1.1, and an obligation depositing 10^10
+    // of the SOL collateral tokens and borrowing 1000 USDC at a rate of 1.
+    fn decode_account(path: &str) -> KaminoLendingAccount {
+        let account = carbon_test_utils::read_account(path).expect("read fixture");
+
+        KaminoLendingDecoder
+            .decode_account(&account)
+            .expect("decode fixture")
+            .data
+    }
+
+    fn reserve(path: &str) -> Reserve {
+        match decode_account(path) {
+            KaminoLendingAccount::Reserve(reserve) => reserve,
+            account => panic!("expected a reserve, got {account:?}"),
+        }
+    }
+
+    fn reserves() -> HashMap<Pubkey, Reserve> {
+        HashMap::from([
+            (
+                SOL_RESERVE,
+                reserve("tests/fixtures/sol_reserve_account.json"),
+            ),
+            (
+                USDC_RESERVE,
+                reserve("tests/fixtures/usdc_reserve_account.json"),
+            ),
+        ])
+    }
+
+    fn obligation() -> Obligation {
+        match decode_account("tests/fixtures/obligation_account.json") {
+            KaminoLendingAccount::Obligation(obligation) => obligation,
+            account => panic!("expected an obligation, got {account:?}"),
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{actual} is not {expected}"
+        );
+    }
+
+    #[test]
+    fn test_reserve() {
+        let reserves = reserves();
+        let sol = &reserves[&SOL_RESERVE];
+
+        assert_eq!(sol.total_supply(), Some(1_500_000_000_000 << 60));
+        // 0.8, truncated to 60 bits.
+        assert_eq!(sol.collateral_exchange_rate(), Some(922337203685477580));
+        assert_close(sol.price(), 150.0);
+    }
+
+    #[test]
+    fn test_valuation() {
+        let valuation = obligation().valuation(&reserves()).unwrap();
+
+        // The truncated exchange rate redeems the deposit for slightly more
+        // than 12.5 SOL.
+        assert_eq!(valuation.deposits.len(), 1);
+        assert_eq!(
+            valuation.deposits[0].amount_sf,
+            14411518807585587212500000000
+        );
+        assert_eq!(
+            valuation.deposits[0].market_value_sf,
+            2161727821137838081875
+        );
+        assert_close(valuation.deposits[0].ui_amount, 12.5);
+        assert_eq!(valuation.borrows.len(), 1);
+        assert_eq!(valuation.borrows[0].amount_sf, 1268213655067531776000000000);
+        assert_close(valuation.borrows[0].ui_amount, 1_100.0);
+
+        assert_eq!(valuation.deposited_value_sf, 2161727821137838081875);
+        assert_eq!(valuation.borrowed_value_sf, 1268213655067531776000);
+        assert_eq!(
+            valuation.borrow_factor_adjusted_debt_value_sf,
+            1268213655067531776000
+        );
+        assert_eq!(valuation.allowed_borrow_value_sf, 1621295865853378561406);
+        assert_eq!(valuation.unhealthy_borrow_value_sf, 1837468647967162368468);
+        assert_close(valuation.ltv().unwrap(), 1_100.0 / 1_875.0);
+        assert_close(valuation.health_factor().unwrap(), 1_593.75 / 1_100.0);
+    }
+
+    #[test]
+    fn test_valuation_missing_reserve() {
+        let mut reserves = reserves();
+        reserves.remove(&USDC_RESERVE);
+
+        assert_eq!(obligation().valuation(&reserves), None);
+    }
+
+    #[test]
+    fn test_stored_health() {
+        let mut obligation = obligation();
+        assert_eq!(obligation.ltv(), None);
+        assert_eq!(obligation.health_factor(), None);
+
+        obligation.deposited_value_sf = f64_to_fraction(2_000.0);
+        obligation.borrowed_assets_market_value_sf = f64_to_fraction(1_000.0);
+        obligation.borrow_factor_adjusted_debt_value_sf = f64_to_fraction(1_200.0);
+        obligation.unhealthy_borrow_value_sf = f64_to_fraction(1_800.0);
+        assert_close(obligation.ltv().unwrap(), 0.5);
+        assert_close(obligation.health_factor().unwrap(), 1.5);
+    }
+}
@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x95089ccaa0fcb0d9")]
 pub struct GlobalConfig {
     pub emergency_mode: u8,
@@ -19,5 +19,6 @@ pub struct GlobalConfig {
     pub pda_authority_bump: u64,
     pub admin_authority: solana_sdk::pubkey::Pubkey,
     pub admin_authority_cached: solana_sdk::pubkey::Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
     pub padding2: [u64; 243],
 }
@@ -6,6 +6,7 @@ use {
 pub mod global_config;
 pub mod order;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum KaminoLimitOrderAccount {
     Order(order::Order),
     GlobalConfig(global_config::GlobalConfig),
@@ -44,4 +45,19 @@ impl<'a> AccountDecoder<'a> for KaminoLimitOrderDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            order::Order,
+            global_config::GlobalConfig,
+        )
+    }
 }
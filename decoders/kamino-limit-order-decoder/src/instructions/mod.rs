@@ -65,4 +65,30 @@ impl<'a> carbon_core::instruction::InstructionDecoder<'a> for KaminoLimitOrderDe
             KaminoLimitOrderInstruction::UserSwapBalancesEvent => user_swap_balances_event::UserSwapBalancesEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            initialize_global_config::InitializeGlobalConfig,
+            initialize_vault::InitializeVault,
+            create_order::CreateOrder,
+            close_order_and_claim_tip::CloseOrderAndClaimTip,
+            take_order::TakeOrder,
+            flash_take_order_start::FlashTakeOrderStart,
+            flash_take_order_end::FlashTakeOrderEnd,
+            update_global_config::UpdateGlobalConfig,
+            update_global_config_admin::UpdateGlobalConfigAdmin,
+            withdraw_host_tip::WithdrawHostTip,
+            log_user_swap_balances::LogUserSwapBalances,
+            order_display_event::OrderDisplayEvent,
+            user_swap_balances_event::UserSwapBalancesEvent,
+        )
+    }
 }
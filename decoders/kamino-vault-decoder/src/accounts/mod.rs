@@ -6,6 +6,7 @@ use {
 pub mod reserve;
 pub mod vault_state;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum KaminoVaultAccount {
     Reserve(reserve::Reserve),
     VaultState(vault_state::VaultState),
@@ -44,4 +45,19 @@ impl AccountDecoder<'_> for KaminoVaultDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            reserve::Reserve,
+            vault_state::VaultState,
+        )
+    }
 }
@@ -3,7 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x2bf2ccca1af73b7f")]
 pub struct Reserve {
     pub version: u64,
@@ -12,12 +12,16 @@ pub struct Reserve {
     pub farm_collateral: solana_sdk::pubkey::Pubkey,
     pub farm_debt: solana_sdk::pubkey::Pubkey,
     pub liquidity: ReserveLiquidity,
+    #[serde(with = "serde_big_array::BigArray")]
     pub reserve_liquidity_padding: [u64; 150],
     pub collateral: ReserveCollateral,
+    #[serde(with = "serde_big_array::BigArray")]
     pub reserve_collateral_padding: [u64; 150],
     pub config: ReserveConfig,
+    #[serde(with = "serde_big_array::BigArray")]
     pub config_padding: [u64; 116],
     pub borrowed_amount_outside_elevation_group: u64,
     pub borrowed_amounts_against_this_reserve_in_elevation_groups: [u64; 32],
+    #[serde(with = "serde_big_array::BigArray")]
     pub padding: [u64; 207],
 }
@@ -3,7 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0xe4c452a562d2eb98")]
 pub struct VaultState {
     pub admin_authority: solana_sdk::pubkey::Pubkey,
@@ -34,8 +34,10 @@ pub struct VaultState {
     pub cumulative_earned_interest_sf: u128,
     pub cumulative_mgmt_fees_sf: u128,
     pub cumulative_perf_fees_sf: u128,
+    #[serde(with = "serde_big_array::BigArray")]
     pub name: [u8; 40],
     pub vault_lookup_table: solana_sdk::pubkey::Pubkey,
     pub vault_farm: solana_sdk::pubkey::Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
     pub padding2: [u128; 245],
 }
@@ -65,4 +65,29 @@ impl carbon_core::instruction::InstructionDecoder<'_> for KaminoVaultDecoder {
             KaminoVaultInstruction::WithdrawFromAvailable => withdraw_from_available::WithdrawFromAvailable,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            init_vault::InitVault,
+            update_reserve_allocation::UpdateReserveAllocation,
+            deposit::Deposit,
+            withdraw::Withdraw,
+            invest::Invest,
+            update_vault_config::UpdateVaultConfig,
+            withdraw_pending_fees::WithdrawPendingFees,
+            update_admin::UpdateAdmin,
+            give_up_pending_fees::GiveUpPendingFees,
+            initialize_shares_metadata::InitializeSharesMetadata,
+            update_shares_metadata::UpdateSharesMetadata,
+            withdraw_from_available::WithdrawFromAvailable,
+        )
+    }
 }
@@ -6,6 +6,7 @@ use crate::PROGRAM_ID;
 use super::LifinityAmmV2Decoder;
 pub mod amm;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum LifinityAmmV2Account {
     Amm(amm::Amm),
 }
@@ -32,6 +33,17 @@ impl<'a> AccountDecoder<'a> for LifinityAmmV2Decoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(account.data.as_slice(), amm::Amm,)
+    }
 }
 
 #[cfg(test)]
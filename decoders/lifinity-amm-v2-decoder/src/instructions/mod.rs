@@ -38,6 +38,22 @@ impl<'a> carbon_core::instruction::InstructionDecoder<'a> for LifinityAmmV2Decod
             LifinityAmmV2Instruction::WithdrawAllTokenTypes => withdraw_all_token_types::WithdrawAllTokenTypes,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            swap::Swap,
+            deposit_all_token_types::DepositAllTokenTypes,
+            withdraw_all_token_types::WithdrawAllTokenTypes,
+        )
+    }
 }
 
 #[cfg(test)]
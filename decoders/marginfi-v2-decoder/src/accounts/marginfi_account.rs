@@ -3,12 +3,13 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x43b2826d7e721c2a")]
 pub struct MarginfiAccount {
     pub group: solana_sdk::pubkey::Pubkey,
     pub authority: solana_sdk::pubkey::Pubkey,
     pub lending_account: LendingAccount,
     pub account_flags: u64,
+    #[serde(with = "serde_big_array::BigArray")]
     pub padding: [u64; 63],
 }
@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0xb617adf097ceb643")]
 pub struct MarginfiGroup {
     pub admin: solana_sdk::pubkey::Pubkey,
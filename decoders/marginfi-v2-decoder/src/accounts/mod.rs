@@ -7,6 +7,7 @@ pub mod bank;
 pub mod marginfi_account;
 pub mod marginfi_group;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum MarginfiV2Account {
     MarginfiAccount(marginfi_account::MarginfiAccount),
     MarginfiGroup(marginfi_group::MarginfiGroup),
@@ -58,4 +59,20 @@ impl<'a> AccountDecoder<'a> for MarginfiV2Decoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            marginfi_account::MarginfiAccount,
+            marginfi_group::MarginfiGroup,
+            bank::Bank,
+        )
+    }
 }
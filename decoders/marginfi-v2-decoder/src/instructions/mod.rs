@@ -133,4 +133,55 @@ impl<'a> carbon_core::instruction::InstructionDecoder<'a> for MarginfiV2Decoder
             MarginfiV2Instruction::MarginfiAccountTransferAccountAuthorityEvent => marginfi_account_transfer_account_authority_event::MarginfiAccountTransferAccountAuthorityEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            marginfi_group_initialize::MarginfiGroupInitialize,
+            marginfi_group_configure::MarginfiGroupConfigure,
+            lending_pool_add_bank::LendingPoolAddBank,
+            lending_pool_add_bank_with_seed::LendingPoolAddBankWithSeed,
+            lending_pool_configure_bank::LendingPoolConfigureBank,
+            lending_pool_setup_emissions::LendingPoolSetupEmissions,
+            lending_pool_update_emissions_parameters::LendingPoolUpdateEmissionsParameters,
+            lending_pool_handle_bankruptcy::LendingPoolHandleBankruptcy,
+            marginfi_account_initialize::MarginfiAccountInitialize,
+            lending_account_deposit::LendingAccountDeposit,
+            lending_account_repay::LendingAccountRepay,
+            lending_account_withdraw::LendingAccountWithdraw,
+            lending_account_borrow::LendingAccountBorrow,
+            lending_account_close_balance::LendingAccountCloseBalance,
+            lending_account_withdraw_emissions::LendingAccountWithdrawEmissions,
+            lending_account_settle_emissions::LendingAccountSettleEmissions,
+            lending_account_liquidate::LendingAccountLiquidate,
+            lending_account_start_flashloan::LendingAccountStartFlashloan,
+            lending_account_end_flashloan::LendingAccountEndFlashloan,
+            lending_pool_accrue_bank_interest::LendingPoolAccrueBankInterest,
+            lending_pool_collect_bank_fees::LendingPoolCollectBankFees,
+            set_account_flag::SetAccountFlag,
+            unset_account_flag::UnsetAccountFlag,
+            set_new_account_authority::SetNewAccountAuthority,
+            marginfi_group_create_event::MarginfiGroupCreateEvent,
+            marginfi_group_configure_event::MarginfiGroupConfigureEvent,
+            lending_pool_bank_create_event::LendingPoolBankCreateEvent,
+            lending_pool_bank_configure_event::LendingPoolBankConfigureEvent,
+            lending_pool_bank_accrue_interest_event::LendingPoolBankAccrueInterestEvent,
+            lending_pool_bank_collect_fees_event::LendingPoolBankCollectFeesEvent,
+            lending_pool_bank_handle_bankruptcy_event::LendingPoolBankHandleBankruptcyEvent,
+            marginfi_account_create_event::MarginfiAccountCreateEvent,
+            lending_account_deposit_event::LendingAccountDepositEvent,
+            lending_account_repay_event::LendingAccountRepayEvent,
+            lending_account_borrow_event::LendingAccountBorrowEvent,
+            lending_account_withdraw_event::LendingAccountWithdrawEvent,
+            lending_account_liquidate_event::LendingAccountLiquidateEvent,
+            marginfi_account_transfer_account_authority_event::MarginfiAccountTransferAccountAuthorityEvent,
+        )
+    }
 }
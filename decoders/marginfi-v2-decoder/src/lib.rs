@@ -3,6 +3,8 @@ use solana_sdk::{pubkey, pubkey::Pubkey};
 pub struct MarginfiV2Decoder;
 pub mod accounts;
 pub mod instructions;
+#[cfg(feature = "math")]
+pub mod math;
 pub mod types;
 
 pub const PROGRAM_ID: Pubkey = pubkey!("MFv2hWf31Z9kbCa1snEPYctwafyhdvnV7FZnsebVacA");
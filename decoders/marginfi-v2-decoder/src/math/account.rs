@@ -0,0 +1,382 @@
+//! Valuation of margin accounts against the current state of their banks.
+
+use {
+    crate::{
+        accounts::{bank::Bank, marginfi_account::MarginfiAccount},
+        types::{RequirementType, RiskTier, WrappedI80F48},
+    },
+    solana_sdk::pubkey::Pubkey,
+    std::collections::HashMap,
+};
+
+/// An asset or liability balance of a margin account.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceValue {
+    pub bank: Pubkey,
+    pub mint: Pubkey,
+    /// The amount of the bank's token, in its smallest unit.
+    pub amount: WrappedI80F48,
+    pub value: WrappedI80F48,
+    /// The value, weighted by the bank's risk weight for the requirement
+    /// type the account was valued at.
+    pub weighted_value: WrappedI80F48,
+    /// The amount of the bank's token, in whole tokens, for display.
+    pub ui_amount: f64,
+}
+
+/// The balances of a margin account and the values its health is computed
+/// from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountValuation {
+    pub assets: Vec<BalanceValue>,
+    pub liabilities: Vec<BalanceValue>,
+    pub asset_value: WrappedI80F48,
+    pub liability_value: WrappedI80F48,
+    pub weighted_asset_value: WrappedI80F48,
+    pub weighted_liability_value: WrappedI80F48,
+}
+
+impl AccountValuation {
+    /// Returns the ratio of the liability value to the asset value, or
+    /// `None` without assets.
+    pub fn ltv(&self) -> Option<f64> {
+        ratio(&self.liability_value, &self.asset_value)
+    }
+
+    /// Returns the ratio of the weighted asset value to the weighted
+    /// liability value: at maintenance weights, the account can be
+    /// liquidated below 1. `None` without liabilities.
+    pub fn health_factor(&self) -> Option<f64> {
+        ratio(&self.weighted_asset_value, &self.weighted_liability_value)
+    }
+
+    /// Returns the weighted asset value less the weighted liability value,
+    /// which the program requires to be positive.
+    pub fn health(&self) -> Option<WrappedI80F48> {
+        self.weighted_asset_value
+            .checked_sub(&self.weighted_liability_value)
+    }
+}
+
+impl Bank {
+    /// Returns the amount of tokens `shares` of the bank's assets are worth,
+    /// in the token's smallest unit.
+    pub fn asset_amount(&self, shares: &WrappedI80F48) -> Option<WrappedI80F48> {
+        shares.checked_mul(&self.asset_share_value)
+    }
+
+    /// Returns the amount of tokens `shares` of the bank's liabilities are
+    /// worth, in the token's smallest unit.
+    pub fn liability_amount(&self, shares: &WrappedI80F48) -> Option<WrappedI80F48> {
+        shares.checked_mul(&self.liability_share_value)
+    }
+
+    /// Returns the weight of the bank's assets at `requirement_type`, given
+    /// the price of a whole token.
+    ///
+    /// Isolated banks' assets don't count as collateral, and the initial
+    /// weight is discounted once the bank's total assets are worth more than
+    /// its `total_asset_value_init_limit`.
+    pub fn asset_weight(
+        &self,
+        requirement_type: &RequirementType,
+        price: &WrappedI80F48,
+    ) -> Option<WrappedI80F48> {
+        let config = &self.config;
+        match requirement_type {
+            RequirementType::Equity => Some(WrappedI80F48::ONE),
+            _ if config.risk_tier == RiskTier::Isolated => Some(WrappedI80F48::ZERO),
+            RequirementType::Maintenance => Some(config.asset_weight_maint.clone()),
+            RequirementType::Initial => {
+                let weight = &config.asset_weight_init;
+                if config.total_asset_value_init_limit == 0 {
+                    return Some(weight.clone());
+                }
+                let limit = WrappedI80F48::from_int(config.total_asset_value_init_limit);
+                let total_asset_value =
+                    self.value(&self.asset_amount(&self.total_asset_shares)?, price)?;
+                if total_asset_value.value > limit.value {
+                    weight.checked_mul(&limit.checked_div(&total_asset_value)?)
+                } else {
+                    Some(weight.clone())
+                }
+            }
+        }
+    }
+
+    /// Returns the weight of the bank's liabilities at `requirement_type`.
+    pub fn liability_weight(&self, requirement_type: &RequirementType) -> WrappedI80F48 {
+        match requirement_type {
+            RequirementType::Initial => self.config.liability_weight_init.clone(),
+            RequirementType::Maintenance => self.config.liability_weight_maint.clone(),
+            RequirementType::Equity => WrappedI80F48::ONE,
+        }
+    }
+
+    /// Returns the value of `amount`, in the token's smallest unit, at the
+    /// price of a whole token.
+    fn value(&self, amount: &WrappedI80F48, price: &WrappedI80F48) -> Option<WrappedI80F48> {
+        amount
+            .checked_mul(price)?
+            .checked_div(&self.decimal_factor()?)
+    }
+
+    /// Values `amount` like the program: the weight is applied to the amount
+    /// before the price.
+    fn balance_value(
+        &self,
+        bank: Pubkey,
+        amount: WrappedI80F48,
+        price: &WrappedI80F48,
+        weight: &WrappedI80F48,
+    ) -> Option<BalanceValue> {
+        let value = self.value(&amount, price)?;
+        let weighted_value = self.value(&amount.checked_mul(weight)?, price)?;
+
+        Some(BalanceValue {
+            bank,
+            mint: self.mint,
+            ui_amount: amount.to_f64() / self.decimal_factor()?.to_f64(),
+            amount,
+            value,
+            weighted_value,
+        })
+    }
+
+    fn decimal_factor(&self) -> Option<WrappedI80F48> {
+        Some(WrappedI80F48::from_int(
+            10u64.checked_pow(self.mint_decimals as u32)?,
+        ))
+    }
+}
+
+impl MarginfiAccount {
+    /// Values the account's active balances at the current share values of
+    /// `banks`, keyed by address, and the prices of a whole token in
+    /// `prices`, keyed by mint, weighted for `requirement_type`, with the
+    /// program's `I80F48` arithmetic.
+    ///
+    /// Returns `None` if a bank or price is missing or a value overflows.
+    pub fn valuation(
+        &self,
+        banks: &HashMap<Pubkey, Bank>,
+        prices: &HashMap<Pubkey, WrappedI80F48>,
+        requirement_type: RequirementType,
+    ) -> Option<AccountValuation> {
+        let mut valuation = AccountValuation {
+            assets: Vec::new(),
+            liabilities: Vec::new(),
+            asset_value: WrappedI80F48::ZERO,
+            liability_value: WrappedI80F48::ZERO,
+            weighted_asset_value: WrappedI80F48::ZERO,
+            weighted_liability_value: WrappedI80F48::ZERO,
+        };
+
+        for balance in &self.lending_account.balances {
+            if !balance.active {
+                continue;
+            }
+            let bank = banks.get(&balance.bank_pk)?;
+            let price = prices.get(&bank.mint)?;
+
+            if balance.asset_shares.value > 0 {
+                let asset = bank.balance_value(
+                    balance.bank_pk,
+                    bank.asset_amount(&balance.asset_shares)?,
+                    price,
+                    &bank.asset_weight(&requirement_type, price)?,
+                )?;
+
+                valuation.asset_value = valuation.asset_value.checked_add(&asset.value)?;
+                valuation.weighted_asset_value = valuation
+                    .weighted_asset_value
+                    .checked_add(&asset.weighted_value)?;
+                valuation.assets.push(asset);
+            }
+
+            if balance.liability_shares.value > 0 {
+                let liability = bank.balance_value(
+                    balance.bank_pk,
+                    bank.liability_amount(&balance.liability_shares)?,
+                    price,
+                    &bank.liability_weight(&requirement_type),
+                )?;
+
+                valuation.liability_value =
+                    valuation.liability_value.checked_add(&liability.value)?;
+                valuation.weighted_liability_value = valuation
+                    .weighted_liability_value
+                    .checked_add(&liability.weighted_value)?;
+                valuation.liabilities.push(liability);
+            }
+        }
+
+        Some(valuation)
+    }
+}
+
+fn ratio(numerator: &WrappedI80F48, denominator: &WrappedI80F48) -> Option<f64> {
+    (denominator.value > 0).then(|| numerator.value as f64 / denominator.value as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{accounts::MarginfiV2Account, MarginfiV2Decoder},
+        carbon_core::account::AccountDecoder,
+    };
+
+    const SOL_BANK: Pubkey = Pubkey::new_from_array([1; 32]);
+    const USDC_BANK: Pubkey = Pubkey::new_from_array([2; 32]);
+    const SOL_MINT: Pubkey = Pubkey::new_from_array([3; 32]);
+    const USDC_MINT: Pubkey = Pubkey::new_from_array([4; 32]);
+
+    // The fixtures hold a SOL bank with an asset share value of 1.1 and 110
+    // SOL of assets, a USDC bank with a liability share value of 1.05, and an
+    // account with 10^10 shares of SOL assets and 10^9 shares of USDC
+    // liabilities. The share values are truncated to 48 fractional bits, so
+    // the amounts and values fall slightly below the round figures in the
+    // comments.
+    fn decode_account(path: &str) -> MarginfiV2Account {
+        let account = carbon_test_utils::read_account(path).expect("read fixture");
+
+        MarginfiV2Decoder
+            .decode_account(&account)
+            .expect("decode fixture")
+            .data
+    }
+
+    fn bank(path: &str) -> Bank {
+        match decode_account(path) {
+            MarginfiV2Account::Bank(bank) => bank,
+            account => panic!("expected a bank, got {account:?}"),
+        }
+    }
+
+    fn banks() -> HashMap<Pubkey, Bank> {
+        HashMap::from([
+            (SOL_BANK, bank("tests/fixtures/sol_bank_account.json")),
+            (USDC_BANK, bank("tests/fixtures/usdc_bank_account.json")),
+        ])
+    }
+
+    fn prices() -> HashMap<Pubkey, WrappedI80F48> {
+        HashMap::from([
+            (SOL_MINT, WrappedI80F48::from_int(150)),
+            (USDC_MINT, WrappedI80F48::from_int(1)),
+        ])
+    }
+
+    fn marginfi_account() -> MarginfiAccount {
+        match decode_account("tests/fixtures/marginfi_account.json") {
+            MarginfiV2Account::MarginfiAccount(account) => account,
+            account => panic!("expected a margin account, got {account:?}"),
+        }
+    }
+
+    fn value(value: i128) -> WrappedI80F48 {
+        WrappedI80F48 { value }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "{actual} is not {expected}"
+        );
+    }
+
+    #[test]
+    fn test_valuation() {
+        let valuation = marginfi_account()
+            .valuation(&banks(), &prices(), RequirementType::Maintenance)
+            .unwrap();
+
+        assert_eq!(valuation.assets.len(), 1);
+        assert_eq!(valuation.assets[0].amount, value(3096224743817210000000000));
+        assert_eq!(valuation.assets[0].value, value(464433711572581500));
+        assert_close(valuation.assets[0].ui_amount, 11.0);
+        assert_eq!(valuation.liabilities.len(), 1);
+        assert_eq!(
+            valuation.liabilities[0].amount,
+            value(295548725546188000000000)
+        );
+        assert_close(valuation.liabilities[0].ui_amount, 1_050.0);
+
+        // $1,650, $1,050, $1,485 and $1,155.
+        assert_eq!(valuation.asset_value, value(464433711572581500));
+        assert_eq!(valuation.liability_value, value(295548725546188000));
+        assert_eq!(valuation.weighted_asset_value, value(417990340415322690));
+        assert_eq!(
+            valuation.weighted_liability_value,
+            value(325103598100806170)
+        );
+        assert_eq!(valuation.health(), Some(value(92886742314516520)));
+        assert_close(valuation.ltv().unwrap(), 1_050.0 / 1_650.0);
+        assert_close(valuation.health_factor().unwrap(), 1_485.0 / 1_155.0);
+    }
+
+    #[test]
+    fn test_valuation_requirement_types() {
+        let mut banks = banks();
+        let account = marginfi_account();
+
+        // $1,320 and $1,260.
+        let initial = account
+            .valuation(&banks, &prices(), RequirementType::Initial)
+            .unwrap();
+        assert_eq!(initial.weighted_asset_value, value(371546969258063880));
+        assert_eq!(initial.weighted_liability_value, value(354658470655425390));
+
+        // $600.
+        let equity = account
+            .valuation(&banks, &prices(), RequirementType::Equity)
+            .unwrap();
+        assert_eq!(equity.health(), Some(value(168884986026393500)));
+
+        // The bank's 110 SOL are worth $16,500, over its $1,000 limit, which
+        // discounts the $1,320 to $80.
+        let sol = banks.get_mut(&SOL_BANK).unwrap();
+        sol.config.total_asset_value_init_limit = 1_000;
+        let initial = account
+            .valuation(&banks, &prices(), RequirementType::Initial)
+            .unwrap();
+        assert_eq!(initial.weighted_asset_value, value(22517998136850106));
+
+        let sol = banks.get_mut(&SOL_BANK).unwrap();
+        sol.config.risk_tier = RiskTier::Isolated;
+        let maintenance = account
+            .valuation(&banks, &prices(), RequirementType::Maintenance)
+            .unwrap();
+        assert_eq!(maintenance.weighted_asset_value, WrappedI80F48::ZERO);
+        assert_eq!(maintenance.health_factor(), Some(0.0));
+    }
+
+    #[test]
+    fn test_valuation_missing_state() {
+        let mut missing_prices = prices();
+        missing_prices.remove(&USDC_MINT);
+        assert_eq!(
+            marginfi_account().valuation(&banks(), &missing_prices, RequirementType::Maintenance),
+            None
+        );
+
+        let mut missing_banks = banks();
+        missing_banks.remove(&SOL_BANK);
+        assert_eq!(
+            marginfi_account().valuation(&missing_banks, &prices(), RequirementType::Maintenance),
+            None
+        );
+    }
+
+    #[test]
+    fn test_valuation_overflow() {
+        let mut prices = prices();
+        prices.insert(SOL_MINT, value(i128::MAX));
+
+        assert_eq!(
+            marginfi_account().valuation(&banks(), &prices, RequirementType::Maintenance),
+            None
+        );
+    }
+}
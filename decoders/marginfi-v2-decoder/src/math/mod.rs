@@ -0,0 +1,24 @@
+//! Token amounts, values, LTV and health of margin accounts, from the share
+//! values of their banks.
+//!
+//! Banks don't store prices, so values are in the currency of the prices
+//! passed in, usually USD. Amounts and values are accumulated as `I80F48`s,
+//! with the program's arithmetic, and token amounts are in the token's
+//! smallest unit; floats are only used for display amounts and ratios.
+
+pub mod account;
+pub mod wrapped_i80f48;
+
+pub use account::{AccountValuation, BalanceValue};
+
+use u256::U256;
+
+mod u256 {
+    // The expansion of `construct_uint!` rounds divisions up by hand.
+    #![allow(clippy::manual_div_ceil)]
+
+    uint::construct_uint! {
+        /// Holds the intermediate products of the `I80F48` arithmetic.
+        pub struct U256(4);
+    }
+}
@@ -0,0 +1,131 @@
+//! Marginfi's fixed point format.
+//!
+//! [`WrappedI80F48`] holds a signed 80.48 fixed point number, the format of
+//! share values, shares and risk weights.
+//!
+//! The checked operations return `None` where the program's `I80F48`
+//! arithmetic would overflow. Products round towards negative infinity and
+//! quotients towards zero.
+
+use {super::U256, crate::types::WrappedI80F48};
+
+/// The number of bits after the point of `I80F48`s.
+pub const I80F48_FRACTIONAL_BITS: i32 = 48;
+
+impl WrappedI80F48 {
+    pub const ZERO: Self = WrappedI80F48 { value: 0 };
+    pub const ONE: Self = WrappedI80F48 {
+        value: 1 << I80F48_FRACTIONAL_BITS,
+    };
+
+    /// Converts an integer to an `I80F48`.
+    pub fn from_int(value: u64) -> Self {
+        WrappedI80F48 {
+            value: (value as i128) << I80F48_FRACTIONAL_BITS,
+        }
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(WrappedI80F48 {
+            value: self.value.checked_add(other.value)?,
+        })
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(WrappedI80F48 {
+            value: self.value.checked_sub(other.value)?,
+        })
+    }
+
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        let product = U256::from(self.value.unsigned_abs())
+            .checked_mul(U256::from(other.value.unsigned_abs()))?;
+        let negative = (self.value < 0) != (other.value < 0);
+        let product = if negative {
+            // Rounds the magnitude up, so the product rounds down.
+            product.checked_add(U256::from((1u64 << I80F48_FRACTIONAL_BITS) - 1))?
+        } else {
+            product
+        };
+
+        from_magnitude(product >> I80F48_FRACTIONAL_BITS as usize, negative)
+    }
+
+    /// Divides by `other`, or returns `None` if `other` is zero.
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        let quotient = (U256::from(self.value.unsigned_abs()) << I80F48_FRACTIONAL_BITS as usize)
+            .checked_div(U256::from(other.value.unsigned_abs()))?;
+
+        from_magnitude(quotient, (self.value < 0) != (other.value < 0))
+    }
+
+    /// Converts a float to an `I80F48`, saturating at the bounds of the
+    /// format.
+    pub fn from_f64(value: f64) -> Self {
+        WrappedI80F48 {
+            value: (value * 2f64.powi(I80F48_FRACTIONAL_BITS)) as i128,
+        }
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.value as f64 / 2f64.powi(I80F48_FRACTIONAL_BITS)
+    }
+}
+
+fn from_magnitude(magnitude: U256, negative: bool) -> Option<WrappedI80F48> {
+    let magnitude = i128::try_from(magnitude).ok()?;
+    Some(WrappedI80F48 {
+        value: if negative { -magnitude } else { magnitude },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(WrappedI80F48 { value: 0 }.to_f64(), 0.0);
+        assert_eq!(WrappedI80F48 { value: 1 << 48 }.to_f64(), 1.0);
+        assert_eq!(WrappedI80F48 { value: -(3 << 47) }.to_f64(), -1.5);
+        assert_eq!(WrappedI80F48::from_f64(1.5).value, 3 << 47);
+        assert_eq!(WrappedI80F48::from_f64(-0.25).value, -(1 << 46));
+        assert_eq!(WrappedI80F48::from_int(3).value, 3 << 48);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let value = |value: i128| WrappedI80F48 { value };
+
+        assert_eq!(
+            value(3 << 47).checked_add(&value(1 << 48)),
+            Some(value(5 << 47))
+        );
+        assert_eq!(value(i128::MAX).checked_add(&value(1)), None);
+        assert_eq!(
+            value(1 << 48).checked_sub(&value(3 << 47)),
+            Some(value(-(1 << 47)))
+        );
+        assert_eq!(
+            value(3 << 47).checked_mul(&value(5 << 48)),
+            Some(value(15 << 47))
+        );
+        assert_eq!(
+            value(-(3 << 47)).checked_mul(&value(5 << 48)),
+            Some(value(-(15 << 47)))
+        );
+        // Rounds towards negative infinity.
+        assert_eq!(value(1).checked_mul(&value(1 << 47)), Some(value(0)));
+        assert_eq!(value(-1).checked_mul(&value(1 << 47)), Some(value(-1)));
+        assert_eq!(value(i128::MAX).checked_mul(&value(2 << 48)), None);
+        assert_eq!(
+            value(3 << 48).checked_div(&value(2 << 48)),
+            Some(value(3 << 47))
+        );
+        assert_eq!(
+            value(-(1 << 48)).checked_div(&value(3 << 48)),
+            Some(value(-((1 << 48) / 3)))
+        );
+        assert_eq!(value(1 << 48).checked_div(&value(0)), None);
+    }
+}
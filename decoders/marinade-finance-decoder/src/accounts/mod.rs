@@ -6,6 +6,7 @@ use {
 pub mod state;
 pub mod ticket_account_data;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum MarinadeFinanceAccount {
     TicketAccountData(ticket_account_data::TicketAccountData),
     State(state::State),
@@ -44,4 +45,19 @@ impl<'a> AccountDecoder<'a> for MarinadeFinanceDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            ticket_account_data::TicketAccountData,
+            state::State,
+        )
+    }
 }
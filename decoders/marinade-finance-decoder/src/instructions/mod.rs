@@ -185,4 +185,70 @@ impl<'a> carbon_core::instruction::InstructionDecoder<'a> for MarinadeFinanceDec
             MarinadeFinanceInstruction::WithdrawStakeAccountEvent => withdraw_stake_account_event::WithdrawStakeAccountEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            initialize::Initialize,
+            change_authority::ChangeAuthority,
+            add_validator::AddValidator,
+            remove_validator::RemoveValidator,
+            set_validator_score::SetValidatorScore,
+            config_validator_system::ConfigValidatorSystem,
+            deposit::Deposit,
+            deposit_stake_account::DepositStakeAccount,
+            liquid_unstake::LiquidUnstake,
+            add_liquidity::AddLiquidity,
+            remove_liquidity::RemoveLiquidity,
+            config_lp::ConfigLp,
+            config_marinade::ConfigMarinade,
+            order_unstake::OrderUnstake,
+            claim::Claim,
+            stake_reserve::StakeReserve,
+            update_active::UpdateActive,
+            update_deactivated::UpdateDeactivated,
+            deactivate_stake::DeactivateStake,
+            emergency_unstake::EmergencyUnstake,
+            partial_unstake::PartialUnstake,
+            merge_stakes::MergeStakes,
+            redelegate::Redelegate,
+            pause::Pause,
+            resume::Resume,
+            withdraw_stake_account::WithdrawStakeAccount,
+            realloc_validator_list::ReallocValidatorList,
+            realloc_stake_list::ReallocStakeList,
+            change_authority_event::ChangeAuthorityEvent,
+            config_lp_event::ConfigLpEvent,
+            config_marinade_event::ConfigMarinadeEvent,
+            initialize_event::InitializeEvent,
+            emergency_pause_event::EmergencyPauseEvent,
+            resume_event::ResumeEvent,
+            realloc_validator_list_event::ReallocValidatorListEvent,
+            realloc_stake_list_event::ReallocStakeListEvent,
+            deactivate_stake_event::DeactivateStakeEvent,
+            merge_stakes_event::MergeStakesEvent,
+            redelegate_event::RedelegateEvent,
+            stake_reserve_event::StakeReserveEvent,
+            update_active_event::UpdateActiveEvent,
+            update_deactivated_event::UpdateDeactivatedEvent,
+            claim_event::ClaimEvent,
+            order_unstake_event::OrderUnstakeEvent,
+            add_liquidity_event::AddLiquidityEvent,
+            liquid_unstake_event::LiquidUnstakeEvent,
+            remove_liquidity_event::RemoveLiquidityEvent,
+            add_validator_event::AddValidatorEvent,
+            remove_validator_event::RemoveValidatorEvent,
+            set_validator_score_event::SetValidatorScoreEvent,
+            deposit_stake_account_event::DepositStakeAccountEvent,
+            deposit_event::DepositEvent,
+            withdraw_stake_account_event::WithdrawStakeAccountEvent,
+        )
+    }
 }
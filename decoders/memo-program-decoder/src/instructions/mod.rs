@@ -31,4 +31,8 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MemoProgramDecoder {
             accounts: instruction.accounts.clone(),
         })
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(spl_memo::ID)
+    }
 }
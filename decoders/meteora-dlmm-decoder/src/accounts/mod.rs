@@ -11,6 +11,7 @@ pub mod position;
 pub mod position_v2;
 pub mod preset_parameter;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum MeteoraDlmmAccount {
     BinArrayBitmapExtension(bin_array_bitmap_extension::BinArrayBitmapExtension),
     BinArray(bin_array::BinArray),
@@ -110,4 +111,24 @@ impl AccountDecoder<'_> for MeteoraDlmmDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            bin_array_bitmap_extension::BinArrayBitmapExtension,
+            bin_array::BinArray,
+            lb_pair::LbPair,
+            oracle::Oracle,
+            position::Position,
+            position_v2::PositionV2,
+            preset_parameter::PresetParameter,
+        )
+    }
 }
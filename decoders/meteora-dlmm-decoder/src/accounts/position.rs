@@ -3,13 +3,16 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0xaabc8fe47a40f7d0")]
 pub struct Position {
     pub lb_pair: solana_sdk::pubkey::Pubkey,
     pub owner: solana_sdk::pubkey::Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
     pub liquidity_shares: [u64; 70],
+    #[serde(with = "serde_big_array::BigArray")]
     pub reward_infos: [UserRewardInfo; 70],
+    #[serde(with = "serde_big_array::BigArray")]
     pub fee_infos: [FeeInfo; 70],
     pub lower_bin_id: i32,
     pub upper_bin_id: i32,
@@ -17,5 +20,6 @@ pub struct Position {
     pub total_claimed_fee_x_amount: u64,
     pub total_claimed_fee_y_amount: u64,
     pub total_claimed_rewards: [u64; 2],
+    #[serde(with = "serde_big_array::BigArray")]
     pub reserved: [u8; 160],
 }
@@ -3,13 +3,16 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x75b0d4c7f5b485b6")]
 pub struct PositionV2 {
     pub lb_pair: solana_sdk::pubkey::Pubkey,
     pub owner: solana_sdk::pubkey::Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
     pub liquidity_shares: [u128; 70],
+    #[serde(with = "serde_big_array::BigArray")]
     pub reward_infos: [UserRewardInfo; 70],
+    #[serde(with = "serde_big_array::BigArray")]
     pub fee_infos: [FeeInfo; 70],
     pub lower_bin_id: i32,
     pub upper_bin_id: i32,
@@ -21,5 +24,6 @@ pub struct PositionV2 {
     pub lock_release_point: u64,
     pub padding0: u8,
     pub fee_owner: solana_sdk::pubkey::Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
     pub reserved: [u8; 87],
 }
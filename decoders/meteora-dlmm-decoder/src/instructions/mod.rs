@@ -220,4 +220,78 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MeteoraDlmmDecoder {
             MeteoraDlmmInstruction::GoToABinEvent => go_to_a_bin_event::GoToABinEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            initialize_lb_pair::InitializeLbPair,
+            initialize_permission_lb_pair::InitializePermissionLbPair,
+            initialize_customizable_permissionless_lb_pair::InitializeCustomizablePermissionlessLbPair,
+            initialize_bin_array_bitmap_extension::InitializeBinArrayBitmapExtension,
+            initialize_bin_array::InitializeBinArray,
+            add_liquidity::AddLiquidity,
+            add_liquidity_by_weight::AddLiquidityByWeight,
+            add_liquidity_by_strategy::AddLiquidityByStrategy,
+            add_liquidity_by_strategy_one_side::AddLiquidityByStrategyOneSide,
+            add_liquidity_one_side::AddLiquidityOneSide,
+            remove_liquidity::RemoveLiquidity,
+            initialize_position::InitializePosition,
+            initialize_position_pda::InitializePositionPda,
+            initialize_position_by_operator::InitializePositionByOperator,
+            update_position_operator::UpdatePositionOperator,
+            swap::Swap,
+            swap_exact_out::SwapExactOut,
+            swap_with_price_impact::SwapWithPriceImpact,
+            withdraw_protocol_fee::WithdrawProtocolFee,
+            initialize_reward::InitializeReward,
+            fund_reward::FundReward,
+            update_reward_funder::UpdateRewardFunder,
+            update_reward_duration::UpdateRewardDuration,
+            claim_reward::ClaimReward,
+            claim_fee::ClaimFee,
+            close_position::ClosePosition,
+            update_fee_parameters::UpdateFeeParameters,
+            increase_oracle_length::IncreaseOracleLength,
+            initialize_preset_parameter::InitializePresetParameter,
+            close_preset_parameter::ClosePresetParameter,
+            remove_all_liquidity::RemoveAllLiquidity,
+            toggle_pair_status::TogglePairStatus,
+            migrate_position::MigratePosition,
+            migrate_bin_array::MigrateBinArray,
+            update_fees_and_rewards::UpdateFeesAndRewards,
+            withdraw_ineligible_reward::WithdrawIneligibleReward,
+            set_activation_point::SetActivationPoint,
+            remove_liquidity_by_range::RemoveLiquidityByRange,
+            add_liquidity_one_side_precise::AddLiquidityOneSidePrecise,
+            go_to_a_bin::GoToABin,
+            set_pre_activation_duration::SetPreActivationDuration,
+            set_pre_activation_swap_address::SetPreActivationSwapAddress,
+            composition_fee_event::CompositionFeeEvent,
+            add_liquidity_event::AddLiquidityEvent,
+            remove_liquidity_event::RemoveLiquidityEvent,
+            swap_event::SwapEvent,
+            claim_reward_event::ClaimRewardEvent,
+            fund_reward_event::FundRewardEvent,
+            initialize_reward_event::InitializeRewardEvent,
+            update_reward_duration_event::UpdateRewardDurationEvent,
+            update_reward_funder_event::UpdateRewardFunderEvent,
+            position_close_event::PositionCloseEvent,
+            claim_fee_event::ClaimFeeEvent,
+            lb_pair_create_event::LbPairCreateEvent,
+            position_create_event::PositionCreateEvent,
+            fee_parameter_update_event::FeeParameterUpdateEvent,
+            increase_observation_event::IncreaseObservationEvent,
+            withdraw_ineligible_reward_event::WithdrawIneligibleRewardEvent,
+            update_position_operator_event::UpdatePositionOperatorEvent,
+            update_position_lock_release_point_event::UpdatePositionLockReleasePointEvent,
+            go_to_a_bin_event::GoToABinEvent,
+        )
+    }
 }
@@ -0,0 +1,115 @@
+//! Bin prices and the fixed point arithmetic of swaps within a bin.
+
+/// The number of bits after the point of Q64.64 prices.
+pub const SCALE_OFFSET: u32 = 64;
+pub const ONE: u128 = 1 << SCALE_OFFSET;
+pub const BASIS_POINT_MAX: u128 = 10_000;
+pub const MAX_BIN_PER_ARRAY: i32 = 70;
+
+const MAX_EXPONENTIAL: u32 = 0x80000;
+
+/// Returns the Q64.64 price of `bin_id`, `(1 + bin_step / 10_000)^bin_id`,
+/// in token Y per token X.
+pub fn price_from_id(bin_id: i32, bin_step: u16) -> Option<u128> {
+    let bps = ((bin_step as u128) << SCALE_OFFSET) / BASIS_POINT_MAX;
+    pow(ONE + bps, bin_id)
+}
+
+/// Raises a Q64.64 `base` to `exp` as the program does: by squaring the
+/// inverse of the base, which keeps the intermediate products in `u128`.
+pub fn pow(base: u128, exp: i32) -> Option<u128> {
+    if exp == 0 {
+        return Some(ONE);
+    }
+
+    let mut invert = exp.is_negative();
+    let exp = exp.unsigned_abs();
+    if exp >= MAX_EXPONENTIAL {
+        return None;
+    }
+
+    let mut squared_base = base;
+    if squared_base >= ONE {
+        squared_base = u128::MAX.checked_div(squared_base)?;
+        invert = !invert;
+    }
+
+    let mut result = ONE;
+    for bit in 0..19 {
+        if exp & (1 << bit) != 0 {
+            result = result.checked_mul(squared_base)? >> SCALE_OFFSET;
+        }
+        squared_base = squared_base.checked_mul(squared_base)? >> SCALE_OFFSET;
+    }
+
+    if result == 0 {
+        return None;
+    }
+    if invert {
+        result = u128::MAX.checked_div(result)?;
+    }
+    Some(result)
+}
+
+/// Returns the index of the bin array containing `bin_id`.
+pub fn bin_array_index(bin_id: i32) -> i64 {
+    bin_id.div_euclid(MAX_BIN_PER_ARRAY) as i64
+}
+
+/// `(x << 64) / price`: converts an amount of token Y to token X.
+pub(crate) fn shl_div(x: u64, price: u128, round_up: bool) -> Option<u64> {
+    if price == 0 {
+        return None;
+    }
+    let numerator = (x as u128) << SCALE_OFFSET;
+    let quotient = if round_up {
+        numerator.div_ceil(price)
+    } else {
+        numerator / price
+    };
+    u64::try_from(quotient).ok()
+}
+
+/// `(x * price) >> 64`: converts an amount of token X to token Y.
+pub(crate) fn mul_shr(x: u64, price: u128, round_up: bool) -> Option<u64> {
+    let high = x as u128 * (price >> SCALE_OFFSET);
+    let low = x as u128 * (price as u64 as u128);
+    let mut result = high.checked_add(low >> SCALE_OFFSET)?;
+    if round_up && low as u64 != 0 {
+        result = result.checked_add(1)?;
+    }
+    u64::try_from(result).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_from_id() {
+        assert_eq!(price_from_id(0, 10), Some(ONE));
+        assert_eq!(price_from_id(1, 10), Some(18465190817783261167));
+        assert_eq!(price_from_id(-1, 10), Some(18428315757951600016));
+        assert_eq!(price_from_id(-5000, 25), Some(69825372527194));
+        assert_eq!(price_from_id(5000, 25), Some(4873334070482946822097674));
+    }
+
+    #[test]
+    fn test_bin_array_index() {
+        assert_eq!(bin_array_index(0), 0);
+        assert_eq!(bin_array_index(69), 0);
+        assert_eq!(bin_array_index(70), 1);
+        assert_eq!(bin_array_index(-1), -1);
+        assert_eq!(bin_array_index(-70), -1);
+        assert_eq!(bin_array_index(-71), -2);
+    }
+
+    #[test]
+    fn test_amount_conversions() {
+        let price = price_from_id(100, 10).unwrap();
+        assert_eq!(mul_shr(1_000_000, price, false), Some(1105115));
+        assert_eq!(mul_shr(1_000_000, price, true), Some(1105116));
+        assert_eq!(shl_div(1_105_116, price, false), Some(1000000));
+        assert_eq!(shl_div(1_105_116, price, true), Some(1000001));
+    }
+}
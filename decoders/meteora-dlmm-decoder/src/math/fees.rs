@@ -0,0 +1,142 @@
+//! Dynamic fee rates.
+//!
+//! A pair's fee rate is a base rate set by its `base_factor` plus a variable
+//! rate that grows with the volatility accumulator, the number of bins
+//! recently crossed. Rates are in billionths.
+
+use {super::bin_math::BASIS_POINT_MAX, crate::accounts::lb_pair::LbPair};
+
+pub const FEE_PRECISION: u128 = 1_000_000_000;
+pub const MAX_FEE_RATE: u128 = 100_000_000;
+
+/// Returns the base fee rate of a pair.
+pub fn base_fee_rate(base_factor: u16, bin_step: u16) -> u128 {
+    base_factor as u128 * bin_step as u128 * 10
+}
+
+/// Returns the variable fee rate of a pair at `volatility_accumulator`,
+/// rounded up.
+pub fn variable_fee_rate(
+    volatility_accumulator: u32,
+    bin_step: u16,
+    variable_fee_control: u32,
+) -> u128 {
+    let volatility = volatility_accumulator as u128 * bin_step as u128;
+    (volatility * volatility * variable_fee_control as u128).div_ceil(100_000_000_000)
+}
+
+/// Returns the fee to charge on top of `amount` so that `amount` is what is
+/// left once the fee is taken.
+pub fn fee_excluded(amount: u64, fee_rate: u128) -> Option<u64> {
+    let fee = (amount as u128)
+        .checked_mul(fee_rate)?
+        .div_ceil(FEE_PRECISION - fee_rate);
+    u64::try_from(fee).ok()
+}
+
+/// Returns the fee taken out of `amount`.
+pub fn fee_included(amount: u64, fee_rate: u128) -> Option<u64> {
+    let fee = (amount as u128)
+        .checked_mul(fee_rate)?
+        .div_ceil(FEE_PRECISION);
+    u64::try_from(fee).ok()
+}
+
+/// The volatility state of a pair, which a swap updates as it crosses bins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Volatility {
+    pub accumulator: u32,
+    pub reference: u32,
+    pub index_reference: i32,
+}
+
+impl Volatility {
+    /// Returns the pair's volatility at the start of a swap at
+    /// `current_timestamp`, decayed by the time since its last update.
+    pub fn at(lb_pair: &LbPair, current_timestamp: i64) -> Self {
+        let parameters = &lb_pair.parameters;
+        let v_parameters = &lb_pair.v_parameters;
+        let mut volatility = Volatility {
+            accumulator: v_parameters.volatility_accumulator,
+            reference: v_parameters.volatility_reference,
+            index_reference: v_parameters.index_reference,
+        };
+
+        let elapsed = current_timestamp.saturating_sub(v_parameters.last_update_timestamp);
+        if elapsed >= parameters.filter_period as i64 {
+            volatility.index_reference = lb_pair.active_id;
+            volatility.reference = if elapsed < parameters.decay_period as i64 {
+                (v_parameters.volatility_accumulator as u64 * parameters.reduction_factor as u64
+                    / BASIS_POINT_MAX as u64) as u32
+            } else {
+                0
+            };
+        }
+        volatility
+    }
+
+    /// Updates the accumulator for a swap reaching `active_id`.
+    pub fn update_accumulator(&mut self, lb_pair: &LbPair, active_id: i32) {
+        let delta_id = (self.index_reference as i64 - active_id as i64).unsigned_abs();
+        let accumulator = self.reference as u64 + delta_id * BASIS_POINT_MAX as u64;
+        self.accumulator =
+            accumulator.min(lb_pair.parameters.max_volatility_accumulator as u64) as u32;
+    }
+
+    pub fn total_fee_rate(&self, lb_pair: &LbPair) -> u128 {
+        total_fee_rate(lb_pair, self.accumulator)
+    }
+}
+
+fn total_fee_rate(lb_pair: &LbPair, volatility_accumulator: u32) -> u128 {
+    let rate = base_fee_rate(lb_pair.parameters.base_factor, lb_pair.bin_step)
+        + variable_fee_rate(
+            volatility_accumulator,
+            lb_pair.bin_step,
+            lb_pair.parameters.variable_fee_control,
+        );
+    rate.min(MAX_FEE_RATE)
+}
+
+impl LbPair {
+    /// Returns the pair's base fee rate.
+    pub fn base_fee_rate(&self) -> u128 {
+        base_fee_rate(self.parameters.base_factor, self.bin_step)
+    }
+
+    /// Returns the pair's variable fee rate at its stored volatility
+    /// accumulator.
+    pub fn variable_fee_rate(&self) -> u128 {
+        variable_fee_rate(
+            self.v_parameters.volatility_accumulator,
+            self.bin_step,
+            self.parameters.variable_fee_control,
+        )
+    }
+
+    /// Returns the pair's fee rate at its stored volatility accumulator,
+    /// capped at [`MAX_FEE_RATE`].
+    pub fn total_fee_rate(&self) -> u128 {
+        total_fee_rate(self, self.v_parameters.volatility_accumulator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_rates() {
+        assert_eq!(base_fee_rate(10_000, 10), 1_000_000);
+        assert_eq!(variable_fee_rate(0, 10, 40_000), 0);
+        assert_eq!(variable_fee_rate(30_000, 10, 40_000), 36_000);
+        assert_eq!(variable_fee_rate(12_345, 25, 7_500), 7_144);
+    }
+
+    #[test]
+    fn test_fees() {
+        assert_eq!(fee_excluded(1_000_000, 2_500_000), Some(2507));
+        assert_eq!(fee_included(1_000_000, 2_500_000), Some(2500));
+        assert_eq!(fee_included(1_000_001, 2_500_000), Some(2501));
+    }
+}
@@ -0,0 +1,62 @@
+//! Price, liquidity, fee and swap quote math for DLMM pairs, following the
+//! program's fixed point arithmetic.
+//!
+//! Liquidity sits in bins of constant price, the price of bin `id` being
+//! `(1 + bin_step / 10_000)^id` in token Y per token X. Bin prices are
+//! Q64.64 fixed point numbers.
+
+pub mod bin_math;
+pub mod fees;
+pub mod quote;
+
+pub use quote::SwapQuote;
+
+use {
+    crate::{
+        accounts::{bin_array::BinArray, lb_pair::LbPair},
+        types::Bin,
+    },
+    bin_math::{bin_array_index, MAX_BIN_PER_ARRAY},
+};
+
+/// Converts a bin id to a price in token Y per token X, adjusted for the
+/// mints' decimals.
+pub fn bin_id_to_price(bin_id: i32, bin_step: u16, decimals_x: u8, decimals_y: u8) -> f64 {
+    (1.0 + bin_step as f64 / 10_000.0).powi(bin_id)
+        * 10f64.powi(decimals_x as i32 - decimals_y as i32)
+}
+
+/// Converts a price in token Y per token X, adjusted for the mints'
+/// decimals, to the id of the bin closest to it.
+pub fn price_to_bin_id(price: f64, bin_step: u16, decimals_x: u8, decimals_y: u8) -> i32 {
+    let price = price * 10f64.powi(decimals_y as i32 - decimals_x as i32);
+    (price.ln() / (1.0 + bin_step as f64 / 10_000.0).ln()).round() as i32
+}
+
+impl LbPair {
+    /// Returns the price of the active bin in token Y per token X, adjusted
+    /// for the mints' decimals.
+    pub fn price(&self, decimals_x: u8, decimals_y: u8) -> f64 {
+        bin_id_to_price(self.active_id, self.bin_step, decimals_x, decimals_y)
+    }
+
+    /// Returns the amounts of tokens X and Y in the active bin, or `None` if
+    /// `bin_arrays` don't include it.
+    pub fn liquidity_in_range(&self, bin_arrays: &[BinArray]) -> Option<(u64, u64)> {
+        let bin = bin_arrays
+            .iter()
+            .find_map(|array| array.bin(self.active_id))?;
+        Some((bin.amount_x, bin.amount_y))
+    }
+}
+
+impl BinArray {
+    /// Returns the bin `bin_id`, if this array holds it.
+    pub fn bin(&self, bin_id: i32) -> Option<&Bin> {
+        if bin_array_index(bin_id) != self.index {
+            return None;
+        }
+        let offset = bin_id as i64 - self.index * MAX_BIN_PER_ARRAY as i64;
+        self.bins.get(offset as usize)
+    }
+}
@@ -0,0 +1,340 @@
+//! Swap quotes across a pair's bin arrays.
+
+use {
+    super::{
+        bin_math::{mul_shr, price_from_id, shl_div},
+        fees::{fee_excluded, fee_included, Volatility},
+    },
+    crate::{
+        accounts::{bin_array::BinArray, lb_pair::LbPair},
+        types::Bin,
+    },
+};
+
+/// The amounts of a quoted swap and the pair's active bin after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SwapQuote {
+    /// The input amount, fees included.
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee_amount: u64,
+    pub active_id: i32,
+}
+
+impl LbPair {
+    /// Quotes swapping exactly `amount_in` of token X, if `swap_for_y`, or of
+    /// token Y, at `current_timestamp`, which decays the volatility the
+    /// variable fee is charged on.
+    ///
+    /// `bin_arrays` must cover the bins the swap crosses, in any order;
+    /// returns `None` if they don't, if the swap leaves the pair's bin range
+    /// or if an amount overflows.
+    pub fn quote_exact_in(
+        &self,
+        bin_arrays: &[BinArray],
+        amount_in: u64,
+        swap_for_y: bool,
+        current_timestamp: i64,
+    ) -> Option<SwapQuote> {
+        self.quote(bin_arrays, amount_in, true, swap_for_y, current_timestamp)
+    }
+
+    /// Quotes swapping for exactly `amount_out` of token Y, if `swap_for_y`,
+    /// or of token X, at `current_timestamp`.
+    ///
+    /// `bin_arrays` must cover the bins the swap crosses, in any order;
+    /// returns `None` if they don't, if the pair's liquidity can't pay out
+    /// `amount_out` or if an amount overflows.
+    pub fn quote_exact_out(
+        &self,
+        bin_arrays: &[BinArray],
+        amount_out: u64,
+        swap_for_y: bool,
+        current_timestamp: i64,
+    ) -> Option<SwapQuote> {
+        self.quote(bin_arrays, amount_out, false, swap_for_y, current_timestamp)
+    }
+
+    fn quote(
+        &self,
+        bin_arrays: &[BinArray],
+        amount: u64,
+        amount_specified_is_input: bool,
+        swap_for_y: bool,
+        current_timestamp: i64,
+    ) -> Option<SwapQuote> {
+        let mut volatility = Volatility::at(self, current_timestamp);
+        let mut active_id = self.active_id;
+        let mut amount_remaining = amount;
+        let mut amount_calculated: u64 = 0;
+        let mut fee_amount: u64 = 0;
+
+        loop {
+            volatility.update_accumulator(self, active_id);
+            let bin = bin_arrays.iter().find_map(|array| array.bin(active_id))?;
+
+            let amount_available = if swap_for_y {
+                bin.amount_y
+            } else {
+                bin.amount_x
+            };
+            if amount_available > 0 {
+                let price = bin_price(bin, active_id, self.bin_step)?;
+                let fee_rate = volatility.total_fee_rate(self);
+                let step = if amount_specified_is_input {
+                    swap_exact_in(
+                        amount_remaining,
+                        amount_available,
+                        price,
+                        fee_rate,
+                        swap_for_y,
+                    )?
+                } else {
+                    swap_exact_out(
+                        amount_remaining,
+                        amount_available,
+                        price,
+                        fee_rate,
+                        swap_for_y,
+                    )?
+                };
+
+                if amount_specified_is_input {
+                    amount_remaining -= step.amount_in;
+                    amount_calculated = amount_calculated.checked_add(step.amount_out)?;
+                } else {
+                    amount_remaining -= step.amount_out;
+                    amount_calculated = amount_calculated.checked_add(step.amount_in)?;
+                }
+                fee_amount = fee_amount.checked_add(step.fee_amount)?;
+            }
+
+            if amount_remaining == 0 {
+                break;
+            }
+
+            active_id = if swap_for_y {
+                active_id.checked_sub(1)?
+            } else {
+                active_id.checked_add(1)?
+            };
+            if active_id < self.parameters.min_bin_id || active_id > self.parameters.max_bin_id {
+                return None;
+            }
+        }
+
+        let (amount_in, amount_out) = if amount_specified_is_input {
+            (amount, amount_calculated)
+        } else {
+            (amount_calculated, amount)
+        };
+        Some(SwapQuote {
+            amount_in,
+            amount_out,
+            fee_amount,
+            active_id,
+        })
+    }
+}
+
+/// The amounts swapped within one bin.
+struct BinSwap {
+    /// The input amount, fees included.
+    amount_in: u64,
+    amount_out: u64,
+    fee_amount: u64,
+}
+
+/// Returns the price a bin stores, or computes it for bins that were never
+/// initialized.
+fn bin_price(bin: &Bin, bin_id: i32, bin_step: u16) -> Option<u128> {
+    if bin.price != 0 {
+        Some(bin.price)
+    } else {
+        price_from_id(bin_id, bin_step)
+    }
+}
+
+/// Returns the input amount, fees excluded, that buys `amount_out` from a
+/// bin at `price`.
+fn amount_in_for(amount_out: u64, price: u128, swap_for_y: bool) -> Option<u64> {
+    if swap_for_y {
+        shl_div(amount_out, price, true)
+    } else {
+        mul_shr(amount_out, price, true)
+    }
+}
+
+fn swap_exact_in(
+    amount_in: u64,
+    amount_available: u64,
+    price: u128,
+    fee_rate: u128,
+    swap_for_y: bool,
+) -> Option<BinSwap> {
+    let max_amount_in = amount_in_for(amount_available, price, swap_for_y)?;
+    let max_fee = fee_excluded(max_amount_in, fee_rate)?;
+    let max_amount_in_with_fee = max_amount_in.checked_add(max_fee)?;
+
+    if amount_in >= max_amount_in_with_fee {
+        return Some(BinSwap {
+            amount_in: max_amount_in_with_fee,
+            amount_out: amount_available,
+            fee_amount: max_fee,
+        });
+    }
+
+    let fee_amount = fee_included(amount_in, fee_rate)?;
+    let amount_in_after_fee = amount_in - fee_amount;
+    let amount_out = if swap_for_y {
+        mul_shr(amount_in_after_fee, price, false)?
+    } else {
+        shl_div(amount_in_after_fee, price, false)?
+    };
+    Some(BinSwap {
+        amount_in,
+        amount_out: amount_out.min(amount_available),
+        fee_amount,
+    })
+}
+
+fn swap_exact_out(
+    amount_out: u64,
+    amount_available: u64,
+    price: u128,
+    fee_rate: u128,
+    swap_for_y: bool,
+) -> Option<BinSwap> {
+    let amount_out = amount_out.min(amount_available);
+    let amount_in = amount_in_for(amount_out, price, swap_for_y)?;
+    let fee_amount = fee_excluded(amount_in, fee_rate)?;
+    Some(BinSwap {
+        amount_in: amount_in.checked_add(fee_amount)?,
+        amount_out,
+        fee_amount,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{accounts::MeteoraDlmmAccount, MeteoraDlmmDecoder},
+        carbon_core::account::AccountDecoder,
+    };
+
+    // The fixtures hold a pair with a bin step of 10 and active bin 5, with X
+    // in bins 5 to 7 and Y in bins -1 to 5, and its bin arrays -1 and 0.
+    fn decode_account(path: &str) -> MeteoraDlmmAccount {
+        let account = carbon_test_utils::read_account(path).expect("read fixture");
+
+        MeteoraDlmmDecoder
+            .decode_account(&account)
+            .expect("decode fixture")
+            .data
+    }
+
+    fn lb_pair() -> LbPair {
+        match decode_account("tests/fixtures/lb_pair_account.json") {
+            MeteoraDlmmAccount::LbPair(lb_pair) => lb_pair,
+            account => panic!("expected an lb pair, got {account:?}"),
+        }
+    }
+
+    fn bin_arrays() -> Vec<BinArray> {
+        [
+            "tests/fixtures/bin_array_-1_account.json",
+            "tests/fixtures/bin_array_0_account.json",
+        ]
+        .into_iter()
+        .map(|path| match decode_account(path) {
+            MeteoraDlmmAccount::BinArray(bin_array) => bin_array,
+            account => panic!("expected a bin array, got {account:?}"),
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_quote_exact_in() {
+        let lb_pair = lb_pair();
+        let bin_arrays = bin_arrays();
+
+        assert_eq!(
+            lb_pair.quote_exact_in(&bin_arrays, 1_000_000, true, 1_010),
+            Some(SwapQuote {
+                amount_in: 1_000_000,
+                amount_out: 1_003_988,
+                fee_amount: 1_016,
+                active_id: 5,
+            })
+        );
+        assert_eq!(
+            lb_pair.quote_exact_in(&bin_arrays, 8_000_000, true, 1_010),
+            Some(SwapQuote {
+                amount_in: 8_000_000,
+                amount_out: 8_013_784,
+                fee_amount: 8_143,
+                active_id: -1,
+            })
+        );
+        assert_eq!(
+            lb_pair.quote_exact_in(&bin_arrays, 2_000_000, false, 2_000),
+            Some(SwapQuote {
+                amount_in: 2_000_000,
+                amount_out: 1_987_046,
+                fee_amount: 2_005,
+                active_id: 6,
+            })
+        );
+    }
+
+    #[test]
+    fn test_quote_exact_out() {
+        let lb_pair = lb_pair();
+        let bin_arrays = bin_arrays();
+
+        assert_eq!(
+            lb_pair.quote_exact_out(&bin_arrays, 1_500_000, false, 1_010),
+            Some(SwapQuote {
+                amount_in: 1_509_564,
+                amount_out: 1_500_000,
+                fee_amount: 1_545,
+                active_id: 6,
+            })
+        );
+        // The volatility decays once the filter period has passed.
+        assert_eq!(
+            lb_pair.quote_exact_out(&bin_arrays, 1_500_000, false, 1_100),
+            Some(SwapQuote {
+                amount_in: 1_509_542,
+                amount_out: 1_500_000,
+                fee_amount: 1_523,
+                active_id: 6,
+            })
+        );
+    }
+
+    #[test]
+    fn test_quote_missing_bin_array() {
+        let lb_pair = lb_pair();
+        let bin_arrays = bin_arrays();
+
+        assert_eq!(
+            lb_pair.quote_exact_in(&bin_arrays, 20_000_000, true, 1_010),
+            None
+        );
+        assert_eq!(
+            lb_pair.quote_exact_out(&bin_arrays, 4_000_000, false, 1_010),
+            None
+        );
+    }
+
+    #[test]
+    fn test_liquidity_in_range() {
+        assert_eq!(
+            lb_pair().liquidity_in_range(&bin_arrays()),
+            Some((1_000_000, 2_000_000))
+        );
+        assert_eq!(lb_pair().liquidity_in_range(&[]), None);
+    }
+}
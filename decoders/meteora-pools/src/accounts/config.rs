@@ -2,7 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x9b0caae01efacc82")]
 pub struct Config {
     pub pool_fees: PoolFees,
@@ -11,5 +11,6 @@ pub struct Config {
     pub pool_creator_authority: solana_sdk::pubkey::Pubkey,
     pub activation_type: u8,
     pub partner_fee_numerator: u64,
+    #[serde(with = "serde_big_array::BigArray")]
     pub padding: [u8; 219],
 }
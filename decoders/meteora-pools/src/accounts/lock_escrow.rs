@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0xbe6a7906c8b6154b")]
 pub struct LockEscrow {
     pub pool: solana_sdk::pubkey::Pubkey,
@@ -8,6 +8,7 @@ pub mod config;
 pub mod lock_escrow;
 pub mod pool;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum MeteoraPoolsProgramAccount {
     Config(config::Config),
     LockEscrow(lock_escrow::LockEscrow),
@@ -57,4 +58,20 @@ impl AccountDecoder<'_> for MeteoraPoolsDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            config::Config,
+            lock_escrow::LockEscrow,
+            pool::Pool,
+        )
+    }
 }
@@ -154,4 +154,61 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MeteoraPoolsDecoder {
             MeteoraPoolsProgramInstruction::PartnerClaimFeesEvent => partner_claim_fees_event::PartnerClaimFeesEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            initialize_permissioned_pool::InitializePermissionedPool,
+            initialize_permissionless_pool::InitializePermissionlessPool,
+            initialize_permissionless_pool_with_fee_tier::InitializePermissionlessPoolWithFeeTier,
+            enable_or_disable_pool::EnableOrDisablePool,
+            swap::Swap,
+            remove_liquidity_single_side::RemoveLiquiditySingleSide,
+            add_imbalance_liquidity::AddImbalanceLiquidity,
+            remove_balance_liquidity::RemoveBalanceLiquidity,
+            add_balance_liquidity::AddBalanceLiquidity,
+            set_pool_fees::SetPoolFees,
+            override_curve_param::OverrideCurveParam,
+            get_pool_info::GetPoolInfo,
+            bootstrap_liquidity::BootstrapLiquidity,
+            create_mint_metadata::CreateMintMetadata,
+            create_lock_escrow::CreateLockEscrow,
+            lock::Lock,
+            claim_fee::ClaimFee,
+            create_config::CreateConfig,
+            close_config::CloseConfig,
+            initialize_permissionless_constant_product_pool_with_config::InitializePermissionlessConstantProductPoolWithConfig,
+            initialize_permissionless_constant_product_pool_with_config2::InitializePermissionlessConstantProductPoolWithConfig2,
+            initialize_customizable_permissionless_constant_product_pool::InitializeCustomizablePermissionlessConstantProductPool,
+            update_activation_point::UpdateActivationPoint,
+            withdraw_protocol_fees::WithdrawProtocolFees,
+            set_whitelisted_vault::SetWhitelistedVault,
+            partner_claim_fee::PartnerClaimFee,
+            add_liquidity_event::AddLiquidityEvent,
+            remove_liquidity_event::RemoveLiquidityEvent,
+            bootstrap_liquidity_event::BootstrapLiquidityEvent,
+            swap_event::SwapEvent,
+            set_pool_fees_event::SetPoolFeesEvent,
+            pool_info_event::PoolInfoEvent,
+            transfer_admin_event::TransferAdminEvent,
+            override_curve_param_event::OverrideCurveParamEvent,
+            pool_created_event::PoolCreatedEvent,
+            pool_enabled_event::PoolEnabledEvent,
+            migrate_fee_account_event::MigrateFeeAccountEvent,
+            create_lock_escrow_event::CreateLockEscrowEvent,
+            lock_event::LockEvent,
+            claim_fee_event::ClaimFeeEvent,
+            create_config_event::CreateConfigEvent,
+            close_config_event::CloseConfigEvent,
+            withdraw_protocol_fees_event::WithdrawProtocolFeesEvent,
+            partner_claim_fees_event::PartnerClaimFeesEvent,
+        )
+    }
 }
@@ -3,7 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
-#[derive(CarbonDeserialize, Debug, PartialEq)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq)]
 #[carbon(discriminator = "0xbdff6146babd1866")]
 pub struct ConfigAccount {
     pub migration_authority: solana_sdk::pubkey::Pubkey,
@@ -22,6 +22,7 @@ pub struct ConfigAccount {
     pub max_supported_token_supply: u64,
     pub bump: u8,
     pub coef_b: u32,
+    #[serde(with = "serde_big_array::BigArray")]
     pub _reserved: [u8; 192],
 }
 
@@ -3,7 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
-#[derive(CarbonDeserialize, Debug, PartialEq)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq)]
 #[carbon(discriminator = "0x085b531c84d8f816")]
 pub struct CurveAccount {
     pub total_supply: u64,
@@ -18,6 +18,7 @@ pub struct CurveAccount {
     pub coef_b: u32,
     pub bump: u8,
     pub migration_target: MigrationTarget,
+    #[serde(with = "serde_big_array::BigArray")]
     pub _reserved: [u8; 327],
 }
 
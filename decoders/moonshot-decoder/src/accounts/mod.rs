@@ -6,6 +6,7 @@ use {
 pub mod config_account;
 pub mod curve_account;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum MoonshotAccount {
     ConfigAccount(config_account::ConfigAccount),
     CurveAccount(curve_account::CurveAccount),
@@ -47,6 +48,21 @@ impl AccountDecoder<'_> for MoonshotDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            config_account::ConfigAccount,
+            curve_account::CurveAccount,
+        )
+    }
 }
 
 #[cfg(test)]
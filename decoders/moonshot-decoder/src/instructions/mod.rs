@@ -46,6 +46,27 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MoonshotDecoder {
             MoonshotInstruction::MigrationEvent => migration_event::MigrationEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            token_mint::TokenMint,
+            buy::Buy,
+            sell::Sell,
+            migrate_funds::MigrateFunds,
+            config_init::ConfigInit,
+            config_update::ConfigUpdate,
+            trade_event::TradeEvent,
+            migration_event::MigrationEvent,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -9,6 +9,7 @@ pub mod hashed_asset_v1;
 pub mod plugin_header_v1;
 pub mod plugin_registry_v1;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum MplCoreProgramAccount {
     PluginHeaderV1(plugin_header_v1::PluginHeaderV1),
     PluginRegistryV1(plugin_registry_v1::PluginRegistryV1),
@@ -87,4 +88,22 @@ impl AccountDecoder<'_> for MplCoreProgramDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            plugin_header_v1::PluginHeaderV1,
+            plugin_registry_v1::PluginRegistryV1,
+            asset_v1::AssetV1,
+            collection_v1::CollectionV1,
+            hashed_asset_v1::HashedAssetV1,
+        )
+    }
 }
@@ -125,4 +125,49 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MplCoreProgramDecoder
             MplCoreProgramInstruction::ExecuteV1 => execute_v1::ExecuteV1,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            create_v1::CreateV1,
+            create_collection_v1::CreateCollectionV1,
+            add_plugin_v1::AddPluginV1,
+            add_collection_plugin_v1::AddCollectionPluginV1,
+            remove_plugin_v1::RemovePluginV1,
+            remove_collection_plugin_v1::RemoveCollectionPluginV1,
+            update_plugin_v1::UpdatePluginV1,
+            update_collection_plugin_v1::UpdateCollectionPluginV1,
+            approve_plugin_authority_v1::ApprovePluginAuthorityV1,
+            approve_collection_plugin_authority_v1::ApproveCollectionPluginAuthorityV1,
+            revoke_plugin_authority_v1::RevokePluginAuthorityV1,
+            revoke_collection_plugin_authority_v1::RevokeCollectionPluginAuthorityV1,
+            burn_v1::BurnV1,
+            burn_collection_v1::BurnCollectionV1,
+            transfer_v1::TransferV1,
+            update_v1::UpdateV1,
+            update_collection_v1::UpdateCollectionV1,
+            compress_v1::CompressV1,
+            decompress_v1::DecompressV1,
+            collect::Collect,
+            create_v2::CreateV2,
+            create_collection_v2::CreateCollectionV2,
+            add_external_plugin_adapter_v1::AddExternalPluginAdapterV1,
+            add_collection_external_plugin_adapter_v1::AddCollectionExternalPluginAdapterV1,
+            remove_external_plugin_adapter_v1::RemoveExternalPluginAdapterV1,
+            remove_collection_external_plugin_adapter_v1::RemoveCollectionExternalPluginAdapterV1,
+            update_external_plugin_adapter_v1::UpdateExternalPluginAdapterV1,
+            update_collection_external_plugin_adapter_v1::UpdateCollectionExternalPluginAdapterV1,
+            write_external_plugin_adapter_data_v1::WriteExternalPluginAdapterDataV1,
+            write_collection_external_plugin_adapter_data_v1::WriteCollectionExternalPluginAdapterDataV1,
+            update_v2::UpdateV2,
+            execute_v1::ExecuteV1,
+        )
+    }
 }
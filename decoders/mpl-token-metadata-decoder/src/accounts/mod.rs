@@ -18,6 +18,7 @@ pub mod token_owned_escrow;
 pub mod token_record;
 pub mod use_authority_record;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum TokenMetadataAccount {
     CollectionAuthorityRecord(collection_authority_record::CollectionAuthorityRecord),
     MetadataDelegateRecord(metadata_delegate_record::MetadataDelegateRecord),
@@ -213,4 +214,31 @@ impl AccountDecoder<'_> for TokenMetadataDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            collection_authority_record::CollectionAuthorityRecord,
+            metadata_delegate_record::MetadataDelegateRecord,
+            holder_delegate_record::HolderDelegateRecord,
+            edition::Edition,
+            edition_marker::EditionMarker,
+            edition_marker_v2::EditionMarkerV2,
+            token_owned_escrow::TokenOwnedEscrow,
+            master_edition_v2::MasterEditionV2,
+            master_edition_v1::MasterEditionV1,
+            metadata::Metadata,
+            token_record::TokenRecord,
+            reservation_list_v2::ReservationListV2,
+            reservation_list_v1::ReservationListV1,
+            use_authority_record::UseAuthorityRecord,
+        )
+    }
 }
@@ -203,4 +203,75 @@ impl carbon_core::instruction::InstructionDecoder<'_> for TokenMetadataDecoder {
             TokenMetadataInstruction::CloseAccounts => close_accounts::CloseAccounts,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            create_metadata_account::CreateMetadataAccount,
+            update_metadata_account::UpdateMetadataAccount,
+            deprecated_create_master_edition::DeprecatedCreateMasterEdition,
+            deprecated_mint_new_edition_from_master_edition_via_printing_token::DeprecatedMintNewEditionFromMasterEditionViaPrintingToken,
+            update_primary_sale_happened_via_token::UpdatePrimarySaleHappenedViaToken,
+            deprecated_set_reservation_list::DeprecatedSetReservationList,
+            deprecated_create_reservation_list::DeprecatedCreateReservationList,
+            sign_metadata::SignMetadata,
+            deprecated_mint_printing_tokens_via_token::DeprecatedMintPrintingTokensViaToken,
+            deprecated_mint_printing_tokens::DeprecatedMintPrintingTokens,
+            create_master_edition::CreateMasterEdition,
+            mint_new_edition_from_master_edition_via_token::MintNewEditionFromMasterEditionViaToken,
+            convert_master_edition_v1_to_v2::ConvertMasterEditionV1ToV2,
+            mint_new_edition_from_master_edition_via_vault_proxy::MintNewEditionFromMasterEditionViaVaultProxy,
+            puff_metadata::PuffMetadata,
+            update_metadata_account_v2::UpdateMetadataAccountV2,
+            create_metadata_account_v2::CreateMetadataAccountV2,
+            create_master_edition_v3::CreateMasterEditionV3,
+            verify_collection::VerifyCollection,
+            utilize::Utilize,
+            approve_use_authority::ApproveUseAuthority,
+            revoke_use_authority::RevokeUseAuthority,
+            unverify_collection::UnverifyCollection,
+            approve_collection_authority::ApproveCollectionAuthority,
+            revoke_collection_authority::RevokeCollectionAuthority,
+            set_and_verify_collection::SetAndVerifyCollection,
+            freeze_delegated_account::FreezeDelegatedAccount,
+            thaw_delegated_account::ThawDelegatedAccount,
+            remove_creator_verification::RemoveCreatorVerification,
+            burn_nft::BurnNft,
+            verify_sized_collection_item::VerifySizedCollectionItem,
+            unverify_sized_collection_item::UnverifySizedCollectionItem,
+            set_and_verify_sized_collection_item::SetAndVerifySizedCollectionItem,
+            create_metadata_account_v3::CreateMetadataAccountV3,
+            set_collection_size::SetCollectionSize,
+            set_token_standard::SetTokenStandard,
+            bubblegum_set_collection_size::BubblegumSetCollectionSize,
+            burn_edition_nft::BurnEditionNft,
+            create_escrow_account::CreateEscrowAccount,
+            close_escrow_account::CloseEscrowAccount,
+            transfer_out_of_escrow::TransferOutOfEscrow,
+            burn::Burn,
+            create::Create,
+            mint::Mint,
+            delegate::Delegate,
+            revoke::Revoke,
+            lock::Lock,
+            unlock::Unlock,
+            migrate::Migrate,
+            transfer::Transfer,
+            update::Update,
+            _use::Use,
+            verify::Verify,
+            unverify::Unverify,
+            collect::Collect,
+            print::Print,
+            resize::Resize,
+            close_accounts::CloseAccounts,
+        )
+    }
 }
@@ -5,6 +5,7 @@ use {
 };
 pub mod name_record_header;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum NameAccount {
     NameRecordHeader(name_record_header::NameRecordHeader),
 }
@@ -33,4 +34,18 @@ impl AccountDecoder<'_> for NameDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            name_record_header::NameRecordHeader,
+        )
+    }
 }
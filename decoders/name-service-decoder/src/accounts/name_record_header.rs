@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x319893826f292f59")]
 pub struct NameRecordHeader {
     pub parent_name: solana_sdk::pubkey::Pubkey,
@@ -44,4 +44,22 @@ impl carbon_core::instruction::InstructionDecoder<'_> for NameDecoder {
             NameInstruction::Realloc => realloc::Realloc,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            create::Create,
+            update::Update,
+            transfer::Transfer,
+            delete::Delete,
+            realloc::Realloc,
+        )
+    }
 }
@@ -3,6 +3,7 @@ use carbon_core::deserialize::CarbonDeserialize;
 
 use super::OkxDexDecoder;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum OkxDexAccount {}
 
 impl<'a> AccountDecoder<'a> for OkxDexDecoder {
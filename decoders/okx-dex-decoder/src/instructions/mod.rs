@@ -68,4 +68,30 @@ impl<'a> carbon_core::instruction::InstructionDecoder<'a> for OkxDexDecoder {
             OkxDexInstruction::SwapEvent => swap_event::SwapEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            commission_sol_from_swap::CommissionSolFromSwap,
+            commission_sol_proxy_swap::CommissionSolProxySwap,
+            commission_sol_swap::CommissionSolSwap,
+            commission_sol_swap2::CommissionSolSwap2,
+            commission_spl_from_swap::CommissionSplFromSwap,
+            commission_spl_proxy_swap::CommissionSplProxySwap,
+            commission_spl_swap::CommissionSplSwap,
+            commission_spl_swap2::CommissionSplSwap2,
+            from_swap_log::FromSwapLog,
+            proxy_swap::ProxySwap,
+            swap::Swap,
+            swap2::Swap2,
+            swap_event::SwapEvent,
+        )
+    }
 }
@@ -3,11 +3,12 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x482ce18db2826139")]
 pub struct BookSide {
     pub roots: [OrderTreeRoot; 2],
     pub reserved_roots: [OrderTreeRoot; 4],
+    #[serde(with = "serde_big_array::BigArray")]
     pub reserved: [u8; 256],
     pub nodes: OrderTreeNodes,
 }
@@ -10,6 +10,7 @@ pub mod open_orders_account;
 pub mod open_orders_indexer;
 pub mod stub_oracle;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum OpenbookV2Account {
     Market(market::Market),
     OpenOrdersAccount(open_orders_account::OpenOrdersAccount),
@@ -96,4 +97,23 @@ impl AccountDecoder<'_> for OpenbookV2Decoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            market::Market,
+            open_orders_account::OpenOrdersAccount,
+            open_orders_indexer::OpenOrdersIndexer,
+            stub_oracle::StubOracle,
+            book_side::BookSide,
+            event_heap::EventHeap,
+        )
+    }
 }
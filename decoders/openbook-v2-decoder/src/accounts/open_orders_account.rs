@@ -3,7 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0xffc24e7b1069d0a5")]
 pub struct OpenOrdersAccount {
     pub owner: solana_sdk::pubkey::Pubkey,
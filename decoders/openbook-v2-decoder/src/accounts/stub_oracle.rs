@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0xe0fbfe63b1ae8904")]
 pub struct StubOracle {
     pub owner: solana_sdk::pubkey::Pubkey,
@@ -9,5 +9,6 @@ pub struct StubOracle {
     pub last_update_ts: i64,
     pub last_update_slot: u64,
     pub deviation: f64,
+    #[serde(with = "serde_big_array::BigArray")]
     pub reserved: [u8; 104],
 }
@@ -133,4 +133,54 @@ impl carbon_core::instruction::InstructionDecoder<'_> for OpenbookV2Decoder {
             OpenbookV2Instruction::OpenOrdersPositionLogEvent => open_orders_position_log_event::OpenOrdersPositionLogEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            create_market::CreateMarket,
+            close_market::CloseMarket,
+            create_open_orders_indexer::CreateOpenOrdersIndexer,
+            close_open_orders_indexer::CloseOpenOrdersIndexer,
+            create_open_orders_account::CreateOpenOrdersAccount,
+            close_open_orders_account::CloseOpenOrdersAccount,
+            place_order::PlaceOrder,
+            edit_order::EditOrder,
+            edit_order_pegged::EditOrderPegged,
+            place_orders::PlaceOrders,
+            cancel_all_and_place_orders::CancelAllAndPlaceOrders,
+            place_order_pegged::PlaceOrderPegged,
+            place_take_order::PlaceTakeOrder,
+            consume_events::ConsumeEvents,
+            consume_given_events::ConsumeGivenEvents,
+            cancel_order::CancelOrder,
+            cancel_order_by_client_order_id::CancelOrderByClientOrderId,
+            cancel_all_orders::CancelAllOrders,
+            deposit::Deposit,
+            refill::Refill,
+            settle_funds::SettleFunds,
+            settle_funds_expired::SettleFundsExpired,
+            sweep_fees::SweepFees,
+            set_delegate::SetDelegate,
+            set_market_expired::SetMarketExpired,
+            prune_orders::PruneOrders,
+            stub_oracle_create::StubOracleCreate,
+            stub_oracle_close::StubOracleClose,
+            stub_oracle_set::StubOracleSet,
+            deposit_log_event::DepositLogEvent,
+            fill_log_event::FillLogEvent,
+            market_meta_data_log_event::MarketMetaDataLogEvent,
+            total_order_fill_event::TotalOrderFillEvent,
+            set_delegate_log_event::SetDelegateLogEvent,
+            settle_funds_log_event::SettleFundsLogEvent,
+            sweep_fees_log_event::SweepFeesLogEvent,
+            open_orders_position_log_event::OpenOrdersPositionLogEvent,
+        )
+    }
 }
@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x384b9f4c8e44be69")]
 pub struct FeeTier {
     pub whirlpools_config: solana_sdk::pubkey::Pubkey,
@@ -12,6 +12,7 @@ pub mod whirlpool;
 pub mod whirlpools_config;
 pub mod whirlpools_config_extension;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum OrcaWhirlpoolAccount {
     WhirlpoolsConfigExtension(whirlpools_config_extension::WhirlpoolsConfigExtension),
     WhirlpoolsConfig(whirlpools_config::WhirlpoolsConfig),
@@ -124,4 +125,25 @@ impl AccountDecoder<'_> for OrcaWhirlpoolDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            whirlpools_config_extension::WhirlpoolsConfigExtension,
+            whirlpools_config::WhirlpoolsConfig,
+            fee_tier::FeeTier,
+            position_bundle::PositionBundle,
+            position::Position,
+            tick_array::TickArray,
+            token_badge::TokenBadge,
+            whirlpool::Whirlpool,
+        )
+    }
 }
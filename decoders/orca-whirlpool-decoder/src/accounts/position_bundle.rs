@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x81a9af41b95f2064")]
 pub struct PositionBundle {
     pub position_bundle_mint: solana_sdk::pubkey::Pubkey,
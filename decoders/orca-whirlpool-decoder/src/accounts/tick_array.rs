@@ -3,10 +3,11 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x4561bdbe6e0742bb")]
 pub struct TickArray {
     pub start_tick_index: i32,
+    #[serde(with = "serde_big_array::BigArray")]
     pub ticks: [Tick; 88],
     pub whirlpool: solana_sdk::pubkey::Pubkey,
 }
@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x74dbcce5f974ff96")]
 pub struct TokenBadge {
     pub whirlpools_config: solana_sdk::pubkey::Pubkey,
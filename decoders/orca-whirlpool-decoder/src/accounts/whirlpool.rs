@@ -3,7 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x3f95d10ce1806309")]
 pub struct Whirlpool {
     pub whirlpools_config: solana_sdk::pubkey::Pubkey,
@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x9d1431e0d957c1fe")]
 pub struct WhirlpoolsConfig {
     pub fee_authority: solana_sdk::pubkey::Pubkey,
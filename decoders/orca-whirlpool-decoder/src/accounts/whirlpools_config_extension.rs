@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x0263d7a3f01a993a")]
 pub struct WhirlpoolsConfigExtension {
     pub whirlpools_config: solana_sdk::pubkey::Pubkey,
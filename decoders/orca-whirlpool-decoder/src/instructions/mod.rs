@@ -175,4 +175,63 @@ impl carbon_core::instruction::InstructionDecoder<'_> for OrcaWhirlpoolDecoder {
             OrcaWhirlpoolInstruction::DeleteTokenBadge => delete_token_badge::DeleteTokenBadge,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            initialize_config::InitializeConfig,
+            initialize_pool::InitializePool,
+            initialize_tick_array::InitializeTickArray,
+            initialize_fee_tier::InitializeFeeTier,
+            initialize_reward::InitializeReward,
+            set_reward_emissions::SetRewardEmissions,
+            open_position::OpenPosition,
+            open_position_with_metadata::OpenPositionWithMetadata,
+            increase_liquidity::IncreaseLiquidity,
+            decrease_liquidity::DecreaseLiquidity,
+            update_fees_and_rewards::UpdateFeesAndRewards,
+            collect_fees::CollectFees,
+            collect_reward::CollectReward,
+            collect_protocol_fees::CollectProtocolFees,
+            swap::Swap,
+            close_position::ClosePosition,
+            set_default_fee_rate::SetDefaultFeeRate,
+            set_default_protocol_fee_rate::SetDefaultProtocolFeeRate,
+            set_fee_rate::SetFeeRate,
+            set_protocol_fee_rate::SetProtocolFeeRate,
+            set_fee_authority::SetFeeAuthority,
+            set_collect_protocol_fees_authority::SetCollectProtocolFeesAuthority,
+            set_reward_authority::SetRewardAuthority,
+            set_reward_authority_by_super_authority::SetRewardAuthorityBySuperAuthority,
+            set_reward_emissions_super_authority::SetRewardEmissionsSuperAuthority,
+            two_hop_swap::TwoHopSwap,
+            initialize_position_bundle::InitializePositionBundle,
+            initialize_position_bundle_with_metadata::InitializePositionBundleWithMetadata,
+            delete_position_bundle::DeletePositionBundle,
+            open_bundled_position::OpenBundledPosition,
+            close_bundled_position::CloseBundledPosition,
+            collect_fees_v2::CollectFeesV2,
+            collect_protocol_fees_v2::CollectProtocolFeesV2,
+            collect_reward_v2::CollectRewardV2,
+            decrease_liquidity_v2::DecreaseLiquidityV2,
+            increase_liquidity_v2::IncreaseLiquidityV2,
+            initialize_pool_v2::InitializePoolV2,
+            initialize_reward_v2::InitializeRewardV2,
+            set_reward_emissions_v2::SetRewardEmissionsV2,
+            swap_v2::SwapV2,
+            two_hop_swap_v2::TwoHopSwapV2,
+            initialize_config_extension::InitializeConfigExtension,
+            set_config_extension_authority::SetConfigExtensionAuthority,
+            set_token_badge_authority::SetTokenBadgeAuthority,
+            initialize_token_badge::InitializeTokenBadge,
+            delete_token_badge::DeleteTokenBadge,
+        )
+    }
 }
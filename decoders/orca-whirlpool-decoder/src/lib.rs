@@ -3,6 +3,8 @@ use solana_sdk::{pubkey, pubkey::Pubkey};
 pub struct OrcaWhirlpoolDecoder;
 pub mod accounts;
 pub mod instructions;
+#[cfg(feature = "math")]
+pub mod math;
 pub mod types;
 
 pub const PROGRAM_ID: Pubkey = pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
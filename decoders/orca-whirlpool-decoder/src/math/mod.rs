@@ -0,0 +1,106 @@
+//! Price, liquidity and swap quote math for whirlpools, following the
+//! program's fixed point arithmetic.
+//!
+//! Square root prices are Q64.64 fixed point numbers, and prices are in
+//! token B per token A. Fee rates are in hundredths of a basis point.
+
+pub mod quote;
+pub mod swap_math;
+pub mod tick_math;
+
+pub use quote::SwapQuote;
+
+use {
+    crate::accounts::whirlpool::Whirlpool,
+    swap_math::{amount_delta_a, amount_delta_b},
+    tick_math::sqrt_price_from_tick_index,
+    u256::U256,
+};
+
+mod u256 {
+    // The expansion of `construct_uint!` rounds divisions up by hand.
+    #![allow(clippy::manual_div_ceil)]
+
+    uint::construct_uint! {
+        /// Holds the intermediate products of the Q64.64 arithmetic.
+        pub struct U256(4);
+    }
+}
+
+/// Converts a Q64.64 square root price to a price in token B per token A,
+/// adjusted for the mints' decimals.
+pub fn sqrt_price_to_price(sqrt_price: u128, decimals_a: u8, decimals_b: u8) -> f64 {
+    let sqrt_price = sqrt_price as f64 / (1u128 << 64) as f64;
+    sqrt_price * sqrt_price * 10f64.powi(decimals_a as i32 - decimals_b as i32)
+}
+
+/// Converts a price in token B per token A, adjusted for the mints'
+/// decimals, to a Q64.64 square root price.
+pub fn price_to_sqrt_price(price: f64, decimals_a: u8, decimals_b: u8) -> u128 {
+    let price = price * 10f64.powi(decimals_b as i32 - decimals_a as i32);
+    (price.sqrt() * (1u128 << 64) as f64) as u128
+}
+
+/// Converts a tick index to a price in token B per token A, adjusted for the
+/// mints' decimals.
+pub fn tick_index_to_price(tick_index: i32, decimals_a: u8, decimals_b: u8) -> f64 {
+    1.0001f64.powi(tick_index) * 10f64.powi(decimals_a as i32 - decimals_b as i32)
+}
+
+/// Returns the amounts of tokens A and B that `liquidity` holds between
+/// `tick_lower_index` and `tick_upper_index` at `sqrt_price`.
+///
+/// Rounding up gives the amounts a deposit of `liquidity` takes, rounding
+/// down the amounts a withdrawal returns.
+pub fn token_amounts_from_liquidity(
+    liquidity: u128,
+    sqrt_price: u128,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    round_up: bool,
+) -> Option<(u64, u64)> {
+    let sqrt_price_lower = sqrt_price_from_tick_index(tick_lower_index)?;
+    let sqrt_price_upper = sqrt_price_from_tick_index(tick_upper_index)?;
+
+    let (amount_a, amount_b) = if sqrt_price <= sqrt_price_lower {
+        (
+            amount_delta_a(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?,
+            0,
+        )
+    } else if sqrt_price < sqrt_price_upper {
+        (
+            amount_delta_a(sqrt_price, sqrt_price_upper, liquidity, round_up)?,
+            amount_delta_b(sqrt_price_lower, sqrt_price, liquidity, round_up)?,
+        )
+    } else {
+        (
+            0,
+            amount_delta_b(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?,
+        )
+    };
+
+    Some((u64::try_from(amount_a).ok()?, u64::try_from(amount_b).ok()?))
+}
+
+impl Whirlpool {
+    /// Returns the spot price in token B per token A, adjusted for the
+    /// mints' decimals.
+    pub fn price(&self, decimals_a: u8, decimals_b: u8) -> f64 {
+        sqrt_price_to_price(self.sqrt_price, decimals_a, decimals_b)
+    }
+
+    /// Returns the amounts of tokens A and B that the active liquidity holds
+    /// between the initializable ticks around the current price.
+    pub fn liquidity_in_range(&self) -> Option<(u64, u64)> {
+        let tick_spacing = self.tick_spacing as i32;
+        let tick_lower_index =
+            self.tick_current_index.checked_div_euclid(tick_spacing)? * tick_spacing;
+        token_amounts_from_liquidity(
+            self.liquidity,
+            self.sqrt_price,
+            tick_lower_index,
+            tick_lower_index + tick_spacing,
+            false,
+        )
+    }
+}
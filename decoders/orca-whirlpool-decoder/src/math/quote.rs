@@ -0,0 +1,325 @@
+//! Swap quotes across a whirlpool's tick arrays.
+
+use {
+    super::{
+        swap_math::compute_swap_step,
+        tick_math::{
+            sqrt_price_from_tick_index, tick_array_start_index, tick_index_from_sqrt_price,
+            MAX_SQRT_PRICE, MAX_TICK_INDEX, MIN_SQRT_PRICE, MIN_TICK_INDEX,
+        },
+    },
+    crate::accounts::{tick_array::TickArray, whirlpool::Whirlpool},
+};
+
+/// The amounts of a quoted swap and the pool's state after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SwapQuote {
+    /// The input amount, fees included.
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee_amount: u64,
+    pub sqrt_price: u128,
+    pub tick_current_index: i32,
+    pub liquidity: u128,
+}
+
+impl Whirlpool {
+    /// Quotes swapping exactly `amount_in` of token A, if `a_to_b`, or of
+    /// token B.
+    ///
+    /// `tick_arrays` must cover the ticks the swap crosses, in any order;
+    /// returns `None` if they don't or if an amount overflows.
+    pub fn quote_exact_in(
+        &self,
+        tick_arrays: &[TickArray],
+        amount_in: u64,
+        a_to_b: bool,
+    ) -> Option<SwapQuote> {
+        self.quote(tick_arrays, amount_in, true, a_to_b)
+    }
+
+    /// Quotes swapping for exactly `amount_out` of token B, if `a_to_b`, or
+    /// of token A.
+    ///
+    /// `tick_arrays` must cover the ticks the swap crosses, in any order;
+    /// returns `None` if they don't, if the pool's liquidity can't pay out
+    /// `amount_out` or if an amount overflows.
+    pub fn quote_exact_out(
+        &self,
+        tick_arrays: &[TickArray],
+        amount_out: u64,
+        a_to_b: bool,
+    ) -> Option<SwapQuote> {
+        self.quote(tick_arrays, amount_out, false, a_to_b)
+    }
+
+    fn quote(
+        &self,
+        tick_arrays: &[TickArray],
+        amount: u64,
+        amount_specified_is_input: bool,
+        a_to_b: bool,
+    ) -> Option<SwapQuote> {
+        let sqrt_price_limit = if a_to_b {
+            MIN_SQRT_PRICE
+        } else {
+            MAX_SQRT_PRICE
+        };
+
+        let mut amount_remaining = amount;
+        let mut amount_calculated: u64 = 0;
+        let mut fee_amount: u64 = 0;
+        let mut sqrt_price = self.sqrt_price;
+        let mut tick_current_index = self.tick_current_index;
+        let mut liquidity = self.liquidity;
+
+        while amount_remaining > 0 && sqrt_price != sqrt_price_limit {
+            let (next_tick_index, liquidity_net) =
+                next_initialized_tick(tick_arrays, tick_current_index, self.tick_spacing, a_to_b)?;
+            let next_tick_sqrt_price = sqrt_price_from_tick_index(next_tick_index)?;
+            let sqrt_price_target = if a_to_b {
+                next_tick_sqrt_price.max(sqrt_price_limit)
+            } else {
+                next_tick_sqrt_price.min(sqrt_price_limit)
+            };
+
+            let step = compute_swap_step(
+                amount_remaining,
+                self.fee_rate,
+                liquidity,
+                sqrt_price,
+                sqrt_price_target,
+                amount_specified_is_input,
+                a_to_b,
+            )?;
+
+            if amount_specified_is_input {
+                amount_remaining = amount_remaining
+                    .checked_sub(step.amount_in)?
+                    .checked_sub(step.fee_amount)?;
+                amount_calculated = amount_calculated.checked_add(step.amount_out)?;
+            } else {
+                amount_remaining = amount_remaining.checked_sub(step.amount_out)?;
+                amount_calculated = amount_calculated
+                    .checked_add(step.amount_in)?
+                    .checked_add(step.fee_amount)?;
+            }
+            fee_amount = fee_amount.checked_add(step.fee_amount)?;
+
+            if step.next_sqrt_price == next_tick_sqrt_price {
+                let liquidity_net = if a_to_b {
+                    -liquidity_net
+                } else {
+                    liquidity_net
+                };
+                liquidity = liquidity.checked_add_signed(liquidity_net)?;
+                tick_current_index = if a_to_b {
+                    next_tick_index - 1
+                } else {
+                    next_tick_index
+                };
+            } else if step.next_sqrt_price != sqrt_price {
+                tick_current_index = tick_index_from_sqrt_price(step.next_sqrt_price)?;
+            }
+            sqrt_price = step.next_sqrt_price;
+        }
+
+        if !amount_specified_is_input && amount_remaining > 0 {
+            return None;
+        }
+
+        let (amount_in, amount_out) = if amount_specified_is_input {
+            (amount - amount_remaining, amount_calculated)
+        } else {
+            (amount_calculated, amount)
+        };
+
+        Some(SwapQuote {
+            amount_in,
+            amount_out,
+            fee_amount,
+            sqrt_price,
+            tick_current_index,
+            liquidity,
+        })
+    }
+}
+
+/// Returns the next initialized tick from `tick_current_index` in the swap's
+/// direction, with its net liquidity.
+///
+/// If the tick arrays end before an initialized tick, returns the last tick
+/// they cover, with no net liquidity, so swaps that don't reach it can still
+/// be quoted.
+fn next_initialized_tick(
+    tick_arrays: &[TickArray],
+    tick_current_index: i32,
+    tick_spacing: u16,
+    a_to_b: bool,
+) -> Option<(i32, i128)> {
+    let spacing = tick_spacing as i32;
+    if spacing == 0 {
+        return None;
+    }
+
+    // a_to_b swaps include the current tick, which they cross when the price
+    // reaches its lower bound.
+    let mut tick_index = tick_current_index.div_euclid(spacing) * spacing;
+    if !a_to_b {
+        tick_index += spacing;
+    }
+
+    let mut last_covered = None;
+    while (MIN_TICK_INDEX..=MAX_TICK_INDEX).contains(&tick_index) {
+        let start_tick_index = tick_array_start_index(tick_index, tick_spacing);
+        let Some(tick_array) = tick_arrays
+            .iter()
+            .find(|tick_array| tick_array.start_tick_index == start_tick_index)
+        else {
+            return last_covered.map(|tick_index| (tick_index, 0));
+        };
+
+        let offset = ((tick_index - start_tick_index) / spacing) as usize;
+        let tick = tick_array.ticks.get(offset)?;
+        if tick.initialized {
+            return Some((tick_index, tick.liquidity_net));
+        }
+
+        last_covered = Some(tick_index);
+        tick_index += if a_to_b { -spacing } else { spacing };
+    }
+
+    Some((tick_index.clamp(MIN_TICK_INDEX, MAX_TICK_INDEX), 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{accounts::OrcaWhirlpoolAccount, OrcaWhirlpoolDecoder},
+        carbon_core::account::AccountDecoder,
+    };
+
+    // The fixtures hold a pool with 64 tick spacing, a 0.3% fee and 10^10
+    // liquidity at tick 10, of which 3 * 10^9 and 2 * 10^9 end at ticks 64 and
+    // 192 and 5 * 10^9 at tick -128, and its tick arrays starting at 0 and
+    // -5632.
+    fn decode_account(path: &str) -> OrcaWhirlpoolAccount {
+        let account = carbon_test_utils::read_account(path).expect("read fixture");
+
+        OrcaWhirlpoolDecoder
+            .decode_account(&account)
+            .expect("decode fixture")
+            .data
+    }
+
+    fn whirlpool() -> Whirlpool {
+        match decode_account("tests/fixtures/whirlpool_account.json") {
+            OrcaWhirlpoolAccount::Whirlpool(whirlpool) => whirlpool,
+            account => panic!("expected a whirlpool, got {account:?}"),
+        }
+    }
+
+    fn tick_arrays() -> Vec<TickArray> {
+        [
+            "tests/fixtures/tick_array_0_account.json",
+            "tests/fixtures/tick_array_-5632_account.json",
+        ]
+        .into_iter()
+        .map(|path| match decode_account(path) {
+            OrcaWhirlpoolAccount::TickArray(tick_array) => tick_array,
+            account => panic!("expected a tick array, got {account:?}"),
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_quote_within_tick() {
+        let quote = whirlpool()
+            .quote_exact_in(&tick_arrays(), 1_000_000, true)
+            .expect("quote");
+
+        assert_eq!(
+            quote,
+            SwapQuote {
+                amount_in: 1_000_000,
+                amount_out: 997897,
+                fee_amount: 3000,
+                sqrt_price: 18454128493995572058,
+                tick_current_index: 8,
+                liquidity: 10_000_000_000,
+            }
+        );
+    }
+
+    #[test]
+    fn test_quote_exact_in_crossing_ticks() {
+        let whirlpool = whirlpool();
+        let tick_arrays = tick_arrays();
+
+        assert_eq!(
+            whirlpool.quote_exact_in(&tick_arrays, 80_000_000, true),
+            Some(SwapQuote {
+                amount_in: 80_000_000,
+                amount_out: 79196814,
+                fee_amount: 240001,
+                sqrt_price: 18290686148328759175,
+                tick_current_index: -170,
+                liquidity: 5_000_000_000,
+            })
+        );
+        assert_eq!(
+            whirlpool.quote_exact_in(&tick_arrays, 1_000_000_000, false),
+            Some(SwapQuote {
+                amount_in: 1_000_000_000,
+                amount_out: 838257737,
+                fee_amount: 3000002,
+                sqrt_price: 22036829542117154993,
+                tick_current_index: 3556,
+                liquidity: 5_000_000_000,
+            })
+        );
+    }
+
+    #[test]
+    fn test_quote_exact_out_crossing_ticks() {
+        let whirlpool = whirlpool();
+        let tick_arrays = tick_arrays();
+
+        assert_eq!(
+            whirlpool.quote_exact_out(&tick_arrays, 70_000_000, true),
+            Some(SwapQuote {
+                amount_in: 70634807,
+                amount_out: 70_000_000,
+                fee_amount: 211906,
+                sqrt_price: 18324616403216535019,
+                tick_current_index: -133,
+                liquidity: 5_000_000_000,
+            })
+        );
+        assert_eq!(
+            whirlpool.quote_exact_out(&tick_arrays, 30_000_000, false),
+            Some(SwapQuote {
+                amount_in: 30211461,
+                amount_out: 30_000_000,
+                fee_amount: 90636,
+                sqrt_price: 18513961186115516439,
+                tick_current_index: 72,
+                liquidity: 7_000_000_000,
+            })
+        );
+    }
+
+    #[test]
+    fn test_quote_beyond_tick_arrays() {
+        assert_eq!(
+            whirlpool().quote_exact_in(&tick_arrays(), 1_000_000_000_000, true),
+            None
+        );
+    }
+
+    #[test]
+    fn test_liquidity_in_range() {
+        assert_eq!(whirlpool().liquidity_in_range(), Some((26948759, 5001000)));
+    }
+}
@@ -0,0 +1,169 @@
+//! Conversions between tick indexes and square root prices.
+
+use super::U256;
+
+pub const MIN_TICK_INDEX: i32 = -443636;
+pub const MAX_TICK_INDEX: i32 = 443636;
+pub const MIN_SQRT_PRICE: u128 = 4295048016;
+pub const MAX_SQRT_PRICE: u128 = 79226673515401279992447579055;
+pub const TICK_ARRAY_SIZE: i32 = 88;
+
+/// `sqrt(1.0001)^(2^i)` as Q64.96 fixed point numbers, rounded down.
+const SQRT_POWERS_X96: [u128; 19] = [
+    79232123823359799118286999567,
+    79236085330515764027303304731,
+    79244008939048815603706035061,
+    79259858533276714757314932305,
+    79291567232598584799939703904,
+    79355022692464371645785046466,
+    79482085999252804386437311141,
+    79736823300114093921829183326,
+    80248749790819932309965073892,
+    81282483887344747381513967011,
+    83390072131320151908154831281,
+    87770609709833776024991924138,
+    97234110755111693312479820773,
+    119332217159966728226237229890,
+    179736315981702064433883588727,
+    407748233172238350107850275304,
+    2098478828474011932436660412517,
+    55581415166113811149459800483533,
+    38992368544603139932233054999993551,
+];
+
+/// `1 / sqrt(1.0001)^(2^i)` as Q64.64 fixed point numbers, rounded down.
+const INVERSE_SQRT_POWERS_X64: [u128; 19] = [
+    18445821805675392311,
+    18444899583751176498,
+    18443055278223354162,
+    18439367220385604838,
+    18431993317065449817,
+    18417254355718160513,
+    18387811781193591352,
+    18329067761203520168,
+    18212142134806087854,
+    17980523815641551639,
+    17526086738831147013,
+    16651378430235024244,
+    15030750278693429944,
+    12247334978882834399,
+    8131365268884726200,
+    3584323654723342297,
+    696457651847595233,
+    26294789957452057,
+    37481735321082,
+];
+
+/// Returns the Q64.64 square root price at `tick_index`, `sqrt(1.0001^tick)`,
+/// or `None` if the tick is out of bounds.
+///
+/// Follows the program's computation, which multiplies the powers of
+/// `sqrt(1.0001)` for each bit of the tick index and rounds down after each
+/// multiplication.
+pub fn sqrt_price_from_tick_index(tick_index: i32) -> Option<u128> {
+    if !(MIN_TICK_INDEX..=MAX_TICK_INDEX).contains(&tick_index) {
+        return None;
+    }
+
+    let abs_tick_index = tick_index.unsigned_abs();
+    if tick_index >= 0 {
+        let mut ratio = U256::from(1u128 << 96);
+        for (i, power) in SQRT_POWERS_X96.iter().enumerate() {
+            if abs_tick_index & (1 << i) != 0 {
+                ratio = ratio.checked_mul(U256::from(*power))? >> 96;
+            }
+        }
+        u128::try_from(ratio >> 32).ok()
+    } else {
+        let mut ratio: u128 = 1 << 64;
+        for (i, power) in INVERSE_SQRT_POWERS_X64.iter().enumerate() {
+            if abs_tick_index & (1 << i) != 0 {
+                ratio = ratio.checked_mul(*power)? >> 64;
+            }
+        }
+        Some(ratio)
+    }
+}
+
+/// Returns the tick index whose range contains `sqrt_price`: the greatest
+/// tick whose square root price is at most `sqrt_price`.
+pub fn tick_index_from_sqrt_price(sqrt_price: u128) -> Option<i32> {
+    if !(MIN_SQRT_PRICE..=MAX_SQRT_PRICE).contains(&sqrt_price) {
+        return None;
+    }
+
+    let (mut low, mut high) = (MIN_TICK_INDEX, MAX_TICK_INDEX);
+    while low < high {
+        let middle = low + (high - low + 1) / 2;
+        if sqrt_price_from_tick_index(middle)? <= sqrt_price {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+    Some(low)
+}
+
+/// Returns the start tick index of the tick array containing `tick_index`.
+pub fn tick_array_start_index(tick_index: i32, tick_spacing: u16) -> i32 {
+    let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+    tick_index.div_euclid(ticks_in_array) * ticks_in_array
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sqrt_price_bounds() {
+        assert_eq!(
+            sqrt_price_from_tick_index(MIN_TICK_INDEX),
+            Some(MIN_SQRT_PRICE)
+        );
+        assert_eq!(
+            sqrt_price_from_tick_index(MAX_TICK_INDEX),
+            Some(MAX_SQRT_PRICE)
+        );
+        assert_eq!(sqrt_price_from_tick_index(MIN_TICK_INDEX - 1), None);
+        assert_eq!(sqrt_price_from_tick_index(MAX_TICK_INDEX + 1), None);
+    }
+
+    #[test]
+    fn test_sqrt_price_from_tick_index() {
+        assert_eq!(sqrt_price_from_tick_index(0), Some(1 << 64));
+        assert_eq!(sqrt_price_from_tick_index(1), Some(18447666387855959850));
+        assert_eq!(sqrt_price_from_tick_index(-1), Some(18445821805675392311));
+        assert_eq!(sqrt_price_from_tick_index(100), Some(18539204128674405812));
+        assert_eq!(sqrt_price_from_tick_index(-100), Some(18354745142194483561));
+        assert_eq!(
+            sqrt_price_from_tick_index(12345),
+            Some(34195943348800206620)
+        );
+        assert_eq!(sqrt_price_from_tick_index(-300000), Some(5647135299341));
+    }
+
+    #[test]
+    fn test_tick_index_from_sqrt_price() {
+        for tick_index in [MIN_TICK_INDEX, -300000, -1, 0, 1, 12345, MAX_TICK_INDEX] {
+            let sqrt_price = sqrt_price_from_tick_index(tick_index).unwrap();
+            assert_eq!(tick_index_from_sqrt_price(sqrt_price), Some(tick_index));
+            if tick_index < MAX_TICK_INDEX {
+                assert_eq!(tick_index_from_sqrt_price(sqrt_price + 1), Some(tick_index));
+            }
+            if tick_index > MIN_TICK_INDEX {
+                assert_eq!(
+                    tick_index_from_sqrt_price(sqrt_price - 1),
+                    Some(tick_index - 1)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_tick_array_start_index() {
+        assert_eq!(tick_array_start_index(0, 64), 0);
+        assert_eq!(tick_array_start_index(5631, 64), 0);
+        assert_eq!(tick_array_start_index(5632, 64), 5632);
+        assert_eq!(tick_array_start_index(-1, 64), -5632);
+    }
+}
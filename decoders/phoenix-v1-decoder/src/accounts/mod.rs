@@ -7,6 +7,7 @@ use super::PhoenixDecoder;
 pub mod market_header;
 pub mod seat;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum PhoenixAccount {
     MarketHeader(market_header::MarketHeader),
     Seat(seat::Seat),
@@ -46,4 +47,19 @@ impl<'a> AccountDecoder<'a> for PhoenixDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            market_header::MarketHeader,
+            seat::Seat,
+        )
+    }
 }
@@ -117,4 +117,45 @@ impl<'a> carbon_core::instruction::InstructionDecoder<'a> for PhoenixDecoder {
             PhoenixInstruction::ChangeFeeRecipient => change_fee_recipient::ChangeFeeRecipient,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            swap::Swap,
+            swap_with_free_funds::SwapWithFreeFunds,
+            place_limit_order::PlaceLimitOrder,
+            place_limit_order_with_free_funds::PlaceLimitOrderWithFreeFunds,
+            reduce_order::ReduceOrder,
+            reduce_order_with_free_funds::ReduceOrderWithFreeFunds,
+            cancel_all_orders::CancelAllOrders,
+            cancel_all_orders_with_free_funds::CancelAllOrdersWithFreeFunds,
+            cancel_up_to::CancelUpTo,
+            cancel_up_to_with_free_funds::CancelUpToWithFreeFunds,
+            cancel_multiple_orders_by_id::CancelMultipleOrdersById,
+            cancel_multiple_orders_by_id_with_free_funds::CancelMultipleOrdersByIdWithFreeFunds,
+            withdraw_funds::WithdrawFunds,
+            deposit_funds::DepositFunds,
+            request_seat::RequestSeat,
+            log::Log,
+            place_multiple_post_only_orders::PlaceMultiplePostOnlyOrders,
+            place_multiple_post_only_orders_with_free_funds::PlaceMultiplePostOnlyOrdersWithFreeFunds,
+            initialize_market::InitializeMarket,
+            claim_authority::ClaimAuthority,
+            name_successor::NameSuccessor,
+            change_market_status::ChangeMarketStatus,
+            change_seat_status::ChangeSeatStatus,
+            request_seat_authorized::RequestSeatAuthorized,
+            evict_seat::EvictSeat,
+            force_cancel_orders::ForceCancelOrders,
+            collect_fees::CollectFees,
+            change_fee_recipient::ChangeFeeRecipient,
+        )
+    }
 }
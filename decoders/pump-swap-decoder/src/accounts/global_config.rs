@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x95089ccaa0fcb0d9")]
 pub struct GlobalConfig {
     pub admin: solana_sdk::pubkey::Pubkey,
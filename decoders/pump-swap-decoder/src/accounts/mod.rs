@@ -7,6 +7,7 @@ use super::PumpSwapDecoder;
 pub mod global_config;
 pub mod pool;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum PumpSwapAccount {
     GlobalConfig(global_config::GlobalConfig),
     Pool(pool::Pool),
@@ -46,4 +47,19 @@ impl<'a> AccountDecoder<'a> for PumpSwapDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            global_config::GlobalConfig,
+            pool::Pool,
+        )
+    }
 }
@@ -89,4 +89,37 @@ impl<'a> carbon_core::instruction::InstructionDecoder<'a> for PumpSwapDecoder {
             PumpSwapInstruction::WithdrawEvent => withdraw_event::WithdrawEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            buy::Buy,
+            create_config::CreateConfig,
+            create_pool::CreatePool,
+            deposit::Deposit,
+            disable::Disable,
+            extend_account::ExtendAccount,
+            sell::Sell,
+            update_admin::UpdateAdmin,
+            update_fee_config::UpdateFeeConfig,
+            withdraw::Withdraw,
+            buy_event::BuyEvent,
+            create_config_event::CreateConfigEvent,
+            create_pool_event::CreatePoolEvent,
+            deposit_event::DepositEvent,
+            disable_event::DisableEvent,
+            extend_account_event::ExtendAccountEvent,
+            sell_event::SellEvent,
+            update_admin_event::UpdateAdminEvent,
+            update_fee_config_event::UpdateFeeConfigEvent,
+            withdraw_event::WithdrawEvent,
+        )
+    }
 }
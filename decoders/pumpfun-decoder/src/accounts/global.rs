@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Global {
     pub _padding: [u8; 8],
     pub initialized: bool,
@@ -11,6 +11,7 @@ pub struct Global {
     pub initial_real_token_reserves: u64,
     pub token_total_supply: u64,
     pub fee_basis_points: u64,
+    #[serde(with = "serde_big_array::BigArray")]
     pub _reserved: [u8; 399],
 }
 
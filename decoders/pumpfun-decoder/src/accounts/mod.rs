@@ -9,6 +9,7 @@ pub mod global;
 pub mod last_withdraw;
 
 #[allow(clippy::large_enum_variant)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum PumpAccount {
     BondingCurve(bonding_curve::BondingCurve),
     Global(global::Global),
@@ -61,6 +62,22 @@ impl AccountDecoder<'_> for PumpfunDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            bonding_curve::BondingCurve,
+            global::Global,
+            last_withdraw::LastWithdraw,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -59,11 +59,38 @@ impl carbon_core::instruction::InstructionDecoder<'_> for PumpfunDecoder {
             PumpfunInstruction::TradeEvent => trade_event::TradeEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            buy::Buy,
+            create::Create,
+            initialize::Initialize,
+            sell::Sell,
+            set_params::SetParams,
+            withdraw::Withdraw,
+            complete_event::CompleteEvent,
+            create_event::CreateEvent,
+            set_params_event::SetParamsEvent,
+            trade_event::TradeEvent,
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionDecoder};
+    use carbon_core::{
+        decode::{DecodeFailure, DecodeOutcome},
+        deserialize::ArrangeAccounts,
+        instruction::InstructionDecoder,
+    };
     use solana_instruction::AccountMeta;
     use solana_pubkey::Pubkey;
 
@@ -447,4 +474,46 @@ mod tests {
         assert_eq!(decoded.program_id, PROGRAM_ID);
         assert_eq!(decoded_arranged_accounts, expected_arranged_accounts);
     }
+
+    #[test]
+    fn test_decode_outcome() {
+        // Arrange
+        let decoder = PumpfunDecoder;
+        let instruction = carbon_test_utils::read_instruction("tests/fixtures/buy_ix.json")
+            .expect("read fixture");
+
+        let mut truncated = instruction.clone();
+        truncated.data.truncate(12);
+
+        let mut unknown = instruction.clone();
+        unknown.data[..8].copy_from_slice(&[0xff; 8]);
+
+        let mut foreign = instruction.clone();
+        foreign.program_id = Pubkey::default();
+        foreign.data.clear();
+
+        // Act
+        let decoded = decoder.decode_instruction_outcome(&instruction, 0);
+        let truncated_outcome = decoder.decode_instruction_outcome(&truncated, 0);
+        let unknown_outcome = decoder.decode_instruction_outcome(&unknown, 0);
+        let foreign_outcome = decoder.decode_instruction_outcome(&foreign, 0);
+
+        // Assert
+        assert!(matches!(decoded, DecodeOutcome::Decoded(_)));
+        assert!(matches!(
+            truncated_outcome,
+            DecodeOutcome::Failed(DecodeFailure::Deserialize {
+                type_name: "Buy",
+                offset: 12,
+                ..
+            })
+        ));
+        assert_eq!(
+            unknown_outcome.failure(),
+            Some(&DecodeFailure::UnknownDiscriminator {
+                discriminator: vec![0xff; 8]
+            })
+        );
+        assert!(matches!(foreign_outcome, DecodeOutcome::NotOwned));
+    }
 }
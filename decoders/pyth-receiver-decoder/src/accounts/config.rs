@@ -0,0 +1,17 @@
+use {
+    super::super::types::*,
+    carbon_core::{borsh, CarbonDeserialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x9b0caae01efacc82")]
+pub struct Config {
+    pub governance_authority: solana_sdk::pubkey::Pubkey,
+    pub target_governance_authority: Option<solana_sdk::pubkey::Pubkey>,
+    pub wormhole: solana_sdk::pubkey::Pubkey,
+    pub valid_data_sources: Vec<DataSource>,
+    pub single_update_fee_in_lamports: u64,
+    pub minimum_signatures: u8,
+}
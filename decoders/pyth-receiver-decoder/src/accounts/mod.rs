@@ -0,0 +1,97 @@
+use carbon_core::account::AccountDecoder;
+use carbon_core::deserialize::CarbonDeserialize;
+
+use super::PythReceiverDecoder;
+use crate::PROGRAM_ID;
+pub mod config;
+pub mod price_update_v2;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub enum PythReceiverAccount {
+    Config(config::Config),
+    PriceUpdateV2(price_update_v2::PriceUpdateV2),
+}
+
+impl AccountDecoder<'_> for PythReceiverDecoder {
+    type AccountType = PythReceiverAccount;
+    fn decode_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) = config::Config::deserialize(account.data.as_slice()) {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: PythReceiverAccount::Config(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        if let Some(decoded_account) =
+            price_update_v2::PriceUpdateV2::deserialize(account.data.as_slice())
+        {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: PythReceiverAccount::PriceUpdateV2(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        // Accounts are allocated for their largest layout: a fully verified
+        // price update is a byte shorter than a partially verified one, and
+        // the config has room for more data sources than it holds.
+        if let Some(decoded_account) = carbon_core::deserialize::deserialize_with_trailing_bytes::<
+            price_update_v2::PriceUpdateV2,
+        >(
+            &[0x22, 0xf1, 0x23, 0x63, 0x9d, 0x7e, 0xf4, 0xcd],
+            account.data.as_slice(),
+        ) {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: PythReceiverAccount::PriceUpdateV2(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        if let Some(decoded_account) =
+            carbon_core::deserialize::deserialize_with_trailing_bytes::<config::Config>(
+                &[0x9b, 0x0c, 0xaa, 0xe0, 0x1e, 0xfa, 0xcc, 0x82],
+                account.data.as_slice(),
+            )
+        {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: PythReceiverAccount::Config(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        None
+    }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            config::Config,
+            price_update_v2::PriceUpdateV2,
+        )
+    }
+}
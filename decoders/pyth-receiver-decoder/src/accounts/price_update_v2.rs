@@ -0,0 +1,100 @@
+use {
+    super::super::types::*,
+    carbon_core::{borsh, CarbonDeserialize},
+};
+
+/// A price posted by the receiver from a verified Pythnet price update.
+///
+/// The price is `price_message.price * 10^price_message.exponent`; the
+/// accessors below return it in the same normalized form as the other oracle
+/// decoders.
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x22f123639d7ef4cd")]
+pub struct PriceUpdateV2 {
+    pub write_authority: solana_sdk::pubkey::Pubkey,
+    pub verification_level: VerificationLevel,
+    pub price_message: PriceFeedMessage,
+    pub posted_slot: u64,
+}
+
+impl PriceUpdateV2 {
+    /// The price mantissa, scaled by `10^exponent()`.
+    pub fn price(&self) -> i128 {
+        self.price_message.price as i128
+    }
+
+    /// The confidence interval around the price, scaled by `10^exponent()`.
+    pub fn conf(&self) -> u128 {
+        self.price_message.conf as u128
+    }
+
+    pub fn exponent(&self) -> i32 {
+        self.price_message.exponent
+    }
+
+    /// The Unix timestamp, in seconds, at which the price was published.
+    pub fn publish_time(&self) -> i64 {
+        self.price_message.publish_time
+    }
+
+    /// The price as a floating point number, for display.
+    pub fn ui_price(&self) -> f64 {
+        self.price() as f64 * 10f64.powi(self.exponent())
+    }
+
+    /// The confidence interval as a floating point number, for display.
+    pub fn ui_conf(&self) -> f64 {
+        self.conf() as f64 * 10f64.powi(self.exponent())
+    }
+
+    /// Whether the update was verified against a quorum of guardian
+    /// signatures. Partially verified updates should not be relied on for
+    /// valuation.
+    pub fn is_fully_verified(&self) -> bool {
+        self.verification_level == VerificationLevel::Full
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, carbon_core::deserialize::CarbonDeserialize};
+
+    #[test]
+    fn test_decode_price_update() {
+        // Arrange
+        let mut data = vec![0x22, 0xf1, 0x23, 0x63, 0x9d, 0x7e, 0xf4, 0xcd];
+        data.extend_from_slice(&[7; 32]);
+        data.push(1);
+        data.extend_from_slice(&[0xef; 32]);
+        data.extend_from_slice(&15_123_456_789i64.to_le_bytes());
+        data.extend_from_slice(&7_654_321u64.to_le_bytes());
+        data.extend_from_slice(&(-8i32).to_le_bytes());
+        data.extend_from_slice(&1_735_689_600i64.to_le_bytes());
+        data.extend_from_slice(&1_735_689_599i64.to_le_bytes());
+        data.extend_from_slice(&15_100_000_000i64.to_le_bytes());
+        data.extend_from_slice(&8_000_000u64.to_le_bytes());
+        data.extend_from_slice(&312_000_000u64.to_le_bytes());
+        // Accounts are allocated for the larger, partially verified layout.
+        data.push(0);
+
+        // Act
+        let update = carbon_core::deserialize::deserialize_with_trailing_bytes::<PriceUpdateV2>(
+            &data[..8],
+            &data,
+        )
+        .expect("decode price update");
+
+        // Assert
+        assert!(PriceUpdateV2::deserialize(&data).is_none());
+        assert!(update.is_fully_verified());
+        assert_eq!(update.price_message.feed_id, [0xef; 32]);
+        assert_eq!(update.price(), 15_123_456_789);
+        assert_eq!(update.conf(), 7_654_321);
+        assert_eq!(update.exponent(), -8);
+        assert_eq!(update.publish_time(), 1_735_689_600);
+        assert_eq!(update.posted_slot, 312_000_000);
+        assert!((update.ui_price() - 151.234_567_89).abs() < 1e-9);
+    }
+}
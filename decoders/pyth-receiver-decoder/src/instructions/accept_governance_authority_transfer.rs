@@ -0,0 +1,30 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xfe27de4f40d9cd7f")]
+pub struct AcceptGovernanceAuthorityTransfer {}
+
+#[derive(Debug, PartialEq)]
+pub struct AcceptGovernanceAuthorityTransferInstructionAccounts {
+    pub payer: solana_sdk::pubkey::Pubkey,
+    pub config: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for AcceptGovernanceAuthorityTransfer {
+    type ArrangedAccounts = AcceptGovernanceAuthorityTransferInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [payer, config, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AcceptGovernanceAuthorityTransferInstructionAccounts {
+            payer: payer.pubkey,
+            config: config.pubkey,
+        })
+    }
+}
@@ -0,0 +1,34 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xafaf6d1f0d989bed")]
+pub struct Initialize {
+    pub initial_config: crate::accounts::config::Config,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InitializeInstructionAccounts {
+    pub payer: solana_sdk::pubkey::Pubkey,
+    pub config: solana_sdk::pubkey::Pubkey,
+    pub system_program: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for Initialize {
+    type ArrangedAccounts = InitializeInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [payer, config, system_program, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeInstructionAccounts {
+            payer: payer.pubkey,
+            config: config.pubkey,
+            system_program: system_program.pubkey,
+        })
+    }
+}
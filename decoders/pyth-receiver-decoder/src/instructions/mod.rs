@@ -0,0 +1,164 @@
+use crate::PROGRAM_ID;
+
+use super::PythReceiverDecoder;
+pub mod accept_governance_authority_transfer;
+pub mod initialize;
+pub mod post_update;
+pub mod post_update_atomic;
+pub mod reclaim_rent;
+pub mod request_governance_authority_transfer;
+pub mod set_data_sources;
+pub mod set_fee;
+pub mod set_minimum_signatures;
+pub mod set_wormhole_address;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum PythReceiverInstruction {
+    Initialize(initialize::Initialize),
+    RequestGovernanceAuthorityTransfer(
+        request_governance_authority_transfer::RequestGovernanceAuthorityTransfer,
+    ),
+    AcceptGovernanceAuthorityTransfer(
+        accept_governance_authority_transfer::AcceptGovernanceAuthorityTransfer,
+    ),
+    SetDataSources(set_data_sources::SetDataSources),
+    SetFee(set_fee::SetFee),
+    SetWormholeAddress(set_wormhole_address::SetWormholeAddress),
+    SetMinimumSignatures(set_minimum_signatures::SetMinimumSignatures),
+    PostUpdateAtomic(post_update_atomic::PostUpdateAtomic),
+    PostUpdate(post_update::PostUpdate),
+    ReclaimRent(reclaim_rent::ReclaimRent),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for PythReceiverDecoder {
+    type InstructionType = PythReceiverInstruction;
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            PythReceiverInstruction::Initialize => initialize::Initialize,
+            PythReceiverInstruction::RequestGovernanceAuthorityTransfer => request_governance_authority_transfer::RequestGovernanceAuthorityTransfer,
+            PythReceiverInstruction::AcceptGovernanceAuthorityTransfer => accept_governance_authority_transfer::AcceptGovernanceAuthorityTransfer,
+            PythReceiverInstruction::SetDataSources => set_data_sources::SetDataSources,
+            PythReceiverInstruction::SetFee => set_fee::SetFee,
+            PythReceiverInstruction::SetWormholeAddress => set_wormhole_address::SetWormholeAddress,
+            PythReceiverInstruction::SetMinimumSignatures => set_minimum_signatures::SetMinimumSignatures,
+            PythReceiverInstruction::PostUpdateAtomic => post_update_atomic::PostUpdateAtomic,
+            PythReceiverInstruction::PostUpdate => post_update::PostUpdate,
+            PythReceiverInstruction::ReclaimRent => reclaim_rent::ReclaimRent,
+        )
+    }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            initialize::Initialize,
+            request_governance_authority_transfer::RequestGovernanceAuthorityTransfer,
+            accept_governance_authority_transfer::AcceptGovernanceAuthorityTransfer,
+            set_data_sources::SetDataSources,
+            set_fee::SetFee,
+            set_wormhole_address::SetWormholeAddress,
+            set_minimum_signatures::SetMinimumSignatures,
+            post_update_atomic::PostUpdateAtomic,
+            post_update::PostUpdate,
+            reclaim_rent::ReclaimRent,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionDecoder};
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_decode_post_update() {
+        // Arrange
+        let mut message = vec![0];
+        message.extend_from_slice(&[0xef; 32]);
+        message.extend_from_slice(&15_123_456_789i64.to_be_bytes());
+        message.extend_from_slice(&7_654_321u64.to_be_bytes());
+        message.extend_from_slice(&(-8i32).to_be_bytes());
+        message.extend_from_slice(&1_735_689_600i64.to_be_bytes());
+        message.extend_from_slice(&1_735_689_599i64.to_be_bytes());
+        message.extend_from_slice(&15_100_000_000i64.to_be_bytes());
+        message.extend_from_slice(&8_000_000u64.to_be_bytes());
+        let expected_ix = PythReceiverInstruction::PostUpdate(post_update::PostUpdate {
+            params: crate::types::PostUpdateParams {
+                merkle_price_update: crate::types::MerklePriceUpdate {
+                    message: message.clone(),
+                    proof: vec![[5; 20], [6; 20]],
+                },
+                treasury_id: 3,
+            },
+        });
+        let accounts: Vec<AccountMeta> = (10..17)
+            .map(|byte| AccountMeta::new_readonly(Pubkey::new_from_array([byte; 32]), false))
+            .collect();
+        let mut data = vec![0x85, 0x5f, 0xcf, 0xaf, 0x0b, 0x4f, 0x76, 0x2c];
+        data.extend_from_slice(&(message.len() as u32).to_le_bytes());
+        data.extend_from_slice(&message);
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.extend_from_slice(&[5; 20]);
+        data.extend_from_slice(&[6; 20]);
+        data.push(3);
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts.clone(),
+            data,
+        };
+
+        // Act
+        let decoder = PythReceiverDecoder;
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let arranged =
+            post_update::PostUpdate::arrange_accounts(&decoded.accounts).expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(arranged.price_update_account, accounts[4].pubkey);
+        let PythReceiverInstruction::PostUpdate(post_update) = decoded.data else {
+            unreachable!()
+        };
+        let price = post_update
+            .params
+            .merkle_price_update
+            .price_feed_message()
+            .expect("price feed message");
+        assert_eq!(price.feed_id, [0xef; 32]);
+        assert_eq!(price.price, 15_123_456_789);
+        assert_eq!(price.conf, 7_654_321);
+        assert_eq!(price.exponent, -8);
+        assert_eq!(price.publish_time, 1_735_689_600);
+        assert_eq!(price.ema_conf, 8_000_000);
+    }
+}
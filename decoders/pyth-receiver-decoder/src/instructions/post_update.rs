@@ -0,0 +1,47 @@
+use {
+    super::super::types::*,
+    carbon_core::{borsh, CarbonDeserialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x855fcfaf0b4f762c")]
+pub struct PostUpdate {
+    pub params: PostUpdateParams,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PostUpdateInstructionAccounts {
+    pub payer: solana_sdk::pubkey::Pubkey,
+    pub encoded_vaa: solana_sdk::pubkey::Pubkey,
+    pub config: solana_sdk::pubkey::Pubkey,
+    pub treasury: solana_sdk::pubkey::Pubkey,
+    pub price_update_account: solana_sdk::pubkey::Pubkey,
+    pub system_program: solana_sdk::pubkey::Pubkey,
+    pub write_authority: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for PostUpdate {
+    type ArrangedAccounts = PostUpdateInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [payer, encoded_vaa, config, treasury, price_update_account, system_program, write_authority, _remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PostUpdateInstructionAccounts {
+            payer: payer.pubkey,
+            encoded_vaa: encoded_vaa.pubkey,
+            config: config.pubkey,
+            treasury: treasury.pubkey,
+            price_update_account: price_update_account.pubkey,
+            system_program: system_program.pubkey,
+            write_authority: write_authority.pubkey,
+        })
+    }
+}
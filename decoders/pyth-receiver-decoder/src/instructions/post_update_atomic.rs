@@ -0,0 +1,47 @@
+use {
+    super::super::types::*,
+    carbon_core::{borsh, CarbonDeserialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x31ac54c0afb434ea")]
+pub struct PostUpdateAtomic {
+    pub params: PostUpdateAtomicParams,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PostUpdateAtomicInstructionAccounts {
+    pub payer: solana_sdk::pubkey::Pubkey,
+    pub guardian_set: solana_sdk::pubkey::Pubkey,
+    pub config: solana_sdk::pubkey::Pubkey,
+    pub treasury: solana_sdk::pubkey::Pubkey,
+    pub price_update_account: solana_sdk::pubkey::Pubkey,
+    pub system_program: solana_sdk::pubkey::Pubkey,
+    pub write_authority: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for PostUpdateAtomic {
+    type ArrangedAccounts = PostUpdateAtomicInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [payer, guardian_set, config, treasury, price_update_account, system_program, write_authority, _remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PostUpdateAtomicInstructionAccounts {
+            payer: payer.pubkey,
+            guardian_set: guardian_set.pubkey,
+            config: config.pubkey,
+            treasury: treasury.pubkey,
+            price_update_account: price_update_account.pubkey,
+            system_program: system_program.pubkey,
+            write_authority: write_authority.pubkey,
+        })
+    }
+}
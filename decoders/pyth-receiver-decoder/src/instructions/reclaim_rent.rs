@@ -0,0 +1,30 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xdac813c5e359c016")]
+pub struct ReclaimRent {}
+
+#[derive(Debug, PartialEq)]
+pub struct ReclaimRentInstructionAccounts {
+    pub payer: solana_sdk::pubkey::Pubkey,
+    pub price_update_account: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for ReclaimRent {
+    type ArrangedAccounts = ReclaimRentInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [payer, price_update_account, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ReclaimRentInstructionAccounts {
+            payer: payer.pubkey,
+            price_update_account: price_update_account.pubkey,
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x5c12439c1b97b7e0")]
+pub struct RequestGovernanceAuthorityTransfer {
+    pub target_governance_authority: solana_sdk::pubkey::Pubkey,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RequestGovernanceAuthorityTransferInstructionAccounts {
+    pub payer: solana_sdk::pubkey::Pubkey,
+    pub config: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for RequestGovernanceAuthorityTransfer {
+    type ArrangedAccounts = RequestGovernanceAuthorityTransferInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [payer, config, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(RequestGovernanceAuthorityTransferInstructionAccounts {
+            payer: payer.pubkey,
+            config: config.pubkey,
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use {
+    super::super::types::*,
+    carbon_core::{borsh, CarbonDeserialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x6b490f77c3745bd2")]
+pub struct SetDataSources {
+    pub valid_data_sources: Vec<DataSource>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SetDataSourcesInstructionAccounts {
+    pub payer: solana_sdk::pubkey::Pubkey,
+    pub config: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for SetDataSources {
+    type ArrangedAccounts = SetDataSourcesInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [payer, config, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetDataSourcesInstructionAccounts {
+            payer: payer.pubkey,
+            config: config.pubkey,
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x129a1812edd61350")]
+pub struct SetFee {
+    pub single_update_fee_in_lamports: u64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SetFeeInstructionAccounts {
+    pub payer: solana_sdk::pubkey::Pubkey,
+    pub config: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for SetFee {
+    type ArrangedAccounts = SetFeeInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [payer, config, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetFeeInstructionAccounts {
+            payer: payer.pubkey,
+            config: config.pubkey,
+        })
+    }
+}
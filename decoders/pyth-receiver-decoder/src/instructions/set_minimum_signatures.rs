@@ -0,0 +1,32 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x05d2ce7c2b446895")]
+pub struct SetMinimumSignatures {
+    pub minimum_signatures: u8,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SetMinimumSignaturesInstructionAccounts {
+    pub payer: solana_sdk::pubkey::Pubkey,
+    pub config: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for SetMinimumSignatures {
+    type ArrangedAccounts = SetMinimumSignaturesInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [payer, config, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetMinimumSignaturesInstructionAccounts {
+            payer: payer.pubkey,
+            config: config.pubkey,
+        })
+    }
+}
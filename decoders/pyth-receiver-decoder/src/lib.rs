@@ -0,0 +1,9 @@
+use solana_sdk::{pubkey, pubkey::Pubkey};
+
+pub struct PythReceiverDecoder;
+
+pub mod accounts;
+pub mod instructions;
+pub mod types;
+
+pub const PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
@@ -0,0 +1,19 @@
+use {
+    super::*,
+    carbon_core::{borsh, CarbonDeserialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct MerklePriceUpdate {
+    pub message: Vec<u8>,
+    pub proof: Vec<[u8; 20]>,
+}
+
+impl MerklePriceUpdate {
+    /// Parses the price update's message, if it is a price feed message.
+    pub fn price_feed_message(&self) -> Option<PriceFeedMessage> {
+        PriceFeedMessage::from_message(&self.message)
+    }
+}
@@ -0,0 +1,12 @@
+pub mod data_source;
+pub use data_source::*;
+pub mod merkle_price_update;
+pub use merkle_price_update::*;
+pub mod post_update_atomic_params;
+pub use post_update_atomic_params::*;
+pub mod post_update_params;
+pub use post_update_params::*;
+pub mod price_feed_message;
+pub use price_feed_message::*;
+pub mod verification_level;
+pub use verification_level::*;
@@ -0,0 +1,13 @@
+use {
+    super::*,
+    carbon_core::{borsh, CarbonDeserialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct PostUpdateAtomicParams {
+    pub vaa: Vec<u8>,
+    pub merkle_price_update: MerklePriceUpdate,
+    pub treasury_id: u8,
+}
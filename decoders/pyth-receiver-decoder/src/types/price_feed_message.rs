@@ -0,0 +1,58 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct PriceFeedMessage {
+    pub feed_id: [u8; 32],
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+    pub prev_publish_time: i64,
+    pub ema_price: i64,
+    pub ema_conf: u64,
+}
+
+impl PriceFeedMessage {
+    /// The type byte of a price feed message in a Pythnet accumulator update.
+    pub const MESSAGE_TYPE: u8 = 0;
+    /// The size of a serialized price feed message, including its type byte.
+    pub const MESSAGE_SIZE: usize = 85;
+
+    /// Parses a Pythnet accumulator message, as carried by the
+    /// `merkle_price_update` of a post update instruction.
+    ///
+    /// Unlike the accounts the receiver writes, accumulator messages are
+    /// big-endian. Returns `None` for other message types, such as TWAP
+    /// messages.
+    pub fn from_message(message: &[u8]) -> Option<Self> {
+        let (&message_type, rest) = message.split_first()?;
+        if message_type != Self::MESSAGE_TYPE || message.len() < Self::MESSAGE_SIZE {
+            return None;
+        }
+
+        let (feed_id, rest) = rest.split_at(32);
+        let field = |offset: usize| -> [u8; 8] {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&rest[offset..offset + 8]);
+            bytes
+        };
+        let mut exponent = [0u8; 4];
+        exponent.copy_from_slice(&rest[16..20]);
+
+        let mut message = PriceFeedMessage {
+            feed_id: [0; 32],
+            price: i64::from_be_bytes(field(0)),
+            conf: u64::from_be_bytes(field(8)),
+            exponent: i32::from_be_bytes(exponent),
+            publish_time: i64::from_be_bytes(field(20)),
+            prev_publish_time: i64::from_be_bytes(field(28)),
+            ema_price: i64::from_be_bytes(field(36)),
+            ema_conf: u64::from_be_bytes(field(44)),
+        };
+        message.feed_id.copy_from_slice(feed_id);
+
+        Some(message)
+    }
+}
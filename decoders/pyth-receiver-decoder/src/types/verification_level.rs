@@ -0,0 +1,11 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+/// The number of Wormhole guardian signatures checked before a price update
+/// was posted.
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub enum VerificationLevel {
+    Partial { num_signatures: u8 },
+    Full,
+}
@@ -5,7 +5,7 @@ use {
 
 pub const AMM_INFO_SIZE: usize = std::mem::size_of::<AmmInfo>();
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 pub struct AmmInfo {
     pub status: u64,
     pub nonce: u64,
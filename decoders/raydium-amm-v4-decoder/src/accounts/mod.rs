@@ -8,6 +8,7 @@ pub mod fees;
 pub mod target_orders;
 
 #[allow(clippy::large_enum_variant)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum RaydiumAmmV4Account {
     TargetOrders(target_orders::TargetOrders),
     Fees(fees::Fees),
@@ -61,4 +62,20 @@ impl AccountDecoder<'_> for RaydiumAmmV4Decoder {
             _ => None,
         }
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            amm_info::AmmInfo,
+            fees::Fees,
+            target_orders::TargetOrders,
+        )
+    }
 }
@@ -5,9 +5,10 @@ use {
 
 pub const TARGET_ORDERS_SIZE: usize = std::mem::size_of::<TargetOrders>();
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 pub struct TargetOrders {
     pub owner: [u64; 4],
+    #[serde(with = "serde_big_array::BigArray")]
     pub buy_orders: [TargetOrder; 50],
     pub padding1: [u64; 8],
     pub target_x: u128,
@@ -20,6 +21,7 @@ pub struct TargetOrders {
     pub placed_y: u128,
     pub calc_pnl_x: u128,
     pub calc_pnl_y: u128,
+    #[serde(with = "serde_big_array::BigArray")]
     pub sell_orders: [TargetOrder; 50],
     pub padding2: [u64; 6],
     pub replace_buy_client_id: [u64; 10],
@@ -77,4 +77,33 @@ impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumAmmV4Decoder {
             RaydiumAmmV4Instruction::UpdateConfigAccount => update_config_account::UpdateConfigAccount,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            initialize::Initialize,
+            initialize2::Initialize2,
+            monitor_step::MonitorStep,
+            deposit::Deposit,
+            withdraw::Withdraw,
+            migrate_to_open_book::MigrateToOpenBook,
+            set_params::SetParams,
+            withdraw_pnl::WithdrawPnl,
+            withdraw_srm::WithdrawSrm,
+            swap_base_in::SwapBaseIn,
+            pre_initialize::PreInitialize,
+            swap_base_out::SwapBaseOut,
+            simulate_info::SimulateInfo,
+            admin_cancel_orders::AdminCancelOrders,
+            create_config_account::CreateConfigAccount,
+            update_config_account::UpdateConfigAccount,
+        )
+    }
 }
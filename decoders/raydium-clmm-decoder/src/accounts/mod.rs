@@ -12,7 +12,7 @@ pub mod protocol_position_state;
 pub mod tick_array_bitmap_extension;
 pub mod tick_array_state;
 
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum RaydiumClmmAccount {
     AmmConfig(amm_config::AmmConfig),
     OperationState(operation_state::OperationState),
@@ -130,4 +130,25 @@ impl AccountDecoder<'_> for RaydiumClmmDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            amm_config::AmmConfig,
+            operation_state::OperationState,
+            observation_state::ObservationState,
+            personal_position_state::PersonalPositionState,
+            pool_state::PoolState,
+            protocol_position_state::ProtocolPositionState,
+            tick_array_state::TickArrayState,
+            tick_array_bitmap_extension::TickArrayBitmapExtension,
+        )
+    }
 }
@@ -1,9 +1,10 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x13ec3aed51deb7fc")]
 pub struct OperationState {
     pub bump: u8,
     pub operation_owners: [solana_sdk::pubkey::Pubkey; 10],
+    #[serde(with = "serde_big_array::BigArray")]
     pub whitelist_mints: [solana_sdk::pubkey::Pubkey; 100],
 }
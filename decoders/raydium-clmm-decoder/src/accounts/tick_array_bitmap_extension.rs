@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x3c9624db61808b99")]
 pub struct TickArrayBitmapExtension {
     pub pool_id: solana_sdk::pubkey::Pubkey,
@@ -3,13 +3,15 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0xc09b55cd31f9812a")]
 pub struct TickArrayState {
     pub pool_id: solana_sdk::pubkey::Pubkey,
     pub start_tick_index: i32,
+    #[serde(with = "serde_big_array::BigArray")]
     pub ticks: [TickState; 60],
     pub initialized_tick_count: u8,
     pub recent_epoch: u64,
+    #[serde(with = "serde_big_array::BigArray")]
     pub padding: [u8; 107],
 }
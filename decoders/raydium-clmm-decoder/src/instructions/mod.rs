@@ -134,4 +134,52 @@ impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumClmmDecoder {
             RaydiumClmmInstruction::LiquidityChangeEvent => liquidity_change_event::LiquidityChangeEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            create_amm_config::CreateAmmConfig,
+            update_amm_config::UpdateAmmConfig,
+            create_pool::CreatePool,
+            update_pool_status::UpdatePoolStatus,
+            create_operation_account::CreateOperationAccount,
+            update_operation_account::UpdateOperationAccount,
+            transfer_reward_owner::TransferRewardOwner,
+            initialize_reward::InitializeReward,
+            collect_remaining_rewards::CollectRemainingRewards,
+            update_reward_infos::UpdateRewardInfos,
+            set_reward_params::SetRewardParams,
+            collect_protocol_fee::CollectProtocolFee,
+            collect_fund_fee::CollectFundFee,
+            open_position::OpenPosition,
+            open_position_v2::OpenPositionV2,
+            open_position_with_token22_nft::OpenPositionWithToken22Nft,
+            close_position::ClosePosition,
+            increase_liquidity::IncreaseLiquidity,
+            increase_liquidity_v2::IncreaseLiquidityV2,
+            decrease_liquidity::DecreaseLiquidity,
+            decrease_liquidity_v2::DecreaseLiquidityV2,
+            swap::Swap,
+            swap_v2::SwapV2,
+            swap_router_base_in::SwapRouterBaseIn,
+            config_change_event::ConfigChangeEvent,
+            create_personal_position_event::CreatePersonalPositionEvent,
+            increase_liquidity_event::IncreaseLiquidityEvent,
+            decrease_liquidity_event::DecreaseLiquidityEvent,
+            liquidity_calculate_event::LiquidityCalculateEvent,
+            collect_personal_fee_event::CollectPersonalFeeEvent,
+            update_reward_infos_event::UpdateRewardInfosEvent,
+            pool_created_event::PoolCreatedEvent,
+            collect_protocol_fee_event::CollectProtocolFeeEvent,
+            swap_event::SwapEvent,
+            liquidity_change_event::LiquidityChangeEvent,
+        )
+    }
 }
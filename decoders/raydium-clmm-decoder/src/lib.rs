@@ -3,6 +3,8 @@ use solana_sdk::{pubkey, pubkey::Pubkey};
 pub struct RaydiumClmmDecoder;
 pub mod accounts;
 pub mod instructions;
+#[cfg(feature = "math")]
+pub mod math;
 pub mod types;
 
 pub const PROGRAM_ID: Pubkey = pubkey!("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK");
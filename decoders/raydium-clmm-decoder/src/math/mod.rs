@@ -0,0 +1,119 @@
+//! Price, liquidity and swap quote math for concentrated liquidity pools,
+//! following the program's fixed point arithmetic.
+//!
+//! Square root prices are Q64.64 fixed point numbers, and prices are in
+//! token 1 per token 0. Fee rates are in hundredths of a basis point.
+
+pub mod quote;
+pub mod swap_math;
+pub mod tick_math;
+
+pub use quote::SwapQuote;
+
+use {
+    crate::accounts::pool_state::PoolState,
+    swap_math::{amount_delta_0, amount_delta_1},
+    tick_math::sqrt_price_at_tick,
+    u256::U256,
+};
+
+mod u256 {
+    // The expansion of `construct_uint!` rounds divisions up by hand.
+    #![allow(clippy::manual_div_ceil)]
+
+    uint::construct_uint! {
+        /// Holds the intermediate products of the Q64.64 arithmetic.
+        pub struct U256(4);
+    }
+}
+
+/// Converts a Q64.64 square root price to a price in token 1 per token 0,
+/// adjusted for the mints' decimals.
+pub fn sqrt_price_x64_to_price(sqrt_price_x64: u128, decimals_0: u8, decimals_1: u8) -> f64 {
+    let sqrt_price = sqrt_price_x64 as f64 / (1u128 << 64) as f64;
+    sqrt_price * sqrt_price * 10f64.powi(decimals_0 as i32 - decimals_1 as i32)
+}
+
+/// Converts a price in token 1 per token 0, adjusted for the mints'
+/// decimals, to a Q64.64 square root price.
+pub fn price_to_sqrt_price_x64(price: f64, decimals_0: u8, decimals_1: u8) -> u128 {
+    let price = price * 10f64.powi(decimals_1 as i32 - decimals_0 as i32);
+    (price.sqrt() * (1u128 << 64) as f64) as u128
+}
+
+/// Converts a tick to a price in token 1 per token 0, adjusted for the
+/// mints' decimals.
+pub fn tick_to_price(tick: i32, decimals_0: u8, decimals_1: u8) -> f64 {
+    1.0001f64.powi(tick) * 10f64.powi(decimals_0 as i32 - decimals_1 as i32)
+}
+
+/// Returns the amounts of tokens 0 and 1 that `liquidity` holds between
+/// `tick_lower` and `tick_upper` at `sqrt_price_x64`.
+///
+/// Rounding up gives the amounts a deposit of `liquidity` takes, rounding
+/// down the amounts a withdrawal returns.
+pub fn token_amounts_from_liquidity(
+    liquidity: u128,
+    sqrt_price_x64: u128,
+    tick_lower: i32,
+    tick_upper: i32,
+    round_up: bool,
+) -> Option<(u64, u64)> {
+    let sqrt_price_lower_x64 = sqrt_price_at_tick(tick_lower)?;
+    let sqrt_price_upper_x64 = sqrt_price_at_tick(tick_upper)?;
+
+    let (amount_0, amount_1) = if sqrt_price_x64 <= sqrt_price_lower_x64 {
+        (
+            amount_delta_0(
+                sqrt_price_lower_x64,
+                sqrt_price_upper_x64,
+                liquidity,
+                round_up,
+            )?,
+            0,
+        )
+    } else if sqrt_price_x64 < sqrt_price_upper_x64 {
+        (
+            amount_delta_0(sqrt_price_x64, sqrt_price_upper_x64, liquidity, round_up)?,
+            amount_delta_1(sqrt_price_lower_x64, sqrt_price_x64, liquidity, round_up)?,
+        )
+    } else {
+        (
+            0,
+            amount_delta_1(
+                sqrt_price_lower_x64,
+                sqrt_price_upper_x64,
+                liquidity,
+                round_up,
+            )?,
+        )
+    };
+
+    Some((u64::try_from(amount_0).ok()?, u64::try_from(amount_1).ok()?))
+}
+
+impl PoolState {
+    /// Returns the spot price in token 1 per token 0, adjusted for the
+    /// mints' decimals.
+    pub fn price(&self) -> f64 {
+        sqrt_price_x64_to_price(
+            self.sqrt_price_x64,
+            self.mint_decimals0,
+            self.mint_decimals1,
+        )
+    }
+
+    /// Returns the amounts of tokens 0 and 1 that the active liquidity holds
+    /// between the initializable ticks around the current price.
+    pub fn liquidity_in_range(&self) -> Option<(u64, u64)> {
+        let tick_spacing = self.tick_spacing as i32;
+        let tick_lower = self.tick_current.checked_div_euclid(tick_spacing)? * tick_spacing;
+        token_amounts_from_liquidity(
+            self.liquidity,
+            self.sqrt_price_x64,
+            tick_lower,
+            tick_lower + tick_spacing,
+            false,
+        )
+    }
+}
@@ -0,0 +1,406 @@
+//! Swap quotes across a pool's tick arrays.
+
+use {
+    super::{
+        swap_math::compute_swap_step,
+        tick_math::{
+            sqrt_price_at_tick, tick_array_start_index, tick_at_sqrt_price, MAX_SQRT_PRICE_X64,
+            MAX_TICK, MIN_SQRT_PRICE_X64, MIN_TICK, TICK_ARRAY_SIZE,
+        },
+    },
+    crate::accounts::{
+        amm_config::AmmConfig, pool_state::PoolState, tick_array_state::TickArrayState,
+    },
+};
+
+/// The amounts of a quoted swap and the pool's state after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SwapQuote {
+    /// The input amount, fees included.
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee_amount: u64,
+    pub sqrt_price_x64: u128,
+    pub tick_current: i32,
+    pub liquidity: u128,
+}
+
+impl PoolState {
+    /// Quotes swapping exactly `amount_in` of token 0, if `zero_for_one`, or
+    /// of token 1, with the trade fee rate of the pool's `amm_config`.
+    ///
+    /// `tick_arrays` must include the initialized tick arrays the swap
+    /// crosses, in any order; returns `None` if they don't, if no initialized
+    /// tick is left in the swap's direction or if an amount overflows.
+    pub fn quote_exact_in(
+        &self,
+        amm_config: &AmmConfig,
+        tick_arrays: &[TickArrayState],
+        amount_in: u64,
+        zero_for_one: bool,
+    ) -> Option<SwapQuote> {
+        self.quote(amm_config, tick_arrays, amount_in, true, zero_for_one)
+    }
+
+    /// Quotes swapping for exactly `amount_out` of token 1, if
+    /// `zero_for_one`, or of token 0, with the trade fee rate of the pool's
+    /// `amm_config`.
+    ///
+    /// `tick_arrays` must include the initialized tick arrays the swap
+    /// crosses, in any order; returns `None` if they don't, if no initialized
+    /// tick is left in the swap's direction, if the pool's liquidity can't
+    /// pay out `amount_out` or if an amount overflows.
+    pub fn quote_exact_out(
+        &self,
+        amm_config: &AmmConfig,
+        tick_arrays: &[TickArrayState],
+        amount_out: u64,
+        zero_for_one: bool,
+    ) -> Option<SwapQuote> {
+        self.quote(amm_config, tick_arrays, amount_out, false, zero_for_one)
+    }
+
+    /// Returns whether the tick array starting at `start_index` is
+    /// initialized, or `None` if it is outside the range of the pool's
+    /// bitmap and tracked by its bitmap extension instead.
+    pub fn is_tick_array_initialized(&self, start_index: i32) -> Option<bool> {
+        let ticks_in_array = TICK_ARRAY_SIZE * self.tick_spacing as i32;
+        let offset = start_index.checked_div_euclid(ticks_in_array)? + 512;
+        if !(0..1024).contains(&offset) {
+            return None;
+        }
+        let offset = offset as usize;
+        Some(self.tick_array_bitmap[offset / 64] & (1 << (offset % 64)) != 0)
+    }
+
+    fn quote(
+        &self,
+        amm_config: &AmmConfig,
+        tick_arrays: &[TickArrayState],
+        amount: u64,
+        amount_specified_is_input: bool,
+        zero_for_one: bool,
+    ) -> Option<SwapQuote> {
+        let sqrt_price_limit_x64 = if zero_for_one {
+            MIN_SQRT_PRICE_X64 + 1
+        } else {
+            MAX_SQRT_PRICE_X64 - 1
+        };
+
+        let mut amount_remaining = amount;
+        let mut amount_calculated: u64 = 0;
+        let mut fee_amount: u64 = 0;
+        let mut sqrt_price_x64 = self.sqrt_price_x64;
+        let mut tick_current = self.tick_current;
+        let mut liquidity = self.liquidity;
+
+        while amount_remaining > 0 && sqrt_price_x64 != sqrt_price_limit_x64 {
+            let (next_tick, liquidity_net) =
+                self.next_initialized_tick(tick_arrays, tick_current, zero_for_one)?;
+            let next_tick_sqrt_price_x64 = sqrt_price_at_tick(next_tick)?;
+            let sqrt_price_target_x64 = if zero_for_one {
+                next_tick_sqrt_price_x64.max(sqrt_price_limit_x64)
+            } else {
+                next_tick_sqrt_price_x64.min(sqrt_price_limit_x64)
+            };
+
+            let step = compute_swap_step(
+                amount_remaining,
+                amm_config.trade_fee_rate,
+                liquidity,
+                sqrt_price_x64,
+                sqrt_price_target_x64,
+                amount_specified_is_input,
+                zero_for_one,
+            )?;
+
+            if amount_specified_is_input {
+                amount_remaining = amount_remaining
+                    .checked_sub(step.amount_in)?
+                    .checked_sub(step.fee_amount)?;
+                amount_calculated = amount_calculated.checked_add(step.amount_out)?;
+            } else {
+                amount_remaining = amount_remaining.checked_sub(step.amount_out)?;
+                amount_calculated = amount_calculated
+                    .checked_add(step.amount_in)?
+                    .checked_add(step.fee_amount)?;
+            }
+            fee_amount = fee_amount.checked_add(step.fee_amount)?;
+
+            if step.next_sqrt_price == next_tick_sqrt_price_x64 {
+                let liquidity_net = if zero_for_one {
+                    -liquidity_net
+                } else {
+                    liquidity_net
+                };
+                liquidity = liquidity.checked_add_signed(liquidity_net)?;
+                tick_current = if zero_for_one {
+                    next_tick - 1
+                } else {
+                    next_tick
+                };
+            } else if step.next_sqrt_price != sqrt_price_x64 {
+                tick_current = tick_at_sqrt_price(step.next_sqrt_price)?;
+            }
+            sqrt_price_x64 = step.next_sqrt_price;
+        }
+
+        if !amount_specified_is_input && amount_remaining > 0 {
+            return None;
+        }
+
+        let (amount_in, amount_out) = if amount_specified_is_input {
+            (amount - amount_remaining, amount_calculated)
+        } else {
+            (amount_calculated, amount)
+        };
+
+        Some(SwapQuote {
+            amount_in,
+            amount_out,
+            fee_amount,
+            sqrt_price_x64,
+            tick_current,
+            liquidity,
+        })
+    }
+
+    /// Returns the next initialized tick from `tick_current` in the swap's
+    /// direction, with its net liquidity.
+    ///
+    /// Tick arrays the pool's bitmap marks as uninitialized are skipped. If
+    /// an initialized tick array is missing from `tick_arrays`, returns the
+    /// last tick covered before it, with no net liquidity, so swaps that
+    /// don't reach it can still be quoted.
+    fn next_initialized_tick(
+        &self,
+        tick_arrays: &[TickArrayState],
+        tick_current: i32,
+        zero_for_one: bool,
+    ) -> Option<(i32, i128)> {
+        let tick_spacing = self.tick_spacing as i32;
+        if tick_spacing == 0 {
+            return None;
+        }
+        let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing;
+
+        // zero_for_one swaps include the current tick, which they cross when
+        // the price reaches its lower bound.
+        let mut tick = tick_current.div_euclid(tick_spacing) * tick_spacing;
+        if !zero_for_one {
+            tick += tick_spacing;
+        }
+
+        let mut last_covered = None;
+        while (MIN_TICK..=MAX_TICK).contains(&tick) {
+            let start_index = tick_array_start_index(tick, self.tick_spacing);
+            match tick_arrays
+                .iter()
+                .find(|tick_array| tick_array.start_tick_index == start_index)
+            {
+                Some(tick_array) => {
+                    let offset = ((tick - start_index) / tick_spacing) as usize;
+                    let tick_state = tick_array.ticks.get(offset)?;
+                    if tick_state.liquidity_gross != 0 {
+                        return Some((tick, tick_state.liquidity_net));
+                    }
+
+                    last_covered = Some(tick);
+                    tick += if zero_for_one {
+                        -tick_spacing
+                    } else {
+                        tick_spacing
+                    };
+                }
+                None if self.is_tick_array_initialized(start_index) == Some(false) => {
+                    if zero_for_one {
+                        last_covered = Some(start_index);
+                        tick = start_index - tick_spacing;
+                    } else {
+                        last_covered = Some(start_index + ticks_in_array - tick_spacing);
+                        tick = start_index + ticks_in_array;
+                    }
+                }
+                None => return last_covered.map(|tick| (tick, 0)),
+            }
+        }
+
+        // The program fails swaps that run out of initialized ticks.
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{accounts::RaydiumClmmAccount, RaydiumClmmDecoder},
+        carbon_core::account::AccountDecoder,
+    };
+
+    // The fixtures hold a pool with 10 tick spacing, a 0.25% fee and 10^10
+    // liquidity at tick 5, of which 3 * 10^9 and 2 * 10^9 end at ticks 20 and
+    // 100 and 5 * 10^9 at tick -50, and its tick arrays starting at -1200,
+    // -600, 0, 1200 and 4200.
+    fn decode_account(path: &str) -> RaydiumClmmAccount {
+        let account = carbon_test_utils::read_account(path).expect("read fixture");
+
+        RaydiumClmmDecoder
+            .decode_account(&account)
+            .expect("decode fixture")
+            .data
+    }
+
+    fn pool_state() -> PoolState {
+        match decode_account("tests/fixtures/pool_state_account.json") {
+            RaydiumClmmAccount::PoolState(pool_state) => pool_state,
+            account => panic!("expected a pool state, got {account:?}"),
+        }
+    }
+
+    fn amm_config() -> AmmConfig {
+        match decode_account("tests/fixtures/amm_config_account.json") {
+            RaydiumClmmAccount::AmmConfig(amm_config) => amm_config,
+            account => panic!("expected an amm config, got {account:?}"),
+        }
+    }
+
+    fn tick_arrays() -> Vec<TickArrayState> {
+        [
+            "tests/fixtures/tick_array_state_0_account.json",
+            "tests/fixtures/tick_array_state_-600_account.json",
+            "tests/fixtures/tick_array_state_-1200_account.json",
+            "tests/fixtures/tick_array_state_1200_account.json",
+            "tests/fixtures/tick_array_state_4200_account.json",
+        ]
+        .into_iter()
+        .map(|path| match decode_account(path) {
+            RaydiumClmmAccount::TickArrayState(tick_array) => tick_array,
+            account => panic!("expected a tick array state, got {account:?}"),
+        })
+        .collect()
+    }
+
+    // The expected quotes are those of the program's swap loop, run with the
+    // program's own tick and swap math on the same pool and tick arrays.
+    #[test]
+    fn test_quote_within_tick() {
+        let quote = pool_state()
+            .quote_exact_in(&amm_config(), &tick_arrays(), 1_000_000, true)
+            .expect("quote");
+
+        assert_eq!(
+            quote,
+            SwapQuote {
+                amount_in: 1_000_000,
+                amount_out: 997899,
+                fee_amount: 2500,
+                sqrt_price_x64: 18449515307326545474,
+                tick_current: 3,
+                liquidity: 10_000_000_000,
+            }
+        );
+    }
+
+    #[test]
+    fn test_quote_exact_in_crossing_ticks() {
+        let pool_state = pool_state();
+        let tick_arrays = tick_arrays();
+
+        assert_eq!(
+            pool_state.quote_exact_in(&amm_config(), &tick_arrays, 80_000_000, true),
+            Some(SwapQuote {
+                amount_in: 80_000_000,
+                amount_out: 78940683,
+                fee_amount: 200001,
+                sqrt_price_x64: 18210785384999557353,
+                tick_current: -258,
+                liquidity: 5_000_000_000,
+            })
+        );
+        // Crosses the uninitialized tick arrays starting at 600 and 3600.
+        assert_eq!(
+            pool_state.quote_exact_in(&amm_config(), &tick_arrays, 1_000_000_000, false),
+            Some(SwapQuote {
+                amount_in: 1_000_000_000,
+                amount_out: 820665310,
+                fee_amount: 2500002,
+                sqrt_price_x64: 22700946775977010973,
+                tick_current: 4150,
+                liquidity: 4_000_000_000,
+            })
+        );
+    }
+
+    #[test]
+    fn test_quote_exact_out_crossing_ticks() {
+        let pool_state = pool_state();
+        let tick_arrays = tick_arrays();
+
+        assert_eq!(
+            pool_state.quote_exact_out(&amm_config(), &tick_arrays, 70_000_000, true),
+            Some(SwapQuote {
+                amount_in: 70819762,
+                amount_out: 70_000_000,
+                fee_amount: 177050,
+                sqrt_price_x64: 18243770683650234727,
+                tick_current: -222,
+                liquidity: 5_000_000_000,
+            })
+        );
+        assert_eq!(
+            pool_state.quote_exact_out(&amm_config(), &tick_arrays, 300_000_000, false),
+            Some(SwapQuote {
+                amount_in: 318644583,
+                amount_out: 300_000_000,
+                fee_amount: 796612,
+                sqrt_price_x64: 19580563118461297681,
+                tick_current: 1193,
+                liquidity: 5_000_000_000,
+            })
+        );
+    }
+
+    #[test]
+    fn test_quote_beyond_initialized_ticks() {
+        let pool_state = pool_state();
+        let tick_arrays = tick_arrays();
+
+        assert_eq!(
+            pool_state.quote_exact_in(&amm_config(), &tick_arrays, 1_000_000_000_000, true),
+            None
+        );
+    }
+
+    #[test]
+    fn test_quote_missing_tick_array() {
+        let mut pool_state = pool_state();
+        let tick_arrays = tick_arrays();
+
+        // Marks the tick array starting at 600 as initialized without
+        // providing it.
+        let offset = 600 / 600 + 512;
+        pool_state.tick_array_bitmap[offset / 64] |= 1 << (offset % 64);
+
+        assert_eq!(
+            pool_state.quote_exact_in(&amm_config(), &tick_arrays, 15_000_000, false),
+            Some(SwapQuote {
+                amount_in: 15_000_000,
+                amount_out: 14930309,
+                fee_amount: 37501,
+                sqrt_price_x64: 18484853296739139764,
+                tick_current: 41,
+                liquidity: 7_000_000_000,
+            })
+        );
+        assert_eq!(
+            pool_state.quote_exact_in(&amm_config(), &tick_arrays, 1_000_000_000, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_liquidity_in_range() {
+        assert_eq!(pool_state().liquidity_in_range(), Some((2498937, 2500188)));
+    }
+}
@@ -0,0 +1,270 @@
+//! Token amounts between square root prices, and single swap steps within
+//! constant liquidity.
+
+use super::U256;
+
+/// The denominator of the `AmmConfig` fee rates, which are in hundredths of
+/// a basis point.
+pub const FEE_RATE_DENOMINATOR: u32 = 1_000_000;
+
+/// Returns the amount of token 0 between two square root prices for
+/// `liquidity`: `liquidity * (upper - lower) / (upper * lower)`.
+pub fn amount_delta_0(
+    sqrt_ratio_a_x64: u128,
+    sqrt_ratio_b_x64: u128,
+    liquidity: u128,
+    round_up: bool,
+) -> Option<u128> {
+    let (lower, upper) = sorted(sqrt_ratio_a_x64, sqrt_ratio_b_x64);
+    if lower == 0 {
+        return None;
+    }
+
+    let numerator = checked_shl_64(U256::from(liquidity).checked_mul(U256::from(upper - lower))?)?;
+    let quotient = div_rounding(numerator, U256::from(upper), round_up)?;
+    u128::try_from(div_rounding(quotient, U256::from(lower), round_up)?).ok()
+}
+
+/// Returns the amount of token 1 between two square root prices for
+/// `liquidity`: `liquidity * (upper - lower)`.
+pub fn amount_delta_1(
+    sqrt_ratio_a_x64: u128,
+    sqrt_ratio_b_x64: u128,
+    liquidity: u128,
+    round_up: bool,
+) -> Option<u128> {
+    let (lower, upper) = sorted(sqrt_ratio_a_x64, sqrt_ratio_b_x64);
+    let product = U256::from(liquidity).checked_mul(U256::from(upper - lower))?;
+    u128::try_from(div_rounding(product, U256::from(1u128 << 64), round_up)?).ok()
+}
+
+/// Returns the square root price after adding or removing `amount` of token
+/// 0, rounded up so the pool never pays out more than the amount covers.
+pub fn next_sqrt_price_from_amount_0_rounding_up(
+    sqrt_price: u128,
+    liquidity: u128,
+    amount: u64,
+    add: bool,
+) -> Option<u128> {
+    if amount == 0 {
+        return Some(sqrt_price);
+    }
+
+    let liquidity_x64 = U256::from(liquidity) << 64;
+    let product = U256::from(amount).checked_mul(U256::from(sqrt_price))?;
+    let denominator = if add {
+        liquidity_x64.checked_add(product)?
+    } else {
+        liquidity_x64.checked_sub(product)?
+    };
+
+    match liquidity_x64.checked_mul(U256::from(sqrt_price)) {
+        Some(numerator) => u128::try_from(div_rounding(numerator, denominator, true)?).ok(),
+        // liquidity / (liquidity / sqrt_price ± amount), for products that
+        // don't fit.
+        None if add => {
+            let quotient = div_rounding(liquidity_x64, U256::from(sqrt_price), false)?;
+            let denominator = quotient.checked_add(U256::from(amount))?;
+            u128::try_from(div_rounding(liquidity_x64, denominator, true)?).ok()
+        }
+        None => None,
+    }
+}
+
+/// Returns the square root price after adding or removing `amount` of token
+/// 1, rounded down so the pool never pays out more than the amount covers.
+pub fn next_sqrt_price_from_amount_1_rounding_down(
+    sqrt_price: u128,
+    liquidity: u128,
+    amount: u64,
+    add: bool,
+) -> Option<u128> {
+    let delta = div_rounding(U256::from(amount) << 64, U256::from(liquidity), !add)?;
+    let delta = u128::try_from(delta).ok()?;
+
+    if add {
+        sqrt_price.checked_add(delta)
+    } else {
+        sqrt_price.checked_sub(delta).filter(|price| *price > 0)
+    }
+}
+
+/// The result of swapping within constant liquidity, up to a target price.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SwapStep {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub next_sqrt_price: u128,
+    pub fee_amount: u64,
+}
+
+/// Swaps as much of `amount_remaining` as `liquidity` allows between
+/// `sqrt_price_current` and `sqrt_price_target`.
+///
+/// `amount_remaining` is the input, fees included, when
+/// `amount_specified_is_input`, and the output otherwise.
+pub fn compute_swap_step(
+    amount_remaining: u64,
+    fee_rate: u32,
+    liquidity: u128,
+    sqrt_price_current: u128,
+    sqrt_price_target: u128,
+    amount_specified_is_input: bool,
+    zero_for_one: bool,
+) -> Option<SwapStep> {
+    let fee_rate = fee_rate as u128;
+    let denominator = FEE_RATE_DENOMINATOR as u128;
+
+    let amount_calc = if amount_specified_is_input {
+        amount_remaining as u128 * (denominator - fee_rate) / denominator
+    } else {
+        amount_remaining as u128
+    };
+
+    // Amounts that don't fit are more than any remaining amount, so the
+    // target isn't reached.
+    let amount_fixed_delta = fixed_delta(
+        sqrt_price_current,
+        sqrt_price_target,
+        liquidity,
+        amount_specified_is_input,
+        zero_for_one,
+    );
+    let next_sqrt_price = match amount_fixed_delta {
+        Some(delta) if delta <= amount_calc => sqrt_price_target,
+        _ if zero_for_one == amount_specified_is_input => {
+            next_sqrt_price_from_amount_0_rounding_up(
+                sqrt_price_current,
+                liquidity,
+                amount_calc as u64,
+                amount_specified_is_input,
+            )?
+        }
+        _ => next_sqrt_price_from_amount_1_rounding_down(
+            sqrt_price_current,
+            liquidity,
+            amount_calc as u64,
+            amount_specified_is_input,
+        )?,
+    };
+    let is_max_swap = next_sqrt_price == sqrt_price_target;
+
+    let amount_fixed_delta = match amount_fixed_delta {
+        Some(delta) if is_max_swap => delta,
+        _ => fixed_delta(
+            sqrt_price_current,
+            next_sqrt_price,
+            liquidity,
+            amount_specified_is_input,
+            zero_for_one,
+        )?,
+    };
+    let amount_unfixed_delta = unfixed_delta(
+        sqrt_price_current,
+        next_sqrt_price,
+        liquidity,
+        amount_specified_is_input,
+        zero_for_one,
+    )?;
+
+    let (amount_in, mut amount_out) = if amount_specified_is_input {
+        (amount_fixed_delta, amount_unfixed_delta)
+    } else {
+        (amount_unfixed_delta, amount_fixed_delta)
+    };
+    if !amount_specified_is_input && amount_out > amount_remaining as u128 {
+        amount_out = amount_remaining as u128;
+    }
+
+    let fee_amount = if amount_specified_is_input && !is_max_swap {
+        (amount_remaining as u128).checked_sub(amount_in)?
+    } else {
+        (amount_in * fee_rate).div_ceil(denominator - fee_rate)
+    };
+
+    Some(SwapStep {
+        amount_in: u64::try_from(amount_in).ok()?,
+        amount_out: u64::try_from(amount_out).ok()?,
+        next_sqrt_price,
+        fee_amount: u64::try_from(fee_amount).ok()?,
+    })
+}
+
+/// The amount of the specified token: the input, rounded up, or the output,
+/// rounded down.
+fn fixed_delta(
+    sqrt_price_current: u128,
+    sqrt_price_target: u128,
+    liquidity: u128,
+    amount_specified_is_input: bool,
+    zero_for_one: bool,
+) -> Option<u128> {
+    if zero_for_one == amount_specified_is_input {
+        amount_delta_0(
+            sqrt_price_current,
+            sqrt_price_target,
+            liquidity,
+            amount_specified_is_input,
+        )
+    } else {
+        amount_delta_1(
+            sqrt_price_current,
+            sqrt_price_target,
+            liquidity,
+            amount_specified_is_input,
+        )
+    }
+}
+
+/// The amount of the other token: the output, rounded down, or the input,
+/// rounded up.
+fn unfixed_delta(
+    sqrt_price_current: u128,
+    sqrt_price_target: u128,
+    liquidity: u128,
+    amount_specified_is_input: bool,
+    zero_for_one: bool,
+) -> Option<u128> {
+    if zero_for_one == amount_specified_is_input {
+        amount_delta_1(
+            sqrt_price_current,
+            sqrt_price_target,
+            liquidity,
+            !amount_specified_is_input,
+        )
+    } else {
+        amount_delta_0(
+            sqrt_price_current,
+            sqrt_price_target,
+            liquidity,
+            !amount_specified_is_input,
+        )
+    }
+}
+
+fn sorted(sqrt_ratio_a_x64: u128, sqrt_ratio_b_x64: u128) -> (u128, u128) {
+    if sqrt_ratio_a_x64 <= sqrt_ratio_b_x64 {
+        (sqrt_ratio_a_x64, sqrt_ratio_b_x64)
+    } else {
+        (sqrt_ratio_b_x64, sqrt_ratio_a_x64)
+    }
+}
+
+/// Divides `numerator` by `denominator`, rounding the quotient up or down,
+/// or returns `None` if `denominator` is zero.
+fn div_rounding(numerator: U256, denominator: U256, round_up: bool) -> Option<U256> {
+    if denominator.is_zero() {
+        return None;
+    }
+    let (quotient, remainder) = numerator.div_mod(denominator);
+    if round_up && !remainder.is_zero() {
+        quotient.checked_add(U256::one())
+    } else {
+        Some(quotient)
+    }
+}
+
+/// Shifts `value` left by 64 bits, or returns `None` if it overflows.
+fn checked_shl_64(value: U256) -> Option<U256> {
+    (value.bits() <= 192).then(|| value << 64)
+}
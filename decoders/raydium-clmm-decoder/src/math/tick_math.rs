@@ -0,0 +1,129 @@
+//! Conversions between ticks and square root prices.
+
+pub const MIN_TICK: i32 = -443636;
+pub const MAX_TICK: i32 = 443636;
+pub const MIN_SQRT_PRICE_X64: u128 = 4295048016;
+pub const MAX_SQRT_PRICE_X64: u128 = 79226673521066979257578248091;
+pub const TICK_ARRAY_SIZE: i32 = 60;
+
+/// `1 / sqrt(1.0001)^(2^i)` as Q64.64 fixed point numbers, with the low bits
+/// truncated exactly as the program's constants are.
+const INVERSE_SQRT_POWERS_X64: [u128; 19] = [
+    0xfffcb933bd6fb800,
+    0xfff97272373d4000,
+    0xfff2e50f5f657000,
+    0xffe5caca7e10f000,
+    0xffcb9843d60f7000,
+    0xff973b41fa98e800,
+    0xff2ea16466c9b000,
+    0xfe5dee046a9a3800,
+    0xfcbe86c7900bb000,
+    0xf987a7253ac65800,
+    0xf3392b0822bb6000,
+    0xe7159475a2caf000,
+    0xd097f3bdfd2f2000,
+    0xa9f746462d9f8000,
+    0x70d869a156f31c00,
+    0x31be135f97ed3200,
+    0x9aa508b5b85a500,
+    0x5d6af8dedc582c,
+    0x2216e584f5fa,
+];
+
+/// Returns the Q64.64 square root price at `tick`, `sqrt(1.0001^tick)`, or
+/// `None` if the tick is out of bounds.
+///
+/// Follows the program's computation, which multiplies the inverse powers of
+/// `sqrt(1.0001)` for each bit of the absolute tick, rounding down after
+/// each multiplication, and inverts the result for positive ticks.
+pub fn sqrt_price_at_tick(tick: i32) -> Option<u128> {
+    if !(MIN_TICK..=MAX_TICK).contains(&tick) {
+        return None;
+    }
+
+    let abs_tick = tick.unsigned_abs();
+    let mut ratio: u128 = 1 << 64;
+    for (i, power) in INVERSE_SQRT_POWERS_X64.iter().enumerate() {
+        if abs_tick & (1 << i) != 0 {
+            ratio = ratio.checked_mul(*power)? >> 64;
+        }
+    }
+
+    if tick > 0 {
+        Some(u128::MAX / ratio)
+    } else {
+        Some(ratio)
+    }
+}
+
+/// Returns the tick whose range contains `sqrt_price_x64`: the greatest tick
+/// whose square root price is at most `sqrt_price_x64`.
+pub fn tick_at_sqrt_price(sqrt_price_x64: u128) -> Option<i32> {
+    if !(MIN_SQRT_PRICE_X64..=MAX_SQRT_PRICE_X64).contains(&sqrt_price_x64) {
+        return None;
+    }
+
+    let (mut low, mut high) = (MIN_TICK, MAX_TICK);
+    while low < high {
+        let middle = low + (high - low + 1) / 2;
+        if sqrt_price_at_tick(middle)? <= sqrt_price_x64 {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+    Some(low)
+}
+
+/// Returns the start tick of the tick array containing `tick`.
+pub fn tick_array_start_index(tick: i32, tick_spacing: u16) -> i32 {
+    let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+    tick.div_euclid(ticks_in_array) * ticks_in_array
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sqrt_price_bounds() {
+        assert_eq!(sqrt_price_at_tick(MIN_TICK), Some(MIN_SQRT_PRICE_X64));
+        assert_eq!(sqrt_price_at_tick(MAX_TICK), Some(MAX_SQRT_PRICE_X64));
+        assert_eq!(sqrt_price_at_tick(MIN_TICK - 1), None);
+        assert_eq!(sqrt_price_at_tick(MAX_TICK + 1), None);
+    }
+
+    #[test]
+    fn test_sqrt_price_at_tick() {
+        // Computed by the program's `get_sqrt_price_at_tick`.
+        assert_eq!(sqrt_price_at_tick(0), Some(1 << 64));
+        assert_eq!(sqrt_price_at_tick(-1), Some(18445821805675395072));
+        assert_eq!(sqrt_price_at_tick(1), Some(18447666387855957090));
+        assert_eq!(sqrt_price_at_tick(-100), Some(18354745142194513203));
+        assert_eq!(sqrt_price_at_tick(100), Some(18539204128674375874));
+        assert_eq!(sqrt_price_at_tick(-168653), Some(4016515618953968));
+        assert_eq!(sqrt_price_at_tick(195435), Some(323239665984394612754394));
+    }
+
+    #[test]
+    fn test_tick_at_sqrt_price() {
+        for tick in [MIN_TICK, -300000, -28861, -1, 0, 1, 12345, 28861, MAX_TICK] {
+            let sqrt_price_x64 = sqrt_price_at_tick(tick).unwrap();
+            assert_eq!(tick_at_sqrt_price(sqrt_price_x64), Some(tick));
+            if tick < MAX_TICK {
+                assert_eq!(tick_at_sqrt_price(sqrt_price_x64 + 1), Some(tick));
+            }
+            if tick > MIN_TICK {
+                assert_eq!(tick_at_sqrt_price(sqrt_price_x64 - 1), Some(tick - 1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_tick_array_start_index() {
+        assert_eq!(tick_array_start_index(0, 10), 0);
+        assert_eq!(tick_array_start_index(599, 10), 0);
+        assert_eq!(tick_array_start_index(600, 10), 600);
+        assert_eq!(tick_array_start_index(-1, 10), -600);
+    }
+}
@@ -8,6 +8,7 @@ pub mod observation_state;
 pub mod pool_state;
 
 #[allow(clippy::large_enum_variant)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum RaydiumCpmmAccount {
     AmmConfig(amm_config::AmmConfig),
     ObservationState(observation_state::ObservationState),
@@ -58,4 +59,20 @@ impl AccountDecoder<'_> for RaydiumCpmmDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            amm_config::AmmConfig,
+            observation_state::ObservationState,
+            pool_state::PoolState,
+        )
+    }
 }
@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0xf7ede3f5d7c3de46")]
 pub struct PoolState {
     pub amm_config: solana_sdk::pubkey::Pubkey,
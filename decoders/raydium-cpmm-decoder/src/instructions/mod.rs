@@ -65,4 +65,29 @@ impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumCpmmDecoder {
             RaydiumCpmmInstruction::SwapEvent => swap_event::SwapEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            create_amm_config::CreateAmmConfig,
+            update_amm_config::UpdateAmmConfig,
+            update_pool_status::UpdatePoolStatus,
+            collect_protocol_fee::CollectProtocolFee,
+            collect_fund_fee::CollectFundFee,
+            initialize::Initialize,
+            deposit::Deposit,
+            withdraw::Withdraw,
+            swap_base_input::SwapBaseInput,
+            swap_base_output::SwapBaseOutput,
+            lp_change_event::LpChangeEvent,
+            swap_event::SwapEvent,
+        )
+    }
 }
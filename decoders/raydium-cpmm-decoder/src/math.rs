@@ -0,0 +1,262 @@
+//! Price and swap quote math for constant product pools.
+//!
+//! A pool's reserves are its vault balances less the protocol and fund fees
+//! it has collected but not yet claimed. The pool account doesn't hold its
+//! vault balances, so they are passed in. Prices are in token 1 per token 0
+//! and fee rates are in millionths.
+
+use crate::accounts::{amm_config::AmmConfig, pool_state::PoolState};
+
+pub const FEE_RATE_DENOMINATOR: u64 = 1_000_000;
+
+/// The amounts of a quoted swap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SwapQuote {
+    /// The input amount, fees included.
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee_amount: u64,
+}
+
+/// Quotes swapping exactly `amount_in` into a pool holding `reserve_in` and
+/// `reserve_out`.
+pub fn swap_base_input(
+    amount_in: u64,
+    reserve_in: u64,
+    reserve_out: u64,
+    trade_fee_rate: u64,
+) -> Option<SwapQuote> {
+    let fee_amount =
+        (amount_in as u128 * trade_fee_rate as u128).div_ceil(FEE_RATE_DENOMINATOR as u128) as u64;
+    let amount_in_less_fee = amount_in.checked_sub(fee_amount)? as u128;
+    let amount_out = amount_in_less_fee * reserve_out as u128
+        / (reserve_in as u128).checked_add(amount_in_less_fee)?;
+
+    Some(SwapQuote {
+        amount_in,
+        amount_out: u64::try_from(amount_out).ok()?,
+        fee_amount,
+    })
+}
+
+/// Quotes swapping for exactly `amount_out` from a pool holding `reserve_in`
+/// and `reserve_out`; returns `None` if `amount_out` would drain the pool.
+pub fn swap_base_output(
+    amount_out: u64,
+    reserve_in: u64,
+    reserve_out: u64,
+    trade_fee_rate: u64,
+) -> Option<SwapQuote> {
+    if amount_out >= reserve_out || trade_fee_rate >= FEE_RATE_DENOMINATOR {
+        return None;
+    }
+
+    let amount_in_less_fee =
+        (amount_out as u128 * reserve_in as u128).div_ceil((reserve_out - amount_out) as u128);
+    let amount_in = (amount_in_less_fee * FEE_RATE_DENOMINATOR as u128)
+        .div_ceil((FEE_RATE_DENOMINATOR - trade_fee_rate) as u128);
+
+    Some(SwapQuote {
+        amount_in: u64::try_from(amount_in).ok()?,
+        amount_out,
+        fee_amount: u64::try_from(amount_in - amount_in_less_fee).ok()?,
+    })
+}
+
+impl PoolState {
+    /// Returns the pool's reserves of tokens 0 and 1, given the balances of
+    /// its vaults.
+    pub fn reserves(&self, vault0_amount: u64, vault1_amount: u64) -> Option<(u64, u64)> {
+        let reserve0 = vault0_amount
+            .checked_sub(self.protocol_fees_token0)?
+            .checked_sub(self.fund_fees_token0)?;
+        let reserve1 = vault1_amount
+            .checked_sub(self.protocol_fees_token1)?
+            .checked_sub(self.fund_fees_token1)?;
+        Some((reserve0, reserve1))
+    }
+
+    /// Returns the spot price in token 1 per token 0, adjusted for the
+    /// mints' decimals, given the balances of the pool's vaults.
+    pub fn price(&self, vault0_amount: u64, vault1_amount: u64) -> Option<f64> {
+        let (reserve0, reserve1) = self.reserves(vault0_amount, vault1_amount)?;
+        if reserve0 == 0 {
+            return None;
+        }
+        Some(
+            reserve1 as f64 / reserve0 as f64
+                * 10f64.powi(self.mint0_decimals as i32 - self.mint1_decimals as i32),
+        )
+    }
+
+    /// Quotes swapping exactly `amount_in` of token 0, if `zero_for_one`, or
+    /// of token 1, given the balances of the pool's vaults.
+    pub fn quote_exact_in(
+        &self,
+        amm_config: &AmmConfig,
+        vault0_amount: u64,
+        vault1_amount: u64,
+        amount_in: u64,
+        zero_for_one: bool,
+    ) -> Option<SwapQuote> {
+        let (reserve_in, reserve_out) =
+            self.directed_reserves(vault0_amount, vault1_amount, zero_for_one)?;
+        swap_base_input(
+            amount_in,
+            reserve_in,
+            reserve_out,
+            amm_config.trade_fee_rate,
+        )
+    }
+
+    /// Quotes swapping for exactly `amount_out` of token 1, if
+    /// `zero_for_one`, or of token 0, given the balances of the pool's
+    /// vaults.
+    pub fn quote_exact_out(
+        &self,
+        amm_config: &AmmConfig,
+        vault0_amount: u64,
+        vault1_amount: u64,
+        amount_out: u64,
+        zero_for_one: bool,
+    ) -> Option<SwapQuote> {
+        let (reserve_in, reserve_out) =
+            self.directed_reserves(vault0_amount, vault1_amount, zero_for_one)?;
+        swap_base_output(
+            amount_out,
+            reserve_in,
+            reserve_out,
+            amm_config.trade_fee_rate,
+        )
+    }
+
+    fn directed_reserves(
+        &self,
+        vault0_amount: u64,
+        vault1_amount: u64,
+        zero_for_one: bool,
+    ) -> Option<(u64, u64)> {
+        let (reserve0, reserve1) = self.reserves(vault0_amount, vault1_amount)?;
+        Some(if zero_for_one {
+            (reserve0, reserve1)
+        } else {
+            (reserve1, reserve0)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{accounts::RaydiumCpmmAccount, RaydiumCpmmDecoder},
+        carbon_core::account::AccountDecoder,
+    };
+
+    // The amounts held by the pool's vaults, fees owed included.
+    const VAULT0_AMOUNT: u64 = 1_000_000_000_000;
+    const VAULT1_AMOUNT: u64 = 50_000_000_000;
+
+    fn decode_account(path: &str) -> RaydiumCpmmAccount {
+        let account = carbon_test_utils::read_account(path).expect("read fixture");
+
+        RaydiumCpmmDecoder
+            .decode_account(&account)
+            .expect("decode fixture")
+            .data
+    }
+
+    fn pool_state() -> PoolState {
+        match decode_account("tests/fixtures/pool_state_account.json") {
+            RaydiumCpmmAccount::PoolState(pool_state) => pool_state,
+            account => panic!("expected a pool state, got {account:?}"),
+        }
+    }
+
+    fn amm_config() -> AmmConfig {
+        match decode_account("tests/fixtures/amm_config_account.json") {
+            RaydiumCpmmAccount::AmmConfig(amm_config) => amm_config,
+            account => panic!("expected an amm config, got {account:?}"),
+        }
+    }
+
+    #[test]
+    fn test_reserves_and_price() {
+        let pool_state = pool_state();
+
+        assert_eq!(
+            pool_state.reserves(VAULT0_AMOUNT, VAULT1_AMOUNT),
+            Some((999_994_000_000, 49_999_700_000))
+        );
+        assert_eq!(pool_state.reserves(1_000_000, VAULT1_AMOUNT), None);
+
+        let price = pool_state.price(VAULT0_AMOUNT, VAULT1_AMOUNT).unwrap();
+        assert!((price - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_quote_exact_in() {
+        let pool_state = pool_state();
+        let amm_config = amm_config();
+
+        assert_eq!(
+            pool_state.quote_exact_in(
+                &amm_config,
+                VAULT0_AMOUNT,
+                VAULT1_AMOUNT,
+                10_000_000_000,
+                true
+            ),
+            Some(SwapQuote {
+                amount_in: 10_000_000_000,
+                amount_out: 493_824_075,
+                fee_amount: 25_000_000,
+            })
+        );
+        assert_eq!(
+            pool_state.quote_exact_in(
+                &amm_config,
+                VAULT0_AMOUNT,
+                VAULT1_AMOUNT,
+                1_000_000_000,
+                false
+            ),
+            Some(SwapQuote {
+                amount_in: 1_000_000_000,
+                amount_out: 19_559_780_046,
+                fee_amount: 2_500_000,
+            })
+        );
+    }
+
+    #[test]
+    fn test_quote_exact_out() {
+        let pool_state = pool_state();
+        let amm_config = amm_config();
+
+        assert_eq!(
+            pool_state.quote_exact_out(
+                &amm_config,
+                VAULT0_AMOUNT,
+                VAULT1_AMOUNT,
+                400_000_000,
+                true
+            ),
+            Some(SwapQuote {
+                amount_in: 8_084_728_341,
+                amount_out: 400_000_000,
+                fee_amount: 20_211_821,
+            })
+        );
+        assert_eq!(
+            pool_state.quote_exact_out(
+                &amm_config,
+                VAULT0_AMOUNT,
+                VAULT1_AMOUNT,
+                49_999_700_000,
+                true
+            ),
+            None
+        );
+    }
+}
@@ -7,6 +7,7 @@ use super::RaydiumLiquidityLockingDecoder;
 pub mod locked_clmm_position_state;
 pub mod locked_cp_liquidity_state;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum RaydiumLiquidityLockingAccount {
     LockedCpLiquidityState(locked_cp_liquidity_state::LockedCpLiquidityState),
     LockedClmmPositionState(locked_clmm_position_state::LockedClmmPositionState),
@@ -50,4 +51,19 @@ impl<'a> AccountDecoder<'a> for RaydiumLiquidityLockingDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            locked_cp_liquidity_state::LockedCpLiquidityState,
+            locked_clmm_position_state::LockedClmmPositionState,
+        )
+    }
 }
@@ -44,4 +44,22 @@ impl<'a> carbon_core::instruction::InstructionDecoder<'a> for RaydiumLiquidityLo
             RaydiumLiquidityLockingInstruction::SettleCpFeeEvent => settle_cp_fee_event::SettleCpFeeEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            lock_clmm_position::LockClmmPosition,
+            collect_clmm_fees_and_rewards::CollectClmmFeesAndRewards,
+            lock_cp_liquidity::LockCpLiquidity,
+            collect_cp_fees::CollectCpFees,
+            settle_cp_fee_event::SettleCpFeeEvent,
+        )
+    }
 }
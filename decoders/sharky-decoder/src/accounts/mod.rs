@@ -9,6 +9,7 @@ pub mod nft_list;
 pub mod order_book;
 pub mod program_version;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum SharkyAccount {
     OrderBook(order_book::OrderBook),
     Loan(loan::Loan),
@@ -81,4 +82,22 @@ impl AccountDecoder<'_> for SharkyDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            order_book::OrderBook,
+            loan::Loan,
+            nft_list::NftList,
+            escrow_pda::EscrowPda,
+            program_version::ProgramVersion,
+        )
+    }
 }
@@ -83,4 +83,35 @@ impl carbon_core::instruction::InstructionDecoder<'_> for SharkyDecoder {
             SharkyInstruction::UpdateProgramVersion => update_program_version::UpdateProgramVersion,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            create_order_book::CreateOrderBook,
+            update_order_book::UpdateOrderBook,
+            close_order_book::CloseOrderBook,
+            offer_loan::OfferLoan,
+            rescind_loan::RescindLoan,
+            take_loan_v3::TakeLoanV3,
+            take_loan_v3_compressed::TakeLoanV3Compressed,
+            foreclose_loan_v3::ForecloseLoanV3,
+            foreclose_loan_v3_compressed::ForecloseLoanV3Compressed,
+            repay_loan_v3_compressed::RepayLoanV3Compressed,
+            repay_loan_v3::RepayLoanV3,
+            extend_loan_v3::ExtendLoanV3,
+            extend_loan_v3_compressed::ExtendLoanV3Compressed,
+            create_nft_list::CreateNftList,
+            update_nft_list::UpdateNftList,
+            close_nft_list::CloseNftList,
+            create_program_version::CreateProgramVersion,
+            update_program_version::UpdateProgramVersion,
+        )
+    }
 }
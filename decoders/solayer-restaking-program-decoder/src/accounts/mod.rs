@@ -5,6 +5,7 @@ use {
 };
 pub mod restaking_pool;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum SolayerRestakingProgramAccount {
     RestakingPool(restaking_pool::RestakingPool),
 }
@@ -32,4 +33,18 @@ impl<'a> AccountDecoder<'a> for SolayerRestakingProgramDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            restaking_pool::RestakingPool,
+        )
+    }
 }
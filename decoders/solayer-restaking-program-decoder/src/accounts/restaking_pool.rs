@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x0c05648f7d5e1ad6")]
 pub struct RestakingPool {
     pub lst_mint: solana_sdk::pubkey::Pubkey,
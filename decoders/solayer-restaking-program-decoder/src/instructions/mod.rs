@@ -38,4 +38,21 @@ impl<'a> carbon_core::instruction::InstructionDecoder<'a> for SolayerRestakingPr
             SolayerRestakingProgramInstruction::BatchThawLstAccounts => batch_thaw_lst_accounts::BatchThawLstAccounts,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            initialize::Initialize,
+            restake::Restake,
+            unrestake::Unrestake,
+            batch_thaw_lst_accounts::BatchThawLstAccounts,
+        )
+    }
 }
@@ -0,0 +1,157 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, decode::DecodeFailure};
+
+/// A concurrent Merkle tree account.
+///
+/// The account holds a `ConcurrentMerkleTreeHeader`, then the tree, whose
+/// layout depends on the max depth and buffer size in the header, then the
+/// canopy. Of the tree, the sequence number, the current root and the number
+/// of leaves appended are decoded; the change log buffer, the rightmost proof
+/// and the canopy are skipped.
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+pub struct ConcurrentMerkleTree {
+    pub header: ConcurrentMerkleTreeHeader,
+    pub sequence_number: u64,
+    pub active_index: u64,
+    pub buffer_size: u64,
+    pub root: [u8; 32],
+    pub rightmost_index: u32,
+}
+
+impl ConcurrentMerkleTree {
+    pub fn header_data(&self) -> &ConcurrentMerkleTreeHeaderDataV1 {
+        match &self.header.header {
+            ConcurrentMerkleTreeHeaderData::V1(header) => header,
+        }
+    }
+}
+
+impl borsh::BorshDeserialize for ConcurrentMerkleTree {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let header = ConcurrentMerkleTreeHeader::deserialize_reader(reader)?;
+        let ConcurrentMerkleTreeHeaderData::V1(header_data) = &header.header;
+        let max_depth = header_data.max_depth as usize;
+        let max_buffer_size = header_data.max_buffer_size as u64;
+
+        let sequence_number = u64::deserialize_reader(reader)?;
+        let active_index = u64::deserialize_reader(reader)?;
+        let buffer_size = u64::deserialize_reader(reader)?;
+        if active_index >= max_buffer_size {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Active index {active_index} out of buffer of {max_buffer_size}"),
+            ));
+        }
+
+        // Each change log is a root, a path of `max_depth` nodes, an index and
+        // padding.
+        let mut root = [0u8; 32];
+        let mut path = vec![0u8; max_depth * 32 + 8];
+        for index in 0..max_buffer_size {
+            let change_log_root = <[u8; 32]>::deserialize_reader(reader)?;
+            reader.read_exact(&mut path)?;
+            if index == active_index {
+                root = change_log_root;
+            }
+        }
+
+        // The rightmost proof is `max_depth` nodes and the rightmost leaf,
+        // followed by its index and padding.
+        let mut proof = vec![0u8; max_depth * 32 + 32];
+        reader.read_exact(&mut proof)?;
+        let rightmost_index = u32::deserialize_reader(reader)?;
+
+        let mut canopy = Vec::new();
+        reader.read_to_end(&mut canopy)?;
+
+        Ok(ConcurrentMerkleTree {
+            header,
+            sequence_number,
+            active_index,
+            buffer_size,
+            root,
+            rightmost_index,
+        })
+    }
+}
+
+impl carbon_core::deserialize::CarbonDeserialize for ConcurrentMerkleTree {
+    fn deserialize(data: &[u8]) -> Option<Self> {
+        Self::try_deserialize(data).ok()
+    }
+
+    fn try_deserialize(data: &[u8]) -> Result<Self, DecodeFailure> {
+        if data.get(..2) != Some(&[1u8, 0][..]) {
+            return Err(DecodeFailure::unknown_discriminator(data, 2));
+        }
+
+        let mut rest = data;
+        borsh::BorshDeserialize::deserialize(&mut rest).map_err(|err: std::io::Error| {
+            DecodeFailure::Deserialize {
+                type_name: "ConcurrentMerkleTree",
+                offset: data.len() - rest.len(),
+                message: err.to_string(),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, carbon_core::deserialize::CarbonDeserialize, solana_sdk::pubkey::Pubkey};
+
+    fn tree_data(max_depth: u32, max_buffer_size: u32, active_index: u64) -> Vec<u8> {
+        let mut data = vec![1, 0];
+        data.extend_from_slice(&max_buffer_size.to_le_bytes());
+        data.extend_from_slice(&max_depth.to_le_bytes());
+        data.extend_from_slice(&[9; 32]);
+        data.extend_from_slice(&300_000_000u64.to_le_bytes());
+        data.extend_from_slice(&[0; 6]);
+        data.extend_from_slice(&17u64.to_le_bytes());
+        data.extend_from_slice(&active_index.to_le_bytes());
+        data.extend_from_slice(&(active_index + 1).to_le_bytes());
+        for index in 0..max_buffer_size as u8 {
+            data.extend_from_slice(&[index + 1; 32]);
+            data.resize(data.len() + max_depth as usize * 32 + 8, 0);
+        }
+        data.resize(data.len() + max_depth as usize * 32 + 32, 0);
+        data.extend_from_slice(&12u32.to_le_bytes());
+        data.extend_from_slice(&[0; 4]);
+        data.resize(data.len() + 64, 0);
+        data
+    }
+
+    #[test]
+    fn test_decode_concurrent_merkle_tree() {
+        let tree = ConcurrentMerkleTree::deserialize(&tree_data(3, 8, 5)).expect("decode tree");
+
+        assert_eq!(tree.header_data().max_depth, 3);
+        assert_eq!(tree.header_data().max_buffer_size, 8);
+        assert_eq!(
+            tree.header_data().authority,
+            Pubkey::new_from_array([9; 32])
+        );
+        assert_eq!(tree.sequence_number, 17);
+        assert_eq!(tree.root, [6; 32]);
+        assert_eq!(tree.rightmost_index, 12);
+    }
+
+    #[test]
+    fn test_decode_failures() {
+        let mut data = tree_data(3, 8, 5);
+        data[0] = 0;
+        assert_eq!(
+            ConcurrentMerkleTree::try_deserialize(&data),
+            Err(DecodeFailure::unknown_discriminator(&data, 2))
+        );
+
+        assert!(matches!(
+            ConcurrentMerkleTree::try_deserialize(&tree_data(3, 8, 9)),
+            Err(DecodeFailure::Deserialize {
+                type_name: "ConcurrentMerkleTree",
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,51 @@
+use carbon_core::account::AccountDecoder;
+use carbon_core::deserialize::CarbonDeserialize;
+
+use super::SplAccountCompressionDecoder;
+use crate::PROGRAM_ID;
+pub mod concurrent_merkle_tree;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub enum SplAccountCompressionAccount {
+    ConcurrentMerkleTree(concurrent_merkle_tree::ConcurrentMerkleTree),
+}
+
+impl AccountDecoder<'_> for SplAccountCompressionDecoder {
+    type AccountType = SplAccountCompressionAccount;
+    fn decode_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) =
+            concurrent_merkle_tree::ConcurrentMerkleTree::deserialize(account.data.as_slice())
+        {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: SplAccountCompressionAccount::ConcurrentMerkleTree(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        None
+    }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            concurrent_merkle_tree::ConcurrentMerkleTree,
+        )
+    }
+}
@@ -0,0 +1,34 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x957812deece158cb")]
+pub struct Append {
+    pub leaf: [u8; 32],
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AppendInstructionAccounts {
+    pub merkle_tree: solana_sdk::pubkey::Pubkey,
+    pub authority: solana_sdk::pubkey::Pubkey,
+    pub noop: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for Append {
+    type ArrangedAccounts = AppendInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [merkle_tree, authority, noop, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AppendInstructionAccounts {
+            merkle_tree: merkle_tree.pubkey,
+            authority: authority.pubkey,
+            noop: noop.pubkey,
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x8b9beea70bf384cd")]
+pub struct AppendCanopyNodes {
+    pub start_index: u32,
+    pub canopy_nodes: Vec<[u8; 32]>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AppendCanopyNodesInstructionAccounts {
+    pub merkle_tree: solana_sdk::pubkey::Pubkey,
+    pub authority: solana_sdk::pubkey::Pubkey,
+    pub noop: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for AppendCanopyNodes {
+    type ArrangedAccounts = AppendCanopyNodesInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [merkle_tree, authority, noop, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AppendCanopyNodesInstructionAccounts {
+            merkle_tree: merkle_tree.pubkey,
+            authority: authority.pubkey,
+            noop: noop.pubkey,
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x320edb6b4e671067")]
+pub struct CloseEmptyTree {}
+
+#[derive(Debug, PartialEq)]
+pub struct CloseEmptyTreeInstructionAccounts {
+    pub merkle_tree: solana_sdk::pubkey::Pubkey,
+    pub authority: solana_sdk::pubkey::Pubkey,
+    pub recipient: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for CloseEmptyTree {
+    type ArrangedAccounts = CloseEmptyTreeInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [merkle_tree, authority, recipient, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CloseEmptyTreeInstructionAccounts {
+            merkle_tree: merkle_tree.pubkey,
+            authority: authority.pubkey,
+            recipient: recipient.pubkey,
+        })
+    }
+}
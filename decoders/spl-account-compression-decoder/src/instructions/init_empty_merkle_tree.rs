@@ -0,0 +1,35 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xbf0b7707b46bdc6e")]
+pub struct InitEmptyMerkleTree {
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InitEmptyMerkleTreeInstructionAccounts {
+    pub merkle_tree: solana_sdk::pubkey::Pubkey,
+    pub authority: solana_sdk::pubkey::Pubkey,
+    pub noop: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for InitEmptyMerkleTree {
+    type ArrangedAccounts = InitEmptyMerkleTreeInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [merkle_tree, authority, noop, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitEmptyMerkleTreeInstructionAccounts {
+            merkle_tree: merkle_tree.pubkey,
+            authority: authority.pubkey,
+            noop: noop.pubkey,
+        })
+    }
+}
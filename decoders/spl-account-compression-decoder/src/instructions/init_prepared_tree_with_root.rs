@@ -0,0 +1,36 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xdaf8c0375bcd7a0a")]
+pub struct InitPreparedTreeWithRoot {
+    pub root: [u8; 32],
+    pub rightmost_leaf: [u8; 32],
+    pub rightmost_index: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InitPreparedTreeWithRootInstructionAccounts {
+    pub merkle_tree: solana_sdk::pubkey::Pubkey,
+    pub authority: solana_sdk::pubkey::Pubkey,
+    pub noop: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for InitPreparedTreeWithRoot {
+    type ArrangedAccounts = InitPreparedTreeWithRootInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [merkle_tree, authority, noop, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitPreparedTreeWithRootInstructionAccounts {
+            merkle_tree: merkle_tree.pubkey,
+            authority: authority.pubkey,
+            noop: noop.pubkey,
+        })
+    }
+}
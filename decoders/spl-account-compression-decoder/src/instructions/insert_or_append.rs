@@ -0,0 +1,36 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x062a32be336db2a8")]
+pub struct InsertOrAppend {
+    pub root: [u8; 32],
+    pub leaf: [u8; 32],
+    pub index: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InsertOrAppendInstructionAccounts {
+    pub merkle_tree: solana_sdk::pubkey::Pubkey,
+    pub authority: solana_sdk::pubkey::Pubkey,
+    pub noop: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for InsertOrAppend {
+    type ArrangedAccounts = InsertOrAppendInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [merkle_tree, authority, noop, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InsertOrAppendInstructionAccounts {
+            merkle_tree: merkle_tree.pubkey,
+            authority: authority.pubkey,
+            noop: noop.pubkey,
+        })
+    }
+}
@@ -0,0 +1,122 @@
+use crate::PROGRAM_ID;
+
+use super::SplAccountCompressionDecoder;
+pub mod append;
+pub mod append_canopy_nodes;
+pub mod close_empty_tree;
+pub mod init_empty_merkle_tree;
+pub mod init_prepared_tree_with_root;
+pub mod insert_or_append;
+pub mod prepare_tree;
+pub mod replace_leaf;
+pub mod transfer_authority;
+pub mod verify_leaf;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum SplAccountCompressionInstruction {
+    InitEmptyMerkleTree(init_empty_merkle_tree::InitEmptyMerkleTree),
+    PrepareTree(prepare_tree::PrepareTree),
+    AppendCanopyNodes(append_canopy_nodes::AppendCanopyNodes),
+    InitPreparedTreeWithRoot(init_prepared_tree_with_root::InitPreparedTreeWithRoot),
+    ReplaceLeaf(replace_leaf::ReplaceLeaf),
+    TransferAuthority(transfer_authority::TransferAuthority),
+    VerifyLeaf(verify_leaf::VerifyLeaf),
+    Append(append::Append),
+    InsertOrAppend(insert_or_append::InsertOrAppend),
+    CloseEmptyTree(close_empty_tree::CloseEmptyTree),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for SplAccountCompressionDecoder {
+    type InstructionType = SplAccountCompressionInstruction;
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            SplAccountCompressionInstruction::InitEmptyMerkleTree => init_empty_merkle_tree::InitEmptyMerkleTree,
+            SplAccountCompressionInstruction::PrepareTree => prepare_tree::PrepareTree,
+            SplAccountCompressionInstruction::AppendCanopyNodes => append_canopy_nodes::AppendCanopyNodes,
+            SplAccountCompressionInstruction::InitPreparedTreeWithRoot => init_prepared_tree_with_root::InitPreparedTreeWithRoot,
+            SplAccountCompressionInstruction::ReplaceLeaf => replace_leaf::ReplaceLeaf,
+            SplAccountCompressionInstruction::TransferAuthority => transfer_authority::TransferAuthority,
+            SplAccountCompressionInstruction::VerifyLeaf => verify_leaf::VerifyLeaf,
+            SplAccountCompressionInstruction::Append => append::Append,
+            SplAccountCompressionInstruction::InsertOrAppend => insert_or_append::InsertOrAppend,
+            SplAccountCompressionInstruction::CloseEmptyTree => close_empty_tree::CloseEmptyTree,
+        )
+    }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            init_empty_merkle_tree::InitEmptyMerkleTree,
+            prepare_tree::PrepareTree,
+            append_canopy_nodes::AppendCanopyNodes,
+            init_prepared_tree_with_root::InitPreparedTreeWithRoot,
+            replace_leaf::ReplaceLeaf,
+            transfer_authority::TransferAuthority,
+            verify_leaf::VerifyLeaf,
+            append::Append,
+            insert_or_append::InsertOrAppend,
+            close_empty_tree::CloseEmptyTree,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use carbon_core::instruction::InstructionDecoder;
+    use solana_sdk::instruction::Instruction;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_replace_leaf() {
+        // Arrange
+        let expected_ix =
+            SplAccountCompressionInstruction::ReplaceLeaf(replace_leaf::ReplaceLeaf {
+                root: [1; 32],
+                previous_leaf: [2; 32],
+                new_leaf: [3; 32],
+                index: 7,
+            });
+        let mut data = vec![0xcc, 0xa5, 0x4c, 0x64, 0x49, 0x93, 0x00, 0x80];
+        data.extend_from_slice(&[1; 32]);
+        data.extend_from_slice(&[2; 32]);
+        data.extend_from_slice(&[3; 32]);
+        data.extend_from_slice(&7u32.to_le_bytes());
+
+        // Act
+        let decoded = SplAccountCompressionDecoder
+            .decode_instruction(&Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![],
+                data,
+            })
+            .expect("decode instruction");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+    }
+}
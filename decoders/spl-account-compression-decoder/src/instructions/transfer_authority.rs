@@ -0,0 +1,32 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x30a94c48e5b437a1")]
+pub struct TransferAuthority {
+    pub new_authority: solana_sdk::pubkey::Pubkey,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TransferAuthorityInstructionAccounts {
+    pub merkle_tree: solana_sdk::pubkey::Pubkey,
+    pub authority: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for TransferAuthority {
+    type ArrangedAccounts = TransferAuthorityInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [merkle_tree, authority, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(TransferAuthorityInstructionAccounts {
+            merkle_tree: merkle_tree.pubkey,
+            authority: authority.pubkey,
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x7cdc16df680afae0")]
+pub struct VerifyLeaf {
+    pub root: [u8; 32],
+    pub leaf: [u8; 32],
+    pub index: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct VerifyLeafInstructionAccounts {
+    pub merkle_tree: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for VerifyLeaf {
+    type ArrangedAccounts = VerifyLeafInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [merkle_tree, _remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(VerifyLeafInstructionAccounts {
+            merkle_tree: merkle_tree.pubkey,
+        })
+    }
+}
@@ -0,0 +1,9 @@
+use solana_sdk::{pubkey, pubkey::Pubkey};
+
+pub struct SplAccountCompressionDecoder;
+
+pub mod accounts;
+pub mod instructions;
+pub mod types;
+
+pub const PROGRAM_ID: Pubkey = pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
@@ -0,0 +1,48 @@
+use {
+    super::*,
+    carbon_core::{borsh, CarbonDeserialize},
+};
+
+/// An event the Account Compression program, or a program using it, logs as
+/// the data of a Noop program instruction.
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub enum AccountCompressionEvent {
+    ChangeLog(ChangeLogEvent),
+    ApplicationData(ApplicationDataEvent),
+}
+
+impl AccountCompressionEvent {
+    /// Decodes the event logged by an instruction of the Noop program.
+    ///
+    /// Returns `None` for instructions of other programs, and for Noop
+    /// instructions that log something else.
+    pub fn from_noop_instruction(
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> Option<Self> {
+        if instruction.program_id != carbon_spl_noop_decoder::PROGRAM_ID {
+            return None;
+        }
+
+        <Self as carbon_core::deserialize::CarbonDeserialize>::deserialize(&instruction.data)
+    }
+
+    pub fn change_log(&self) -> Option<&ChangeLogEventV1> {
+        match self {
+            AccountCompressionEvent::ChangeLog(ChangeLogEvent::V1(change_log)) => Some(change_log),
+            AccountCompressionEvent::ApplicationData(_) => None,
+        }
+    }
+
+    /// The data logged by the program using the Account Compression program,
+    /// such as a Bubblegum `LeafSchemaEvent`.
+    pub fn application_data(&self) -> Option<&[u8]> {
+        match self {
+            AccountCompressionEvent::ApplicationData(ApplicationDataEvent::V1(event)) => {
+                Some(&event.application_data)
+            }
+            AccountCompressionEvent::ChangeLog(_) => None,
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct ApplicationDataEventV1 {
+    pub application_data: Vec<u8>,
+}
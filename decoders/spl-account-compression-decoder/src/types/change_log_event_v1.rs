@@ -0,0 +1,18 @@
+use {
+    super::*,
+    carbon_core::{borsh, CarbonDeserialize},
+};
+
+/// The path from a changed leaf to the root of its tree, after the change.
+///
+/// `id` is the tree, `index` the leaf's index and `seq` the tree's sequence
+/// number after the change.
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct ChangeLogEventV1 {
+    pub id: solana_sdk::pubkey::Pubkey,
+    pub path: Vec<PathNode>,
+    pub seq: u64,
+    pub index: u32,
+}
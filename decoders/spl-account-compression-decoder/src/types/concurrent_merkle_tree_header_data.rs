@@ -0,0 +1,11 @@
+use {
+    super::*,
+    carbon_core::{borsh, CarbonDeserialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub enum ConcurrentMerkleTreeHeaderData {
+    V1(ConcurrentMerkleTreeHeaderDataV1),
+}
@@ -0,0 +1,13 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct ConcurrentMerkleTreeHeaderDataV1 {
+    pub max_buffer_size: u32,
+    pub max_depth: u32,
+    pub authority: solana_sdk::pubkey::Pubkey,
+    pub creation_slot: u64,
+    pub is_batch_initialized: bool,
+    pub padding: [u8; 5],
+}
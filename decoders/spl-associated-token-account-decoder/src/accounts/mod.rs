@@ -1,6 +1,7 @@
 use super::SplAssociatedTokenAccountDecoder;
 use carbon_core::account::AccountDecoder;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum SplAssociatedTokenAccountAccount {}
 
 impl AccountDecoder<'_> for SplAssociatedTokenAccountDecoder {
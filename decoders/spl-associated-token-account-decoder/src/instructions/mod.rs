@@ -38,4 +38,20 @@ impl carbon_core::instruction::InstructionDecoder<'_> for SplAssociatedTokenAcco
             SplAssociatedTokenAccountInstruction::RecoverNested => recover_nested::RecoverNested,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            create::Create,
+            create_idempotent::CreateIdempotent,
+            recover_nested::RecoverNested,
+        )
+    }
 }
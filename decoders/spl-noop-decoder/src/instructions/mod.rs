@@ -0,0 +1,46 @@
+use crate::PROGRAM_ID;
+
+use super::SplNoopDecoder;
+pub mod noop;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum SplNoopInstruction {
+    Noop(noop::Noop),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for SplNoopDecoder {
+    type InstructionType = SplNoopInstruction;
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            SplNoopInstruction::Noop => noop::Noop,
+        )
+    }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(instruction.data.as_slice(), noop::Noop,)
+    }
+}
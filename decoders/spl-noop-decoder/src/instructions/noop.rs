@@ -0,0 +1,43 @@
+use carbon_core::{borsh, decode::DecodeFailure};
+
+/// The only instruction of the Noop program, which accepts any data.
+///
+/// The data has no discriminator or length prefix: it is the whole
+/// instruction data.
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+pub struct Noop {
+    pub data: Vec<u8>,
+}
+
+pub struct NoopInstructionAccounts {}
+
+impl carbon_core::deserialize::ArrangeAccounts for Noop {
+    type ArrangedAccounts = NoopInstructionAccounts;
+
+    fn arrange_accounts(
+        _accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        Some(NoopInstructionAccounts {})
+    }
+}
+
+impl borsh::BorshDeserialize for Noop {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        Ok(Noop { data })
+    }
+}
+
+impl carbon_core::deserialize::CarbonDeserialize for Noop {
+    fn deserialize(data: &[u8]) -> Option<Self> {
+        Self::try_deserialize(data).ok()
+    }
+
+    fn try_deserialize(data: &[u8]) -> Result<Self, DecodeFailure> {
+        Ok(Noop {
+            data: data.to_vec(),
+        })
+    }
+}
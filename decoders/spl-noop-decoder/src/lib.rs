@@ -0,0 +1,7 @@
+use solana_sdk::{pubkey, pubkey::Pubkey};
+
+pub struct SplNoopDecoder;
+
+pub mod instructions;
+
+pub const PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
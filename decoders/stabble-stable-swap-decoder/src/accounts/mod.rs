@@ -8,6 +8,7 @@ pub mod pool;
 pub mod strategy;
 pub mod vault;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum StableSwapAccount {
     Pool(pool::Pool),
     Strategy(strategy::Strategy),
@@ -56,4 +57,20 @@ impl<'a> AccountDecoder<'a> for StableSwapDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            pool::Pool,
+            strategy::Strategy,
+            vault::Vault,
+        )
+    }
 }
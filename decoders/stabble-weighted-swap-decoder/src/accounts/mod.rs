@@ -7,6 +7,7 @@ use super::WeightedSwapDecoder;
 pub mod pool;
 pub mod vault;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum WeightedSwapAccount {
     Pool(pool::Pool),
     Vault(vault::Vault),
@@ -44,4 +45,15 @@ impl<'a> AccountDecoder<'a> for WeightedSwapDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(account.data.as_slice(), pool::Pool, vault::Vault,)
+    }
 }
@@ -74,4 +74,32 @@ impl<'a> carbon_core::instruction::InstructionDecoder<'a> for WeightedSwapDecode
             WeightedSwapInstruction::PoolUpdatedEvent => pool_updated_event::PoolUpdatedEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            accept_owner::AcceptOwner,
+            change_max_supply::ChangeMaxSupply,
+            change_swap_fee::ChangeSwapFee,
+            deposit::Deposit,
+            initialize::Initialize,
+            pause::Pause,
+            reject_owner::RejectOwner,
+            shutdown::Shutdown,
+            swap::Swap,
+            swap_v2::SwapV2,
+            transfer_owner::TransferOwner,
+            unpause::Unpause,
+            withdraw::Withdraw,
+            pool_balance_updated_event::PoolBalanceUpdatedEvent,
+            pool_updated_event::PoolUpdatedEvent,
+        )
+    }
 }
@@ -1,15 +1,48 @@
 use carbon_core::account::AccountDecoder;
+use carbon_core::deserialize::CarbonDeserialize;
 
 use super::StakeProgramDecoder;
+use crate::PROGRAM_ID;
+pub mod stake_state_v2;
 
-pub enum StakeProgramAccount {}
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub enum StakeProgramAccount {
+    StakeStateV2(stake_state_v2::StakeStateV2),
+}
 
 impl AccountDecoder<'_> for StakeProgramDecoder {
     type AccountType = StakeProgramAccount;
     fn decode_account(
         &self,
-        _account: &solana_sdk::account::Account,
+        account: &solana_sdk::account::Account,
     ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) =
+            stake_state_v2::StakeStateV2::deserialize(account.data.as_slice())
+        {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: StakeProgramAccount::StakeStateV2(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(account.data.as_slice(), stake_state_v2::StakeStateV2,)
+    }
 }
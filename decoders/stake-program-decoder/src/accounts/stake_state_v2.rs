@@ -0,0 +1,151 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, decode::DecodeFailure};
+
+/// The state of a stake account.
+///
+/// Stake accounts are serialized with bincode rather than borsh: the variant
+/// is a little-endian `u32`, and the account is zero-padded to 200 bytes.
+/// The fields themselves share borsh's layout.
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub enum StakeStateV2 {
+    Uninitialized,
+    Initialized(Meta),
+    Stake(Meta, Stake, StakeFlags),
+    RewardsPool,
+}
+
+impl StakeStateV2 {
+    pub fn meta(&self) -> Option<&Meta> {
+        match self {
+            StakeStateV2::Initialized(meta) | StakeStateV2::Stake(meta, _, _) => Some(meta),
+            StakeStateV2::Uninitialized | StakeStateV2::RewardsPool => None,
+        }
+    }
+
+    pub fn stake(&self) -> Option<&Stake> {
+        match self {
+            StakeStateV2::Stake(_, stake, _) => Some(stake),
+            _ => None,
+        }
+    }
+
+    pub fn delegation(&self) -> Option<&Delegation> {
+        self.stake().map(|stake| &stake.delegation)
+    }
+}
+
+impl borsh::BorshDeserialize for StakeStateV2 {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let variant = u32::deserialize_reader(reader)?;
+
+        match variant {
+            0 => Ok(StakeStateV2::Uninitialized),
+            1 => Ok(StakeStateV2::Initialized(Meta::deserialize_reader(reader)?)),
+            2 => Ok(StakeStateV2::Stake(
+                Meta::deserialize_reader(reader)?,
+                Stake::deserialize_reader(reader)?,
+                StakeFlags::deserialize_reader(reader)?,
+            )),
+            3 => Ok(StakeStateV2::RewardsPool),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Unknown StakeStateV2 variant {variant}"),
+            )),
+        }
+    }
+}
+
+impl carbon_core::deserialize::CarbonDeserialize for StakeStateV2 {
+    fn deserialize(data: &[u8]) -> Option<Self> {
+        Self::try_deserialize(data).ok()
+    }
+
+    fn try_deserialize(data: &[u8]) -> Result<Self, DecodeFailure> {
+        match data.get(..4) {
+            Some(variant)
+                if u32::from_le_bytes([variant[0], variant[1], variant[2], variant[3]]) <= 3 => {}
+            _ => return Err(DecodeFailure::unknown_discriminator(data, 4)),
+        }
+
+        let mut rest = data;
+        borsh::BorshDeserialize::deserialize(&mut rest).map_err(|err: std::io::Error| {
+            DecodeFailure::Deserialize {
+                type_name: "StakeStateV2",
+                offset: data.len() - rest.len(),
+                message: err.to_string(),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, carbon_core::deserialize::CarbonDeserialize, solana_sdk::pubkey::Pubkey};
+
+    fn stake_account_data() -> Vec<u8> {
+        let mut data = Vec::with_capacity(200);
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.extend_from_slice(&2_282_880u64.to_le_bytes());
+        data.extend_from_slice(&[1; 32]);
+        data.extend_from_slice(&[2; 32]);
+        data.extend_from_slice(&0i64.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.extend_from_slice(&[0; 32]);
+        data.extend_from_slice(&[3; 32]);
+        data.extend_from_slice(&5_000_000_000u64.to_le_bytes());
+        data.extend_from_slice(&600u64.to_le_bytes());
+        data.extend_from_slice(&u64::MAX.to_le_bytes());
+        data.extend_from_slice(&0.25f64.to_le_bytes());
+        data.extend_from_slice(&123_456u64.to_le_bytes());
+        data.push(0);
+        data.resize(200, 0);
+        data
+    }
+
+    #[test]
+    fn test_decode_stake() {
+        let state = StakeStateV2::deserialize(&stake_account_data()).expect("decode stake");
+
+        let meta = state.meta().expect("meta");
+        assert_eq!(meta.rent_exempt_reserve, 2_282_880);
+        assert_eq!(meta.authorized.staker, Pubkey::new_from_array([1; 32]));
+        assert_eq!(meta.authorized.withdrawer, Pubkey::new_from_array([2; 32]));
+
+        let stake = state.stake().expect("stake");
+        assert_eq!(stake.credits_observed, 123_456);
+        assert_eq!(
+            stake.delegation.voter_pubkey,
+            Pubkey::new_from_array([3; 32])
+        );
+        assert_eq!(stake.delegation.stake, 5_000_000_000);
+        assert_eq!(stake.delegation.activation_epoch, 600);
+        assert_eq!(stake.delegation.deactivation_epoch, u64::MAX);
+    }
+
+    #[test]
+    fn test_decode_failures() {
+        let mut data = stake_account_data();
+        data[0] = 7;
+        assert_eq!(
+            StakeStateV2::try_deserialize(&data),
+            Err(DecodeFailure::unknown_discriminator(&data, 4))
+        );
+
+        let data = stake_account_data();
+        assert!(matches!(
+            StakeStateV2::try_deserialize(&data[..100]),
+            Err(DecodeFailure::Deserialize {
+                type_name: "StakeStateV2",
+                ..
+            })
+        ));
+
+        let mut data = vec![0; 200];
+        data[0] = 3;
+        assert_eq!(
+            StakeStateV2::deserialize(&data),
+            Some(StakeStateV2::RewardsPool)
+        );
+    }
+}
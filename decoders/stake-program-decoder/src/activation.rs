@@ -0,0 +1,375 @@
+//! Activating, active and deactivating stake of a delegation at an epoch.
+//!
+//! Delegated stake warms up and cools down over several epochs: each epoch,
+//! at most a fraction of the cluster's effective stake (the warmup/cooldown
+//! rate) can become active or inactive, shared pro rata between the
+//! delegations that are activating or deactivating. Replaying that schedule
+//! needs the cluster-wide totals of past epochs, which the stake history
+//! sysvar (`SysvarStakeHistory1111111111111111111111111`) provides.
+//!
+//! The computation follows the stake program's own, so the results match
+//! the values reported by RPC nodes for the same history.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let history = StakeHistory::from_account_data(&stake_history_account.data)?;
+//! if let Some(status) = stake_state.activation_status(epoch, &history, None) {
+//!     println!("{} active, {} activating", status.effective, status.activating);
+//! }
+//! ```
+
+use crate::{
+    accounts::stake_state_v2::StakeStateV2,
+    types::{Delegation, StakeHistoryEntry},
+};
+
+/// The warmup/cooldown rate before the `reduce_stake_warmup_cooldown`
+/// feature.
+pub const DEFAULT_WARMUP_COOLDOWN_RATE: f64 = 0.25;
+/// The warmup/cooldown rate from the epoch the `reduce_stake_warmup_cooldown`
+/// feature was activated.
+pub const NEW_WARMUP_COOLDOWN_RATE: f64 = 0.09;
+
+/// The maximum number of epochs kept in the stake history sysvar.
+pub const MAX_STAKE_HISTORY_ENTRIES: usize = 512;
+
+/// Returns the warmup/cooldown rate that applies to `epoch`.
+pub fn warmup_cooldown_rate(epoch: u64, new_rate_activation_epoch: Option<u64>) -> f64 {
+    if epoch < new_rate_activation_epoch.unwrap_or(u64::MAX) {
+        DEFAULT_WARMUP_COOLDOWN_RATE
+    } else {
+        NEW_WARMUP_COOLDOWN_RATE
+    }
+}
+
+/// The cluster's stake totals per epoch, as kept by the stake history sysvar.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StakeHistory {
+    entries: Vec<(u64, StakeHistoryEntry)>,
+}
+
+impl StakeHistory {
+    pub fn new(mut entries: Vec<(u64, StakeHistoryEntry)>) -> Self {
+        entries.sort_by(|(a, _), (b, _)| b.cmp(a));
+        entries.dedup_by_key(|(epoch, _)| *epoch);
+        Self { entries }
+    }
+
+    /// Parses the data of the stake history sysvar account, a bincode vector
+    /// of `(epoch, entry)` pairs, newest first.
+    pub fn from_account_data(data: &[u8]) -> Option<Self> {
+        let (length, mut rest) = data.split_first_chunk::<8>()?;
+        let length = u64::from_le_bytes(*length) as usize;
+
+        let mut entries = Vec::with_capacity(length.min(MAX_STAKE_HISTORY_ENTRIES));
+        for _ in 0..length {
+            let entry: (u64, StakeHistoryEntry) =
+                carbon_core::borsh::BorshDeserialize::deserialize(&mut rest).ok()?;
+            entries.push(entry);
+        }
+
+        Some(Self::new(entries))
+    }
+
+    pub fn get(&self, epoch: u64) -> Option<&StakeHistoryEntry> {
+        self.entries
+            .binary_search_by(|(entry_epoch, _)| epoch.cmp(entry_epoch))
+            .ok()
+            .map(|index| &self.entries[index].1)
+    }
+
+    /// The entries, newest first.
+    pub fn entries(&self) -> &[(u64, StakeHistoryEntry)] {
+        &self.entries
+    }
+}
+
+/// The stake of a delegation at an epoch.
+///
+/// # Fields
+///
+/// - `effective`: The stake that counts towards the validator's stake,
+///   including stake that is deactivating.
+/// - `activating`: The stake that is not effective yet.
+/// - `deactivating`: The part of `effective` that is cooling down.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StakeActivationStatus {
+    pub effective: u64,
+    pub activating: u64,
+    pub deactivating: u64,
+}
+
+impl Delegation {
+    /// Returns the delegation's effective, activating and deactivating stake
+    /// at `target_epoch`.
+    ///
+    /// # Parameters
+    ///
+    /// - `target_epoch`: The epoch to compute the stake for.
+    /// - `history`: The stake history sysvar, covering the epochs from the
+    ///   delegation's activation to `target_epoch`.
+    /// - `new_rate_activation_epoch`: The epoch the
+    ///   `reduce_stake_warmup_cooldown` feature was activated, if it was.
+    pub fn stake_activating_and_deactivating(
+        &self,
+        target_epoch: u64,
+        history: &StakeHistory,
+        new_rate_activation_epoch: Option<u64>,
+    ) -> StakeActivationStatus {
+        let (effective_stake, activating_stake) =
+            self.stake_and_activating(target_epoch, history, new_rate_activation_epoch);
+
+        if target_epoch < self.deactivation_epoch {
+            return StakeActivationStatus {
+                effective: effective_stake,
+                activating: activating_stake,
+                deactivating: 0,
+            };
+        }
+
+        if target_epoch == self.deactivation_epoch {
+            return StakeActivationStatus {
+                effective: effective_stake,
+                activating: 0,
+                deactivating: effective_stake,
+            };
+        }
+
+        let Some(mut prev_cluster_stake) = history.get(self.deactivation_epoch) else {
+            return StakeActivationStatus::default();
+        };
+        let mut prev_epoch = self.deactivation_epoch;
+        let mut current_effective_stake = effective_stake;
+
+        loop {
+            let current_epoch = prev_epoch + 1;
+            if prev_cluster_stake.deactivating == 0 {
+                break;
+            }
+
+            let weight = current_effective_stake as f64 / prev_cluster_stake.deactivating as f64;
+            let rate = warmup_cooldown_rate(current_epoch, new_rate_activation_epoch);
+            let newly_not_effective_cluster_stake = prev_cluster_stake.effective as f64 * rate;
+            let newly_not_effective_stake =
+                ((weight * newly_not_effective_cluster_stake) as u64).max(1);
+
+            current_effective_stake =
+                current_effective_stake.saturating_sub(newly_not_effective_stake);
+            if current_effective_stake == 0 || current_epoch >= target_epoch {
+                break;
+            }
+
+            match history.get(current_epoch) {
+                Some(cluster_stake) => {
+                    prev_epoch = current_epoch;
+                    prev_cluster_stake = cluster_stake;
+                }
+                None => break,
+            }
+        }
+
+        StakeActivationStatus {
+            effective: current_effective_stake,
+            activating: 0,
+            deactivating: current_effective_stake,
+        }
+    }
+
+    /// Returns the effective and activating stake at `target_epoch`, ignoring
+    /// deactivation.
+    fn stake_and_activating(
+        &self,
+        target_epoch: u64,
+        history: &StakeHistory,
+        new_rate_activation_epoch: Option<u64>,
+    ) -> (u64, u64) {
+        let delegated_stake = self.stake;
+
+        // Bootstrap stake is active from genesis.
+        if self.activation_epoch == u64::MAX {
+            return (delegated_stake, 0);
+        }
+        // Deactivated in the epoch it was activated, so it never became active.
+        if self.activation_epoch == self.deactivation_epoch {
+            return (0, 0);
+        }
+        if target_epoch == self.activation_epoch {
+            return (0, delegated_stake);
+        }
+        if target_epoch < self.activation_epoch {
+            return (0, 0);
+        }
+
+        // Without history for the activation epoch, the stake is assumed to
+        // have fully warmed up.
+        let Some(mut prev_cluster_stake) = history.get(self.activation_epoch) else {
+            return (delegated_stake, 0);
+        };
+        let mut prev_epoch = self.activation_epoch;
+        let mut current_effective_stake = 0;
+
+        loop {
+            let current_epoch = prev_epoch + 1;
+            if prev_cluster_stake.activating == 0 {
+                break;
+            }
+
+            let remaining_activating_stake = delegated_stake - current_effective_stake;
+            let weight = remaining_activating_stake as f64 / prev_cluster_stake.activating as f64;
+            let rate = warmup_cooldown_rate(current_epoch, new_rate_activation_epoch);
+            let newly_effective_cluster_stake = prev_cluster_stake.effective as f64 * rate;
+            let newly_effective_stake = ((weight * newly_effective_cluster_stake) as u64).max(1);
+
+            current_effective_stake += newly_effective_stake;
+            if current_effective_stake >= delegated_stake {
+                current_effective_stake = delegated_stake;
+                break;
+            }
+            if current_epoch >= target_epoch || current_epoch >= self.deactivation_epoch {
+                break;
+            }
+
+            match history.get(current_epoch) {
+                Some(cluster_stake) => {
+                    prev_epoch = current_epoch;
+                    prev_cluster_stake = cluster_stake;
+                }
+                None => break,
+            }
+        }
+
+        (
+            current_effective_stake,
+            delegated_stake - current_effective_stake,
+        )
+    }
+}
+
+impl StakeStateV2 {
+    /// Returns the stake's activation status at `target_epoch`, or `None` for
+    /// accounts without a delegation.
+    ///
+    /// See [`Delegation::stake_activating_and_deactivating`].
+    pub fn activation_status(
+        &self,
+        target_epoch: u64,
+        history: &StakeHistory,
+        new_rate_activation_epoch: Option<u64>,
+    ) -> Option<StakeActivationStatus> {
+        self.delegation().map(|delegation| {
+            delegation.stake_activating_and_deactivating(
+                target_epoch,
+                history,
+                new_rate_activation_epoch,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, solana_sdk::pubkey::Pubkey};
+
+    fn delegation(stake: u64, activation_epoch: u64, deactivation_epoch: u64) -> Delegation {
+        Delegation {
+            voter_pubkey: Pubkey::default(),
+            stake,
+            activation_epoch,
+            deactivation_epoch,
+            warmup_cooldown_rate: DEFAULT_WARMUP_COOLDOWN_RATE,
+        }
+    }
+
+    fn entry(effective: u64, activating: u64, deactivating: u64) -> StakeHistoryEntry {
+        StakeHistoryEntry {
+            effective,
+            activating,
+            deactivating,
+        }
+    }
+
+    #[test]
+    fn test_stake_history_from_account_data() {
+        let mut data = 2u64.to_le_bytes().to_vec();
+        for (epoch, effective) in [(11u64, 20u64), (10, 10)] {
+            data.extend_from_slice(&epoch.to_le_bytes());
+            data.extend_from_slice(&effective.to_le_bytes());
+            data.extend_from_slice(&1u64.to_le_bytes());
+            data.extend_from_slice(&2u64.to_le_bytes());
+        }
+        data.resize(data.len() + 64, 0);
+
+        let history = StakeHistory::from_account_data(&data).expect("stake history");
+        assert_eq!(history.entries().len(), 2);
+        assert_eq!(history.get(10), Some(&entry(10, 1, 2)));
+        assert_eq!(history.get(11), Some(&entry(20, 1, 2)));
+        assert_eq!(history.get(12), None);
+    }
+
+    #[test]
+    fn test_warmup() {
+        let history = StakeHistory::new(vec![(10, entry(1_000_000, 400_000, 0))]);
+        let delegation = delegation(400_000, 10, u64::MAX);
+
+        let status = |epoch, new_rate_activation_epoch| {
+            delegation.stake_activating_and_deactivating(epoch, &history, new_rate_activation_epoch)
+        };
+
+        assert_eq!(status(9, None), StakeActivationStatus::default());
+        assert_eq!(status(10, None).activating, 400_000);
+        assert_eq!(
+            status(11, None),
+            StakeActivationStatus {
+                effective: 250_000,
+                activating: 150_000,
+                deactivating: 0,
+            }
+        );
+        assert_eq!(status(11, Some(0)).effective, 90_000);
+        // No history past epoch 10, so the warmup stops where it is known.
+        assert_eq!(status(20, None).effective, 250_000);
+    }
+
+    #[test]
+    fn test_cooldown() {
+        let history = StakeHistory::new(vec![(20, entry(1_000_000, 0, 400_000))]);
+        let delegation = delegation(400_000, 5, 20);
+
+        let status = |epoch| delegation.stake_activating_and_deactivating(epoch, &history, None);
+
+        assert_eq!(status(19).effective, 400_000);
+        assert_eq!(
+            status(20),
+            StakeActivationStatus {
+                effective: 400_000,
+                activating: 0,
+                deactivating: 400_000,
+            }
+        );
+        assert_eq!(
+            status(21),
+            StakeActivationStatus {
+                effective: 150_000,
+                activating: 0,
+                deactivating: 150_000,
+            }
+        );
+    }
+
+    #[test]
+    fn test_bootstrap_and_instant_deactivation() {
+        let history = StakeHistory::default();
+
+        assert_eq!(
+            delegation(100, u64::MAX, u64::MAX)
+                .stake_activating_and_deactivating(3, &history, None)
+                .effective,
+            100
+        );
+        assert_eq!(
+            delegation(100, 7, 7).stake_activating_and_deactivating(8, &history, None),
+            StakeActivationStatus::default()
+        );
+    }
+}
@@ -74,4 +74,32 @@ impl carbon_core::instruction::InstructionDecoder<'_> for StakeProgramDecoder {
             StakeProgramInstruction::DeactivateDelinquent => deactivate_delinquent::DeactivateDelinquent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            initialize::Initialize,
+            authorize::Authorize,
+            delegate_stake::DelegateStake,
+            split::Split,
+            withdraw::Withdraw,
+            deactivate::Deactivate,
+            set_lockup::SetLockup,
+            merge::Merge,
+            authorize_with_seed::AuthorizeWithSeed,
+            initialize_checked::InitializeChecked,
+            authorize_checked::AuthorizeChecked,
+            authorize_checked_with_seed::AuthorizeCheckedWithSeed,
+            set_lockup_checked::SetLockupChecked,
+            get_minimum_delegation::GetMinimumDelegation,
+            deactivate_delinquent::DeactivateDelinquent,
+        )
+    }
 }
@@ -3,6 +3,7 @@ use solana_sdk::{pubkey, pubkey::Pubkey};
 pub struct StakeProgramDecoder;
 
 pub mod accounts;
+pub mod activation;
 pub mod instructions;
 pub mod types;
 
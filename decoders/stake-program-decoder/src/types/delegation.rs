@@ -0,0 +1,10 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct Delegation {
+    pub voter_pubkey: solana_sdk::pubkey::Pubkey,
+    pub stake: u64,
+    pub activation_epoch: u64,
+    pub deactivation_epoch: u64,
+    pub warmup_cooldown_rate: f64,
+}
@@ -0,0 +1,9 @@
+use super::*;
+
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct Stake {
+    pub delegation: Delegation,
+    pub credits_observed: u64,
+}
@@ -0,0 +1,18 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize,
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Clone,
+    Hash,
+    Default,
+)]
+pub struct StakeHistoryEntry {
+    pub effective: u64,
+    pub activating: u64,
+    pub deactivating: u64,
+}
@@ -0,0 +1,141 @@
+use {
+    super::super::types::*,
+    carbon_core::{borsh, CarbonDeserialize},
+    serde_big_array::BigArray,
+};
+
+/// A Switchboard On-Demand pull feed.
+///
+/// Oracles write their responses to `submissions`; `result` holds the
+/// median of the latest valid ones. Switchboard values are fixed-point
+/// numbers with 18 decimals, which the accessors below expose in the same
+/// normalized form as the other oracle decoders.
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[carbon(discriminator = "0xc41b6cc40ad7db28")]
+pub struct PullFeedAccountData {
+    pub submissions: [OracleSubmission; 32],
+    pub authority: solana_sdk::pubkey::Pubkey,
+    pub queue: solana_sdk::pubkey::Pubkey,
+    pub feed_hash: [u8; 32],
+    pub initialized_at: i64,
+    pub permissions: u64,
+    pub max_variance: u64,
+    pub min_responses: u32,
+    pub name: [u8; 32],
+    pub padding1: [u8; 2],
+    pub historical_result_idx: u8,
+    pub min_sample_size: u8,
+    pub last_update_timestamp: i64,
+    pub lut_slot: u64,
+    pub reserved1: [u8; 32],
+    pub result: CurrentResult,
+    pub max_staleness: u32,
+    pub padding2: [u8; 12],
+    pub historical_results: [CompactResult; 32],
+    pub ebuf4: [u8; 8],
+    pub ebuf3: [u8; 24],
+    #[serde(with = "BigArray")]
+    pub ebuf2: [u8; 256],
+}
+
+impl PullFeedAccountData {
+    /// The number of decimals of Switchboard fixed-point values.
+    pub const PRECISION: u32 = 18;
+
+    /// The feed's current value, scaled by `10^exponent()`.
+    pub fn price(&self) -> i128 {
+        self.result.value
+    }
+
+    /// The standard deviation of the oracle responses the current value was
+    /// computed from, scaled by `10^exponent()`.
+    pub fn conf(&self) -> u128 {
+        self.result.std_dev.unsigned_abs()
+    }
+
+    pub fn exponent(&self) -> i32 {
+        -(Self::PRECISION as i32)
+    }
+
+    /// The Unix timestamp, in seconds, of the feed's last update.
+    pub fn publish_time(&self) -> i64 {
+        self.last_update_timestamp
+    }
+
+    /// The price as a floating point number, for display.
+    pub fn ui_price(&self) -> f64 {
+        self.price() as f64 * 10f64.powi(self.exponent())
+    }
+
+    /// The confidence interval as a floating point number, for display.
+    pub fn ui_conf(&self) -> f64 {
+        self.conf() as f64 * 10f64.powi(self.exponent())
+    }
+
+    /// Whether the feed has a current value. Feeds that were never updated,
+    /// or whose responses didn't reach `min_sample_size`, have none.
+    pub fn has_result(&self) -> bool {
+        self.result.num_samples > 0
+    }
+
+    /// The feed's name, without its zero padding.
+    pub fn name(&self) -> String {
+        let end = self
+            .name
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(self.name.len());
+        String::from_utf8_lossy(&self.name[..end]).into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, carbon_core::deserialize::CarbonDeserialize};
+
+    #[test]
+    fn test_decode_pull_feed() {
+        // Arrange
+        let mut data = vec![0xc4, 0x1b, 0x6c, 0xc4, 0x0a, 0xd7, 0xdb, 0x28];
+        data.resize(8 + 32 * 64, 0);
+        data.extend_from_slice(&[1; 32]);
+        data.extend_from_slice(&[2; 32]);
+        data.extend_from_slice(&[3; 32]);
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        let mut name = [0u8; 32];
+        name[..7].copy_from_slice(b"SOL/USD");
+        data.extend_from_slice(&name);
+        data.extend_from_slice(&[0, 0, 0, 1]);
+        data.extend_from_slice(&1_735_689_600i64.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.extend_from_slice(&[0; 32]);
+        data.extend_from_slice(&151_250_000_000_000_000_000i128.to_le_bytes());
+        data.extend_from_slice(&50_000_000_000_000_000i128.to_le_bytes());
+        data.resize(data.len() + 4 * 16, 0);
+        data.push(3);
+        data.resize(data.len() + 7 + 3 * 8, 0);
+        data.extend_from_slice(&100u32.to_le_bytes());
+        data.resize(data.len() + 12 + 32 * 16 + 8 + 24 + 256, 0);
+
+        // Act
+        let feed = PullFeedAccountData::deserialize(&data).expect("decode pull feed");
+
+        // Assert
+        assert_eq!(data.len(), 3208);
+        assert_eq!(feed.name(), "SOL/USD");
+        assert_eq!(
+            feed.queue,
+            solana_sdk::pubkey::Pubkey::new_from_array([2; 32])
+        );
+        assert!(feed.has_result());
+        assert_eq!(feed.price(), 151_250_000_000_000_000_000);
+        assert_eq!(feed.conf(), 50_000_000_000_000_000);
+        assert_eq!(feed.exponent(), -18);
+        assert_eq!(feed.publish_time(), 1_735_689_600);
+        assert_eq!(feed.max_staleness, 100);
+        assert!((feed.ui_price() - 151.25).abs() < 1e-9);
+    }
+}
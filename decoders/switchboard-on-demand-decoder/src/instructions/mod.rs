@@ -0,0 +1,110 @@
+use crate::PROGRAM_ID;
+
+use super::SwitchboardOnDemandDecoder;
+pub mod pull_feed_submit_response;
+pub mod pull_feed_submit_response_many;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum SwitchboardOnDemandInstruction {
+    PullFeedSubmitResponse(pull_feed_submit_response::PullFeedSubmitResponse),
+    PullFeedSubmitResponseMany(pull_feed_submit_response_many::PullFeedSubmitResponseMany),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for SwitchboardOnDemandDecoder {
+    type InstructionType = SwitchboardOnDemandInstruction;
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            SwitchboardOnDemandInstruction::PullFeedSubmitResponse => pull_feed_submit_response::PullFeedSubmitResponse,
+            SwitchboardOnDemandInstruction::PullFeedSubmitResponseMany => pull_feed_submit_response_many::PullFeedSubmitResponseMany,
+        )
+    }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            pull_feed_submit_response::PullFeedSubmitResponse,
+            pull_feed_submit_response_many::PullFeedSubmitResponseMany,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionDecoder};
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_decode_pull_feed_submit_response() {
+        // Arrange
+        let expected_ix = SwitchboardOnDemandInstruction::PullFeedSubmitResponse(
+            pull_feed_submit_response::PullFeedSubmitResponse {
+                params: crate::types::PullFeedSubmitResponseParams {
+                    slot: 312_000_000,
+                    submissions: vec![crate::types::Submission {
+                        value: 151_250_000_000_000_000_000,
+                        signature: [4; 64],
+                        recovery_id: 1,
+                        offset: 2,
+                    }],
+                },
+            },
+        );
+        let accounts: Vec<AccountMeta> = (10..20)
+            .map(|byte| AccountMeta::new_readonly(Pubkey::new_from_array([byte; 32]), false))
+            .collect();
+        let mut data = vec![0x96, 0x16, 0xd7, 0xa6, 0x8f, 0x5d, 0x30, 0x89];
+        data.extend_from_slice(&312_000_000u64.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&151_250_000_000_000_000_000i128.to_le_bytes());
+        data.extend_from_slice(&[4; 64]);
+        data.extend_from_slice(&[1, 2]);
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts.clone(),
+            data,
+        };
+
+        // Act
+        let decoder = SwitchboardOnDemandDecoder;
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let arranged =
+            pull_feed_submit_response::PullFeedSubmitResponse::arrange_accounts(&decoded.accounts)
+                .expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(arranged.feed, accounts[0].pubkey);
+        assert_eq!(arranged.queue, accounts[1].pubkey);
+    }
+}
@@ -0,0 +1,49 @@
+use {
+    super::super::types::*,
+    carbon_core::{borsh, CarbonDeserialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x9616d7a68f5d3089")]
+pub struct PullFeedSubmitResponse {
+    pub params: PullFeedSubmitResponseParams,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PullFeedSubmitResponseInstructionAccounts {
+    pub feed: solana_sdk::pubkey::Pubkey,
+    pub queue: solana_sdk::pubkey::Pubkey,
+    pub slot_hashes: solana_sdk::pubkey::Pubkey,
+    pub payer: solana_sdk::pubkey::Pubkey,
+    pub system_program: solana_sdk::pubkey::Pubkey,
+    pub reward_vault: solana_sdk::pubkey::Pubkey,
+    pub token_program: solana_sdk::pubkey::Pubkey,
+    pub token_mint: solana_sdk::pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for PullFeedSubmitResponse {
+    type ArrangedAccounts = PullFeedSubmitResponseInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_sdk::instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [feed, queue, slot_hashes, payer, system_program, reward_vault, token_program, token_mint, _remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PullFeedSubmitResponseInstructionAccounts {
+            feed: feed.pubkey,
+            queue: queue.pubkey,
+            slot_hashes: slot_hashes.pubkey,
+            payer: payer.pubkey,
+            system_program: system_program.pubkey,
+            reward_vault: reward_vault.pubkey,
+            token_program: token_program.pubkey,
+            token_mint: token_mint.pubkey,
+        })
+    }
+}
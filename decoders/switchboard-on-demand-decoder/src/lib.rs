@@ -0,0 +1,9 @@
+use solana_sdk::{pubkey, pubkey::Pubkey};
+
+pub struct SwitchboardOnDemandDecoder;
+
+pub mod accounts;
+pub mod instructions;
+pub mod types;
+
+pub const PROGRAM_ID: Pubkey = pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
@@ -0,0 +1,14 @@
+pub mod compact_result;
+pub use compact_result::*;
+pub mod current_result;
+pub use current_result::*;
+pub mod multi_submission;
+pub use multi_submission::*;
+pub mod oracle_submission;
+pub use oracle_submission::*;
+pub mod pull_feed_submit_response_many_params;
+pub use pull_feed_submit_response_many_params::*;
+pub mod pull_feed_submit_response_params;
+pub use pull_feed_submit_response_params::*;
+pub mod submission;
+pub use submission::*;
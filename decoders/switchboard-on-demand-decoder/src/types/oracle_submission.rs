@@ -0,0 +1,11 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct OracleSubmission {
+    pub oracle: solana_sdk::pubkey::Pubkey,
+    pub slot: u64,
+    pub landed_at: u64,
+    pub value: i128,
+}
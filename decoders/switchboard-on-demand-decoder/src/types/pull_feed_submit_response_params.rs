@@ -0,0 +1,12 @@
+use {
+    super::*,
+    carbon_core::{borsh, CarbonDeserialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct PullFeedSubmitResponseParams {
+    pub slot: u64,
+    pub submissions: Vec<Submission>,
+}
@@ -0,0 +1,15 @@
+use {
+    carbon_core::{borsh, CarbonDeserialize},
+    serde_big_array::BigArray,
+};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct Submission {
+    pub value: i128,
+    #[serde(with = "BigArray")]
+    pub signature: [u8; 64],
+    pub recovery_id: u8,
+    pub offset: u8,
+}
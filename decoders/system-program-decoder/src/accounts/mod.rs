@@ -4,6 +4,7 @@ use carbon_core::deserialize::CarbonDeserialize;
 use super::SystemProgramDecoder;
 pub mod nonce;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum SystemAccount {
     Nonce(nonce::Nonce),
 }
@@ -59,6 +59,32 @@ impl carbon_core::instruction::InstructionDecoder<'_> for SystemProgramDecoder {
             SystemProgramInstruction::UpgradeNonceAccount => upgrade_nonce_account::UpgradeNonceAccount,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(solana_sdk::system_program::id())
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            create_account::CreateAccount,
+            assign::Assign,
+            transfer_sol::TransferSol,
+            create_account_with_seed::CreateAccountWithSeed,
+            advance_nonce_account::AdvanceNonceAccount,
+            withdraw_nonce_account::WithdrawNonceAccount,
+            initialize_nonce_account::InitializeNonceAccount,
+            authorize_nonce_account::AuthorizeNonceAccount,
+            allocate::Allocate,
+            allocate_with_seed::AllocateWithSeed,
+            assign_with_seed::AssignWithSeed,
+            transfer_sol_with_seed::TransferSolWithSeed,
+            upgrade_nonce_account::UpgradeNonceAccount,
+        )
+    }
 }
 
 #[cfg(test)]
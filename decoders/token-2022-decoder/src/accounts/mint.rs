@@ -1,11 +1,74 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use {
+    super::{super::types::*, packed::PackedReader},
+    crate::extensions::{self, ACCOUNT_TYPE_MINT, MINT_LENGTH},
+    carbon_core::{borsh, decode::DecodeFailure, deserialize::CarbonDeserialize},
+    solana_sdk::pubkey::Pubkey,
+};
 
-#[derive(CarbonDeserialize, Debug)]
-#[carbon(discriminator = "0x6a5edd53c00a4a4a")]
+/// A mint, with the extensions stored after its base layout.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct Mint {
-    pub mint_authority: Option<solana_sdk::pubkey::Pubkey>,
+    pub mint_authority: Option<Pubkey>,
     pub supply: u64,
     pub decimals: u8,
     pub is_initialized: bool,
-    pub freeze_authority: Option<solana_sdk::pubkey::Pubkey>,
+    pub freeze_authority: Option<Pubkey>,
+    pub extensions: Vec<Extension>,
+}
+
+impl Mint {
+    pub fn extension(&self, extension_type: ExtensionType) -> Option<&Extension> {
+        self.extensions
+            .iter()
+            .find(|extension| extension.extension_type() == Some(extension_type.clone()))
+    }
+
+    /// Returns the transfer fee that applies at `epoch`, if the mint has a
+    /// transfer fee config.
+    pub fn transfer_fee(&self, epoch: u64) -> Option<&TransferFee> {
+        extensions::epoch_transfer_fee(&self.extensions, epoch)
+    }
+
+    /// Returns the amount received from a transfer of `amount` at `epoch`,
+    /// after the transfer fee is withheld.
+    pub fn net_transfer_amount(&self, amount: u64, epoch: u64) -> Option<u64> {
+        let fee = match self.transfer_fee(epoch) {
+            Some(transfer_fee) => transfer_fee.calculate_fee(amount)?,
+            None => 0,
+        };
+
+        amount.checked_sub(fee)
+    }
+}
+
+impl borsh::BorshDeserialize for Mint {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        <Self as CarbonDeserialize>::try_deserialize(&data).map_err(|failure| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, failure.to_string())
+        })
+    }
+}
+
+impl CarbonDeserialize for Mint {
+    fn deserialize(data: &[u8]) -> Option<Self> {
+        Self::try_deserialize(data).ok()
+    }
+
+    fn try_deserialize(data: &[u8]) -> Result<Self, DecodeFailure> {
+        let extensions =
+            extensions::decode_extensions(data, MINT_LENGTH, ACCOUNT_TYPE_MINT, "Mint")?;
+        let base = PackedReader::new(&data[..MINT_LENGTH], "Mint");
+
+        Ok(Self {
+            mint_authority: base.optional_pubkey(0)?,
+            supply: base.u64(36)?,
+            decimals: data[44],
+            is_initialized: base.bool(45)?,
+            freeze_authority: base.optional_pubkey(46)?,
+            extensions,
+        })
+    }
 }
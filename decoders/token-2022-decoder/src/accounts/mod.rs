@@ -5,6 +5,7 @@ use {
 };
 pub mod mint;
 pub mod multisig;
+mod packed;
 pub mod token;
 
 pub enum Token2022Account {
@@ -55,4 +56,20 @@ impl AccountDecoder<'_> for Token2022Decoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            mint::Mint,
+            token::Token,
+            multisig::Multisig,
+        )
+    }
 }
@@ -0,0 +1,73 @@
+use {carbon_core::decode::DecodeFailure, solana_sdk::pubkey::Pubkey};
+
+/// Reads the fixed-offset fields of the base mint and token account layouts,
+/// where optional values are prefixed with a `u32` tag.
+pub(crate) struct PackedReader<'a> {
+    data: &'a [u8],
+    type_name: &'static str,
+}
+
+impl<'a> PackedReader<'a> {
+    pub(crate) fn new(data: &'a [u8], type_name: &'static str) -> Self {
+        Self { data, type_name }
+    }
+
+    pub(crate) fn failure(&self, offset: usize, message: String) -> DecodeFailure {
+        DecodeFailure::Deserialize {
+            type_name: self.type_name,
+            offset,
+            message,
+        }
+    }
+
+    fn bytes<const N: usize>(&self, offset: usize) -> Result<[u8; N], DecodeFailure> {
+        let bytes = self
+            .data
+            .get(offset..offset + N)
+            .ok_or_else(|| self.failure(offset, format!("expected {N} more bytes")))?;
+
+        let mut array = [0; N];
+        array.copy_from_slice(bytes);
+        Ok(array)
+    }
+
+    pub(crate) fn bool(&self, offset: usize) -> Result<bool, DecodeFailure> {
+        match self.bytes::<1>(offset)?[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(self.failure(offset, format!("invalid bool {value}"))),
+        }
+    }
+
+    pub(crate) fn u64(&self, offset: usize) -> Result<u64, DecodeFailure> {
+        Ok(u64::from_le_bytes(self.bytes(offset)?))
+    }
+
+    pub(crate) fn pubkey(&self, offset: usize) -> Result<Pubkey, DecodeFailure> {
+        Ok(Pubkey::new_from_array(self.bytes(offset)?))
+    }
+
+    fn is_some(&self, offset: usize) -> Result<bool, DecodeFailure> {
+        match u32::from_le_bytes(self.bytes(offset)?) {
+            0 => Ok(false),
+            1 => Ok(true),
+            tag => Err(self.failure(offset, format!("invalid option tag {tag}"))),
+        }
+    }
+
+    pub(crate) fn optional_pubkey(&self, offset: usize) -> Result<Option<Pubkey>, DecodeFailure> {
+        if !self.is_some(offset)? {
+            return Ok(None);
+        }
+
+        self.pubkey(offset + 4).map(Some)
+    }
+
+    pub(crate) fn optional_u64(&self, offset: usize) -> Result<Option<u64>, DecodeFailure> {
+        if !self.is_some(offset)? {
+            return Ok(None);
+        }
+
+        self.u64(offset + 4).map(Some)
+    }
+}
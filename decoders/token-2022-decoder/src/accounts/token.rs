@@ -1,17 +1,68 @@
 use {
-    super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    super::{super::types::*, packed::PackedReader},
+    crate::extensions::{self, ACCOUNT_LENGTH, ACCOUNT_TYPE_ACCOUNT},
+    carbon_core::{borsh, decode::DecodeFailure, deserialize::CarbonDeserialize},
+    solana_sdk::pubkey::Pubkey,
 };
 
-#[derive(CarbonDeserialize, Debug)]
-#[carbon(discriminator = "0xc971463ece9dd1b2")]
+/// A token account, with the extensions stored after its base layout.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct Token {
-    pub mint: solana_sdk::pubkey::Pubkey,
-    pub owner: solana_sdk::pubkey::Pubkey,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
     pub amount: u64,
-    pub delegate: Option<solana_sdk::pubkey::Pubkey>,
+    pub delegate: Option<Pubkey>,
     pub state: AccountState,
     pub is_native: Option<u64>,
     pub delegated_amount: u64,
-    pub close_authority: Option<solana_sdk::pubkey::Pubkey>,
+    pub close_authority: Option<Pubkey>,
+    pub extensions: Vec<Extension>,
+}
+
+impl Token {
+    pub fn extension(&self, extension_type: ExtensionType) -> Option<&Extension> {
+        self.extensions
+            .iter()
+            .find(|extension| extension.extension_type() == Some(extension_type.clone()))
+    }
+}
+
+impl borsh::BorshDeserialize for Token {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        <Self as CarbonDeserialize>::try_deserialize(&data).map_err(|failure| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, failure.to_string())
+        })
+    }
+}
+
+impl CarbonDeserialize for Token {
+    fn deserialize(data: &[u8]) -> Option<Self> {
+        Self::try_deserialize(data).ok()
+    }
+
+    fn try_deserialize(data: &[u8]) -> Result<Self, DecodeFailure> {
+        let extensions =
+            extensions::decode_extensions(data, ACCOUNT_LENGTH, ACCOUNT_TYPE_ACCOUNT, "Token")?;
+        let base = PackedReader::new(&data[..ACCOUNT_LENGTH], "Token");
+
+        Ok(Self {
+            mint: base.pubkey(0)?,
+            owner: base.pubkey(32)?,
+            amount: base.u64(64)?,
+            delegate: base.optional_pubkey(72)?,
+            state: match data[108] {
+                0 => AccountState::Uninitialized,
+                1 => AccountState::Initialized,
+                2 => AccountState::Frozen,
+                state => return Err(base.failure(108, format!("invalid account state {state}"))),
+            },
+            is_native: base.optional_u64(109)?,
+            delegated_amount: base.u64(121)?,
+            close_authority: base.optional_pubkey(129)?,
+            extensions,
+        })
+    }
 }
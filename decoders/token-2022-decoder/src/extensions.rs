@@ -0,0 +1,542 @@
+//! Decoding of the extensions stored after a mint's or token account's base
+//! layout.
+//!
+//! A Token-2022 account with extensions is laid out as the base account,
+//! padded to the length of a token account (165 bytes), followed by a byte
+//! holding the account type (1 for mints, 2 for token accounts) and a
+//! type-length-value (TLV) region. Each TLV entry is a `u16` extension type, a
+//! `u16` length and the extension's data, with little-endian integers.
+//!
+//! Extensions are decoded into `Extension` values. Extension types this
+//! decoder does not know are kept as `Extension::Unknown` with their raw data,
+//! so newer extensions do not prevent reading the rest of the account.
+
+use {
+    crate::types::{AccountState, Extension, ExtensionType, TransferFee},
+    carbon_core::{borsh, decode::DecodeFailure},
+    solana_sdk::pubkey::Pubkey,
+};
+
+/// The length of a mint without extensions.
+pub const MINT_LENGTH: usize = 82;
+/// The length of a token account without extensions. Mints with extensions
+/// are padded to this length.
+pub const ACCOUNT_LENGTH: usize = 165;
+/// The length of a multisig account, which never has extensions.
+pub const MULTISIG_LENGTH: usize = 355;
+
+/// The account type byte of mints.
+pub const ACCOUNT_TYPE_MINT: u8 = 1;
+/// The account type byte of token accounts.
+pub const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+/// One in basis points, the denominator of transfer fee rates.
+pub const ONE_IN_BASIS_POINTS: u128 = 10_000;
+
+/// Decodes the extensions of an account whose base layout is `base_length`
+/// bytes long.
+///
+/// Accounts of exactly `base_length` bytes have no extensions. Longer accounts
+/// must hold `account_type` after the padding, followed by the TLV region.
+/// The `type_name` and offsets of failures refer to the whole account data.
+pub fn decode_extensions(
+    data: &[u8],
+    base_length: usize,
+    account_type: u8,
+    type_name: &'static str,
+) -> Result<Vec<Extension>, DecodeFailure> {
+    if data.len() == base_length {
+        return Ok(Vec::new());
+    }
+
+    if data.len() <= ACCOUNT_LENGTH || data.len() == MULTISIG_LENGTH {
+        return Err(DecodeFailure::Deserialize {
+            type_name,
+            offset: 0,
+            message: format!("unexpected account length {}", data.len()),
+        });
+    }
+
+    if data[ACCOUNT_LENGTH] != account_type {
+        return Err(DecodeFailure::unknown_discriminator(
+            &data[ACCOUNT_LENGTH..],
+            1,
+        ));
+    }
+
+    let mut extensions = Vec::new();
+    let mut offset = ACCOUNT_LENGTH + 1;
+
+    while let Some(header) = data.get(offset..offset + 4) {
+        let extension_type = u16::from_le_bytes([header[0], header[1]]);
+        let length = u16::from_le_bytes([header[2], header[3]]) as usize;
+
+        // The rest of the account is unused space.
+        if extension_type == 0 {
+            break;
+        }
+
+        let value_offset = offset + 4;
+        let Some(value) = data.get(value_offset..value_offset + length) else {
+            return Err(DecodeFailure::Deserialize {
+                type_name,
+                offset,
+                message: format!(
+                    "extension {extension_type} of {length} bytes overruns the account"
+                ),
+            });
+        };
+
+        let extension = decode_extension(extension_type, value).map_err(
+            |(value_offset_in_extension, message)| DecodeFailure::Deserialize {
+                type_name,
+                offset: value_offset + value_offset_in_extension,
+                message: format!("extension {extension_type}: {message}"),
+            },
+        )?;
+        extensions.push(extension);
+
+        offset = value_offset + length;
+    }
+
+    Ok(extensions)
+}
+
+/// Decodes the value of a single extension. Failures hold the offset into the
+/// value where reading stopped.
+fn decode_extension(extension_type: u16, value: &[u8]) -> Result<Extension, (usize, String)> {
+    let mut reader = PodReader::new(value);
+
+    let extension = match extension_type {
+        1 => Extension::TransferFeeConfig {
+            transfer_fee_config_authority: reader.pubkey()?,
+            withdraw_withheld_authority: reader.pubkey()?,
+            withheld_amount: reader.u64()?,
+            older_transfer_fee: reader.transfer_fee()?,
+            newer_transfer_fee: reader.transfer_fee()?,
+        },
+        2 => Extension::TransferFeeAmount {
+            withheld_amount: reader.u64()?,
+        },
+        3 => Extension::MintCloseAuthority {
+            close_authority: reader.pubkey()?,
+        },
+        4 => Extension::ConfidentialTransferMint {
+            authority: reader.optional_pubkey()?,
+            auto_approve_new_accounts: reader.bool()?,
+            auditor_elgamal_pubkey: reader.optional_pubkey()?,
+        },
+        5 => Extension::ConfidentialTransferAccount {
+            approved: reader.bool()?,
+            elgamal_pubkey: reader.pubkey()?,
+            pending_balance_low: reader.bytes()?,
+            pending_balance_high: reader.bytes()?,
+            available_balance: reader.bytes()?,
+            decryptable_available_balance: reader.bytes()?,
+            allow_confidential_credits: reader.bool()?,
+            allow_non_confidential_credits: reader.bool()?,
+            pending_balance_credit_counter: reader.u64()?,
+            maximum_pending_balance_credit_counter: reader.u64()?,
+            expected_pending_balance_credit_counter: reader.u64()?,
+            actual_pending_balance_credit_counter: reader.u64()?,
+        },
+        6 => Extension::DefaultAccountState {
+            state: reader.account_state()?,
+        },
+        7 => Extension::ImmutableOwner {},
+        8 => Extension::MemoTransfer {
+            require_incoming_transfer_memos: reader.bool()?,
+        },
+        9 => Extension::NonTransferable {},
+        10 => Extension::InterestBearingConfig {
+            rate_authority: reader.pubkey()?,
+            initialization_timestamp: reader.u64()?,
+            pre_update_average_rate: reader.i16()?,
+            last_update_timestamp: reader.u64()?,
+            current_rate: reader.i16()?,
+        },
+        11 => Extension::CpiGuard {
+            lock_cpi: reader.bool()?,
+        },
+        12 => Extension::PermanentDelegate {
+            delegate: reader.pubkey()?,
+        },
+        13 => Extension::NonTransferableAccount {},
+        14 => Extension::TransferHook {
+            authority: reader.pubkey()?,
+            program_id: reader.pubkey()?,
+        },
+        15 => Extension::TransferHookAccount {
+            transferring: reader.bool()?,
+        },
+        16 => Extension::ConfidentialTransferFee {
+            authority: reader.optional_pubkey()?,
+            elgamal_pubkey: reader.pubkey()?,
+            harvest_to_mint_enabled: reader.bool()?,
+            withheld_amount: reader.bytes()?,
+        },
+        17 => Extension::ConfidentialTransferFeeAmount {
+            withheld_amount: reader.bytes()?,
+        },
+        18 => Extension::MetadataPointer {
+            authority: reader.optional_pubkey()?,
+            metadata_address: reader.optional_pubkey()?,
+        },
+        19 => {
+            let update_authority = reader.optional_pubkey()?;
+            let mint = reader.pubkey()?;
+            let (name, symbol, uri, additional_metadata) = reader.borsh()?;
+
+            Extension::TokenMetadata {
+                update_authority,
+                mint,
+                name,
+                symbol,
+                uri,
+                additional_metadata,
+            }
+        }
+        20 => Extension::GroupPointer {
+            authority: reader.optional_pubkey()?,
+            group_address: reader.optional_pubkey()?,
+        },
+        21 => Extension::TokenGroup {
+            update_authority: reader.optional_pubkey()?,
+            mint: reader.pubkey()?,
+            size: reader.u64()?,
+            max_size: reader.u64()?,
+        },
+        22 => Extension::GroupMemberPointer {
+            authority: reader.optional_pubkey()?,
+            member_address: reader.optional_pubkey()?,
+        },
+        23 => Extension::TokenGroupMember {
+            mint: reader.pubkey()?,
+            group: reader.pubkey()?,
+            member_number: reader.u64()?,
+        },
+        _ => Extension::Unknown {
+            extension_type,
+            data: value.to_vec(),
+        },
+    };
+
+    Ok(extension)
+}
+
+/// Reads the fixed-size, little-endian fields of an extension.
+struct PodReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> PodReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N], (usize, String)> {
+        let bytes = self
+            .data
+            .get(self.offset..self.offset + N)
+            .ok_or_else(|| (self.offset, format!("expected {N} more bytes")))?;
+        self.offset += N;
+
+        let mut array = [0; N];
+        array.copy_from_slice(bytes);
+        Ok(array)
+    }
+
+    fn bool(&mut self) -> Result<bool, (usize, String)> {
+        Ok(self.bytes::<1>()?[0] != 0)
+    }
+
+    fn i16(&mut self) -> Result<i16, (usize, String)> {
+        Ok(i16::from_le_bytes(self.bytes()?))
+    }
+
+    fn u16(&mut self) -> Result<u16, (usize, String)> {
+        Ok(u16::from_le_bytes(self.bytes()?))
+    }
+
+    fn u64(&mut self) -> Result<u64, (usize, String)> {
+        Ok(u64::from_le_bytes(self.bytes()?))
+    }
+
+    fn pubkey(&mut self) -> Result<Pubkey, (usize, String)> {
+        Ok(Pubkey::new_from_array(self.bytes()?))
+    }
+
+    /// Reads a pubkey that is unset when all of its bytes are zero.
+    fn optional_pubkey(&mut self) -> Result<Option<Pubkey>, (usize, String)> {
+        let pubkey = self.pubkey()?;
+        Ok((pubkey != Pubkey::default()).then_some(pubkey))
+    }
+
+    fn account_state(&mut self) -> Result<AccountState, (usize, String)> {
+        let offset = self.offset;
+        match self.bytes::<1>()?[0] {
+            0 => Ok(AccountState::Uninitialized),
+            1 => Ok(AccountState::Initialized),
+            2 => Ok(AccountState::Frozen),
+            state => Err((offset, format!("invalid account state {state}"))),
+        }
+    }
+
+    fn transfer_fee(&mut self) -> Result<TransferFee, (usize, String)> {
+        Ok(TransferFee {
+            epoch: self.u64()?,
+            maximum_fee: self.u64()?,
+            transfer_fee_basis_points: self.u16()?,
+        })
+    }
+
+    /// Reads borsh-serialized fields, used by the variable-length extensions.
+    fn borsh<T: borsh::BorshDeserialize>(&mut self) -> Result<T, (usize, String)> {
+        let mut rest = &self.data[self.offset..];
+        let result = T::deserialize(&mut rest);
+        self.offset = self.data.len() - rest.len();
+        result.map_err(|err| (self.offset, err.to_string()))
+    }
+}
+
+impl Extension {
+    /// The type of the extension, or `None` for unknown extensions.
+    pub fn extension_type(&self) -> Option<ExtensionType> {
+        let extension_type = match self {
+            Extension::Uninitialized => ExtensionType::Uninitialized,
+            Extension::TransferFeeConfig { .. } => ExtensionType::TransferFeeConfig,
+            Extension::TransferFeeAmount { .. } => ExtensionType::TransferFeeAmount,
+            Extension::MintCloseAuthority { .. } => ExtensionType::MintCloseAuthority,
+            Extension::ConfidentialTransferMint { .. } => ExtensionType::ConfidentialTransferMint,
+            Extension::ConfidentialTransferAccount { .. } => {
+                ExtensionType::ConfidentialTransferAccount
+            }
+            Extension::DefaultAccountState { .. } => ExtensionType::DefaultAccountState,
+            Extension::ImmutableOwner {} => ExtensionType::ImmutableOwner,
+            Extension::MemoTransfer { .. } => ExtensionType::MemoTransfer,
+            Extension::NonTransferable {} => ExtensionType::NonTransferable,
+            Extension::InterestBearingConfig { .. } => ExtensionType::InterestBearingConfig,
+            Extension::CpiGuard { .. } => ExtensionType::CpiGuard,
+            Extension::PermanentDelegate { .. } => ExtensionType::PermanentDelegate,
+            Extension::NonTransferableAccount {} => ExtensionType::NonTransferableAccount,
+            Extension::TransferHook { .. } => ExtensionType::TransferHook,
+            Extension::TransferHookAccount { .. } => ExtensionType::TransferHookAccount,
+            Extension::ConfidentialTransferFee { .. } => ExtensionType::ConfidentialTransferFee,
+            Extension::ConfidentialTransferFeeAmount { .. } => {
+                ExtensionType::ConfidentialTransferFeeAmount
+            }
+            Extension::MetadataPointer { .. } => ExtensionType::MetadataPointer,
+            Extension::TokenMetadata { .. } => ExtensionType::TokenMetadata,
+            Extension::GroupPointer { .. } => ExtensionType::GroupPointer,
+            Extension::TokenGroup { .. } => ExtensionType::TokenGroup,
+            Extension::GroupMemberPointer { .. } => ExtensionType::GroupMemberPointer,
+            Extension::TokenGroupMember { .. } => ExtensionType::TokenGroupMember,
+            Extension::Unknown { .. } => return None,
+        };
+
+        Some(extension_type)
+    }
+}
+
+impl TransferFee {
+    /// Returns the fee withheld from a transfer of `amount`, rounded up and
+    /// capped at `maximum_fee`, or `None` on overflow.
+    pub fn calculate_fee(&self, amount: u64) -> Option<u64> {
+        let basis_points = self.transfer_fee_basis_points as u128;
+        if basis_points == 0 || amount == 0 {
+            return Some(0);
+        }
+
+        let numerator = (amount as u128).checked_mul(basis_points)?;
+        let fee = numerator.div_ceil(ONE_IN_BASIS_POINTS);
+
+        Some(u64::try_from(fee).ok()?.min(self.maximum_fee))
+    }
+}
+
+/// Returns the transfer fee of a `TransferFeeConfig` extension that applies
+/// at `epoch`: the newer fee once its epoch is reached, the older one before.
+pub fn epoch_transfer_fee(extensions: &[Extension], epoch: u64) -> Option<&TransferFee> {
+    extensions.iter().find_map(|extension| match extension {
+        Extension::TransferFeeConfig {
+            older_transfer_fee,
+            newer_transfer_fee,
+            ..
+        } => Some(if epoch >= newer_transfer_fee.epoch {
+            newer_transfer_fee
+        } else {
+            older_transfer_fee
+        }),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::accounts::{mint::Mint, token::Token},
+        carbon_core::deserialize::CarbonDeserialize,
+    };
+
+    fn push_extension(data: &mut Vec<u8>, extension_type: u16, value: &[u8]) {
+        data.extend_from_slice(&extension_type.to_le_bytes());
+        data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        data.extend_from_slice(value);
+    }
+
+    fn push_string(data: &mut Vec<u8>, value: &str) {
+        data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        data.extend_from_slice(value.as_bytes());
+    }
+
+    fn mint_data() -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&[1; 32]);
+        data.extend_from_slice(&1_000_000u64.to_le_bytes());
+        data.push(6);
+        data.push(1);
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&[0; 32]);
+        data
+    }
+
+    fn transfer_fee(epoch: u64, maximum_fee: u64, basis_points: u16) -> Vec<u8> {
+        let mut value = epoch.to_le_bytes().to_vec();
+        value.extend_from_slice(&maximum_fee.to_le_bytes());
+        value.extend_from_slice(&basis_points.to_le_bytes());
+        value
+    }
+
+    #[test]
+    fn test_mint_without_extensions() {
+        let mint = Mint::deserialize(&mint_data()).expect("decode mint");
+
+        assert_eq!(mint.mint_authority, Some(Pubkey::new_from_array([1; 32])));
+        assert_eq!(mint.supply, 1_000_000);
+        assert_eq!(mint.decimals, 6);
+        assert!(mint.is_initialized);
+        assert_eq!(mint.freeze_authority, None);
+        assert!(mint.extensions.is_empty());
+        assert_eq!(mint.net_transfer_amount(500, 0), Some(500));
+    }
+
+    #[test]
+    fn test_mint_with_extensions() {
+        let mut data = mint_data();
+        data.resize(ACCOUNT_LENGTH, 0);
+        data.push(ACCOUNT_TYPE_MINT);
+
+        let mut transfer_fee_config = [[2; 32], [3; 32]].concat();
+        transfer_fee_config.extend_from_slice(&7u64.to_le_bytes());
+        transfer_fee_config.extend_from_slice(&transfer_fee(0, 5_000, 100));
+        transfer_fee_config.extend_from_slice(&transfer_fee(10, 50, 250));
+        push_extension(&mut data, 1, &transfer_fee_config);
+
+        push_extension(&mut data, 18, &[[4; 32], [0; 32]].concat());
+
+        let mut token_metadata = [[0; 32], [5; 32]].concat();
+        push_string(&mut token_metadata, "Token");
+        push_string(&mut token_metadata, "TKN");
+        push_string(&mut token_metadata, "https://example.com/token.json");
+        token_metadata.extend_from_slice(&1u32.to_le_bytes());
+        push_string(&mut token_metadata, "website");
+        push_string(&mut token_metadata, "example.com");
+        push_extension(&mut data, 19, &token_metadata);
+
+        push_extension(&mut data, 99, &[1, 2, 3]);
+        data.resize(data.len() + 16, 0);
+
+        let mint = Mint::deserialize(&data).expect("decode mint");
+        assert_eq!(mint.extensions.len(), 4);
+
+        assert_eq!(
+            mint.extension(ExtensionType::MetadataPointer),
+            Some(&Extension::MetadataPointer {
+                authority: Some(Pubkey::new_from_array([4; 32])),
+                metadata_address: None,
+            })
+        );
+        assert_eq!(
+            mint.extension(ExtensionType::TokenMetadata),
+            Some(&Extension::TokenMetadata {
+                update_authority: None,
+                mint: Pubkey::new_from_array([5; 32]),
+                name: "Token".to_string(),
+                symbol: "TKN".to_string(),
+                uri: "https://example.com/token.json".to_string(),
+                additional_metadata: vec![("website".to_string(), "example.com".to_string())],
+            })
+        );
+        assert_eq!(
+            mint.extensions[3],
+            Extension::Unknown {
+                extension_type: 99,
+                data: vec![1, 2, 3],
+            }
+        );
+
+        assert_eq!(mint.transfer_fee(9).map(|fee| fee.epoch), Some(0));
+        assert_eq!(mint.net_transfer_amount(10_001, 9), Some(9_900));
+        assert_eq!(mint.net_transfer_amount(10_000, 10), Some(9_950));
+        assert_eq!(mint.net_transfer_amount(0, 10), Some(0));
+    }
+
+    #[test]
+    fn test_token_with_extensions() {
+        let mut data = [[1; 32], [2; 32]].concat();
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&[0; 32]);
+        data.push(2);
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&[3; 32]);
+        assert_eq!(data.len(), ACCOUNT_LENGTH);
+
+        let token = Token::deserialize(&data).expect("decode token");
+        assert_eq!(token.amount, 42);
+        assert_eq!(token.state, AccountState::Frozen);
+        assert_eq!(token.close_authority, Some(Pubkey::new_from_array([3; 32])));
+        assert!(Mint::deserialize(&data).is_none());
+
+        data.push(ACCOUNT_TYPE_ACCOUNT);
+        push_extension(&mut data, 7, &[]);
+        push_extension(&mut data, 2, &9u64.to_le_bytes());
+
+        let token = Token::deserialize(&data).expect("decode token");
+        assert_eq!(
+            token.extensions,
+            vec![
+                Extension::ImmutableOwner {},
+                Extension::TransferFeeAmount { withheld_amount: 9 },
+            ]
+        );
+        assert!(matches!(
+            Mint::try_deserialize(&data),
+            Err(DecodeFailure::UnknownDiscriminator { .. })
+        ));
+    }
+
+    #[test]
+    fn test_truncated_extension() {
+        let mut data = mint_data();
+        data.resize(ACCOUNT_LENGTH, 0);
+        data.push(ACCOUNT_TYPE_MINT);
+        push_extension(&mut data, 12, &[6; 32]);
+        data.truncate(data.len() - 8);
+
+        assert_eq!(
+            Mint::try_deserialize(&data),
+            Err(DecodeFailure::Deserialize {
+                type_name: "Mint",
+                offset: ACCOUNT_LENGTH + 1,
+                message: "extension 12 of 32 bytes overruns the account".to_string(),
+            })
+        );
+    }
+}
@@ -275,4 +275,99 @@ impl carbon_core::instruction::InstructionDecoder<'_> for Token2022Decoder {
             Token2022Instruction::InitializeTokenGroupMember => initialize_token_group_member::InitializeTokenGroupMember,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            initialize_mint::InitializeMint,
+            initialize_account::InitializeAccount,
+            initialize_multisig::InitializeMultisig,
+            transfer::Transfer,
+            approve::Approve,
+            revoke::Revoke,
+            set_authority::SetAuthority,
+            mint_to::MintTo,
+            burn::Burn,
+            close_account::CloseAccount,
+            freeze_account::FreezeAccount,
+            thaw_account::ThawAccount,
+            transfer_checked::TransferChecked,
+            approve_checked::ApproveChecked,
+            mint_to_checked::MintToChecked,
+            burn_checked::BurnChecked,
+            initialize_account2::InitializeAccount2,
+            sync_native::SyncNative,
+            initialize_account3::InitializeAccount3,
+            initialize_multisig2::InitializeMultisig2,
+            initialize_mint2::InitializeMint2,
+            get_account_data_size::GetAccountDataSize,
+            initialize_immutable_owner::InitializeImmutableOwner,
+            amount_to_ui_amount::AmountToUiAmount,
+            ui_amount_to_amount::UiAmountToAmount,
+            initialize_mint_close_authority::InitializeMintCloseAuthority,
+            initialize_transfer_fee_config::InitializeTransferFeeConfig,
+            transfer_checked_with_fee::TransferCheckedWithFee,
+            withdraw_withheld_tokens_from_mint::WithdrawWithheldTokensFromMint,
+            withdraw_withheld_tokens_from_accounts::WithdrawWithheldTokensFromAccounts,
+            harvest_withheld_tokens_to_mint::HarvestWithheldTokensToMint,
+            set_transfer_fee::SetTransferFee,
+            initialize_confidential_transfer_mint::InitializeConfidentialTransferMint,
+            update_confidential_transfer_mint::UpdateConfidentialTransferMint,
+            configure_confidential_transfer_account::ConfigureConfidentialTransferAccount,
+            approve_confidential_transfer_account::ApproveConfidentialTransferAccount,
+            empty_confidential_transfer_account::EmptyConfidentialTransferAccount,
+            confidential_deposit::ConfidentialDeposit,
+            confidential_withdraw::ConfidentialWithdraw,
+            confidential_transfer::ConfidentialTransfer,
+            apply_confidential_pending_balance::ApplyConfidentialPendingBalance,
+            enable_confidential_credits::EnableConfidentialCredits,
+            disable_confidential_credits::DisableConfidentialCredits,
+            enable_non_confidential_credits::EnableNonConfidentialCredits,
+            disable_non_confidential_credits::DisableNonConfidentialCredits,
+            confidential_transfer_with_fee::ConfidentialTransferWithFee,
+            initialize_default_account_state::InitializeDefaultAccountState,
+            update_default_account_state::UpdateDefaultAccountState,
+            reallocate::Reallocate,
+            enable_memo_transfers::EnableMemoTransfers,
+            disable_memo_transfers::DisableMemoTransfers,
+            create_native_mint::CreateNativeMint,
+            initialize_non_transferable_mint::InitializeNonTransferableMint,
+            initialize_interest_bearing_mint::InitializeInterestBearingMint,
+            update_rate_interest_bearing_mint::UpdateRateInterestBearingMint,
+            enable_cpi_guard::EnableCpiGuard,
+            disable_cpi_guard::DisableCpiGuard,
+            initialize_permanent_delegate::InitializePermanentDelegate,
+            initialize_transfer_hook::InitializeTransferHook,
+            update_transfer_hook::UpdateTransferHook,
+            initialize_confidential_transfer_fee::InitializeConfidentialTransferFee,
+            withdraw_withheld_tokens_from_mint_for_confidential_transfer_fee::WithdrawWithheldTokensFromMintForConfidentialTransferFee,
+            withdraw_withheld_tokens_from_accounts_for_confidential_transfer_fee::WithdrawWithheldTokensFromAccountsForConfidentialTransferFee,
+            harvest_withheld_tokens_to_mint_for_confidential_transfer_fee::HarvestWithheldTokensToMintForConfidentialTransferFee,
+            enable_harvest_to_mint::EnableHarvestToMint,
+            disable_harvest_to_mint::DisableHarvestToMint,
+            withdraw_excess_lamports::WithdrawExcessLamports,
+            initialize_metadata_pointer::InitializeMetadataPointer,
+            update_metadata_pointer::UpdateMetadataPointer,
+            initialize_group_pointer::InitializeGroupPointer,
+            update_group_pointer::UpdateGroupPointer,
+            initialize_group_member_pointer::InitializeGroupMemberPointer,
+            update_group_member_pointer::UpdateGroupMemberPointer,
+            initialize_token_metadata::InitializeTokenMetadata,
+            update_token_metadata_field::UpdateTokenMetadataField,
+            remove_token_metadata_key::RemoveTokenMetadataKey,
+            update_token_metadata_update_authority::UpdateTokenMetadataUpdateAuthority,
+            emit_token_metadata::EmitTokenMetadata,
+            initialize_token_group::InitializeTokenGroup,
+            update_token_group_max_size::UpdateTokenGroupMaxSize,
+            update_token_group_update_authority::UpdateTokenGroupUpdateAuthority,
+            initialize_token_group_member::InitializeTokenGroupMember,
+        )
+    }
 }
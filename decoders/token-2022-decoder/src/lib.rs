@@ -2,6 +2,7 @@ use solana_sdk::{pubkey, pubkey::Pubkey};
 
 pub struct Token2022Decoder;
 pub mod accounts;
+pub mod extensions;
 pub mod instructions;
 pub mod types;
 
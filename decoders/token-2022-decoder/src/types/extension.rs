@@ -115,4 +115,8 @@ pub enum Extension {
         group: solana_sdk::pubkey::Pubkey,
         member_number: u64,
     },
+    Unknown {
+        extension_type: u16,
+        data: Vec<u8>,
+    },
 }
@@ -51,4 +51,8 @@ impl AccountDecoder<'_> for TokenProgramDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(spl_token::id())
+    }
 }
@@ -103,4 +103,42 @@ impl carbon_core::instruction::InstructionDecoder<'_> for TokenProgramDecoder {
             TokenProgramInstruction::UiAmountToAmount => ui_amount_to_amount::UiAmountToAmount,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(spl_token::id())
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            amount_to_ui_amount::AmountToUiAmount,
+            approve_checked::ApproveChecked,
+            approve::Approve,
+            burn_checked::BurnChecked,
+            burn::Burn,
+            close_account::CloseAccount,
+            freeze_account::FreezeAccount,
+            get_account_data_size::GetAccountDataSize,
+            initialize_account::InitializeAccount,
+            initialize_account2::InitializeAccount2,
+            initialize_account3::InitializeAccount3,
+            initialize_immutable_owner::InitializeImmutableOwner,
+            initialize_mint::InitializeMint,
+            initialize_mint2::InitializeMint2,
+            initialize_multisig::InitializeMultisig,
+            initialize_multisig2::InitializeMultisig2,
+            mint_to_checked::MintToChecked,
+            mint_to::MintTo,
+            revoke::Revoke,
+            set_authority::SetAuthority,
+            sync_native::SyncNative,
+            thaw_account::ThawAccount,
+            transfer_checked::TransferChecked,
+            transfer::Transfer,
+            ui_amount_to_amount::UiAmountToAmount,
+        )
+    }
 }
@@ -6,6 +6,7 @@ use crate::PROGRAM_ID;
 use super::VirtualsDecoder;
 pub mod virtuals_pool;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum VirtualsAccount {
     VirtualsPool(virtuals_pool::VirtualsPool),
 }
@@ -34,4 +35,15 @@ impl AccountDecoder<'_> for VirtualsDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(account.data.as_slice(), virtuals_pool::VirtualsPool,)
+    }
 }
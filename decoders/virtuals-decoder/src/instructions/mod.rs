@@ -65,4 +65,29 @@ impl carbon_core::instruction::InstructionDecoder<'_> for VirtualsDecoder {
             VirtualsInstruction::SellEvent => sell_event::SellEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            buy::Buy,
+            claim_fees::ClaimFees,
+            create_meteora_pool::CreateMeteoraPool,
+            initialize::Initialize,
+            initialize_meteora_accounts::InitializeMeteoraAccounts,
+            launch::Launch,
+            sell::Sell,
+            update_pool_creator::UpdatePoolCreator,
+            buy_event::BuyEvent,
+            graduation_event::GraduationEvent,
+            launch_event::LaunchEvent,
+            sell_event::SellEvent,
+        )
+    }
 }
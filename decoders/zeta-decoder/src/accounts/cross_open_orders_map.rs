@@ -1,6 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0xc5185209520e309a")]
 pub struct CrossOpenOrdersMap {
     pub user_key: solana_sdk::pubkey::Pubkey,
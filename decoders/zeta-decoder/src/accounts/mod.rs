@@ -28,6 +28,7 @@ pub mod whitelist_insurance_account;
 pub mod whitelist_trading_fees_account;
 pub mod zeta_group;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum ZetaAccount {
     Pricing(pricing::Pricing),
     Greeks(greeks::Greeks),
@@ -338,4 +339,40 @@ impl<'a> AccountDecoder<'a> for ZetaDecoder {
 
         None
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_account(
+        &self,
+        account: &solana_sdk::account::Account,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            account.data.as_slice(),
+            pricing::Pricing,
+            greeks::Greeks,
+            market_indexes::MarketIndexes,
+            open_orders_map::OpenOrdersMap,
+            cross_open_orders_map::CrossOpenOrdersMap,
+            state::State,
+            underlying::Underlying,
+            settlement_account::SettlementAccount,
+            perp_sync_queue::PerpSyncQueue,
+            zeta_group::ZetaGroup,
+            market_node::MarketNode,
+            spread_account::SpreadAccount,
+            cross_margin_account_manager::CrossMarginAccountManager,
+            cross_margin_account::CrossMarginAccount,
+            margin_account::MarginAccount,
+            trigger_order::TriggerOrder,
+            socialized_loss_account::SocializedLossAccount,
+            whitelist_deposit_account::WhitelistDepositAccount,
+            whitelist_insurance_account::WhitelistInsuranceAccount,
+            insurance_deposit_account::InsuranceDepositAccount,
+            whitelist_trading_fees_account::WhitelistTradingFeesAccount,
+            referrer_id_account::ReferrerIdAccount,
+            referrer_pubkey_account::ReferrerPubkeyAccount,
+        )
+    }
 }
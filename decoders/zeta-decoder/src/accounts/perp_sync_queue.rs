@@ -2,11 +2,12 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x5c37389de6b8ab42")]
 pub struct PerpSyncQueue {
     pub nonce: u8,
     pub head: u16,
     pub length: u16,
+    #[serde(with = "serde_big_array::BigArray")]
     pub queue: [AnchorDecimal; 600],
 }
@@ -2,7 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x3982fc88a7b12fa2")]
 pub struct SpreadAccount {
     pub authority: solana_sdk::pubkey::Pubkey,
@@ -10,8 +10,11 @@ pub struct SpreadAccount {
     pub balance: u64,
     pub series_expiry: [u64; 5],
     pub series_expiry_padding: u64,
+    #[serde(with = "serde_big_array::BigArray")]
     pub positions: [Position; 46],
+    #[serde(with = "serde_big_array::BigArray")]
     pub positions_padding: [Position; 92],
     pub asset: Asset,
+    #[serde(with = "serde_big_array::BigArray")]
     pub padding: [u8; 262],
 }
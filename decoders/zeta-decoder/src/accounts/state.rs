@@ -2,7 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0xd8926b5e684bb6b1")]
 pub struct State {
     pub admin: solana_sdk::pubkey::Pubkey,
@@ -47,5 +47,6 @@ pub struct State {
     pub pricing_admin: solana_sdk::pubkey::Pubkey,
     pub treasury_split_token_account: solana_sdk::pubkey::Pubkey,
     pub treasury_split_percentage: u8,
+    #[serde(with = "serde_big_array::BigArray")]
     pub padding: [u8; 185],
 }
@@ -2,7 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
 #[carbon(discriminator = "0x7911d26b6deb0e0c")]
 pub struct ZetaGroup {
     pub nonce: u8,
@@ -14,8 +14,11 @@ pub struct ZetaGroup {
     pub greeks: solana_sdk::pubkey::Pubkey,
     pub pricing_parameters: PricingParameters,
     pub margin_parameters: MarginParameters,
+    #[serde(with = "serde_big_array::BigArray")]
     pub margin_parameters_padding: [u8; 104],
+    #[serde(with = "serde_big_array::BigArray")]
     pub products: [Product; 46],
+    #[serde(with = "serde_big_array::BigArray")]
     pub products_padding: [Product; 91],
     pub perp: Product,
     pub expiry_series: [ExpirySeries; 2],
@@ -29,5 +32,6 @@ pub struct ZetaGroup {
     pub oracle_backup_feed: solana_sdk::pubkey::Pubkey,
     pub perps_only: bool,
     pub flex_underlying: bool,
+    #[serde(with = "serde_big_array::BigArray")]
     pub padding: [u8; 964],
 }
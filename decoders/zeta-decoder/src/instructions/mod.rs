@@ -512,4 +512,166 @@ impl<'a> carbon_core::instruction::InstructionDecoder<'a> for ZetaDecoder {
             ZetaInstruction::PlaceMultiOrdersEvent => place_multi_orders_event::PlaceMultiOrdersEvent,
         )
     }
+
+    fn program_id(&self) -> Option<solana_sdk::pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn diagnose_instruction(
+        &self,
+        instruction: &solana_sdk::instruction::Instruction,
+    ) -> carbon_core::decode::DecodeFailure {
+        carbon_core::diagnose_decode_failure!(
+            instruction.data.as_slice(),
+            initialize_zeta_pricing::InitializeZetaPricing,
+            update_zeta_pricing_pubkeys::UpdateZetaPricingPubkeys,
+            initialize_zeta_group::InitializeZetaGroup,
+            override_expiry::OverrideExpiry,
+            migrate_to_new_cross_margin_account::MigrateToNewCrossMarginAccount,
+            migrate_to_cross_margin_account::MigrateToCrossMarginAccount,
+            initialize_cross_margin_account_manager::InitializeCrossMarginAccountManager,
+            initialize_cross_margin_account_manager_v2::InitializeCrossMarginAccountManagerV2,
+            initialize_cross_margin_account::InitializeCrossMarginAccount,
+            initialize_margin_account::InitializeMarginAccount,
+            initialize_spread_account::InitializeSpreadAccount,
+            close_cross_margin_account_manager::CloseCrossMarginAccountManager,
+            close_cross_margin_account::CloseCrossMarginAccount,
+            close_margin_account::CloseMarginAccount,
+            close_spread_account::CloseSpreadAccount,
+            initialize_underlying::InitializeUnderlying,
+            initialize_perp_sync_queue::InitializePerpSyncQueue,
+            initialize_market_indexes::InitializeMarketIndexes,
+            initialize_market_node::InitializeMarketNode,
+            halt::Halt,
+            unhalt::Unhalt,
+            update_halt_state::UpdateHaltState,
+            update_volatility::UpdateVolatility,
+            update_interest_rate::UpdateInterestRate,
+            add_perp_market_index::AddPerpMarketIndex,
+            add_market_indexes::AddMarketIndexes,
+            initialize_zeta_state::InitializeZetaState,
+            initialize_zeta_treasury_wallet::InitializeZetaTreasuryWallet,
+            initialize_zeta_referrals_rewards_wallet::InitializeZetaReferralsRewardsWallet,
+            update_admin::UpdateAdmin,
+            update_secondary_admin::UpdateSecondaryAdmin,
+            update_trigger_admin::UpdateTriggerAdmin,
+            update_ma_type_admin::UpdateMaTypeAdmin,
+            update_referrals_admin::UpdateReferralsAdmin,
+            update_pricing_admin::UpdatePricingAdmin,
+            update_treasury_split_token_account::UpdateTreasurySplitTokenAccount,
+            update_maker_rebate_percentage::UpdateMakerRebatePercentage,
+            update_take_trigger_order_fee_percentage::UpdateTakeTriggerOrderFeePercentage,
+            update_zeta_state::UpdateZetaState,
+            update_oracle::UpdateOracle,
+            update_oracle_backup_feed::UpdateOracleBackupFeed,
+            update_pricing_parameters::UpdatePricingParameters,
+            update_margin_parameters::UpdateMarginParameters,
+            update_zeta_group_margin_parameters::UpdateZetaGroupMarginParameters,
+            update_perp_parameters::UpdatePerpParameters,
+            update_zeta_group_perp_parameters::UpdateZetaGroupPerpParameters,
+            update_zeta_group_expiry_parameters::UpdateZetaGroupExpiryParameters,
+            toggle_zeta_group_perps_only::ToggleZetaGroupPerpsOnly,
+            clean_zeta_markets::CleanZetaMarkets,
+            clean_zeta_market_halted::CleanZetaMarketHalted,
+            settle_positions_halted::SettlePositionsHalted,
+            initialize_market_strikes::InitializeMarketStrikes,
+            expire_series_override::ExpireSeriesOverride,
+            expire_series::ExpireSeries,
+            initialize_market_pda::InitializeMarketPda,
+            initialize_zeta_specific_market_vaults::InitializeZetaSpecificMarketVaults,
+            initialize_zeta_market::InitializeZetaMarket,
+            initialize_market_tif_epoch_cycle::InitializeMarketTifEpochCycle,
+            update_pricing_v2::UpdatePricingV2,
+            update_pricing_v3::UpdatePricingV3,
+            apply_perp_funding::ApplyPerpFunding,
+            deposit::Deposit,
+            deposit_v2::DepositV2,
+            deposit_permissionless::DepositPermissionless,
+            deposit_insurance_vault::DepositInsuranceVault,
+            deposit_insurance_vault_v2::DepositInsuranceVaultV2,
+            choose_airdrop_community::ChooseAirdropCommunity,
+            withdraw::Withdraw,
+            withdraw_v2::WithdrawV2,
+            withdraw_insurance_vault::WithdrawInsuranceVault,
+            withdraw_insurance_vault_v2::WithdrawInsuranceVaultV2,
+            initialize_open_orders::InitializeOpenOrders,
+            initialize_open_orders_v2::InitializeOpenOrdersV2,
+            initialize_open_orders_v3::InitializeOpenOrdersV3,
+            close_open_orders::CloseOpenOrders,
+            close_open_orders_v2::CloseOpenOrdersV2,
+            close_open_orders_v3::CloseOpenOrdersV3,
+            close_open_orders_v4::CloseOpenOrdersV4,
+            admin_reset_dex_open_orders::AdminResetDexOpenOrders,
+            initialize_whitelist_deposit_account::InitializeWhitelistDepositAccount,
+            initialize_whitelist_insurance_account::InitializeWhitelistInsuranceAccount,
+            initialize_whitelist_trading_fees_account::InitializeWhitelistTradingFeesAccount,
+            initialize_insurance_deposit_account::InitializeInsuranceDepositAccount,
+            initialize_combined_insurance_vault::InitializeCombinedInsuranceVault,
+            initialize_combined_vault::InitializeCombinedVault,
+            initialize_combined_socialized_loss_account::InitializeCombinedSocializedLossAccount,
+            place_order::PlaceOrder,
+            place_order_v2::PlaceOrderV2,
+            place_order_v3::PlaceOrderV3,
+            place_perp_order::PlacePerpOrder,
+            place_perp_order_v2::PlacePerpOrderV2,
+            place_order_v4::PlaceOrderV4,
+            place_perp_order_v3::PlacePerpOrderV3,
+            place_perp_order_v4::PlacePerpOrderV4,
+            place_perp_order_v5::PlacePerpOrderV5,
+            place_multi_orders::PlaceMultiOrders,
+            place_trigger_order::PlaceTriggerOrder,
+            execute_trigger_order_v2::ExecuteTriggerOrderV2,
+            take_trigger_order::TakeTriggerOrder,
+            execute_trigger_order::ExecuteTriggerOrder,
+            force_cancel_trigger_order::ForceCancelTriggerOrder,
+            cancel_trigger_order_v2::CancelTriggerOrderV2,
+            cancel_trigger_order::CancelTriggerOrder,
+            update_min_lot::UpdateMinLot,
+            update_tick_size::UpdateTickSize,
+            initialize_min_lots_and_tick_sizes::InitializeMinLotsAndTickSizes,
+            edit_trigger_order::EditTriggerOrder,
+            edit_trigger_order_v2::EditTriggerOrderV2,
+            cancel_order::CancelOrder,
+            cancel_order_no_error::CancelOrderNoError,
+            cancel_all_market_orders::CancelAllMarketOrders,
+            cancel_order_halted::CancelOrderHalted,
+            cancel_order_by_client_order_id::CancelOrderByClientOrderId,
+            cancel_order_by_client_order_id_no_error::CancelOrderByClientOrderIdNoError,
+            prune_expired_tif_orders::PruneExpiredTifOrders,
+            prune_expired_tif_orders_v2::PruneExpiredTifOrdersV2,
+            force_cancel_order_by_order_id_v2::ForceCancelOrderByOrderIdV2,
+            force_cancel_order_by_order_id::ForceCancelOrderByOrderId,
+            admin_set_order_state::AdminSetOrderState,
+            admin_force_cancel_orders::AdminForceCancelOrders,
+            force_cancel_orders_v2::ForceCancelOrdersV2,
+            force_cancel_orders::ForceCancelOrders,
+            admin_crank_event_queue::AdminCrankEventQueue,
+            crank_event_queue::CrankEventQueue,
+            collect_treasury_funds::CollectTreasuryFunds,
+            treasury_movement::TreasuryMovement,
+            rebalance_insurance_vault::RebalanceInsuranceVault,
+            rebalance_insurance_vault_v2::RebalanceInsuranceVaultV2,
+            liquidate_v2::LiquidateV2,
+            liquidate::Liquidate,
+            burn_vault_tokens::BurnVaultTokens,
+            settle_dex_funds::SettleDexFunds,
+            position_movement::PositionMovement,
+            transfer_excess_spread_balance::TransferExcessSpreadBalance,
+            toggle_market_maker::ToggleMarketMaker,
+            initialize_referrer_accounts::InitializeReferrerAccounts,
+            close_referrer_accounts::CloseReferrerAccounts,
+            edit_ma_type::EditMaType,
+            edit_delegated_pubkey::EditDelegatedPubkey,
+            reset_num_flex_underlyings::ResetNumFlexUnderlyings,
+            trade_event::TradeEvent,
+            trade_event_v2_event::TradeEventV2Event,
+            trade_event_v3_event::TradeEventV3Event,
+            position_movement_event::PositionMovementEvent,
+            place_order_event::PlaceOrderEvent,
+            liquidation_event::LiquidationEvent,
+            order_complete_event::OrderCompleteEvent,
+            apply_funding_event::ApplyFundingEvent,
+            place_multi_orders_event::PlaceMultiOrdersEvent,
+        )
+    }
 }
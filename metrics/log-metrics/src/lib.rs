@@ -1,10 +1,188 @@
+//! A `Metrics` implementation that reports pipeline metrics on every flush.
+//!
+//! By default, a human-readable summary is written through the `log` crate.
+//! With [`LogFormat::Json`], every flush instead produces one JSON record with
+//! the counters, gauges and histogram percentiles, for log pipelines such as
+//! Loki or ELK. Either format can be written to stdout, a file or a rotating
+//! file with [`LogMetrics::with_destination`].
+//!
+//! # Example
+//!
+//! ```ignore
+//! let metrics = LogMetrics::new()
+//!     .with_format(LogFormat::Json)
+//!     .with_destination(LogDestination::rotating_file("metrics.ndjson", 10 * 1024 * 1024, 5));
+//! ```
+
 use {
     async_trait::async_trait,
-    carbon_core::{error::CarbonResult, metrics::Metrics},
-    std::{collections::HashMap, time::Instant},
+    carbon_core::{
+        error::{CarbonResult, Error},
+        metrics::{series_key, Metrics},
+    },
+    serde_json::{json, Map, Value},
+    std::{
+        collections::HashMap,
+        fs::{self, File, OpenOptions},
+        io::{self, Write},
+        path::{Path, PathBuf},
+        sync::Mutex,
+        time::{Instant, SystemTime, UNIX_EPOCH},
+    },
     tokio::sync::RwLock,
 };
 
+/// The format of the records written on every flush.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable lines, with averages for histograms.
+    #[default]
+    Text,
+    /// One JSON object per flush, with p50, p90 and p99 for histograms.
+    Json,
+}
+
+/// Where the records are written.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum LogDestination {
+    /// The `log` crate, at the `info` level.
+    #[default]
+    Log,
+    /// The process's standard output.
+    Stdout,
+    /// A file that is appended to, and created if it does not exist.
+    File(PathBuf),
+    /// A file that is rotated once it grows past `max_bytes`. The previous
+    /// files are kept as `<path>.1` (the most recent) to `<path>.<max_files>`.
+    RotatingFile {
+        path: PathBuf,
+        max_bytes: u64,
+        max_files: usize,
+    },
+}
+
+impl LogDestination {
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        LogDestination::File(path.into())
+    }
+
+    pub fn rotating_file(path: impl Into<PathBuf>, max_bytes: u64, max_files: usize) -> Self {
+        LogDestination::RotatingFile {
+            path: path.into(),
+            max_bytes,
+            max_files,
+        }
+    }
+
+    fn open(&self) -> CarbonResult<Option<Box<dyn Write + Send + Sync>>> {
+        match self {
+            LogDestination::Log => Ok(None),
+            LogDestination::Stdout => Ok(Some(Box::new(io::stdout()))),
+            LogDestination::File(path) => Ok(Some(Box::new(open_append(path)?))),
+            LogDestination::RotatingFile {
+                path,
+                max_bytes,
+                max_files,
+            } => Ok(Some(Box::new(RotatingFile::open(
+                path.clone(),
+                *max_bytes,
+                *max_files,
+            )?))),
+        }
+    }
+}
+
+fn open_append(path: &Path) -> CarbonResult<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|err| Error::Custom(format!("Failed to open {}: {err}", path.display())))
+}
+
+/// A file writer that rotates the file once it grows past `max_bytes`.
+///
+/// Rotation happens between writes, so a record is never split across two
+/// files. Each flush writes its records in a single `write_all` call.
+struct RotatingFile {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    file: File,
+    size: u64,
+}
+
+impl RotatingFile {
+    fn open(path: PathBuf, max_bytes: u64, max_files: usize) -> CarbonResult<Self> {
+        let file = open_append(&path)?;
+        let size = file.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+
+        Ok(Self {
+            path,
+            max_bytes,
+            max_files,
+            file,
+            size,
+        })
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{index}"));
+        PathBuf::from(path)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        if self.max_files == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            for index in (1..self.max_files).rev() {
+                let from = self.rotated_path(index);
+                if from.exists() {
+                    fs::rename(&from, self.rotated_path(index + 1))?;
+                }
+            }
+            fs::rename(&self.path, self.rotated_path(1))?;
+        }
+
+        self.file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        self.size = 0;
+
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+
+        self.file.write_all(buf)?;
+        self.size += buf.len() as u64;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
 pub struct LogMetrics {
     pub updates_received: RwLock<u64>,
     pub updates_processed: RwLock<u64>,
@@ -23,6 +201,10 @@ pub struct LogMetrics {
 
     pub start: RwLock<Instant>,
     pub last_flush: RwLock<Instant>,
+
+    format: LogFormat,
+    destination: LogDestination,
+    writer: Mutex<Option<Box<dyn Write + Send + Sync>>>,
 }
 
 impl Default for LogMetrics {
@@ -42,6 +224,9 @@ impl Default for LogMetrics {
             histograms: RwLock::new(HashMap::new()),
             start: RwLock::new(Instant::now()),
             last_flush: RwLock::new(Instant::now()),
+            format: LogFormat::default(),
+            destination: LogDestination::default(),
+            writer: Mutex::new(None),
         }
     }
 }
@@ -50,6 +235,73 @@ impl LogMetrics {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn with_format(mut self, format: LogFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn with_destination(mut self, destination: LogDestination) -> Self {
+        self.destination = destination;
+        self.writer = Mutex::new(None);
+        self
+    }
+
+    /// Writes the lines of one flush to the destination, opening it first if
+    /// needed.
+    fn emit(&self, lines: &[String]) -> CarbonResult<()> {
+        if self.destination == LogDestination::Log {
+            for line in lines {
+                log::info!("{line}");
+            }
+            return Ok(());
+        }
+
+        let mut writer = self
+            .writer
+            .lock()
+            .map_err(|_| Error::Custom("Log metrics writer poisoned".to_string()))?;
+        if writer.is_none() {
+            *writer = self.destination.open()?;
+        }
+
+        if let Some(writer) = writer.as_mut() {
+            let mut buffer = lines.join("\n");
+            buffer.push('\n');
+            writer
+                .write_all(buffer.as_bytes())
+                .and_then(|_| writer.flush())
+                .map_err(|err| Error::Custom(format!("Failed to write metrics: {err}")))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Summarizes histogram samples as count, min, max and percentiles.
+fn histogram_summary(values: &[f64]) -> Value {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    json!({
+        "count": sorted.len(),
+        "min": sorted.first().copied().unwrap_or(0.0),
+        "max": sorted.last().copied().unwrap_or(0.0),
+        "p50": percentile(&sorted, 0.50),
+        "p90": percentile(&sorted, 0.90),
+        "p99": percentile(&sorted, 0.99),
+    })
+}
+
+/// Returns the nearest-rank percentile of sorted samples, or `0.0` without
+/// samples.
+fn percentile(sorted: &[f64], percentile: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+
+    let rank = (percentile * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
 }
 
 #[async_trait]
@@ -61,6 +313,12 @@ impl Metrics for LogMetrics {
         let mut last_flush = self.last_flush.write().await;
         *last_flush = *start;
 
+        if let Ok(mut writer) = self.writer.lock() {
+            if writer.is_none() {
+                *writer = self.destination.open()?;
+            }
+        }
+
         Ok(())
     }
 
@@ -87,58 +345,122 @@ impl Metrics for LogMetrics {
         let start = self.start.read().await;
         let mut last_flush = self.last_flush.write().await;
 
-        log::info!(
-            "{:02}:{:02}:{:02} (+{:?}) | {} processed ({}%), {} successful, {} failed ({}%), {} in queue, avg: {}ms, min: {}ms, max: {}ms",
-            start.elapsed().as_secs() / 3600,
-            (start.elapsed().as_secs() % 3600) / 60,
-            start.elapsed().as_secs() % 60,
-            last_flush.elapsed(),
-            updates_processed,
-            if total_updates_received > 0 {(*updates_processed * 100) / total_updates_received} else {0},
-            updates_successful,
-            updates_failed,
-            if *updates_processed > 0 {(*updates_failed * 100) / *updates_processed} else {0},
-            updates_queued,
-            updates_processing_times_avg,
-            updates_processing_times_min,
-            updates_processing_times_max
-        );
-
-        for counter in self.counters.read().await.iter() {
-            log::info!("{}: {}", counter.0, counter.1);
-        }
-
-        for gauge in self.gauges.read().await.iter() {
-            log::info!("{}: {}", gauge.0, gauge.1);
-        }
-
-        for histogram in self.histograms.read().await.iter() {
-            let histogram_values = histogram.1;
-
-            let avg = if !histogram_values.is_empty() {
-                histogram_values.iter().sum::<f64>() / histogram_values.len() as f64
-            } else {
-                0.0
-            };
-            let min = histogram_values
-                .iter()
-                .min_by(|a, b| a.partial_cmp(b).unwrap())
-                .copied()
-                .unwrap_or(0.0);
-            let max = histogram_values
-                .iter()
-                .max_by(|a, b| a.partial_cmp(b).unwrap())
-                .copied()
-                .unwrap_or(0.0);
+        let lines = match self.format {
+            LogFormat::Text => {
+                let mut lines = vec![format!(
+                    "{:02}:{:02}:{:02} (+{:?}) | {} processed ({}%), {} successful, {} failed ({}%), {} in queue, avg: {}ms, min: {}ms, max: {}ms",
+                    start.elapsed().as_secs() / 3600,
+                    (start.elapsed().as_secs() % 3600) / 60,
+                    start.elapsed().as_secs() % 60,
+                    last_flush.elapsed(),
+                    updates_processed,
+                    (*updates_processed * 100).checked_div(total_updates_received).unwrap_or(0),
+                    updates_successful,
+                    updates_failed,
+                    (*updates_failed * 100).checked_div(*updates_processed).unwrap_or(0),
+                    updates_queued,
+                    updates_processing_times_avg,
+                    updates_processing_times_min,
+                    updates_processing_times_max
+                )];
+
+                for counter in self.counters.read().await.iter() {
+                    lines.push(format!("{}: {}", counter.0, counter.1));
+                }
+
+                for gauge in self.gauges.read().await.iter() {
+                    lines.push(format!("{}: {}", gauge.0, gauge.1));
+                }
+
+                for histogram in self.histograms.read().await.iter() {
+                    let histogram_values = histogram.1;
+
+                    let avg = if !histogram_values.is_empty() {
+                        histogram_values.iter().sum::<f64>() / histogram_values.len() as f64
+                    } else {
+                        0.0
+                    };
+                    let min = histogram_values
+                        .iter()
+                        .min_by(|a, b| a.total_cmp(b))
+                        .copied()
+                        .unwrap_or(0.0);
+                    let max = histogram_values
+                        .iter()
+                        .max_by(|a, b| a.total_cmp(b))
+                        .copied()
+                        .unwrap_or(0.0);
+
+                    lines.push(format!(
+                        "{} -> avg: {}, min: {}, max: {}",
+                        histogram.0, avg, min, max
+                    ));
+                }
+
+                lines
+            }
+            LogFormat::Json => {
+                let mut counters = Map::new();
+                counters.insert(
+                    "account_updates_processed".to_string(),
+                    Value::from(*self.account_updates_processed.read().await),
+                );
+                counters.insert(
+                    "transaction_updates_processed".to_string(),
+                    Value::from(*self.transaction_updates_processed.read().await),
+                );
+                counters.insert(
+                    "account_deletions_processed".to_string(),
+                    Value::from(*self.account_deletions_processed.read().await),
+                );
+                for (name, value) in self.counters.read().await.iter() {
+                    counters.insert(name.clone(), Value::from(*value));
+                }
+
+                let mut gauges = Map::new();
+                for (name, value) in self.gauges.read().await.iter() {
+                    gauges.insert(name.clone(), Value::from(*value));
+                }
+
+                let mut histograms = Map::new();
+                for (name, values) in self.histograms.read().await.iter() {
+                    histograms.insert(name.clone(), histogram_summary(values));
+                }
+                let processing_times = updates_processing_times
+                    .iter()
+                    .map(|time| *time as f64)
+                    .collect::<Vec<_>>();
+                histograms.insert(
+                    "updates_processing_times_milliseconds".to_string(),
+                    histogram_summary(&processing_times),
+                );
+
+                let timestamp_ms = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|duration| duration.as_millis() as u64)
+                    .unwrap_or(0);
+
+                let record = json!({
+                    "timestamp_ms": timestamp_ms,
+                    "uptime_seconds": start.elapsed().as_secs_f64(),
+                    "since_last_flush_seconds": last_flush.elapsed().as_secs_f64(),
+                    "updates": {
+                        "received": *updates_received,
+                        "processed": *updates_processed,
+                        "successful": *updates_successful,
+                        "failed": *updates_failed,
+                        "queued": *updates_queued,
+                    },
+                    "counters": counters,
+                    "gauges": gauges,
+                    "histograms": histograms,
+                });
+
+                vec![record.to_string()]
+            }
+        };
 
-            log::info!(
-                "{} -> avg: {}, min: {}, max: {}",
-                histogram.0,
-                avg,
-                min,
-                max
-            );
-        }
+        self.emit(&lines)?;
 
         self.histograms.write().await.clear();
 
@@ -153,7 +475,18 @@ impl Metrics for LogMetrics {
         Ok(())
     }
 
-    async fn increment_counter(&self, name: &str, value: u64) -> CarbonResult<()> {
+    async fn increment_counter(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: u64,
+    ) -> CarbonResult<()> {
+        if !labels.is_empty() {
+            let mut counters = self.counters.write().await;
+            *counters.entry(series_key(name, labels)).or_insert(0) += value;
+            return Ok(());
+        }
+
         match name {
             "updates_received" => {
                 let mut updates_received = self.updates_received.write().await;
@@ -195,7 +528,18 @@ impl Metrics for LogMetrics {
         Ok(())
     }
 
-    async fn update_gauge(&self, name: &str, value: f64) -> CarbonResult<()> {
+    async fn update_gauge(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        if !labels.is_empty() {
+            let mut gauges = self.gauges.write().await;
+            gauges.insert(series_key(name, labels), value);
+            return Ok(());
+        }
+
         match name {
             "updates_queued" => {
                 let mut updates_queued = self.updates_queued.write().await;
@@ -211,7 +555,21 @@ impl Metrics for LogMetrics {
         Ok(())
     }
 
-    async fn record_histogram(&self, name: &str, value: f64) -> CarbonResult<()> {
+    async fn record_histogram(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        if !labels.is_empty() {
+            let mut histograms = self.histograms.write().await;
+            histograms
+                .entry(series_key(name, labels))
+                .or_insert(Vec::new())
+                .push(value);
+            return Ok(());
+        }
+
         match name {
             "updates_processing_times" => {
                 let mut updates_processing_times = self.updates_processing_times.write().await;
@@ -227,3 +585,106 @@ impl Metrics for LogMetrics {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("carbon-log-metrics-{}-{name}", std::process::id()));
+        for index in 0..4 {
+            let mut rotated = path.clone().into_os_string();
+            rotated.push(format!(".{index}"));
+            let _ = fs::remove_file(rotated);
+        }
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_percentile() {
+        let values = (1..=100).map(|value| value as f64).collect::<Vec<_>>();
+
+        assert_eq!(percentile(&values, 0.50), 50.0);
+        assert_eq!(percentile(&values, 0.90), 90.0);
+        assert_eq!(percentile(&values, 0.99), 99.0);
+        assert_eq!(percentile(&[7.0], 0.99), 7.0);
+        assert_eq!(percentile(&[], 0.50), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_json_flush() {
+        let path = temp_path("json");
+        let metrics = LogMetrics::new()
+            .with_format(LogFormat::Json)
+            .with_destination(LogDestination::file(&path));
+        metrics.initialize().await.unwrap();
+
+        metrics
+            .increment_counter("updates_processed", &[], 3)
+            .await
+            .unwrap();
+        metrics
+            .increment_counter("pipe_decode_failures", &[("pipe", "account")], 2)
+            .await
+            .unwrap();
+        metrics.update_gauge("queue_depth", &[], 4.0).await.unwrap();
+        for value in [30.0, 10.0, 20.0] {
+            metrics
+                .record_histogram("process_time", &[], value)
+                .await
+                .unwrap();
+        }
+        metrics.flush().await.unwrap();
+        metrics.flush().await.unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        let records = contents
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(records.len(), 2);
+
+        let record = &records[0];
+        assert_eq!(record["updates"]["processed"], 3);
+        assert_eq!(
+            record["counters"]["pipe_decode_failures{pipe=\"account\"}"],
+            2
+        );
+        assert_eq!(record["gauges"]["queue_depth"], 4.0);
+        assert_eq!(record["histograms"]["process_time"]["count"], 3);
+        assert_eq!(record["histograms"]["process_time"]["p50"], 20.0);
+        assert_eq!(record["histograms"]["process_time"]["p99"], 30.0);
+        assert!(records[1]["histograms"].get("process_time").is_none());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_rotating_file() {
+        let path = temp_path("rotating");
+        let mut file = RotatingFile::open(path.clone(), 10, 2).unwrap();
+
+        for line in ["first 001\n", "second 02\n", "third 003\n", "fourth 04\n"] {
+            file.write_all(line.as_bytes()).unwrap();
+        }
+        file.flush().unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fourth 04\n");
+        assert_eq!(
+            fs::read_to_string(file.rotated_path(1)).unwrap(),
+            "third 003\n"
+        );
+        assert_eq!(
+            fs::read_to_string(file.rotated_path(2)).unwrap(),
+            "second 02\n"
+        );
+        assert!(!file.rotated_path(3).exists());
+
+        for index in 1..=2 {
+            fs::remove_file(file.rotated_path(index)).unwrap();
+        }
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,457 @@
+//! A `Metrics` implementation that exports pipeline metrics to an
+//! OpenTelemetry collector over OTLP/gRPC.
+//!
+//! Counters, gauges and histograms are recorded through the OpenTelemetry
+//! SDK, with labels as attributes. The SDK aggregates the values in memory;
+//! they are exported when the pipeline flushes its metrics and, in between,
+//! every `export_interval`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let metrics = OtlpMetrics::builder()
+//!     .endpoint("http://otel-collector:4317")
+//!     .service_name("raydium-indexer")
+//!     .resource_attribute("deployment.environment", "production")
+//!     .temporality(Temporality::Delta)
+//!     .build()?;
+//! ```
+//!
+//! To export through an existing `SdkMeterProvider`, for example one shared
+//! with other instrumentation or backed by a test exporter, use
+//! [`OtlpMetrics::with_meter_provider`].
+//!
+//! ## Notes
+//!
+//! - The SDK exports from a Tokio task, so the pipeline must run on a Tokio
+//!   runtime. Dropping the metrics without calling `shutdown` blocks until the
+//!   final export finishes, which deadlocks a current-thread runtime.
+
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        error::{CarbonResult, Error},
+        metrics::Metrics,
+    },
+    opentelemetry::{
+        metrics::{Counter, Gauge, Histogram, Meter, MeterProvider},
+        KeyValue,
+    },
+    opentelemetry_otlp::WithExportConfig,
+    opentelemetry_sdk::{
+        metrics::{PeriodicReader, SdkMeterProvider},
+        runtime, Resource,
+    },
+    std::{
+        collections::HashMap,
+        sync::{OnceLock, RwLock},
+        time::Duration,
+    },
+};
+
+pub use opentelemetry_sdk::metrics::Temporality;
+
+/// The default OTLP/gRPC collector endpoint.
+pub const DEFAULT_ENDPOINT: &str = "http://localhost:4317";
+
+/// The default interval between exports outside of pipeline flushes.
+pub const DEFAULT_EXPORT_INTERVAL: Duration = Duration::from_secs(60);
+
+const METER_NAME: &str = "carbon";
+
+struct Instruments {
+    provider: SdkMeterProvider,
+    meter: Meter,
+}
+
+pub struct OtlpMetrics {
+    pub counters: RwLock<HashMap<String, Counter<u64>>>,
+    pub gauges: RwLock<HashMap<String, Gauge<f64>>>,
+    pub histograms: RwLock<HashMap<String, Histogram<f64>>>,
+
+    config: OtlpMetricsBuilder,
+    instruments: OnceLock<Instruments>,
+}
+
+impl Default for OtlpMetrics {
+    fn default() -> Self {
+        OtlpMetrics::builder()
+            .build()
+            .expect("the default OTLP configuration is valid")
+    }
+}
+
+impl OtlpMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a builder for a customized `OtlpMetrics`.
+    pub fn builder() -> OtlpMetricsBuilder {
+        OtlpMetricsBuilder::default()
+    }
+
+    /// Records through `provider` instead of creating an OTLP exporter.
+    ///
+    /// `flush` and `shutdown` are forwarded to the provider. The builder's
+    /// `namespace` is not applied.
+    pub fn with_meter_provider(provider: SdkMeterProvider) -> Self {
+        let meter = provider.meter(METER_NAME);
+        let instruments = OnceLock::new();
+        let _ = instruments.set(Instruments { provider, meter });
+
+        Self {
+            counters: RwLock::new(HashMap::new()),
+            gauges: RwLock::new(HashMap::new()),
+            histograms: RwLock::new(HashMap::new()),
+            config: OtlpMetricsBuilder::default(),
+            instruments,
+        }
+    }
+
+    fn instruments(&self) -> CarbonResult<&Instruments> {
+        self.instruments
+            .get()
+            .ok_or_else(|| Error::Custom("OTLP metrics used before initialization".to_string()))
+    }
+
+    fn name(&self, name: &str) -> String {
+        match &self.config.namespace {
+            Some(namespace) => format!("{namespace}.{name}"),
+            None => name.to_string(),
+        }
+    }
+}
+
+fn attributes(labels: &[(&str, &str)]) -> Vec<KeyValue> {
+    labels
+        .iter()
+        .map(|(key, value)| KeyValue::new(key.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Builder for [`OtlpMetrics`].
+///
+/// # Fields
+///
+/// - `endpoint`: The collector's OTLP/gRPC endpoint. Defaults to
+///   `http://localhost:4317`.
+/// - `service_name`: The `service.name` resource attribute. Defaults to
+///   `carbon`.
+/// - `resource_attributes`: Additional resource attributes, attached to every
+///   exported metric.
+/// - `namespace`: An optional prefix joined to every metric name with `.`.
+/// - `temporality`: Whether sums and histograms are exported as cumulative
+///   totals or as deltas since the last export. Defaults to cumulative.
+/// - `export_interval`: The interval between exports outside of pipeline
+///   flushes. Defaults to 60 seconds.
+/// - `timeout`: The timeout of each export request.
+#[derive(Debug, Clone)]
+pub struct OtlpMetricsBuilder {
+    pub endpoint: String,
+    pub service_name: String,
+    pub resource_attributes: Vec<(String, String)>,
+    pub namespace: Option<String>,
+    pub temporality: Temporality,
+    pub export_interval: Duration,
+    pub timeout: Option<Duration>,
+}
+
+impl Default for OtlpMetricsBuilder {
+    fn default() -> Self {
+        Self {
+            endpoint: DEFAULT_ENDPOINT.to_string(),
+            service_name: METER_NAME.to_string(),
+            resource_attributes: Vec::new(),
+            namespace: None,
+            temporality: Temporality::default(),
+            export_interval: DEFAULT_EXPORT_INTERVAL,
+            timeout: None,
+        }
+    }
+}
+
+impl OtlpMetricsBuilder {
+    /// Exports to the collector at `endpoint`.
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+
+    /// Sets the `service.name` resource attribute.
+    pub fn service_name(mut self, service_name: impl Into<String>) -> Self {
+        self.service_name = service_name.into();
+        self
+    }
+
+    /// Adds a resource attribute, e.g. `deployment.environment="production"`.
+    pub fn resource_attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.resource_attributes.push((key.into(), value.into()));
+        self
+    }
+
+    /// Prefixes every metric name with `namespace`, e.g. `carbon` turns
+    /// `updates_processed` into `carbon.updates_processed`.
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Sets the aggregation temporality. Datadog expects
+    /// [`Temporality::Delta`].
+    pub fn temporality(mut self, temporality: Temporality) -> Self {
+        self.temporality = temporality;
+        self
+    }
+
+    /// Sets the interval between exports outside of pipeline flushes.
+    pub fn export_interval(mut self, export_interval: Duration) -> Self {
+        self.export_interval = export_interval;
+        self
+    }
+
+    /// Sets the timeout of each export request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Validates the configuration and creates the `OtlpMetrics`.
+    ///
+    /// The exporter itself is created by `Metrics::initialize`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the endpoint is empty or the export interval is
+    /// zero.
+    pub fn build(self) -> CarbonResult<OtlpMetrics> {
+        if self.endpoint.is_empty() {
+            return Err(Error::Custom("OTLP endpoint is empty".to_string()));
+        }
+
+        if self.export_interval.is_zero() {
+            return Err(Error::Custom(
+                "OTLP export interval must be greater than zero".to_string(),
+            ));
+        }
+
+        Ok(OtlpMetrics {
+            counters: RwLock::new(HashMap::new()),
+            gauges: RwLock::new(HashMap::new()),
+            histograms: RwLock::new(HashMap::new()),
+            config: self,
+            instruments: OnceLock::new(),
+        })
+    }
+
+    fn meter_provider(&self) -> CarbonResult<SdkMeterProvider> {
+        let mut exporter = opentelemetry_otlp::MetricExporter::builder()
+            .with_tonic()
+            .with_endpoint(&self.endpoint)
+            .with_temporality(self.temporality);
+
+        if let Some(timeout) = self.timeout {
+            exporter = exporter.with_timeout(timeout);
+        }
+
+        let exporter = exporter
+            .build()
+            .map_err(|e| Error::Custom(format!("Failed to create OTLP exporter: {}", e)))?;
+
+        let reader = PeriodicReader::builder(exporter, runtime::Tokio)
+            .with_interval(self.export_interval)
+            .build();
+
+        let resource = Resource::new(
+            std::iter::once(KeyValue::new("service.name", self.service_name.clone())).chain(
+                self.resource_attributes
+                    .iter()
+                    .map(|(key, value)| KeyValue::new(key.clone(), value.clone())),
+            ),
+        );
+
+        Ok(SdkMeterProvider::builder()
+            .with_reader(reader)
+            .with_resource(resource)
+            .build())
+    }
+}
+
+#[async_trait]
+impl Metrics for OtlpMetrics {
+    async fn initialize(&self) -> CarbonResult<()> {
+        if self.instruments.get().is_some() {
+            return Ok(());
+        }
+
+        let provider = self.config.meter_provider()?;
+        let meter = provider.meter(METER_NAME);
+        let _ = self.instruments.set(Instruments { provider, meter });
+
+        log::info!("OTLP metrics exporting to {}", self.config.endpoint);
+
+        Ok(())
+    }
+
+    async fn flush(&self) -> CarbonResult<()> {
+        let provider = self.instruments()?.provider.clone();
+
+        // The SDK blocks until the export task has run, so keep it off the
+        // async worker threads.
+        tokio::task::spawn_blocking(move || provider.force_flush())
+            .await
+            .map_err(|e| Error::Custom(format!("Failed to export OTLP metrics: {}", e)))?
+            .map_err(|e| Error::Custom(format!("Failed to export OTLP metrics: {}", e)))
+    }
+
+    async fn shutdown(&self) -> CarbonResult<()> {
+        let Some(instruments) = self.instruments.get() else {
+            return Ok(());
+        };
+        let provider = instruments.provider.clone();
+
+        tokio::task::spawn_blocking(move || provider.shutdown())
+            .await
+            .map_err(|e| Error::Custom(format!("Failed to shut down OTLP metrics: {}", e)))?
+            .map_err(|e| Error::Custom(format!("Failed to shut down OTLP metrics: {}", e)))
+    }
+
+    async fn update_gauge(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        let attributes = attributes(labels);
+
+        if let Some(gauge) = self.gauges.read().unwrap().get(name) {
+            gauge.record(value, &attributes);
+            return Ok(());
+        }
+
+        let gauge = self.instruments()?.meter.f64_gauge(self.name(name)).build();
+        gauge.record(value, &attributes);
+        self.gauges.write().unwrap().insert(name.to_string(), gauge);
+
+        Ok(())
+    }
+
+    async fn increment_counter(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: u64,
+    ) -> CarbonResult<()> {
+        let attributes = attributes(labels);
+
+        if let Some(counter) = self.counters.read().unwrap().get(name) {
+            counter.add(value, &attributes);
+            return Ok(());
+        }
+
+        let counter = self
+            .instruments()?
+            .meter
+            .u64_counter(self.name(name))
+            .build();
+        counter.add(value, &attributes);
+        self.counters
+            .write()
+            .unwrap()
+            .insert(name.to_string(), counter);
+
+        Ok(())
+    }
+
+    async fn record_histogram(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        let attributes = attributes(labels);
+
+        if let Some(histogram) = self.histograms.read().unwrap().get(name) {
+            histogram.record(value, &attributes);
+            return Ok(());
+        }
+
+        let histogram = self
+            .instruments()?
+            .meter
+            .f64_histogram(self.name(name))
+            .build();
+        histogram.record(value, &attributes);
+        self.histograms
+            .write()
+            .unwrap()
+            .insert(name.to_string(), histogram);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        opentelemetry_sdk::{
+            metrics::data::{Gauge as GaugeData, Sum},
+            testing::metrics::InMemoryMetricExporter,
+        },
+    };
+
+    #[tokio::test]
+    async fn test_flush_exports_recorded_series() {
+        let exporter = InMemoryMetricExporter::default();
+        let provider = SdkMeterProvider::builder()
+            .with_reader(PeriodicReader::builder(exporter.clone(), runtime::Tokio).build())
+            .build();
+        let metrics = OtlpMetrics::with_meter_provider(provider);
+        metrics.initialize().await.unwrap();
+
+        let labels = [("pipe", "instruction"), ("decoder", "PumpfunDecoder")];
+        metrics
+            .increment_counter("pipe_updates_processed", &labels, 2)
+            .await
+            .unwrap();
+        metrics
+            .increment_counter("pipe_updates_processed", &labels, 3)
+            .await
+            .unwrap();
+        metrics.update_gauge("slot_lag", &[], 7.0).await.unwrap();
+        metrics
+            .record_histogram("updates_process_time_nanoseconds", &[], 1500.0)
+            .await
+            .unwrap();
+        metrics.flush().await.unwrap();
+
+        let exported = exporter.get_finished_metrics().unwrap();
+        let metrics_by_name = exported
+            .iter()
+            .flat_map(|resource_metrics| &resource_metrics.scope_metrics)
+            .flat_map(|scope_metrics| &scope_metrics.metrics)
+            .map(|metric| (metric.name.to_string(), metric))
+            .collect::<HashMap<_, _>>();
+
+        let counter = metrics_by_name["pipe_updates_processed"]
+            .data
+            .as_any()
+            .downcast_ref::<Sum<u64>>()
+            .unwrap();
+        assert_eq!(counter.data_points.len(), 1);
+        assert_eq!(counter.data_points[0].value, 5);
+        assert_eq!(counter.data_points[0].attributes.len(), 2);
+
+        let gauge = metrics_by_name["slot_lag"]
+            .data
+            .as_any()
+            .downcast_ref::<GaugeData<f64>>()
+            .unwrap();
+        assert_eq!(gauge.data_points[0].value, 7.0);
+
+        assert!(metrics_by_name.contains_key("updates_process_time_nanoseconds"));
+
+        metrics.shutdown().await.unwrap();
+    }
+}
@@ -1,59 +1,307 @@
+//! A `Metrics` implementation that exports pipeline metrics to Prometheus.
+//!
+//! By default `PrometheusMetrics` serves a scrape endpoint on
+//! `127.0.0.1:9100`. Use [`PrometheusMetrics::builder`] to change the bind
+//! address, prefix every metric with a namespace, configure histogram buckets
+//! or attach global labels. Short-lived jobs that are never scraped, such as
+//! backfills, can push to a Prometheus push gateway instead.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let metrics = PrometheusMetrics::builder()
+//!     .bind_address(([0, 0, 0, 0], 9100))
+//!     .namespace("carbon")
+//!     .buckets(&[1e5, 1e6, 1e7, 1e8, 1e9])
+//!     .global_label("indexer", "raydium")
+//!     .build()?;
+//!
+//! let backfill_metrics = PrometheusMetrics::builder()
+//!     .push_gateway(
+//!         "http://localhost:9091/metrics/job/backfill",
+//!         Duration::from_secs(10),
+//!     )
+//!     .build()?;
+//! ```
+//!
+//! Each instance owns its own recorder, so several differently configured
+//! instances can run in the same process.
+
 use {
     async_trait::async_trait,
     carbon_core::{
         error::{CarbonResult, Error},
-        metrics::Metrics,
+        metrics::{series_key, Metrics},
+    },
+    metrics::{Key, Level, Metadata, Recorder},
+    metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusRecorder},
+    std::{
+        collections::HashMap,
+        net::SocketAddr,
+        sync::{Mutex, OnceLock},
+        time::Duration,
     },
-    metrics::{counter, gauge, histogram},
-    metrics_exporter_prometheus::PrometheusBuilder,
-    std::{collections::HashMap, net::SocketAddrV4, sync::Once},
-    tokio::sync::RwLock,
+    tokio::{sync::RwLock, task::JoinHandle},
 };
 
+static METADATA: Metadata<'static> =
+    Metadata::new(module_path!(), Level::INFO, Some(module_path!()));
+
+/// Where the collected metrics are exported to.
+#[derive(Debug, Clone)]
+pub enum PrometheusExporter {
+    /// Serves a scrape endpoint on the given address.
+    Listener(SocketAddr),
+    /// Pushes to a Prometheus push gateway on a fixed interval.
+    PushGateway {
+        endpoint: String,
+        interval: Duration,
+        username: Option<String>,
+        password: Option<String>,
+    },
+}
+
+impl Default for PrometheusExporter {
+    fn default() -> Self {
+        PrometheusExporter::Listener(SocketAddr::from(([127, 0, 0, 1], 9100)))
+    }
+}
+
 pub struct PrometheusMetrics {
     pub counters: RwLock<HashMap<String, metrics::Counter>>,
     pub gauges: RwLock<HashMap<String, metrics::Gauge>>,
     pub histograms: RwLock<HashMap<String, metrics::Histogram>>,
+
+    exporter: PrometheusExporter,
+    namespace: Option<String>,
+    builder: Mutex<Option<PrometheusBuilder>>,
+    recorder: OnceLock<PrometheusRecorder>,
+    exporter_task: Mutex<Option<JoinHandle<()>>>,
 }
 
 impl Default for PrometheusMetrics {
     fn default() -> Self {
-        Self {
-            counters: RwLock::new(HashMap::new()),
-            gauges: RwLock::new(HashMap::new()),
-            histograms: RwLock::new(HashMap::new()),
-        }
+        PrometheusMetrics::builder()
+            .build()
+            .expect("the default Prometheus configuration is valid")
     }
 }
+
 impl PrometheusMetrics {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Returns a builder for a customized `PrometheusMetrics`.
+    pub fn builder() -> PrometheusMetricsBuilder {
+        PrometheusMetricsBuilder::default()
+    }
+
+    fn key(&self, name: &str, labels: &[(&str, &str)]) -> Key {
+        let labels = labels
+            .iter()
+            .map(|(key, value)| metrics::Label::new(key.to_string(), value.to_string()))
+            .collect::<Vec<_>>();
+
+        Key::from_parts(prefixed_name(self.namespace.as_deref(), name), labels)
+    }
+
+    fn recorder(&self) -> CarbonResult<&PrometheusRecorder> {
+        self.recorder.get().ok_or_else(|| {
+            Error::Custom("Prometheus metrics used before initialization".to_string())
+        })
+    }
+}
+
+/// Builder for [`PrometheusMetrics`].
+///
+/// # Fields
+///
+/// - `exporter`: Where metrics are exported to. Defaults to a scrape endpoint
+///   on `127.0.0.1:9100`.
+/// - `namespace`: An optional prefix joined to every metric name with `_`.
+/// - `buckets`: Histogram buckets applied to all histograms. Without buckets,
+///   histograms are rendered as summaries.
+/// - `metric_buckets`: Histogram buckets for individual metrics, overriding
+///   `buckets`.
+/// - `global_labels`: Labels added to every series.
+#[derive(Debug, Default, Clone)]
+pub struct PrometheusMetricsBuilder {
+    pub exporter: PrometheusExporter,
+    pub namespace: Option<String>,
+    pub buckets: Option<Vec<f64>>,
+    pub metric_buckets: Vec<(String, Vec<f64>)>,
+    pub global_labels: Vec<(String, String)>,
+}
+
+impl PrometheusMetricsBuilder {
+    /// Serves the scrape endpoint on `address`.
+    pub fn bind_address(mut self, address: impl Into<SocketAddr>) -> Self {
+        self.exporter = PrometheusExporter::Listener(address.into());
+        self
+    }
+
+    /// Pushes metrics to the push gateway at `endpoint` every `interval`
+    /// instead of serving a scrape endpoint.
+    ///
+    /// The endpoint includes the grouping path, e.g.
+    /// `http://localhost:9091/metrics/job/backfill`.
+    pub fn push_gateway(mut self, endpoint: impl Into<String>, interval: Duration) -> Self {
+        self.exporter = PrometheusExporter::PushGateway {
+            endpoint: endpoint.into(),
+            interval,
+            username: None,
+            password: None,
+        };
+        self
+    }
+
+    /// Sets basic auth credentials for the push gateway. Has no effect unless
+    /// `push_gateway` was called first.
+    pub fn push_gateway_credentials(
+        mut self,
+        username: impl Into<String>,
+        password: Option<String>,
+    ) -> Self {
+        if let PrometheusExporter::PushGateway {
+            username: ref mut current_username,
+            password: ref mut current_password,
+            ..
+        } = self.exporter
+        {
+            *current_username = Some(username.into());
+            *current_password = password;
+        }
+        self
+    }
+
+    /// Prefixes every metric name with `namespace`, e.g. `carbon` turns
+    /// `updates_processed` into `carbon_updates_processed`.
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Sets the histogram buckets used for all histograms.
+    pub fn buckets(mut self, buckets: &[f64]) -> Self {
+        self.buckets = Some(buckets.to_vec());
+        self
+    }
+
+    /// Sets the histogram buckets for the histogram called `name`, before the
+    /// namespace is applied.
+    pub fn metric_buckets(mut self, name: impl Into<String>, buckets: &[f64]) -> Self {
+        self.metric_buckets.push((name.into(), buckets.to_vec()));
+        self
+    }
+
+    /// Adds a label to every series, e.g. `indexer="raydium"`.
+    pub fn global_label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.global_labels.push((key.into(), value.into()));
+        self
+    }
+
+    /// Validates the configuration and creates the `PrometheusMetrics`.
+    ///
+    /// The exporter itself is started by `Metrics::initialize`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the push gateway endpoint is not a valid URI or a
+    /// bucket list is empty.
+    pub fn build(self) -> CarbonResult<PrometheusMetrics> {
+        let namespace = self.namespace.as_deref();
+        let mut builder = match &self.exporter {
+            PrometheusExporter::Listener(address) => {
+                PrometheusBuilder::new().with_http_listener(*address)
+            }
+            PrometheusExporter::PushGateway {
+                endpoint,
+                interval,
+                username,
+                password,
+            } => PrometheusBuilder::new()
+                .with_push_gateway(endpoint, *interval, username.clone(), password.clone())
+                .map_err(|err| Error::Custom(format!("Invalid push gateway: {err}")))?,
+        };
+
+        if let Some(buckets) = &self.buckets {
+            builder = builder
+                .set_buckets(buckets)
+                .map_err(|err| Error::Custom(format!("Invalid histogram buckets: {err}")))?;
+        }
+
+        for (name, buckets) in &self.metric_buckets {
+            builder = builder
+                .set_buckets_for_metric(Matcher::Full(prefixed_name(namespace, name)), buckets)
+                .map_err(|err| {
+                    Error::Custom(format!("Invalid histogram buckets for {name}: {err}"))
+                })?;
+        }
+
+        for (key, value) in &self.global_labels {
+            builder = builder.add_global_label(key, value);
+        }
+
+        Ok(PrometheusMetrics {
+            counters: RwLock::new(HashMap::new()),
+            gauges: RwLock::new(HashMap::new()),
+            histograms: RwLock::new(HashMap::new()),
+            exporter: self.exporter,
+            namespace: self.namespace,
+            builder: Mutex::new(Some(builder)),
+            recorder: OnceLock::new(),
+            exporter_task: Mutex::new(None),
+        })
+    }
+}
+
+fn prefixed_name(namespace: Option<&str>, name: &str) -> String {
+    match namespace {
+        Some(namespace) => format!("{namespace}_{name}"),
+        None => name.to_string(),
+    }
 }
 
 #[async_trait]
 impl Metrics for PrometheusMetrics {
     async fn initialize(&self) -> CarbonResult<()> {
-        static INIT: Once = Once::new();
-
-        let mut result = Ok(());
-        INIT.call_once(|| {
-            let builder = PrometheusBuilder::new()
-                .with_http_listener("127.0.0.1:9100".parse::<SocketAddrV4>().unwrap());
-
-            match builder.install() {
-                Ok(_handle) => {
-                    log::info!("Prometheus exporter installed and listening on 127.0.0.1:9100");
-                }
-                Err(e) => {
-                    result = Err(Error::Custom(format!(
-                        "Failed to install Prometheus exporter: {}",
-                        e
-                    )));
-                }
+        let Some(builder) = self.builder.lock().unwrap().take() else {
+            return Ok(());
+        };
+
+        let (recorder, exporter) = builder.build().map_err(|e| {
+            Error::Custom(format!("Failed to install Prometheus exporter: {}", e))
+        })?;
+
+        let task = tokio::spawn(async move {
+            if let Err(e) = exporter.await {
+                log::error!("Prometheus exporter stopped: {:?}", e);
             }
         });
-        result
+
+        *self.exporter_task.lock().unwrap() = Some(task);
+        let _ = self.recorder.set(recorder);
+
+        match &self.exporter {
+            PrometheusExporter::Listener(address) => {
+                log::info!(
+                    "Prometheus exporter installed and listening on {}",
+                    address
+                );
+            }
+            PrometheusExporter::PushGateway {
+                endpoint, interval, ..
+            } => {
+                log::info!(
+                    "Prometheus exporter pushing to {} every {:?}",
+                    endpoint,
+                    interval
+                );
+            }
+        }
+
+        Ok(())
     }
 
     async fn flush(&self) -> CarbonResult<()> {
@@ -61,48 +309,220 @@ impl Metrics for PrometheusMetrics {
     }
 
     async fn shutdown(&self) -> CarbonResult<()> {
+        // The push gateway exporter pushes at the end of each interval, so wait
+        // for one more push to deliver the final values.
+        if let PrometheusExporter::PushGateway { interval, .. } = &self.exporter {
+            if self.recorder.get().is_some() {
+                tokio::time::sleep(*interval).await;
+            }
+        }
+
+        if let Some(task) = self.exporter_task.lock().unwrap().take() {
+            task.abort();
+        }
+
         Ok(())
     }
 
-    async fn update_gauge(&self, name: &str, value: f64) -> CarbonResult<()> {
+    async fn update_gauge(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        let key = series_key(name, labels);
         let mut gauge = self.gauges.write().await;
 
-        if let Some(gauge) = gauge.get(name) {
+        if let Some(gauge) = gauge.get(&key) {
             gauge.set(value);
         } else {
-            let new_gauge = gauge!(name.to_string());
+            let new_gauge = self
+                .recorder()?
+                .register_gauge(&self.key(name, labels), &METADATA);
             new_gauge.set(value);
-            gauge.insert(name.to_string(), new_gauge);
+            gauge.insert(key, new_gauge);
         }
 
         Ok(())
     }
 
-    async fn increment_counter(&self, name: &str, value: u64) -> CarbonResult<()> {
+    async fn increment_counter(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: u64,
+    ) -> CarbonResult<()> {
+        let key = series_key(name, labels);
         let mut counter = self.counters.write().await;
 
-        if let Some(counter) = counter.get(name) {
+        if let Some(counter) = counter.get(&key) {
             counter.increment(value);
         } else {
-            let new_counter = counter!(name.to_string());
+            let new_counter = self
+                .recorder()?
+                .register_counter(&self.key(name, labels), &METADATA);
             new_counter.increment(value);
-            counter.insert(name.to_string(), new_counter);
+            counter.insert(key, new_counter);
         }
 
         Ok(())
     }
 
-    async fn record_histogram(&self, name: &str, value: f64) -> CarbonResult<()> {
+    async fn record_histogram(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        let key = series_key(name, labels);
         let mut histogram = self.histograms.write().await;
 
-        if let Some(histogram) = histogram.get(name) {
+        if let Some(histogram) = histogram.get(&key) {
             histogram.record(value);
         } else {
-            let new_histogram = histogram!(name.to_string());
+            let new_histogram = self
+                .recorder()?
+                .register_histogram(&self.key(name, labels), &METADATA);
             new_histogram.record(value);
-            histogram.insert(name.to_string(), new_histogram);
+            histogram.insert(key, new_histogram);
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds `builder` and installs its recorder without starting the HTTP
+    /// listener, so the exposition can be rendered directly.
+    fn recorder_only(builder: PrometheusMetricsBuilder) -> PrometheusMetrics {
+        let metrics = builder.build().unwrap();
+        let recorder = metrics
+            .builder
+            .lock()
+            .unwrap()
+            .take()
+            .unwrap()
+            .build_recorder();
+        let _ = metrics.recorder.set(recorder);
+        metrics
+    }
+
+    fn render(metrics: &PrometheusMetrics) -> String {
+        metrics.recorder().unwrap().handle().render()
+    }
+
+    #[tokio::test]
+    async fn test_namespace() {
+        // Arrange
+        let metrics = recorder_only(PrometheusMetrics::builder().namespace("carbon"));
+
+        // Act
+        metrics
+            .increment_counter("updates_processed", &[], 3)
+            .await
+            .unwrap();
+        metrics
+            .update_gauge("updates_queued", &[], 7.0)
+            .await
+            .unwrap();
+        let rendered = render(&metrics);
+
+        // Assert
+        assert!(rendered.contains("carbon_updates_processed 3\n"));
+        assert!(rendered.contains("carbon_updates_queued 7\n"));
+        assert!(!rendered.contains("\nupdates_processed"));
+    }
+
+    #[tokio::test]
+    async fn test_global_label() {
+        // Arrange
+        let metrics = recorder_only(
+            PrometheusMetrics::builder()
+                .global_label("indexer", "raydium")
+                .global_label("region", "eu"),
+        );
+
+        // Act
+        metrics
+            .increment_counter("pipe_updates_processed", &[("pipe", "swaps")], 2)
+            .await
+            .unwrap();
+        let rendered = render(&metrics);
+
+        // Assert
+        let series = rendered
+            .lines()
+            .find(|line| line.starts_with("pipe_updates_processed{"))
+            .unwrap();
+        assert!(series.contains("indexer=\"raydium\""));
+        assert!(series.contains("region=\"eu\""));
+        assert!(series.contains("pipe=\"swaps\""));
+        assert!(series.ends_with(" 2"));
+    }
+
+    #[tokio::test]
+    async fn test_metric_buckets() {
+        // Arrange
+        let metrics = recorder_only(
+            PrometheusMetrics::builder()
+                .namespace("carbon")
+                .buckets(&[10.0, 100.0])
+                .metric_buckets("pipe_process_time_nanoseconds", &[1e3, 1e6]),
+        );
+
+        // Act
+        for value in [500.0, 5e5, 5e7] {
+            metrics
+                .record_histogram("pipe_process_time_nanoseconds", &[], value)
+                .await
+                .unwrap();
+        }
+        metrics
+            .record_histogram("updates_process_time_milliseconds", &[], 50.0)
+            .await
+            .unwrap();
+        let rendered = render(&metrics);
+
+        // Assert
+        assert!(rendered.contains("carbon_pipe_process_time_nanoseconds_bucket{le=\"1000\"} 1\n"));
+        assert!(
+            rendered.contains("carbon_pipe_process_time_nanoseconds_bucket{le=\"1000000\"} 2\n")
+        );
+        assert!(rendered.contains("carbon_pipe_process_time_nanoseconds_bucket{le=\"+Inf\"} 3\n"));
+        assert!(rendered.contains("carbon_pipe_process_time_nanoseconds_count 3\n"));
+        assert!(!rendered.contains("carbon_pipe_process_time_nanoseconds_bucket{le=\"10\"}"));
+        assert!(rendered.contains("carbon_updates_process_time_milliseconds_bucket{le=\"10\"} 0\n"));
+        assert!(
+            rendered.contains("carbon_updates_process_time_milliseconds_bucket{le=\"100\"} 1\n")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_summaries_without_buckets() {
+        // Arrange
+        let metrics = recorder_only(PrometheusMetrics::builder());
+
+        // Act
+        metrics
+            .record_histogram("updates_process_time_nanoseconds", &[], 1e6)
+            .await
+            .unwrap();
+        let rendered = render(&metrics);
+
+        // Assert
+        assert!(rendered.contains("# TYPE updates_process_time_nanoseconds summary\n"));
+        assert!(!rendered.contains("_bucket"));
+    }
+
+    #[test]
+    fn test_invalid_buckets() {
+        assert!(PrometheusMetrics::builder().buckets(&[]).build().is_err());
+        assert!(PrometheusMetrics::builder()
+            .metric_buckets("pipe_process_time_nanoseconds", &[])
+            .build()
+            .is_err());
+    }
+}
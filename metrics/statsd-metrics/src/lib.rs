@@ -0,0 +1,485 @@
+//! A `Metrics` implementation that sends pipeline metrics to a StatsD or
+//! DogStatsD agent over UDP.
+//!
+//! Values are aggregated in memory and sent when the pipeline flushes its
+//! metrics: counters are summed, gauges keep their latest value and histogram
+//! samples are sent individually. The lines are packed into datagrams of at
+//! most `max_packet_size` bytes.
+//!
+//! Labels are sent as DogStatsD tags (`|#key:value`). Plain StatsD has no
+//! tags, so with [`StatsdFlavor::Statsd`] label values are appended to the
+//! metric name instead, e.g. `pipe_updates_failed.instruction.PumpfunDecoder`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let metrics = StatsdMetrics::builder()
+//!     .address("127.0.0.1:8125")
+//!     .prefix("carbon")
+//!     .global_tag("env", "production")
+//!     .build()?;
+//! ```
+
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        error::{CarbonResult, Error},
+        metrics::Metrics,
+    },
+    std::{
+        collections::HashMap,
+        sync::{Mutex, OnceLock},
+    },
+    tokio::net::UdpSocket,
+};
+
+/// The default StatsD agent address.
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:8125";
+
+/// The default maximum datagram size, which fits a standard Ethernet MTU.
+pub const DEFAULT_MAX_PACKET_SIZE: usize = 1432;
+
+/// The StatsD dialect to emit.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StatsdFlavor {
+    /// Plain StatsD. Labels are appended to the metric name and histograms are
+    /// sent as timers (`|ms`).
+    Statsd,
+    /// DogStatsD, as accepted by the Datadog agent. Labels are sent as tags and
+    /// histograms as `|h`.
+    #[default]
+    DogStatsd,
+}
+
+/// A metric name and its rendered DogStatsD tags, if any.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Series {
+    name: String,
+    tags: String,
+}
+
+impl Series {
+    fn line(&self, value: impl std::fmt::Display, metric_type: &str) -> String {
+        format!("{}:{}|{}{}", self.name, value, metric_type, self.tags)
+    }
+}
+
+#[derive(Default)]
+struct Buffer {
+    counters: HashMap<Series, u64>,
+    gauges: HashMap<Series, f64>,
+    histograms: Vec<(Series, f64)>,
+}
+
+pub struct StatsdMetrics {
+    config: StatsdMetricsBuilder,
+    socket: OnceLock<UdpSocket>,
+    buffer: Mutex<Buffer>,
+}
+
+impl Default for StatsdMetrics {
+    fn default() -> Self {
+        StatsdMetrics::builder()
+            .build()
+            .expect("the default StatsD configuration is valid")
+    }
+}
+
+impl StatsdMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a builder for a customized `StatsdMetrics`.
+    pub fn builder() -> StatsdMetricsBuilder {
+        StatsdMetricsBuilder::default()
+    }
+
+    fn series(&self, name: &str, labels: &[(&str, &str)]) -> Series {
+        let mut series = Series {
+            name: String::new(),
+            tags: String::new(),
+        };
+
+        if let Some(prefix) = &self.config.prefix {
+            series.name.push_str(&sanitize(prefix));
+            series.name.push('.');
+        }
+        series.name.push_str(&sanitize(name));
+
+        match self.config.flavor {
+            StatsdFlavor::Statsd => {
+                for (_, value) in labels {
+                    series.name.push('.');
+                    series.name.push_str(&sanitize(value));
+                }
+            }
+            StatsdFlavor::DogStatsd => {
+                let tags = self
+                    .config
+                    .global_tags
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value.as_str()))
+                    .chain(labels.iter().copied())
+                    .map(|(key, value)| format!("{}:{}", sanitize(key), sanitize(value)))
+                    .collect::<Vec<_>>();
+
+                if !tags.is_empty() {
+                    series.tags = format!("|#{}", tags.join(","));
+                }
+            }
+        }
+
+        series
+    }
+
+    /// Renders the buffered values as StatsD lines.
+    fn render(&self, buffer: Buffer) -> Vec<String> {
+        let histogram_type = match self.config.flavor {
+            StatsdFlavor::Statsd => "ms",
+            StatsdFlavor::DogStatsd => "h",
+        };
+
+        let mut lines = Vec::with_capacity(
+            buffer.counters.len() + buffer.gauges.len() + buffer.histograms.len(),
+        );
+
+        for (series, value) in buffer.counters {
+            lines.push(series.line(value, "c"));
+        }
+
+        for (series, value) in buffer.gauges {
+            if !value.is_finite() {
+                continue;
+            }
+            // A leading sign makes StatsD apply the value as a delta, so reset
+            // the gauge before setting it to a negative value.
+            if value < 0.0 {
+                lines.push(series.line(0, "g"));
+            }
+            lines.push(series.line(value, "g"));
+        }
+
+        for (series, value) in buffer.histograms {
+            if value.is_finite() {
+                lines.push(series.line(value, histogram_type));
+            }
+        }
+
+        lines
+    }
+}
+
+/// Builder for [`StatsdMetrics`].
+///
+/// # Fields
+///
+/// - `address`: The agent's address. Defaults to `127.0.0.1:8125`.
+/// - `prefix`: An optional prefix joined to every metric name with `.`.
+/// - `flavor`: The StatsD dialect. Defaults to DogStatsD.
+/// - `global_tags`: Tags added to every DogStatsD line.
+/// - `max_packet_size`: The maximum datagram size in bytes. Defaults to 1432.
+#[derive(Debug, Clone)]
+pub struct StatsdMetricsBuilder {
+    pub address: String,
+    pub prefix: Option<String>,
+    pub flavor: StatsdFlavor,
+    pub global_tags: Vec<(String, String)>,
+    pub max_packet_size: usize,
+}
+
+impl Default for StatsdMetricsBuilder {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_ADDRESS.to_string(),
+            prefix: None,
+            flavor: StatsdFlavor::default(),
+            global_tags: Vec::new(),
+            max_packet_size: DEFAULT_MAX_PACKET_SIZE,
+        }
+    }
+}
+
+impl StatsdMetricsBuilder {
+    /// Sends metrics to the agent at `address`, e.g. `datadog-agent:8125`.
+    pub fn address(mut self, address: impl Into<String>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Prefixes every metric name with `prefix`, e.g. `carbon` turns
+    /// `updates_processed` into `carbon.updates_processed`.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Sets the StatsD dialect.
+    pub fn flavor(mut self, flavor: StatsdFlavor) -> Self {
+        self.flavor = flavor;
+        self
+    }
+
+    /// Adds a tag to every line, e.g. `env:production`. Ignored by plain
+    /// StatsD.
+    pub fn global_tag(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.global_tags.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sets the maximum datagram size. Raise it only if the network path to
+    /// the agent supports larger packets.
+    pub fn max_packet_size(mut self, max_packet_size: usize) -> Self {
+        self.max_packet_size = max_packet_size;
+        self
+    }
+
+    /// Validates the configuration and creates the `StatsdMetrics`.
+    ///
+    /// The socket is opened by `Metrics::initialize`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the address is empty or the maximum packet size is
+    /// zero.
+    pub fn build(self) -> CarbonResult<StatsdMetrics> {
+        if self.address.is_empty() {
+            return Err(Error::Custom("StatsD address is empty".to_string()));
+        }
+
+        if self.max_packet_size == 0 {
+            return Err(Error::Custom(
+                "StatsD maximum packet size must be greater than zero".to_string(),
+            ));
+        }
+
+        Ok(StatsdMetrics {
+            config: self,
+            socket: OnceLock::new(),
+            buffer: Mutex::new(Buffer::default()),
+        })
+    }
+}
+
+/// Replaces the characters that delimit StatsD fields.
+fn sanitize(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            ':' | '|' | '@' | '#' | ',' | '\n' | '\r' => '_',
+            c => c,
+        })
+        .collect()
+}
+
+/// Joins lines with `\n` into payloads of at most `max_packet_size` bytes. A
+/// line longer than the limit is sent on its own.
+fn pack(lines: Vec<String>, max_packet_size: usize) -> Vec<String> {
+    let mut packets = Vec::new();
+    let mut packet = String::new();
+
+    for line in lines {
+        if !packet.is_empty() && packet.len() + 1 + line.len() > max_packet_size {
+            packets.push(std::mem::take(&mut packet));
+        }
+        if !packet.is_empty() {
+            packet.push('\n');
+        }
+        packet.push_str(&line);
+    }
+
+    if !packet.is_empty() {
+        packets.push(packet);
+    }
+
+    packets
+}
+
+#[async_trait]
+impl Metrics for StatsdMetrics {
+    async fn initialize(&self) -> CarbonResult<()> {
+        if self.socket.get().is_some() {
+            return Ok(());
+        }
+
+        let socket = UdpSocket::bind("0.0.0.0:0")
+            .await
+            .map_err(|e| Error::Custom(format!("Failed to bind StatsD socket: {}", e)))?;
+        socket.connect(&self.config.address).await.map_err(|e| {
+            Error::Custom(format!(
+                "Failed to connect to StatsD agent at {}: {}",
+                self.config.address, e
+            ))
+        })?;
+
+        let _ = self.socket.set(socket);
+        log::info!("StatsD metrics sending to {}", self.config.address);
+
+        Ok(())
+    }
+
+    async fn flush(&self) -> CarbonResult<()> {
+        let socket = self.socket.get().ok_or_else(|| {
+            Error::Custom("StatsD metrics used before initialization".to_string())
+        })?;
+
+        let buffer = std::mem::take(&mut *self.buffer.lock().unwrap());
+        let lines = self.render(buffer);
+
+        for packet in pack(lines, self.config.max_packet_size) {
+            // StatsD is fire-and-forget; a missing agent must not stop the
+            // pipeline.
+            if let Err(e) = socket.send(packet.as_bytes()).await {
+                log::warn!("Failed to send StatsD metrics: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn shutdown(&self) -> CarbonResult<()> {
+        if self.socket.get().is_some() {
+            self.flush().await?;
+        }
+
+        Ok(())
+    }
+
+    async fn update_gauge(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        let series = self.series(name, labels);
+        self.buffer.lock().unwrap().gauges.insert(series, value);
+
+        Ok(())
+    }
+
+    async fn increment_counter(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: u64,
+    ) -> CarbonResult<()> {
+        let series = self.series(name, labels);
+        *self
+            .buffer
+            .lock()
+            .unwrap()
+            .counters
+            .entry(series)
+            .or_default() += value;
+
+        Ok(())
+    }
+
+    async fn record_histogram(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        let series = self.series(name, labels);
+        self.buffer.lock().unwrap().histograms.push((series, value));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn receive_lines(socket: &UdpSocket) -> Vec<String> {
+        let mut buf = vec![0u8; 65_536];
+        let len = tokio::time::timeout(std::time::Duration::from_secs(5), socket.recv(&mut buf))
+            .await
+            .expect("timed out waiting for StatsD packet")
+            .expect("failed to receive StatsD packet");
+
+        let mut lines = String::from_utf8_lossy(&buf[..len])
+            .lines()
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        lines.sort();
+        lines
+    }
+
+    #[tokio::test]
+    async fn test_flush_sends_aggregated_dogstatsd_lines() {
+        let agent = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let metrics = StatsdMetrics::builder()
+            .address(agent.local_addr().unwrap().to_string())
+            .prefix("carbon")
+            .global_tag("env", "test")
+            .build()
+            .unwrap();
+        metrics.initialize().await.unwrap();
+
+        let labels = [("pipe", "instruction"), ("decoder", "PumpfunDecoder")];
+        metrics
+            .increment_counter("pipe_updates_processed", &labels, 2)
+            .await
+            .unwrap();
+        metrics
+            .increment_counter("pipe_updates_processed", &labels, 3)
+            .await
+            .unwrap();
+        metrics.update_gauge("slot_lag", &[], 1.0).await.unwrap();
+        metrics.update_gauge("slot_lag", &[], -4.0).await.unwrap();
+        metrics
+            .record_histogram("updates_process_time_nanoseconds", &[], 1500.0)
+            .await
+            .unwrap();
+        metrics.flush().await.unwrap();
+
+        assert_eq!(
+            receive_lines(&agent).await,
+            vec![
+                "carbon.pipe_updates_processed:5|c|#env:test,pipe:instruction,decoder:PumpfunDecoder",
+                "carbon.slot_lag:-4|g|#env:test",
+                "carbon.slot_lag:0|g|#env:test",
+                "carbon.updates_process_time_nanoseconds:1500|h|#env:test",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_plain_statsd_folds_labels_into_name() {
+        let agent = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let metrics = StatsdMetrics::builder()
+            .address(agent.local_addr().unwrap().to_string())
+            .flavor(StatsdFlavor::Statsd)
+            .build()
+            .unwrap();
+        metrics.initialize().await.unwrap();
+
+        metrics
+            .increment_counter("datasource_errors", &[("datasource", "Rpc:Crawler")], 1)
+            .await
+            .unwrap();
+        metrics
+            .record_histogram("pipe_process_time_nanoseconds", &[("pipe", "account")], 2.5)
+            .await
+            .unwrap();
+        metrics.flush().await.unwrap();
+
+        assert_eq!(
+            receive_lines(&agent).await,
+            vec![
+                "datasource_errors.Rpc_Crawler:1|c",
+                "pipe_process_time_nanoseconds.account:2.5|ms",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pack_respects_max_packet_size() {
+        let lines = vec!["a:1|c".to_string(); 5];
+        let packets = pack(lines, 11);
+
+        assert_eq!(packets, vec!["a:1|c\na:1|c", "a:1|c\na:1|c", "a:1|c"]);
+    }
+}